@@ -0,0 +1,388 @@
+use std::{cmp, fs, path::PathBuf};
+
+use rosu_v2::model::GameMode;
+use skia_safe::{
+    ClipOp, Data, Font, ISize, Image, Paint, RRect, Rect, TextBlobBuilder, font_style::Slant,
+    utils::text_utils::Align,
+};
+
+use crate::{
+    builder::{card::CardBuilder, font::FontBuilder, paint::PaintBuilder},
+    card::CardInner,
+    error::{BannerError, FontError},
+    font::FontData,
+    skills::{CardTitle, Skills},
+    svg::Svg,
+};
+
+pub(crate) const BANNER_H: i32 = 360;
+pub(crate) const BANNER_W: i32 = 980;
+
+const BANNER_PANEL_MARGIN: i32 = 24;
+const BANNER_PAD: i32 = 48;
+const BANNER_AVATAR_W: i32 = BANNER_H - 2 * BANNER_PAD;
+const BANNER_CONTENT_X: i32 = BANNER_PAD + BANNER_AVATAR_W + 32;
+const BANNER_CONTENT_W: i32 = BANNER_W - BANNER_CONTENT_X - BANNER_PAD;
+const BANNER_MODE_W: i32 = 64;
+const BANNER_TITLE_Y: i32 = 82;
+const BANNER_NAME_Y: i32 = 140;
+const BANNER_FLAG_H: i32 = 40;
+const BANNER_FLAG_MARGIN_LEFT: i32 = 16;
+const BANNER_SKILLS_Y: i32 = 176;
+const BANNER_STATS_Y: i32 = 294;
+const BANNER_STATS_MARGIN: i32 = 32;
+const BANNER_DATE_Y: i32 = BANNER_H - BANNER_PANEL_MARGIN - 10;
+
+impl CardBuilder<'_> {
+    pub(crate) fn draw_banner(
+        &mut self,
+        card: &CardInner<'_>,
+        skills: &Skills,
+        title: &CardTitle,
+        font_data: &FontData,
+    ) -> Result<&mut Self, BannerError> {
+        draw_panel(self);
+        draw_pfp(self, card.pfp)?;
+        draw_mode_icon(self, skills.mode(), card.assets.clone())?;
+        let name_w = draw_title(self, title, card.username, font_data)?;
+        draw_flag(self, card.flag, name_w)?;
+        draw_skills(self, skills, card.previous.as_ref(), font_data)?;
+        let stats_w = draw_ranks(self, card.rank_global, card.rank_country, font_data)?;
+        draw_level(self, card.level, stats_w, font_data)?;
+        draw_date(self, card.date, font_data)?;
+
+        Ok(self)
+    }
+}
+
+fn draw_panel(card: &mut CardBuilder<'_>) {
+    let rect = Rect::new(
+        BANNER_PANEL_MARGIN as f32,
+        BANNER_PANEL_MARGIN as f32,
+        (BANNER_W - BANNER_PANEL_MARGIN) as f32,
+        (BANNER_H - BANNER_PANEL_MARGIN) as f32,
+    );
+
+    let paint = PaintBuilder::color(card.palette.panel).alpha(102).build();
+    card.canvas.draw_round_rect(rect, 16.0, 16.0, &paint);
+}
+
+fn draw_pfp(card: &mut CardBuilder<'_>, pfp: &[u8]) -> Result<(), BannerError> {
+    // SAFETY: `pfp` has a longer lifetime than `Data`
+    let data = unsafe { Data::new_bytes(pfp) };
+
+    let img = Image::from_encoded_with_alpha_type(data, None).ok_or(BannerError::Avatar)?;
+
+    let ISize {
+        width: img_w,
+        height: img_h,
+    } = img.dimensions();
+
+    let max = cmp::max(img_w, img_h);
+    let scale = BANNER_AVATAR_W as f32 / max as f32;
+
+    let offset_x = BANNER_AVATAR_W as f32 - scale * img_w as f32;
+    let offset_y = BANNER_AVATAR_W as f32 - scale * img_h as f32;
+
+    let pos_x = scale.recip() * (BANNER_PAD as f32 + offset_x / 2.0);
+    let pos_y = scale.recip() * (BANNER_PAD as f32 + offset_y / 2.0);
+
+    let rect = Rect::new(
+        BANNER_PAD as f32,
+        BANNER_PAD as f32,
+        (BANNER_PAD + BANNER_AVATAR_W) as f32,
+        (BANNER_PAD + BANNER_AVATAR_W) as f32,
+    );
+    let rrect = RRect::new_rect_xy(rect, 16.0, 16.0);
+
+    card.canvas.save();
+
+    card.canvas
+        .clip_rrect(rrect, Some(ClipOp::Intersect), Some(true))
+        .scale((scale, scale))
+        .draw_image(&img, (pos_x, pos_y), None)
+        .restore();
+
+    Ok(())
+}
+
+fn draw_mode_icon(
+    card: &mut CardBuilder<'_>,
+    mode: GameMode,
+    mut assets: PathBuf,
+) -> Result<(), BannerError> {
+    let filename = match mode {
+        GameMode::Osu => "Standard.svg",
+        GameMode::Taiko => "Taiko.svg",
+        GameMode::Catch => "Catch.svg",
+        GameMode::Mania => "Mania.svg",
+    };
+
+    assets.push("gamemodes");
+    assets.push(filename);
+    let bytes = fs::read(assets).map_err(BannerError::ModeFile)?;
+    let svg = Svg::parse(&bytes).map_err(BannerError::ModeSvg)?;
+
+    let paint = PaintBuilder::color(card.palette.mode)
+        .alpha(204)
+        .anti_alias()
+        .build();
+
+    let scale = BANNER_MODE_W as f32 / cmp::max(svg.view_box_w, svg.view_box_h) as f32;
+    let translate_x = (BANNER_W - BANNER_PAD - BANNER_MODE_W) as f32;
+    let translate_y = BANNER_PAD as f32;
+
+    card.canvas
+        .translate((translate_x, translate_y))
+        .scale((scale, scale))
+        .draw_path(&svg.path, &paint)
+        .scale((scale.recip(), scale.recip()))
+        .translate((-translate_x, -translate_y));
+
+    Ok(())
+}
+
+/// Draws the title and the username and returns the username's width.
+fn draw_title(
+    card: &mut CardBuilder<'_>,
+    title: &CardTitle,
+    name: &str,
+    font_data: &FontData,
+) -> Result<f32, BannerError> {
+    let title_text = title.to_string();
+
+    let paint = PaintBuilder::color(card.palette.text).alpha(204).build();
+    let max_w = (BANNER_CONTENT_W - BANNER_MODE_W - 16) as f32;
+    let font = fit_font(
+        600,
+        Slant::Italic,
+        font_data,
+        28.0,
+        &title_text,
+        max_w,
+        &paint,
+    )?;
+
+    card.canvas.draw_str(
+        &title_text,
+        (BANNER_CONTENT_X as f32, BANNER_TITLE_Y as f32),
+        &font,
+        &paint,
+    );
+
+    let paint = PaintBuilder::color(card.palette.text).build();
+    let max_w = (BANNER_CONTENT_W - BANNER_MODE_W - 2 * BANNER_FLAG_MARGIN_LEFT) as f32;
+    let font = fit_font(800, Slant::Upright, font_data, 54.0, name, max_w, &paint)?;
+
+    card.canvas.draw_str(
+        name,
+        (BANNER_CONTENT_X as f32, BANNER_NAME_Y as f32),
+        &font,
+        &paint,
+    );
+
+    let (name_w, _) = font.measure_str(name, Some(&paint));
+
+    Ok(name_w)
+}
+
+fn draw_flag(card: &mut CardBuilder<'_>, flag: &[u8], name_w: f32) -> Result<(), BannerError> {
+    // SAFETY: `flag` has a longer lifetime than `Data`
+    let data = unsafe { Data::new_bytes(flag) };
+    let img = Image::from_encoded_with_alpha_type(data, None).ok_or(BannerError::Flag)?;
+
+    let scale = BANNER_FLAG_H as f32 / img.height() as f32;
+    let pos_x = (BANNER_CONTENT_X + BANNER_FLAG_MARGIN_LEFT) as f32 + name_w;
+    let pos_y = (BANNER_NAME_Y - BANNER_FLAG_H + 2) as f32;
+
+    card.canvas
+        .translate((pos_x, pos_y))
+        .scale((scale, scale))
+        .draw_image(&img, (0, 0), None)
+        .scale((scale.recip(), scale.recip()))
+        .translate((-pos_x, -pos_y));
+
+    Ok(())
+}
+
+fn draw_skills(
+    card: &mut CardBuilder<'_>,
+    skills: &Skills,
+    previous: Option<&Skills>,
+    font_data: &FontData,
+) -> Result<(), BannerError> {
+    let drawables = skills.named_values();
+    let deltas = previous
+        .and_then(|previous| skills.deltas(previous))
+        .unwrap_or_default();
+    let column_w = BANNER_CONTENT_W / drawables.len() as i32;
+
+    let paint = PaintBuilder::color(card.palette.text).build();
+    let highlight_paint = PaintBuilder::color(card.palette.highlight).build();
+
+    let name_font = FontBuilder::build(300, Slant::Italic, font_data, 22.0)?;
+    let trunc_font = FontBuilder::build(900, Slant::Upright, font_data, 44.0)?;
+    let fract_font = FontBuilder::build(400, Slant::Upright, font_data, 44.0)?;
+
+    for ((name, value), i) in drawables.into_iter().zip(0..) {
+        let pos_x = BANNER_CONTENT_X + i * column_w;
+
+        // Rectangle
+        let rect = Rect::new(0.0, 0.0, 4.0, 28.0);
+
+        card.canvas
+            .translate((pos_x, BANNER_SKILLS_Y))
+            .draw_rect(rect, &highlight_paint)
+            .translate((-pos_x, -BANNER_SKILLS_Y));
+
+        // Name
+        let name_pos = ((pos_x + 14) as f32, (BANNER_SKILLS_Y + 22) as f32);
+        card.canvas.draw_str(name, name_pos, &name_font, &paint);
+
+        // Delta
+        if let Some(&delta) = deltas.get(i as usize) {
+            let (name_w, _) = name_font.measure_str(name, Some(&paint));
+            let delta_x = name_pos.0 + name_w + 10.0;
+            card.draw_skill_delta(delta, (delta_x, name_pos.1), 18.0, font_data)?;
+        }
+
+        // Value
+        let mut builder = TextBlobBuilder::new();
+        let trunc = format!("{}.", value.trunc() as i32);
+        let fract = format!("{:0>2}", (value.fract() * 100.0) as i32);
+
+        let trunc_glyphs = builder.alloc_run(&trunc_font, trunc.len(), (0.0, 0.0), None);
+        trunc_font.str_to_glyphs(&trunc, trunc_glyphs);
+        let (trunc_w, _) = trunc_font.measure_str(&trunc, Some(&paint));
+
+        let fract_glyphs = builder.alloc_run(&fract_font, fract.len(), (trunc_w, 0.0), None);
+        fract_font.str_to_glyphs(&fract, fract_glyphs);
+        let blob = builder.make().ok_or(BannerError::SkillTextBlob)?;
+
+        let value_pos = (pos_x as f32, (BANNER_SKILLS_Y + 72) as f32);
+        card.canvas.draw_text_blob(blob, value_pos, &paint);
+    }
+
+    Ok(())
+}
+
+/// Draws the global and country rank and returns their total width.
+fn draw_ranks(
+    card: &mut CardBuilder<'_>,
+    global_rank: u32,
+    country_rank: u32,
+    font_data: &FontData,
+) -> Result<f32, BannerError> {
+    let label_font = FontBuilder::build(300, Slant::Italic, font_data, 22.0)?;
+    let label_paint = PaintBuilder::color(card.palette.text).alpha(168).build();
+    let value_font = FontBuilder::build(700, Slant::Upright, font_data, 28.0)?;
+    let value_paint = PaintBuilder::color(card.palette.text).build();
+
+    let mut pos_x = BANNER_CONTENT_X as f32;
+    let pos_y = BANNER_STATS_Y as f32;
+
+    for (label, rank) in [("Global", global_rank), ("Country", country_rank)] {
+        card.canvas
+            .draw_str(label, (pos_x, pos_y), &label_font, &label_paint);
+        let (label_w, _) = label_font.measure_str(label, Some(&label_paint));
+        pos_x += label_w + 8.0;
+
+        let rank = format!("#{rank}");
+        card.canvas
+            .draw_str(&rank, (pos_x, pos_y), &value_font, &value_paint);
+        let (rank_w, _) = value_font.measure_str(&rank, Some(&value_paint));
+        pos_x += rank_w + BANNER_STATS_MARGIN as f32;
+    }
+
+    Ok(pos_x - BANNER_CONTENT_X as f32)
+}
+
+fn draw_level(
+    card: &mut CardBuilder<'_>,
+    level: f32,
+    offset_x: f32,
+    font_data: &FontData,
+) -> Result<(), BannerError> {
+    let level_value = card.int_buf.format(level.trunc() as u32);
+    let percent = level.fract();
+
+    let font = FontBuilder::build(300, Slant::Italic, font_data, 22.0)?;
+    let paint = PaintBuilder::color(card.palette.text).alpha(168).build();
+    let mut pos_x = BANNER_CONTENT_X as f32 + offset_x;
+    let pos_y = BANNER_STATS_Y as f32;
+
+    let level_text = "Level";
+    card.canvas
+        .draw_str(level_text, (pos_x, pos_y), &font, &paint);
+    let (level_w, _) = font.measure_str(level_text, Some(&paint));
+    pos_x += level_w + 8.0;
+
+    let font = FontBuilder::build(800, Slant::Italic, font_data, 28.0)?;
+    let paint = PaintBuilder::color(card.palette.text).build();
+    card.canvas
+        .draw_str(level_value, (pos_x, pos_y), &font, &paint);
+    let (value_w, _) = font.measure_str(level_value, Some(&paint));
+    pos_x += value_w + 14.0;
+
+    // Bar
+    let rect_w = (BANNER_W - BANNER_PAD) as f32 - pos_x;
+
+    if rect_w <= 0.0 {
+        return Ok(());
+    }
+
+    let rect = Rect::new(pos_x, pos_y - 10.0, pos_x + rect_w, pos_y - 7.0);
+    let paint = PaintBuilder::color(card.palette.text).alpha(51).build();
+    card.canvas.draw_round_rect(rect, 3.0, 3.0, &paint);
+
+    let rect = Rect::new(pos_x, pos_y - 13.0, pos_x + rect_w * percent, pos_y - 4.0);
+    let paint = PaintBuilder::color(card.palette.highlight).build();
+    card.canvas.draw_round_rect(rect, 9.0, 9.0, &paint);
+
+    Ok(())
+}
+
+fn draw_date(
+    card: &mut CardBuilder<'_>,
+    date: &str,
+    font_data: &FontData,
+) -> Result<(), BannerError> {
+    let font = FontBuilder::build(200, Slant::Italic, font_data, 18.0)?;
+    let paint = PaintBuilder::color(card.palette.text).alpha(168).build();
+    let pos_x = BANNER_W - BANNER_PAD;
+
+    card.canvas.draw_str_align(
+        date,
+        (pos_x as f32, BANNER_DATE_Y as f32),
+        &font,
+        &paint,
+        Align::Right,
+    );
+
+    Ok(())
+}
+
+/// Builds the largest font, starting at `size`, that fits `text` within
+/// `max_w`.
+fn fit_font(
+    weight: i32,
+    slant: Slant,
+    font_data: &FontData,
+    mut size: f32,
+    text: &str,
+    max_w: f32,
+    paint: &Paint,
+) -> Result<Font, FontError> {
+    const MIN_SIZE: f32 = 16.0;
+
+    loop {
+        let font = FontBuilder::build(weight, slant, font_data, size)?;
+        let (text_w, _) = font.measure_str(text, Some(paint));
+
+        if text_w <= max_w || size <= MIN_SIZE {
+            return Ok(font);
+        }
+
+        size -= 2.0;
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
     error::FooterError,
     font::FontData,
     svg::Svg,
+    theme::argb,
 };
 
 pub(crate) const FOOTER_H: i32 = 184;
@@ -47,11 +48,11 @@ fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), FooterError> {
 
     let start = Gradient {
         pos: ((W / 2) as f32, 0.0),
-        argb: (153, 0, 0, 0),
+        argb: argb(153, card.palette.panel),
     };
     let end = Gradient {
         pos: ((W / 2) as f32, FOOTER_H as f32),
-        argb: (76, 0, 0, 0),
+        argb: argb(76, card.palette.panel),
     };
 
     let paint = PaintBuilder::gradient(start, end)?.build();
@@ -89,7 +90,7 @@ fn draw_name(card: &mut CardBuilder<'_>, mut assets: PathBuf) -> Result<(), Foot
     assets.push("branding/text.svg");
     let bytes = fs::read(assets).map_err(FooterError::BrandingFile)?;
     let svg = Svg::parse(&bytes).map_err(FooterError::BrandingSvg)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let paint = PaintBuilder::color(card.palette.text).anti_alias().build();
     let translate_x = FOOTER_H + FOOTER_TEXT_MARGIN;
     let translate_y = H - FOOTER_H + (FOOTER_H - FOOTER_TEXT_H) / 2 + 1;
     let scale_x = FOOTER_TEXT_H as f32 / svg.view_box_h as f32;
@@ -111,7 +112,7 @@ fn draw_date(
     font_data: &FontData,
 ) -> Result<(), FooterError> {
     let font = FontBuilder::build(200, Slant::Italic, font_data, 45.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = PaintBuilder::color(card.palette.text).build();
     let pos_x = W - FOOTER_DATE_MARGIN_RIGHT;
     let pos_y = H - FOOTER_H + 63 + 45;
 
@@ -15,6 +15,7 @@ use crate::{
     font::FontData,
     skills::CardTitle,
     svg::Svg,
+    theme::argb,
 };
 
 pub(crate) const HEADER_H: i32 = 250;
@@ -36,7 +37,7 @@ impl CardBuilder<'_> {
         draw_background(self)?;
         let title = draw_title(self, title, data.username, font_data)?;
         draw_flag(self, data.flag, title)?;
-        draw_mode_background(self, mode)?;
+        draw_mode_background(self)?;
         draw_mode_icon(self, mode, data.assets.clone())?;
 
         Ok(self)
@@ -55,11 +56,11 @@ fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), HeaderError> {
 
     let start = Gradient {
         pos: ((W / 2) as f32, 0.0),
-        argb: (171, 0, 0, 0),
+        argb: argb(171, card.palette.panel),
     };
     let end = Gradient {
         pos: ((W / 2) as f32, HEADER_H as f32),
-        argb: (204, 0, 0, 0),
+        argb: argb(204, card.palette.panel),
     };
 
     let paint = PaintBuilder::gradient(start, end)?.build();
@@ -79,7 +80,7 @@ fn draw_title(
     let title_w = (W - HEADER_MODE_W - 2 * HEADER_PAD_LEFT) as f32;
 
     let font = FontBuilder::build(600, Slant::Italic, font_data, 50.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(204).build();
+    let paint = PaintBuilder::color(card.palette.text).alpha(204).build();
 
     let space_iter = title_text
         .bytes()
@@ -138,7 +139,7 @@ fn draw_title(
     let blob = builder.make().ok_or(HeaderError::TitleTextBlob)?;
 
     let name_font = FontBuilder::build(800, Slant::Upright, font_data, 70.0)?;
-    let name_paint = PaintBuilder::rgb(255, 255, 255).build();
+    let name_paint = PaintBuilder::color(card.palette.text).build();
 
     let title_h = row_y + name_font.size() + (name_font.spacing() - name_font.size())
         - HEADER_NAME_MARGIN_TOP as f32;
@@ -173,7 +174,7 @@ fn draw_flag(card: &mut CardBuilder<'_>, flag: &[u8], title: Title) -> Result<()
     Ok(())
 }
 
-fn draw_mode_background(card: &mut CardBuilder<'_>, mode: GameMode) -> Result<(), HeaderError> {
+fn draw_mode_background(card: &mut CardBuilder<'_>) -> Result<(), HeaderError> {
     let rect_w = HEADER_MODE_W as f32;
     let rect = Rect::new(0.0, 0.0, rect_w, HEADER_MODE_H as f32);
 
@@ -202,14 +203,7 @@ fn draw_mode_background(card: &mut CardBuilder<'_>, mode: GameMode) -> Result<()
         .draw_rrect(rrect, &paint)
         .translate((-translate_x, -0));
 
-    let (r, g, b) = match mode {
-        GameMode::Osu => (255, 102, 170),
-        GameMode::Taiko => (94, 203, 162),
-        GameMode::Catch => (102, 204, 255),
-        GameMode::Mania => (197, 102, 255),
-    };
-
-    let paint = PaintBuilder::rgb(r, g, b).alpha(64).build();
+    let paint = PaintBuilder::color(card.palette.mode).alpha(64).build();
     let translate_x = W - HEADER_MODE_W;
     card.canvas
         .translate((translate_x, 0))
@@ -236,7 +230,7 @@ fn draw_mode_icon(
     let bytes = fs::read(assets).map_err(HeaderError::ModeFile)?;
     let svg = Svg::parse(&bytes).map_err(HeaderError::ModeSvg)?;
 
-    let mode_paint = PaintBuilder::rgb(255, 255, 255)
+    let mode_paint = PaintBuilder::color(card.palette.text)
         .alpha(204)
         .anti_alias()
         .build();
@@ -15,6 +15,7 @@ use crate::{
     error::InfoError,
     font::FontData,
     skills::Skills,
+    theme::argb,
 };
 
 const INFO_PAD: i32 = 53;
@@ -43,7 +44,7 @@ impl CardBuilder<'_> {
         draw_pfp(self, card.pfp)?;
         draw_global_rank(self, card.rank_global, font_data)?;
         draw_country_rank(self, card.rank_country, font_data)?;
-        draw_skills(self, skills, font_data)?;
+        draw_skills(self, skills, card.previous.as_ref(), font_data)?;
         draw_level(self, card.level, font_data)?;
         draw_medals(self, card.medals, card.total_medals, font_data)?;
 
@@ -53,7 +54,7 @@ impl CardBuilder<'_> {
 
 fn draw_upper_left_background(card: &mut CardBuilder<'_>) -> Result<(), InfoError> {
     let rect = Rect::new(0.0, 0.0, INFO_UPPER_LEFT_W as f32, INFO_UPPER_H as f32);
-    let paint = PaintBuilder::rgb(0, 0, 0).alpha(51).build();
+    let paint = PaintBuilder::color(card.palette.panel).alpha(51).build();
     let translate_x = INFO_PAD;
     let translate_y = HEADER_H + INFO_PAD;
 
@@ -70,11 +71,11 @@ fn draw_upper_right_background(card: &mut CardBuilder<'_>) -> Result<(), InfoErr
 
     let start = Gradient {
         pos: ((INFO_UPPER_RIGHT_W / 2) as f32, 0.0),
-        argb: (102, 0, 0, 0),
+        argb: argb(102, card.palette.panel),
     };
     let end = Gradient {
         pos: ((INFO_UPPER_RIGHT_W / 2) as f32, INFO_UPPER_H as f32),
-        argb: (45, 0, 0, 0),
+        argb: argb(45, card.palette.panel),
     };
     let paint = PaintBuilder::gradient(start, end)?.build();
     let translate_x = INFO_PAD + INFO_UPPER_LEFT_W + INFO_UPPER_MARGIN;
@@ -92,11 +93,11 @@ fn draw_bottom_background(card: &mut CardBuilder<'_>) -> Result<(), InfoError> {
     let rect = Rect::new(0.0, 0.0, INFO_LOWER_W as f32, INFO_LOWER_H as f32);
     let start = Gradient {
         pos: ((W / 2) as f32, 0.0),
-        argb: (45, 0, 0, 0),
+        argb: argb(45, card.palette.panel),
     };
     let end = Gradient {
         pos: ((W / 2) as f32, INFO_LOWER_H as f32),
-        argb: (102, 0, 0, 0),
+        argb: argb(102, card.palette.panel),
     };
     let paint = PaintBuilder::gradient(start, end)?.build();
     let translate_x = INFO_PAD;
@@ -155,7 +156,7 @@ fn draw_global_rank(
     font_data: &FontData,
 ) -> Result<(), InfoError> {
     let rank = format!("#{rank}");
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = PaintBuilder::color(card.palette.text).build();
     let font = FontBuilder::build(400, Slant::Italic, font_data, 32.0)?;
 
     let pos_x = INFO_PAD + INFO_GLOBAL_RANK_PAD;
@@ -179,7 +180,7 @@ fn draw_country_rank(
     font_data: &FontData,
 ) -> Result<(), InfoError> {
     let rank = format!("#{rank}");
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = PaintBuilder::color(card.palette.text).build();
     let font = FontBuilder::build(300, Slant::Italic, font_data, 27.0)?;
 
     let pos_x = INFO_PAD + INFO_UPPER_LEFT_W - INFO_GLOBAL_RANK_PAD;
@@ -212,46 +213,13 @@ fn draw_country_rank(
 fn draw_skills(
     card: &mut CardBuilder<'_>,
     skills: &Skills,
+    previous: Option<&Skills>,
     font_data: &FontData,
 ) -> Result<(), InfoError> {
-    struct DrawableSkill {
-        name: &'static str,
-        value: f64,
-    }
-
-    impl DrawableSkill {
-        fn new(name: &'static str, value: f64) -> Self {
-            Self { name, value }
-        }
-    }
-
-    let drawables = match skills {
-        Skills::Osu { acc, aim, speed } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let aim = DrawableSkill::new("AIM", *aim);
-            let speed = DrawableSkill::new("SPEED", *speed);
-
-            vec![acc, aim, speed]
-        }
-        Skills::Taiko { acc, strain } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let strain = DrawableSkill::new("STRAIN", *strain);
-
-            vec![acc, strain]
-        }
-        Skills::Catch { acc, movement } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let movement = DrawableSkill::new("MOVEMENT", *movement);
-
-            vec![acc, movement]
-        }
-        Skills::Mania { acc, strain } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let strain = DrawableSkill::new("STRAIN", *strain);
-
-            vec![acc, strain]
-        }
-    };
+    let drawables = skills.named_values();
+    let deltas = previous
+        .and_then(|previous| skills.deltas(previous))
+        .unwrap_or_default();
 
     // `init_y`: y-pos of skill's rect
     // `margin`: pixels inbetween two rects' y-pos
@@ -261,7 +229,8 @@ fn draw_skills(
         _ => unreachable!(),
     };
 
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = PaintBuilder::color(card.palette.text).build();
+    let highlight_paint = PaintBuilder::color(card.palette.highlight).build();
 
     let rect_x = INFO_PAD + INFO_UPPER_LEFT_W + INFO_UPPER_MARGIN + 30;
 
@@ -273,16 +242,14 @@ fn draw_skills(
 
     let fract_font = FontBuilder::build(400, Slant::Upright, font_data, 67.0)?;
 
-    for (skill, i) in drawables.into_iter().zip(0..) {
-        let DrawableSkill { name, value } = skill;
-
+    for ((name, value), i) in drawables.into_iter().zip(0..) {
         // Rectangle
         let rect = Rect::new(0.0, 0.0, 4.0, 43.0);
         let rect_y = init_y + i * margin;
 
         card.canvas
             .translate((rect_x, rect_y))
-            .draw_rect(rect, &paint)
+            .draw_rect(rect, &highlight_paint)
             .translate((-rect_x, -rect_y));
 
         // Name
@@ -305,6 +272,13 @@ fn draw_skills(
         let blob = builder.make().ok_or(InfoError::SkillTextBlob)?;
         card.canvas
             .draw_text_blob(blob, (trunc_x as f32, trunc_y as f32), &paint);
+
+        // Delta
+        if let Some(&delta) = deltas.get(i as usize) {
+            let (fract_w, _) = fract_font.measure_str(&fract, Some(&paint));
+            let delta_x = trunc_x as f32 + trunc_w + fract_w + 16.0;
+            card.draw_skill_delta(delta, (delta_x, trunc_y as f32), 30.0, font_data)?;
+        }
     }
 
     Ok(())
@@ -320,7 +294,7 @@ fn draw_level(
     let percent = level.fract();
 
     let font = FontBuilder::build(300, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build();
+    let paint = PaintBuilder::color(card.palette.text).alpha(168).build();
 
     let pos_x = INFO_PAD + 32;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 46;
@@ -330,7 +304,7 @@ fn draw_level(
     let (level_w, _) = font.measure_str(level_text, Some(&paint));
 
     let font = FontBuilder::build(800, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = PaintBuilder::color(card.palette.text).build();
     let pos_x = (INFO_PAD + 40) as f32 + level_w;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 46;
     card.canvas
@@ -340,7 +314,7 @@ fn draw_level(
     // Bar
     let rect_w = (W - 2 * INFO_PAD - 86) as f32 - level_w - value_w;
     let rect = Rect::new(0.0, 0.0, rect_w, 3.0);
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(51).build();
+    let paint = PaintBuilder::color(card.palette.text).alpha(51).build();
     let translate_x = (INFO_PAD + 54) as f32 + level_w + value_w;
     let translate_y =
         HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 33;
@@ -351,7 +325,7 @@ fn draw_level(
         .translate((-translate_x, -translate_y as f32));
 
     let rect = Rect::new(0.0, 0.0, rect_w * percent, 9.0);
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = PaintBuilder::color(card.palette.highlight).build();
     let translate_x = (INFO_PAD + 54) as f32 + level_w + value_w;
     let translate_y =
         HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 30;
@@ -418,7 +392,7 @@ fn draw_medals(
 
     // Text left
     let font = FontBuilder::build(300, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build();
+    let paint = PaintBuilder::color(card.palette.text).alpha(168).build();
     let pos_x = INFO_PAD + 32;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 87;
     let medal_text = "Medals";
@@ -427,7 +401,7 @@ fn draw_medals(
     let (medal_w, _) = font.measure_str(medal_text, Some(&paint));
 
     let font = FontBuilder::build(800, Slant::Italic, font_data, 35.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build(); // simulating brightness
+    let paint = PaintBuilder::color(card.palette.text).alpha(168).build(); // simulating brightness
     let pos_x = (INFO_PAD + 40) as f32 + medal_w;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 87;
     let medal_percent_str = format!("{}%", (percent * 100.0) as u32);
@@ -445,7 +419,7 @@ fn draw_medals(
 
     // Text right
     let font = FontBuilder::build(400, Slant::Upright, font_data, 30.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).alpha(168).build();
+    let paint = PaintBuilder::color(card.palette.text).alpha(168).build();
     let pos_x = W - (INFO_PAD + 32);
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 86;
     let total_medals_str = format!("/{total_medals}");
@@ -460,7 +434,7 @@ fn draw_medals(
     let (total_medals_w, _) = font.measure_str(total_medals_str, Some(&paint));
 
     let font = FontBuilder::build(500, Slant::Upright, font_data, 30.0)?;
-    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let paint = PaintBuilder::color(card.palette.text).build();
     let pos_x = (W - (INFO_PAD + 32)) as f32 - total_medals_w;
     let pos_y = HEADER_H + INFO_PAD + INFO_UPPER_H + INFO_LOWER_MARGIN + INFO_LOWER_MARGIN + 86;
     let medals_str = card.int_buf.format(curr_medals);
@@ -5,40 +5,92 @@ mod info;
 use std::{fs, path::PathBuf};
 
 use itoa::Buffer;
-use skia_safe::{Canvas, Data, Image};
+use skia_safe::{Canvas, Data, Image, Rect};
 
-use crate::{error::BackgroundError, skills::CardTitle};
+use crate::{
+    builder::paint::{Gradient, PaintBuilder},
+    error::BackgroundError,
+    skills::CardTitle,
+    theme::{Palette, Texture, argb},
+};
 
 pub(crate) const H: i32 = 1260;
 pub(crate) const W: i32 = 980;
 
+const STRIPE_GAP: i32 = 48;
+const STRIPE_W: f32 = 16.0;
+
 pub(crate) struct CardBuilder<'c> {
-    canvas: &'c Canvas,
-    int_buf: Buffer,
+    pub(crate) canvas: &'c Canvas,
+    pub(crate) int_buf: Buffer,
+    pub(crate) palette: Palette,
 }
 
 impl<'a> CardBuilder<'a> {
-    pub(crate) fn new(canvas: &'a Canvas) -> Self {
+    pub(crate) fn new(canvas: &'a Canvas, palette: Palette) -> Self {
         Self {
             canvas,
             int_buf: Buffer::new(),
+            palette,
         }
     }
 
     pub(crate) fn draw_background(
         &mut self,
+        texture: &Texture,
         title: &CardTitle,
         mut assets: PathBuf,
     ) -> Result<&mut Self, BackgroundError> {
-        assets.push("backgrounds");
-        assets.push(title.prefix.filename());
-        let bytes = fs::read(assets).map_err(BackgroundError::File)?;
+        let size = self.canvas.base_layer_size();
+        let (w, h) = (size.width, size.height);
+
+        match texture {
+            Texture::Title => {
+                assets.push("backgrounds");
+                assets.push(title.prefix.filename());
+                let bytes = fs::read(assets).map_err(BackgroundError::File)?;
+
+                // SAFETY: `bytes` and `Data` share the same lifetime
+                let data = unsafe { Data::new_bytes(&bytes) };
 
-        // SAFETY: `bytes` and `Data` share the same lifetime
-        let data = unsafe { Data::new_bytes(&bytes) };
+                let img = Image::from_encoded_with_alpha_type(data, None)
+                    .ok_or(BackgroundError::Image)?;
 
-        let img = Image::from_encoded_with_alpha_type(data, None).ok_or(BackgroundError::Image)?;
-        self.canvas.draw_image(&img, (0, 0), None);
+                // Center the image in case the card's dimensions differ
+                let pos_x = (w - img.width()) / 2;
+                let pos_y = (h - img.height()) / 2;
+                self.canvas.draw_image(&img, (pos_x, pos_y), None);
+            }
+            Texture::Gradient { start, end } => {
+                let start = Gradient {
+                    pos: (0.0, 0.0),
+                    argb: argb(255, *start),
+                };
+                let end = Gradient {
+                    pos: (w as f32, h as f32),
+                    argb: argb(255, *end),
+                };
+                let paint = PaintBuilder::gradient(start, end)?.build();
+                let rect = Rect::new(0.0, 0.0, w as f32, h as f32);
+                self.canvas.draw_rect(rect, &paint);
+            }
+            Texture::Stripes { base, stripe } => {
+                let rect = Rect::new(0.0, 0.0, w as f32, h as f32);
+                let paint = PaintBuilder::color(*base).build();
+                self.canvas.draw_rect(rect, &paint);
+
+                let paint = PaintBuilder::color(*stripe)
+                    .stroke_width(STRIPE_W)
+                    .anti_alias()
+                    .build();
+
+                for x in (-h..w).step_by(STRIPE_GAP as usize) {
+                    let start = (x as f32, 0.0);
+                    let end = ((x + h) as f32, h as f32);
+                    self.canvas.draw_line(start, end, &paint);
+                }
+            }
+        }
 
         Ok(self)
     }
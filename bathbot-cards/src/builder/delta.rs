@@ -0,0 +1,69 @@
+use skia_safe::{Path, font_style::Slant};
+
+use crate::{
+    builder::{card::CardBuilder, font::FontBuilder, paint::PaintBuilder},
+    error::FontError,
+    font::FontData,
+    theme::Rgb,
+};
+
+const DELTA_UP: Rgb = (94, 203, 130);
+const DELTA_DOWN: Rgb = (250, 89, 111);
+
+impl CardBuilder<'_> {
+    /// Draws an arrow followed by the signed `delta` with the text's baseline
+    /// at `pos`.
+    pub(crate) fn draw_skill_delta(
+        &mut self,
+        delta: f64,
+        pos: (f32, f32),
+        size: f32,
+        font_data: &FontData,
+    ) -> Result<(), FontError> {
+        let (x, y) = pos;
+        let font = FontBuilder::build(600, Slant::Upright, font_data, size)?;
+
+        // Skill values are shown with two decimals so anything smaller is
+        // considered unchanged
+        if delta.abs() < 0.005 {
+            let paint = PaintBuilder::color(self.palette.text).alpha(128).build();
+            self.canvas.draw_str("±0.00", (x, y), &font, &paint);
+
+            return Ok(());
+        }
+
+        let (rgb, text) = if delta > 0.0 {
+            (DELTA_UP, format!("+{delta:.2}"))
+        } else {
+            (DELTA_DOWN, format!("{delta:.2}"))
+        };
+
+        let paint = PaintBuilder::color(rgb).anti_alias().build();
+
+        let arrow_w = size * 0.7;
+        let arrow_h = size * 0.6;
+        let top = y - size * 0.65;
+        let bottom = top + arrow_h;
+
+        let mut path = Path::new();
+
+        if delta > 0.0 {
+            path.move_to((x, bottom))
+                .line_to((x + arrow_w, bottom))
+                .line_to((x + arrow_w / 2.0, top))
+                .close();
+        } else {
+            path.move_to((x, top))
+                .line_to((x + arrow_w, top))
+                .line_to((x + arrow_w / 2.0, bottom))
+                .close();
+        }
+
+        self.canvas.draw_path(&path, &paint);
+
+        let text_x = x + arrow_w + size * 0.25;
+        self.canvas.draw_str(&text, (text_x, y), &font, &paint);
+
+        Ok(())
+    }
+}
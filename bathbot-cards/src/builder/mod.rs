@@ -1,3 +1,5 @@
+pub(crate) mod banner;
 pub(crate) mod card;
+pub(crate) mod delta;
 pub(crate) mod font;
 pub(crate) mod paint;
@@ -2,7 +2,7 @@ use std::mem;
 
 use skia_safe::{BlurStyle, Color, MaskFilter, Paint, Shader, TileMode};
 
-use crate::error::PaintError;
+use crate::{error::PaintError, theme::Rgb};
 
 pub(crate) struct Gradient {
     pub(crate) pos: (f32, f32),
@@ -25,6 +25,10 @@ impl PaintBuilder {
         Self { paint }
     }
 
+    pub(crate) fn color((r, g, b): Rgb) -> Self {
+        Self::rgb(r, g, b)
+    }
+
     pub(crate) fn alpha(&mut self, alpha: u8) -> &mut Self {
         self.paint.set_alpha(alpha);
 
@@ -37,6 +41,12 @@ impl PaintBuilder {
         self
     }
 
+    pub(crate) fn stroke_width(&mut self, width: f32) -> &mut Self {
+        self.paint.set_stroke_width(width);
+
+        self
+    }
+
     pub(crate) fn mask_filter(
         &mut self,
         style: BlurStyle,
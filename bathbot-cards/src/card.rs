@@ -5,10 +5,11 @@ use rosu_v2::model::{GameMode, score::Score};
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    builder::card::{CardBuilder, H, W},
+    builder::card::CardBuilder,
     error::CardError,
     font::FontData,
     skills::{CardTitle, Skills},
+    theme::{CardLayout, CardTheme},
 };
 
 pub struct UserNext;
@@ -30,6 +31,8 @@ pub struct BathbotCard<'a, Status> {
     pub(crate) skills: Skills,
     pub(crate) title: CardTitle,
     pub(crate) inner: CardInner<'a>,
+    pub(crate) theme: CardTheme,
+    pub(crate) layout: CardLayout,
     _phantom: PhantomData<Status>,
 }
 
@@ -45,9 +48,14 @@ pub(crate) struct CardInner<'a> {
     pub(crate) flag: &'a [u8],
     pub(crate) date: &'a str,
     pub(crate) assets: PathBuf,
+    pub(crate) previous: Option<Skills>,
 }
 
 impl<'a, Status> BathbotCard<'a, Status> {
+    pub fn skills(&self) -> &Skills {
+        &self.skills
+    }
+
     fn cast<NewStatus>(&mut self) -> &mut BathbotCard<'a, NewStatus> {
         // SAFETY: only `_phantom` changes which is a ZST
         unsafe { mem::transmute(self) }
@@ -65,6 +73,8 @@ impl BathbotCard<'_, UserNext> {
             title: CardTitle::new(&skills, scores, legacy_scores),
             skills,
             inner: CardInner::default(),
+            theme: CardTheme::default(),
+            layout: CardLayout::default(),
             _phantom: PhantomData,
         }
     }
@@ -131,15 +141,49 @@ impl<'a> BathbotCard<'a, AssetsPathNext> {
 }
 
 impl BathbotCard<'_, ReadyToDraw> {
+    pub fn theme(&mut self, theme: CardTheme) -> &mut Self {
+        self.theme = theme;
+
+        self
+    }
+
+    pub fn layout(&mut self, layout: CardLayout) -> &mut Self {
+        self.layout = layout;
+
+        self
+    }
+
+    /// Skills to compare the current skills against.
+    ///
+    /// Ignored if they're of a different gamemode.
+    pub fn previous_skills(&mut self, previous: Skills) -> &mut Self {
+        self.inner.previous = Some(previous);
+
+        self
+    }
+
     pub fn draw(&self) -> Result<Vec<u8>, CardError> {
         let fonts = FontData::new(self.inner.assets.clone())?;
-        let mut surface = surfaces::raster_n32_premul((W, H)).ok_or(CardError::CreateSurface)?;
+        let mode = self.skills.mode();
+        let dimensions = self.layout.dimensions();
+        let mut surface =
+            surfaces::raster_n32_premul(dimensions).ok_or(CardError::CreateSurface)?;
 
-        CardBuilder::new(surface.canvas())
-            .draw_background(&self.title, self.inner.assets.clone())?
-            .draw_header(self.skills.mode(), &self.inner, &self.title, &fonts)?
-            .draw_info(&self.inner, &self.skills, &fonts)?
-            .draw_footer(&self.inner, &fonts)?;
+        let mut builder = CardBuilder::new(surface.canvas(), self.theme.palette(mode));
+        let texture = self.theme.texture(mode);
+        builder.draw_background(&texture, &self.title, self.inner.assets.clone())?;
+
+        match self.layout {
+            CardLayout::Full => {
+                builder
+                    .draw_header(mode, &self.inner, &self.title, &fonts)?
+                    .draw_info(&self.inner, &self.skills, &fonts)?
+                    .draw_footer(&self.inner, &fonts)?;
+            }
+            CardLayout::Banner => {
+                builder.draw_banner(&self.inner, &self.skills, &self.title, &fonts)?;
+            }
+        }
 
         surface
             .image_snapshot()
@@ -148,3 +192,256 @@ impl BathbotCard<'_, ReadyToDraw> {
             .ok_or(CardError::EncodeAsPng)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use skia_safe::{CachingHint, Color, Data, Image, ImageInfo};
+
+    use super::*;
+
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    /// Set this environment variable to overwrite the reference snapshots.
+    const UPDATE_SNAPSHOTS: &str = "UPDATE_CARD_SNAPSHOTS";
+    /// Channel difference up to which two pixels are considered equal.
+    const CHANNEL_TOLERANCE: u8 = 16;
+    /// Share of pixels that may differ from the reference e.g. due to
+    /// platform-specific font anti-aliasing.
+    const PIXEL_TOLERANCE: f64 = 0.005;
+
+    const THEMES: [CardTheme; 3] = [CardTheme::Classic, CardTheme::Mode, CardTheme::Midnight];
+    const LAYOUTS: [CardLayout; 2] = [CardLayout::Full, CardLayout::Banner];
+
+    fn solid_png(w: i32, h: i32, color: Color) -> Vec<u8> {
+        let mut surface = surfaces::raster_n32_premul((w, h)).unwrap();
+        surface.canvas().clear(color);
+
+        surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .unwrap()
+            .as_bytes()
+            .to_vec()
+    }
+
+    fn skills(mode: GameMode) -> Skills {
+        match mode {
+            GameMode::Osu => Skills::Osu {
+                acc: 71.23,
+                aim: 84.56,
+                speed: 62.1,
+            },
+            GameMode::Taiko => Skills::Taiko {
+                acc: 55.5,
+                strain: 48.02,
+            },
+            GameMode::Catch => Skills::Catch {
+                acc: 33.3,
+                movement: 91.9,
+            },
+            GameMode::Mania => Skills::Mania {
+                acc: 97.0,
+                strain: 12.34,
+            },
+        }
+    }
+
+    fn render(
+        mode: GameMode,
+        theme: CardTheme,
+        layout: CardLayout,
+        previous: Option<Skills>,
+    ) -> Vec<u8> {
+        let pfp = solid_png(256, 256, Color::from_rgb(200, 80, 80));
+        let flag = solid_png(93, 70, Color::from_rgb(80, 80, 200));
+        let skills = skills(mode);
+
+        let mut card = BathbotCard::<UserNext> {
+            title: CardTitle::new(&skills, &[], false),
+            skills,
+            inner: CardInner::default(),
+            theme: CardTheme::default(),
+            layout: CardLayout::default(),
+            _phantom: PhantomData,
+        };
+
+        let card = card
+            .user("Badewanne3", 100.42)
+            .ranks(1234, 56)
+            .medals(234, 312)
+            .bytes(&pfp, &flag)
+            .date("17.10.2026")
+            .assets(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets"))
+            .theme(theme)
+            .layout(layout);
+
+        if let Some(previous) = previous {
+            card.previous_skills(previous);
+        }
+
+        card.draw().unwrap()
+    }
+
+    fn snapshot_path(theme: CardTheme, layout: CardLayout) -> PathBuf {
+        let name = format!("{layout:?}_{theme:?}.png").to_lowercase();
+
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join(name)
+    }
+
+    fn decode(png: &[u8]) -> Image {
+        Image::from_encoded(Data::new_copy(png)).unwrap()
+    }
+
+    /// RGBA bytes of the decoded png.
+    fn pixels(png: &[u8]) -> (i32, i32, Vec<u8>) {
+        let img = decode(png);
+        let (w, h) = (img.width(), img.height());
+        let info = ImageInfo::new_n32_premul((w, h), None);
+        let row_bytes = info.min_row_bytes();
+        let mut pixels = vec![0; row_bytes * h as usize];
+
+        assert!(img.read_pixels(&info, &mut pixels, row_bytes, (0, 0), CachingHint::Disallow));
+
+        (w, h, pixels)
+    }
+
+    #[test]
+    fn matches_reference_snapshots() {
+        let update = env::var_os(UPDATE_SNAPSHOTS).is_some();
+
+        for theme in THEMES {
+            for layout in LAYOUTS {
+                let actual = render(GameMode::Osu, theme, layout, None);
+                let path = snapshot_path(theme, layout);
+
+                if update {
+                    fs::create_dir_all(path.parent().unwrap()).unwrap();
+                    fs::write(&path, &actual).unwrap();
+
+                    continue;
+                }
+
+                let expected = match fs::read(&path) {
+                    Ok(expected) => expected,
+                    Err(err) => panic!(
+                        "Missing reference snapshot {path:?} ({err}); \
+                        render it with {UPDATE_SNAPSHOTS}=1 and commit it"
+                    ),
+                };
+
+                let (w, h, actual) = pixels(&actual);
+                let (expected_w, expected_h, expected) = pixels(&expected);
+                assert_eq!((w, h), (expected_w, expected_h), "{theme:?} {layout:?}");
+
+                let differing = actual
+                    .chunks_exact(4)
+                    .zip(expected.chunks_exact(4))
+                    .filter(|(a, b)| {
+                        a.iter()
+                            .zip(b.iter())
+                            .any(|(a, b)| a.abs_diff(*b) > CHANNEL_TOLERANCE)
+                    })
+                    .count();
+
+                let share = differing as f64 / (w * h) as f64;
+
+                assert!(
+                    share <= PIXEL_TOLERANCE,
+                    "{theme:?} {layout:?} differs from {path:?} in {:.2}% of pixels; \
+                    rerun with {UPDATE_SNAPSHOTS}=1 if the change is intended",
+                    share * 100.0,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_theme_renders_png() {
+        for mode in [GameMode::Osu, GameMode::Taiko] {
+            for theme in THEMES {
+                for layout in LAYOUTS {
+                    let bytes = render(mode, theme, layout, None);
+                    assert!(
+                        bytes.starts_with(PNG_SIGNATURE),
+                        "{mode:?} {theme:?} {layout:?}"
+                    );
+
+                    let img = decode(&bytes);
+                    let (w, h) = layout.dimensions();
+                    assert_eq!((img.width(), img.height()), (w, h));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rendering_is_deterministic() {
+        for theme in THEMES {
+            for layout in LAYOUTS {
+                let first = render(GameMode::Catch, theme, layout, None);
+                let second = render(GameMode::Catch, theme, layout, None);
+                assert!(first == second, "{theme:?} {layout:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn themes_differ() {
+        for layout in LAYOUTS {
+            let snapshots: Vec<_> = THEMES
+                .iter()
+                .map(|&theme| render(GameMode::Mania, theme, layout, None))
+                .collect();
+
+            for (i, a) in snapshots.iter().enumerate() {
+                for b in snapshots[i + 1..].iter() {
+                    assert!(a != b, "{layout:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn skill_deltas() {
+        let previous = Skills::Osu {
+            acc: 70.0,
+            aim: 90.0,
+            speed: 62.1,
+        };
+
+        let deltas = skills(GameMode::Osu).deltas(&previous).unwrap();
+        let expected = [1.23, -5.44, 0.0];
+
+        for (delta, expected) in deltas.into_iter().zip(expected) {
+            assert!((delta - expected).abs() < 1e-9, "{delta} vs {expected}");
+        }
+
+        assert!(skills(GameMode::Taiko).deltas(&previous).is_none());
+    }
+
+    #[test]
+    fn previous_skills_are_drawn() {
+        let previous = Skills::Osu {
+            acc: 75.0,
+            aim: 80.0,
+            speed: 62.1,
+        };
+
+        for theme in THEMES {
+            for layout in LAYOUTS {
+                let without = render(GameMode::Osu, theme, layout, None);
+                let with = render(GameMode::Osu, theme, layout, Some(previous));
+                assert!(without != with, "{theme:?} {layout:?}");
+
+                // Snapshots of a different mode are ignored
+                let other_mode = render(GameMode::Taiko, theme, layout, Some(previous));
+                let taiko = render(GameMode::Taiko, theme, layout, None);
+                assert!(other_mode == taiko, "{theme:?} {layout:?}");
+            }
+        }
+    }
+}
@@ -20,6 +20,8 @@ pub enum CardError {
     Info(#[from] InfoError),
     #[error("Failed to draw footer")]
     Footer(#[from] FooterError),
+    #[error("Failed to draw banner")]
+    Banner(#[from] BannerError),
     #[error("Failed to encode as PNG")]
     EncodeAsPng,
 }
@@ -44,6 +46,8 @@ pub enum BackgroundError {
     Image,
     #[error("Failed to read image file")]
     File(#[source] IoError),
+    #[error("Paint error")]
+    Paint(#[from] PaintError),
 }
 
 #[derive(Debug, ThisError)]
@@ -90,6 +94,24 @@ pub enum FooterError {
     BrandingSvg(#[source] SvgError),
 }
 
+#[derive(Debug, ThisError)]
+pub enum BannerError {
+    #[error("Failed to create avatar image")]
+    Avatar,
+    #[error("Failed to create flag image")]
+    Flag,
+    #[error("Font error")]
+    Font(#[from] FontError),
+    #[error("Failed to read mode file")]
+    ModeFile(#[source] IoError),
+    #[error("Failed to parse mode svg")]
+    ModeSvg(#[source] SvgError),
+    #[error("Paint error")]
+    Paint(#[from] PaintError),
+    #[error("Failed to make skill text blob")]
+    SkillTextBlob,
+}
+
 #[derive(Debug, ThisError)]
 pub enum SvgError {
     #[error("Failed to create path")]
@@ -4,5 +4,10 @@ mod error;
 mod font;
 mod skills;
 mod svg;
+mod theme;
 
-pub use self::card::{BathbotCard, RequiredAttributes};
+pub use self::{
+    card::{BathbotCard, RequiredAttributes},
+    skills::Skills,
+    theme::{CardLayout, CardTheme},
+};
@@ -18,6 +18,7 @@ pub(crate) use self::{
 };
 use crate::card::Maps;
 
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Skills {
     Osu { acc: f64, aim: f64, speed: f64 },
     Taiko { acc: f64, strain: f64 },
@@ -251,7 +252,37 @@ impl Skills {
         }
     }
 
-    pub(crate) fn mode(&self) -> GameMode {
+    /// Names and values of all skills in the order they are drawn.
+    pub fn named_values(&self) -> Vec<(&'static str, f64)> {
+        match *self {
+            Skills::Osu { acc, aim, speed } => {
+                vec![("ACCURACY", acc), ("AIM", aim), ("SPEED", speed)]
+            }
+            Skills::Taiko { acc, strain } => vec![("ACCURACY", acc), ("STRAIN", strain)],
+            Skills::Catch { acc, movement } => vec![("ACCURACY", acc), ("MOVEMENT", movement)],
+            Skills::Mania { acc, strain } => vec![("ACCURACY", acc), ("STRAIN", strain)],
+        }
+    }
+
+    /// Differences between each skill value and its counterpart in `previous`.
+    ///
+    /// Returns `None` if the skills are of different gamemodes.
+    pub fn deltas(&self, previous: &Self) -> Option<Vec<f64>> {
+        if self.mode() != previous.mode() {
+            return None;
+        }
+
+        let deltas = self
+            .named_values()
+            .into_iter()
+            .zip(previous.named_values())
+            .map(|((_, curr), (_, prev))| curr - prev)
+            .collect();
+
+        Some(deltas)
+    }
+
+    pub fn mode(&self) -> GameMode {
         match self {
             Skills::Osu { .. } => GameMode::Osu,
             Skills::Taiko { .. } => GameMode::Taiko,
@@ -0,0 +1,128 @@
+use rosu_v2::model::GameMode;
+
+use crate::builder::{
+    banner::{BANNER_H, BANNER_W},
+    card::{H, W},
+};
+
+pub(crate) type Rgb = (u8, u8, u8);
+
+/// Colors and background of a card.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum CardTheme {
+    /// Background based on the card title and white text
+    #[default]
+    Classic,
+    /// Gradient background and accents in the color of the gamemode
+    Mode,
+    /// Dark striped background with muted colors
+    Midnight,
+}
+
+/// Arrangement of a card's elements.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum CardLayout {
+    /// Tall card containing all values
+    #[default]
+    Full,
+    /// Compact banner only containing the main values
+    Banner,
+}
+
+impl CardLayout {
+    pub(crate) fn dimensions(self) -> (i32, i32) {
+        match self {
+            Self::Full => (W, H),
+            Self::Banner => (BANNER_W, BANNER_H),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct Palette {
+    /// Color of all regular text
+    pub(crate) text: Rgb,
+    /// Color of the gamemode area
+    pub(crate) mode: Rgb,
+    /// Color of skill markers and the level bar
+    pub(crate) highlight: Rgb,
+    /// Base color of the translucent panels
+    pub(crate) panel: Rgb,
+}
+
+pub(crate) enum Texture {
+    /// Image depending on the card title's prefix
+    Title,
+    /// Diagonal gradient from the top left to the bottom right
+    Gradient { start: Rgb, end: Rgb },
+    /// Solid color with diagonal stripes
+    Stripes { base: Rgb, stripe: Rgb },
+}
+
+impl CardTheme {
+    pub(crate) fn palette(self, mode: GameMode) -> Palette {
+        match self {
+            Self::Classic => Palette {
+                text: (255, 255, 255),
+                mode: mode_rgb(mode),
+                highlight: (255, 255, 255),
+                panel: (0, 0, 0),
+            },
+            Self::Mode => {
+                let rgb = mode_rgb(mode);
+
+                Palette {
+                    text: (255, 255, 255),
+                    mode: rgb,
+                    highlight: mix(rgb, (255, 255, 255), 0.5),
+                    panel: mix(rgb, (0, 0, 0), 0.8),
+                }
+            }
+            Self::Midnight => Palette {
+                text: (226, 228, 240),
+                mode: (124, 134, 255),
+                highlight: (124, 134, 255),
+                panel: (4, 6, 20),
+            },
+        }
+    }
+
+    pub(crate) fn texture(self, mode: GameMode) -> Texture {
+        match self {
+            Self::Classic => Texture::Title,
+            Self::Mode => {
+                let rgb = mode_rgb(mode);
+
+                Texture::Gradient {
+                    start: mix(rgb, (0, 0, 0), 0.35),
+                    end: mix(rgb, (0, 0, 0), 0.75),
+                }
+            }
+            Self::Midnight => Texture::Stripes {
+                base: (17, 19, 36),
+                stripe: (25, 28, 52),
+            },
+        }
+    }
+}
+
+pub(crate) fn mode_rgb(mode: GameMode) -> Rgb {
+    match mode {
+        GameMode::Osu => (255, 102, 170),
+        GameMode::Taiko => (94, 203, 162),
+        GameMode::Catch => (102, 204, 255),
+        GameMode::Mania => (197, 102, 255),
+    }
+}
+
+pub(crate) fn argb(alpha: u8, (r, g, b): Rgb) -> (u8, u8, u8, u8) {
+    (alpha, r, g, b)
+}
+
+/// Linear interpolation between two colors where a `factor` of `0.0` returns
+/// `from` and `1.0` returns `to`.
+fn mix(from: Rgb, to: Rgb, factor: f32) -> Rgb {
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * factor).round() as u8;
+
+    (lerp(from.0, to.0), lerp(from.1, to.1), lerp(from.2, to.2))
+}
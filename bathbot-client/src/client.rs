@@ -34,19 +34,7 @@ impl Client {
         #[cfg(feature = "twitch")] (twitch_client_id, twitch_token): (&str, &str),
         github_token: &str,
     ) -> Result<Self> {
-        ClientMetrics::init();
-
-        let crypto_provider = rustls::crypto::ring::default_provider();
-
-        let https = HttpsConnectorBuilder::new()
-            .with_provider_and_webpki_roots(crypto_provider)
-            .wrap_err("Failed to configure https connector")?
-            .https_or_http()
-            .enable_http1()
-            .enable_http2()
-            .build();
-
-        let client = Builder::new(TokioExecutor::new()).build(https);
+        let client = Self::inner_client()?;
 
         #[cfg(feature = "twitch")]
         let twitch = Self::get_twitch_token(&client, twitch_client_id, twitch_token)
@@ -62,6 +50,37 @@ impl Client {
         })
     }
 
+    /// Creates a client without requesting a twitch token beforehand so any
+    /// twitch request will fail.
+    pub fn without_twitch(github_token: &str) -> Result<Self> {
+        Ok(Self {
+            client: Self::inner_client()?,
+            ratelimiters: Ratelimiters::new(),
+            #[cfg(feature = "twitch")]
+            twitch: bathbot_model::TwitchData {
+                client_id: hyper::header::HeaderValue::from_static(""),
+                oauth_token: Default::default(),
+            },
+            github_auth: format!("Bearer {github_token}").into_boxed_str(),
+        })
+    }
+
+    fn inner_client() -> Result<InnerClient> {
+        ClientMetrics::init();
+
+        let crypto_provider = rustls::crypto::ring::default_provider();
+
+        let https = HttpsConnectorBuilder::new()
+            .with_provider_and_webpki_roots(crypto_provider)
+            .wrap_err("Failed to configure https connector")?
+            .https_or_http()
+            .enable_http1()
+            .enable_http2()
+            .build();
+
+        Ok(Builder::new(TokioExecutor::new()).build(https))
+    }
+
     pub(crate) async fn ratelimit(&self, site: Site) {
         self.ratelimiters.get(site).acquire_one().await
     }
@@ -0,0 +1,67 @@
+use bathbot_model::{DanserRender, DanserRenderState, DanserReplay, DanserSkin};
+use eyre::WrapErr;
+use itoa::Buffer as IntBuffer;
+
+use crate::{Client, ClientError, multipart::Multipart, site::Site};
+
+impl Client {
+    /// POST `{base_url}/render`
+    ///
+    /// Commission a render on a self-hosted danser service. The options are
+    /// passed on as JSON.
+    pub async fn commission_danser_render(
+        &self,
+        base_url: &str,
+        replay: DanserReplay<'_>,
+        skin: DanserSkin<'_>,
+        options: &[u8],
+    ) -> Result<DanserRender, ClientError> {
+        let url = format!("{}/render", base_url.trim_end_matches('/'));
+
+        let mut buf = IntBuffer::new();
+        let mut form = Multipart::new();
+
+        match replay {
+            DanserReplay::File(bytes) => form.push_file("replayFile", "replay.osr", bytes),
+            DanserReplay::Url(url) => form.push_text("replayURL", url),
+        };
+
+        match skin {
+            DanserSkin::Official(name) => form.push_text("skin", name),
+            DanserSkin::Custom(id) => form.push_int("customSkin", id, &mut buf),
+        };
+
+        form.push_text("options", options);
+
+        let bytes = self
+            .make_multipart_post_request(url, Site::Danser, form)
+            .await?;
+
+        let render = serde_json::from_slice(&bytes).wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("Failed to deserialize danser render: {body}")
+        })?;
+
+        Ok(render)
+    }
+
+    /// GET `{base_url}/render/{render_id}`
+    pub async fn get_danser_render_state(
+        &self,
+        base_url: &str,
+        render_id: u32,
+    ) -> Result<DanserRenderState, ClientError> {
+        let url = format!("{}/render/{render_id}", base_url.trim_end_matches('/'));
+
+        let bytes = self.make_get_request(url, Site::Danser).await?;
+
+        let state = serde_json::from_slice(&bytes).wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("Failed to deserialize danser render state: {body}")
+        })?;
+
+        Ok(state)
+    }
+}
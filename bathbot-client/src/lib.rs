@@ -5,6 +5,7 @@ extern crate eyre;
 extern crate tracing;
 
 mod client;
+mod danser;
 mod discord;
 mod error;
 mod github;
@@ -79,6 +79,35 @@ impl Multipart {
         self
     }
 
+    pub fn push_file<K, F, B>(&mut self, key: K, filename: F, bytes: B) -> &mut Self
+    where
+        K: AsRef<[u8]>,
+        F: AsRef<[u8]>,
+        B: AsRef<[u8]>,
+    {
+        self.bytes.extend_from_slice(Self::NEWLINE);
+        self.bytes
+            .extend_from_slice(b"Content-Disposition: form-data; name=\"");
+        self.bytes.extend_from_slice(key.as_ref());
+        self.bytes.extend_from_slice(b"\"; filename=\"");
+        self.bytes.extend_from_slice(filename.as_ref());
+        self.bytes.extend_from_slice(b"\"");
+
+        self.bytes.extend_from_slice(Self::NEWLINE);
+        self.bytes
+            .extend_from_slice(b"Content-Type: application/octet-stream");
+        self.bytes.extend_from_slice(Self::NEWLINE);
+        self.bytes.extend_from_slice(Self::NEWLINE);
+
+        self.bytes.extend_from_slice(bytes.as_ref());
+
+        self.bytes.extend_from_slice(Self::NEWLINE);
+        self.bytes.extend_from_slice(Self::BOUNDARY_TERMINATOR);
+        self.bytes.extend_from_slice(&self.boundary);
+
+        self
+    }
+
     pub fn content_type(&self) -> Vec<u8> {
         const NAME: &[u8] = b"multipart/form-data; boundary=";
 
@@ -150,4 +179,31 @@ mod tests {
 
         assert_eq!(form, expect);
     }
+
+    #[test]
+    fn test_file() {
+        let mut form = Multipart::new();
+
+        form.push_text("key", "value")
+            .push_file("file", "replay.osr", b"abc");
+
+        let boundary = str_from_utf8(&form.boundary).unwrap();
+
+        let expect = format!(
+            "--{boundary}\r\n\
+            Content-Disposition: form-data; name=\"key\"\r\n\
+            \r\n\
+            value\r\n\
+            --{boundary}\r\n\
+            Content-Disposition: form-data; name=\"file\"; filename=\"replay.osr\"\r\n\
+            Content-Type: application/octet-stream\r\n\
+            \r\n\
+            abc\r\n\
+            --{boundary}--"
+        );
+
+        let form = String::from_utf8(form.build()).unwrap();
+
+        assert_eq!(form, expect);
+    }
 }
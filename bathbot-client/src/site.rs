@@ -51,6 +51,7 @@ macro_rules! sites {
 }
 
 sites! {
+    Danser -> 2,
     DiscordAttachment -> 2,
     Flags -> 10,
     Github -> 5,
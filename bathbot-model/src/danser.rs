@@ -0,0 +1,63 @@
+use serde::Deserialize;
+
+/// The replay that should be rendered by a danser service
+pub enum DanserReplay<'a> {
+    File(&'a [u8]),
+    Url(&'a str),
+}
+
+pub enum DanserSkin<'a> {
+    Official(&'a str),
+    Custom(u32),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DanserRender {
+    #[serde(rename = "renderID")]
+    pub render_id: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "state", rename_all = "lowercase")]
+pub enum DanserRenderState {
+    Queued,
+    Rendering {
+        progress: Box<str>,
+    },
+    Done {
+        #[serde(rename = "videoUrl")]
+        video_url: Box<str>,
+    },
+    Failed {
+        error: Box<str>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_render_state() {
+        let state: DanserRenderState = serde_json::from_str(r#"{"state":"queued"}"#).unwrap();
+        assert!(matches!(state, DanserRenderState::Queued));
+
+        let json = r#"{"state":"rendering","progress":"Rendering... 42%"}"#;
+        let state: DanserRenderState = serde_json::from_str(json).unwrap();
+        assert!(
+            matches!(state, DanserRenderState::Rendering { ref progress } if progress.as_ref() == "Rendering... 42%")
+        );
+
+        let json = r#"{"state":"done","videoUrl":"http://localhost:8080/videos/1.mp4"}"#;
+        let state: DanserRenderState = serde_json::from_str(json).unwrap();
+        assert!(
+            matches!(state, DanserRenderState::Done { ref video_url } if video_url.as_ref() == "http://localhost:8080/videos/1.mp4")
+        );
+
+        let json = r#"{"state":"failed","error":"Beatmap not found"}"#;
+        let state: DanserRenderState = serde_json::from_str(json).unwrap();
+        assert!(matches!(state, DanserRenderState::Failed { .. }));
+
+        assert!(serde_json::from_str::<DanserRenderState>(r#"{"state":"unknown"}"#).is_err());
+    }
+}
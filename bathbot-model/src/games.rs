@@ -1,7 +1,7 @@
 #![allow(non_upper_case_globals)]
 
 use std::{
-    fmt::{Display, Write},
+    fmt::{Display, Formatter, Result as FmtResult, Write},
     str::FromStr,
 };
 
@@ -13,6 +13,64 @@ pub struct BgGameScore {
     pub score: i32,
 }
 
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+pub enum BgGameVariant {
+    #[default]
+    #[option(name = "Title", value = "title")]
+    Title = 0,
+    #[option(name = "Mapper", value = "mapper")]
+    Mapper = 1,
+    #[option(name = "Star rating", value = "stars")]
+    Stars = 2,
+    #[option(name = "Blurred title", value = "blurred")]
+    Blurred = 3,
+    #[option(name = "Pixelated title", value = "pixelated")]
+    Pixelated = 4,
+}
+
+impl BgGameVariant {
+    /// Whether the game should show the whole background instead of a crop.
+    pub fn full_image(self) -> bool {
+        matches!(self, Self::Blurred | Self::Pixelated)
+    }
+
+    /// Whether the title of the map's song needs to be guessed.
+    pub fn guess_title(self) -> bool {
+        matches!(self, Self::Title | Self::Blurred | Self::Pixelated)
+    }
+}
+
+impl Display for BgGameVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let variant = match self {
+            Self::Title => "Title",
+            Self::Mapper => "Mapper",
+            Self::Stars => "Star rating",
+            Self::Blurred => "Blurred title",
+            Self::Pixelated => "Pixelated title",
+        };
+
+        f.write_str(variant)
+    }
+}
+
+impl FromStr for BgGameVariant {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let variant = match value.cow_to_ascii_lowercase().as_ref() {
+            "title" => Self::Title,
+            "mapper" | "creator" => Self::Mapper,
+            "stars" | "star" | "sr" => Self::Stars,
+            "blur" | "blurred" => Self::Blurred,
+            "pixel" | "pixelated" | "pixelate" => Self::Pixelated,
+            _ => return Err(()),
+        };
+
+        Ok(variant)
+    }
+}
+
 pub struct HlGameScore {
     pub discord_id: i64,
     pub highscore: i32,
@@ -22,6 +80,45 @@ pub struct HlGameScore {
 pub enum HlVersion {
     #[option(name = "Score PP", value = "score_pp")]
     ScorePp = 0,
+    #[option(name = "Global rank", value = "global_rank")]
+    GlobalRank = 1,
+    #[option(name = "Map playcount", value = "map_playcount")]
+    MapPlaycount = 2,
+    #[option(name = "Mapset favourites", value = "mapset_favourites")]
+    MapsetFavourites = 3,
+    #[option(name = "Medal rarity", value = "medal_rarity")]
+    MedalRarity = 4,
+}
+
+impl Display for HlVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let version = match self {
+            Self::ScorePp => "Score PP",
+            Self::GlobalRank => "Global rank",
+            Self::MapPlaycount => "Map playcount",
+            Self::MapsetFavourites => "Mapset favourites",
+            Self::MedalRarity => "Medal rarity",
+        };
+
+        f.write_str(version)
+    }
+}
+
+impl FromStr for HlVersion {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let version = match value.cow_to_ascii_lowercase().as_ref() {
+            "score_pp" | "pp" => Self::ScorePp,
+            "global_rank" | "rank" => Self::GlobalRank,
+            "map_playcount" | "playcount" => Self::MapPlaycount,
+            "mapset_favourites" | "favourites" | "favorites" => Self::MapsetFavourites,
+            "medal_rarity" | "medals" => Self::MedalRarity,
+            _ => return Err(()),
+        };
+
+        Ok(version)
+    }
 }
 
 bitflags::bitflags! {
@@ -1,4 +1,5 @@
 mod country_code;
+mod danser;
 mod deser;
 mod either;
 mod games;
@@ -25,8 +26,8 @@ pub mod twilight;
 pub mod rkyv_util;
 
 pub use self::{
-    country_code::*, deser::ModeAsSeed, either::Either, games::*, github::*, huismetbenen::*,
-    kittenroleplay::*, osekai::*, osu::*, osu_stats::*, osutrack::*,
+    country_code::*, danser::*, deser::ModeAsSeed, either::Either, games::*, github::*,
+    huismetbenen::*, kittenroleplay::*, osekai::*, osu::*, osu_stats::*, osutrack::*,
     personal_best::PersonalBestIndex, ranking_entries::*, relax::*, respektive::*, score_slim::*,
     twitch::*, user_stats::*,
 };
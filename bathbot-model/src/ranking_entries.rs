@@ -1,13 +1,14 @@
 use std::{collections::BTreeMap, fmt::Write, ops::RangeBounds};
 
 use bathbot_util::{AuthorBuilder, FooterBuilder, datetime::HowLongAgoText};
-use rosu_v2::prelude::{CountryCode, GameMode, Username};
+use rosu_v2::prelude::{CountryCode, GameMode, GameModsIntermode, Username};
 use time::OffsetDateTime;
 use twilight_model::id::{Id, marker::GuildMarker};
 
 use crate::{
-    BgGameScore, HlGameScore, HlVersion, UserModeStatsColumn, UserStatsColumn, UserStatsEntries,
-    UserStatsEntry, twilight::util::ImageHashRkyv,
+    BgGameScore, BgGameVariant, HlGameScore, HlVersion, ScoreStatsColumn, ScoreStatsPeriod,
+    UserModeStatsColumn, UserStatsColumn, UserStatsEntries, UserStatsEntry,
+    twilight::util::ImageHashRkyv,
 };
 
 pub struct RankingEntry<V> {
@@ -16,6 +17,17 @@ pub struct RankingEntry<V> {
     pub value: V,
 }
 
+/// A score of a server member that is ranked by its pp.
+pub struct ServerTopPlay {
+    pub map_id: u32,
+    /// `None` if the map could not be retrieved
+    pub map: Option<String>,
+    pub mods: GameModsIntermode,
+    pub pp: f32,
+    pub accuracy: f32,
+    pub ended_at: OffsetDateTime,
+}
+
 impl<V> From<UserStatsEntry<V>> for RankingEntry<V> {
     #[inline]
     fn from(entry: UserStatsEntry<V>) -> Self {
@@ -37,6 +49,7 @@ pub enum RankingEntries {
     PpF32(BTreeMap<usize, RankingEntry<f32>>),
     PpU32(BTreeMap<usize, RankingEntry<u32>>),
     Rank(BTreeMap<usize, RankingEntry<u32>>),
+    TopPlays(BTreeMap<usize, RankingEntry<ServerTopPlay>>),
 }
 
 impl RankingEntries {
@@ -51,6 +64,7 @@ impl RankingEntries {
             RankingEntries::PpF32(entries) => entries.contains_key(&key),
             RankingEntries::PpU32(entries) => entries.contains_key(&key),
             RankingEntries::Rank(entries) => entries.contains_key(&key),
+            RankingEntries::TopPlays(entries) => entries.contains_key(&key),
         }
     }
 
@@ -65,6 +79,7 @@ impl RankingEntries {
             RankingEntries::PpF32(entries) => entries.is_empty(),
             RankingEntries::PpU32(entries) => entries.is_empty(),
             RankingEntries::Rank(entries) => entries.is_empty(),
+            RankingEntries::TopPlays(entries) => entries.is_empty(),
         }
     }
 
@@ -79,6 +94,7 @@ impl RankingEntries {
             RankingEntries::PpF32(entries) => entries.len(),
             RankingEntries::PpU32(entries) => entries.len(),
             RankingEntries::Rank(entries) => entries.len(),
+            RankingEntries::TopPlays(entries) => entries.len(),
         }
     }
 
@@ -93,6 +109,7 @@ impl RankingEntries {
             RankingEntries::PpF32(entries) => entries.range(range).count(),
             RankingEntries::PpU32(entries) => entries.range(range).count(),
             RankingEntries::Rank(entries) => entries.range(range).count(),
+            RankingEntries::TopPlays(entries) => entries.range(range).count(),
         }
     }
 
@@ -113,6 +130,7 @@ impl RankingEntries {
             RankingEntries::PpF32(entries) => entries.values().position(cmp_name(name)),
             RankingEntries::PpU32(entries) => entries.values().position(cmp_name(name)),
             RankingEntries::Rank(entries) => entries.values().position(cmp_name(name)),
+            RankingEntries::TopPlays(entries) => entries.values().position(cmp_name(name)),
         }
     }
 }
@@ -185,6 +203,7 @@ pub enum RankingKind {
     BgScores {
         global: bool,
         scores: Vec<BgGameScore>,
+        variant: BgGameVariant,
     },
     Commands {
         bootup_time: OffsetDateTime,
@@ -213,6 +232,17 @@ pub enum RankingKind {
     RankedScore {
         mode: GameMode,
     },
+    ScoreStats {
+        guild_icon: Option<(Id<GuildMarker>, ImageHashRkyv)>,
+        mode: GameMode,
+        column: ScoreStatsColumn,
+        period: ScoreStatsPeriod,
+    },
+    TopPlays {
+        guild_icon: Option<(Id<GuildMarker>, ImageHashRkyv)>,
+        mode: GameMode,
+        period: ScoreStatsPeriod,
+    },
     UserStats {
         guild_icon: Option<(Id<GuildMarker>, ImageHashRkyv)>,
         kind: UserStatsKind,
@@ -236,11 +266,13 @@ impl EmbedHeader {
 impl RankingKind {
     pub fn embed_header(&self) -> EmbedHeader {
         match self {
-            Self::BgScores { global, .. } => {
+            Self::BgScores {
+                global, variant, ..
+            } => {
                 let text = if *global {
-                    "Global leaderboard for correct guesses"
+                    format!("Global leaderboard for correct guesses ({variant})")
                 } else {
-                    "Server leaderboard for correct guesses"
+                    format!("Server leaderboard for correct guesses ({variant})")
                 };
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
@@ -251,9 +283,7 @@ impl RankingKind {
                 EmbedHeader::Author(AuthorBuilder::new(text))
             }
             Self::HlScores { version, .. } => {
-                let text = match version {
-                    HlVersion::ScorePp => "Server leaderboard for Higherlower (Score PP)",
-                };
+                let text = format!("Server leaderboard for Higherlower ({version})");
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
             }
@@ -344,6 +374,39 @@ impl RankingKind {
 
                 EmbedHeader::title(text, url)
             }
+            Self::ScoreStats {
+                guild_icon,
+                mode,
+                column,
+                period,
+            } => {
+                let column = match column {
+                    ScoreStatsColumn::BestPlay => "Best play",
+                    ScoreStatsColumn::RankedScore => "Ranked score gained",
+                    ScoreStatsColumn::Playcount => "Passes submitted",
+                };
+
+                let text = format!(
+                    "Server leaderboard for osu!{mode}: {column} in the last {period}",
+                    mode = mode_str(*mode),
+                    period = period_str(*period),
+                );
+
+                EmbedHeader::Author(guild_author(text, guild_icon.as_ref()))
+            }
+            Self::TopPlays {
+                guild_icon,
+                mode,
+                period,
+            } => {
+                let text = format!(
+                    "Server top plays for osu!{mode} in the last {period}",
+                    mode = mode_str(*mode),
+                    period = period_str(*period),
+                );
+
+                EmbedHeader::Author(guild_author(text, guild_icon.as_ref()))
+            }
             Self::UserStats { guild_icon, kind } => {
                 let mut author_text = "Server leaderboard".to_owned();
 
@@ -395,15 +458,8 @@ impl RankingKind {
                 };
 
                 let _ = write!(author_text, ": {stats_kind}");
-                let mut author = AuthorBuilder::new(author_text);
-
-                if let Some((id, icon)) = guild_icon {
-                    let ext = if icon.animated { "gif" } else { "webp" };
-                    let url = format!("https://cdn.discordapp.com/icons/{id}/{icon}.{ext}");
-                    author = author.icon_url(url);
-                }
 
-                EmbedHeader::Author(author)
+                EmbedHeader::Author(guild_author(author_text, guild_icon.as_ref()))
             }
         }
     }
@@ -439,6 +495,9 @@ impl RankingKind {
             | RankingKind::OsekaiSubscribers => {
                 text.push_str(" • Check out osekai.net for more info")
             }
+            RankingKind::ScoreStats { .. } | RankingKind::TopPlays { .. } => {
+                text.push_str(" • Only considers scores the bot has seen")
+            }
             _ => {}
         };
 
@@ -456,6 +515,29 @@ pub enum UserStatsKind {
     },
 }
 
+fn guild_author(
+    text: String,
+    guild_icon: Option<&(Id<GuildMarker>, ImageHashRkyv)>,
+) -> AuthorBuilder {
+    let mut author = AuthorBuilder::new(text);
+
+    if let Some((id, icon)) = guild_icon {
+        let ext = if icon.animated { "gif" } else { "webp" };
+        let url = format!("https://cdn.discordapp.com/icons/{id}/{icon}.{ext}");
+        author = author.icon_url(url);
+    }
+
+    author
+}
+
+fn period_str(period: ScoreStatsPeriod) -> &'static str {
+    match period {
+        ScoreStatsPeriod::Day => "day",
+        ScoreStatsPeriod::Week => "week",
+        ScoreStatsPeriod::Month => "month",
+    }
+}
+
 fn mode_str(mode: GameMode) -> &'static str {
     match mode {
         GameMode::Osu => "",
@@ -1,4 +1,4 @@
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime};
 use twilight_interactions::command::{CommandOption, CreateOption};
 
 pub enum UserStatsEntries {
@@ -99,3 +99,34 @@ pub enum UserModeStatsColumn {
     #[option(name = "Total hits", value = "total_hits")]
     TotalHits,
 }
+
+/// Leaderboards based on archived scores
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum ScoreStatsColumn {
+    #[option(name = "Best play", value = "best_play")]
+    BestPlay,
+    #[option(name = "Ranked score gained", value = "ranked_score")]
+    RankedScore,
+    #[option(name = "Passes submitted", value = "playcount")]
+    Playcount,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum ScoreStatsPeriod {
+    #[option(name = "Day", value = "day")]
+    Day,
+    #[option(name = "Week", value = "week")]
+    Week,
+    #[option(name = "Month", value = "month")]
+    Month,
+}
+
+impl ScoreStatsPeriod {
+    pub fn duration(self) -> Duration {
+        match self {
+            Self::Day => Duration::DAY,
+            Self::Week => Duration::WEEK,
+            Self::Month => Duration::days(30),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+ALTER TABLE tracked_osu_users
+    DROP COLUMN mods,
+    DROP COLUMN mods_kind,
+    DROP COLUMN min_stars,
+    DROP COLUMN max_stars,
+    DROP COLUMN map_statuses;
\ No newline at end of file
@@ -0,0 +1,6 @@
+ALTER TABLE tracked_osu_users
+    ADD COLUMN mods         VARCHAR,
+    ADD COLUMN mods_kind    INT2,
+    ADD COLUMN min_stars    FLOAT4,
+    ADD COLUMN max_stars    FLOAT4,
+    ADD COLUMN map_statuses INT2;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE tracked_osu_leaderboard_users;
\ No newline at end of file
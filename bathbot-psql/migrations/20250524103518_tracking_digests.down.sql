@@ -0,0 +1,2 @@
+DROP TABLE tracked_osu_digest_scores;
+DROP TABLE tracked_osu_digests;
\ No newline at end of file
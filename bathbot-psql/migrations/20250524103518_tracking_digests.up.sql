@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS tracked_osu_digests (
+    channel_id INT8 NOT NULL,
+    interval   INT2 NOT NULL,
+    next_post  TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (channel_id)
+);
+
+CREATE TABLE IF NOT EXISTS tracked_osu_digest_scores (
+    channel_id  INT8 NOT NULL,
+    score_id    INT8 NOT NULL,
+    user_id     INT4 NOT NULL,
+    username    VARCHAR(15) NOT NULL,
+    gamemode    INT2 NOT NULL,
+    map_id      INT4 NOT NULL,
+    map_title   VARCHAR NOT NULL,
+    mods        VARCHAR NOT NULL,
+    pp          FLOAT4 NOT NULL,
+    top_index   INT2 NOT NULL,
+    pp_before   FLOAT4 NOT NULL,
+    pp_after    FLOAT4 NOT NULL,
+    ended_at    TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (channel_id, score_id)
+);
\ No newline at end of file
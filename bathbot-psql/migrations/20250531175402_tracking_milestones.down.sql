@@ -0,0 +1 @@
+DROP TABLE tracked_osu_milestone_users;
\ No newline at end of file
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS tracked_osu_milestone_users (
+    user_id        INT4 NOT NULL,
+    gamemode       INT2 NOT NULL,
+    channel_id     INT8 NOT NULL,
+    ranks          INT4[] NOT NULL,
+    pp_step        INT4,
+    playcount_step INT4,
+    PRIMARY KEY (user_id, gamemode, channel_id)
+);
+
+CREATE INDEX tracked_osu_milestone_users_channel_index ON tracked_osu_milestone_users (channel_id);
\ No newline at end of file
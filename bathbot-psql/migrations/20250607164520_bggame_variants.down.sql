@@ -0,0 +1,4 @@
+DELETE FROM bggame_scores WHERE variant <> 0;
+ALTER TABLE bggame_scores DROP CONSTRAINT bggame_scores_pkey;
+ALTER TABLE bggame_scores DROP COLUMN variant;
+ALTER TABLE bggame_scores ADD PRIMARY KEY (discord_id);
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE bggame_scores ADD COLUMN variant INT2 NOT NULL DEFAULT 0;
+ALTER TABLE bggame_scores DROP CONSTRAINT bggame_scores_pkey;
+ALTER TABLE bggame_scores ADD PRIMARY KEY (discord_id, variant);
\ No newline at end of file
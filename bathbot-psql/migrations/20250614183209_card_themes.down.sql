@@ -0,0 +1,3 @@
+ALTER TABLE user_configs
+  DROP COLUMN card_theme,
+  DROP COLUMN card_layout;
\ No newline at end of file
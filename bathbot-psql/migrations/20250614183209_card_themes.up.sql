@@ -0,0 +1,3 @@
+ALTER TABLE user_configs
+  ADD COLUMN card_theme INT2,
+  ADD COLUMN card_layout INT2;
\ No newline at end of file
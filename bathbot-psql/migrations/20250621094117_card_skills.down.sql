@@ -0,0 +1 @@
+DROP TABLE IF EXISTS osu_user_card_skills;
\ No newline at end of file
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS osu_user_card_skills (
+    user_id    INT4 NOT NULL,
+    gamemode   INT2 NOT NULL,
+    acc        FLOAT8 NOT NULL,
+    aim        FLOAT8,
+    speed      FLOAT8,
+    strain     FLOAT8,
+    movement   FLOAT8,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, gamemode, created_at)
+);
\ No newline at end of file
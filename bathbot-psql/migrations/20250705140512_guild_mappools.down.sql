@@ -0,0 +1 @@
+DROP TABLE IF EXISTS guild_mappools;
\ No newline at end of file
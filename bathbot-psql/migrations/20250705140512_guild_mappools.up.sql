@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS guild_mappools (
+    guild_id INT8 NOT NULL,
+    name VARCHAR(32) NOT NULL,
+    slot VARCHAR(8) NOT NULL,
+    map_id INT4 NOT NULL,
+    PRIMARY KEY (guild_id, name, slot)
+);
\ No newline at end of file
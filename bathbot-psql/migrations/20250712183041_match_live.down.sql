@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS match_live_channels;
+
+DROP TABLE IF EXISTS match_live_matches;
\ No newline at end of file
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS match_live_matches (
+    match_id INT4 NOT NULL,
+    last_event_id INT8 NOT NULL,
+    mappool VARCHAR,
+    PRIMARY KEY (match_id)
+);
+
+CREATE TABLE IF NOT EXISTS match_live_channels (
+    match_id INT4 NOT NULL,
+    channel_id INT8 NOT NULL,
+    msg_id INT8 NOT NULL,
+    PRIMARY KEY (match_id, channel_id)
+);
\ No newline at end of file
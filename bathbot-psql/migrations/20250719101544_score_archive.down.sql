@@ -0,0 +1 @@
+DROP TABLE IF EXISTS osu_archived_scores;
\ No newline at end of file
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS osu_archived_scores (
+    score_id  INT8 NOT NULL,
+    user_id   INT4 NOT NULL,
+    map_id    INT4 NOT NULL,
+    mapset_id INT4,
+    gamemode  INT2 NOT NULL,
+    mods      VARCHAR NOT NULL,
+    score     INT8 NOT NULL,
+    accuracy  FLOAT4 NOT NULL,
+    max_combo INT4 NOT NULL,
+    grade     INT2 NOT NULL,
+    pp        FLOAT4,
+    ended_at  TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (score_id)
+);
+
+CREATE INDEX IF NOT EXISTS osu_archived_scores_user_mode_ended_at_index ON osu_archived_scores (user_id, gamemode, ended_at DESC);
\ No newline at end of file
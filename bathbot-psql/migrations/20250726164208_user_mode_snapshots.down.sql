@@ -0,0 +1 @@
+DROP TABLE IF EXISTS osu_user_mode_snapshots;
\ No newline at end of file
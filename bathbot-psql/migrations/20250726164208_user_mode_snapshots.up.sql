@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS osu_user_mode_snapshots (
+    user_id      INT4 NOT NULL,
+    gamemode     INT2 NOT NULL,
+    pp           FLOAT4 NOT NULL,
+    global_rank  INT4 NOT NULL,
+    country_rank INT4 NOT NULL,
+    accuracy     FLOAT4 NOT NULL,
+    playcount    INT4 NOT NULL,
+    ranked_score INT8 NOT NULL,
+    total_hits   INT8 NOT NULL,
+    snapshot_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, gamemode, snapshot_at)
+);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS guild_challenges;
\ No newline at end of file
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS guild_challenges (
+    challenge_id SERIAL PRIMARY KEY,
+    guild_id     INT8 NOT NULL,
+    channel_id   INT8 NOT NULL,
+    map_id       INT4 NOT NULL,
+    gamemode     INT2 NOT NULL,
+    mods         VARCHAR(32),
+    starts_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ends_at      TIMESTAMPTZ NOT NULL,
+    announced    BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX IF NOT EXISTS guild_challenges_guild_id_index ON guild_challenges (guild_id);
+
+-- Only one challenge per guild may await its announcement at a time
+CREATE UNIQUE INDEX IF NOT EXISTS guild_challenges_active_guild_id_index ON guild_challenges (guild_id) WHERE NOT announced;
\ No newline at end of file
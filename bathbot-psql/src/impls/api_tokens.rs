@@ -0,0 +1,69 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::database::Database;
+
+impl Database {
+    /// Returns the discord user that owns the given token.
+    pub async fn select_api_token_owner(&self, token: &str) -> Result<Option<Id<UserMarker>>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  discord_id
+FROM
+  api_tokens
+WHERE
+  token_hash = sha256(convert_to($1, 'UTF8'))"#,
+            token
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| Id::new(row.discord_id as u64)))
+    }
+
+    /// Stores the hash of the given token, replacing the user's previous token.
+    pub async fn upsert_api_token(&self, user_id: Id<UserMarker>, token: &str) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO api_tokens (discord_id, token_hash)
+VALUES
+  ($1, sha256(convert_to($2, 'UTF8'))) ON CONFLICT (discord_id) DO
+UPDATE
+SET
+  token_hash = excluded.token_hash,
+  created_at = NOW()"#,
+            user_id.get() as i64,
+            token,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_api_token(&self, user_id: Id<UserMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  api_tokens
+WHERE
+  discord_id = $1"#,
+            user_id.get() as i64,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -28,7 +28,9 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  score_data 
+  score_data, 
+  card_theme, 
+  card_layout 
 FROM 
   user_configs 
 WHERE 
@@ -68,6 +70,59 @@ WHERE
         Ok(osu_id.map(|id| id as u32))
     }
 
+    /// Returns the osu! user ids of all users that linked their account
+    pub async fn select_linked_osu_user_ids(&self) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  DISTINCT osu_id
+FROM
+  user_configs
+WHERE
+  osu_id IS NOT NULL"#
+        );
+
+        let mut rows = query.fetch(self);
+        let mut user_ids = Vec::with_capacity(1024);
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to get next")?;
+
+            if let Some(user_id) = row.osu_id {
+                user_ids.push(user_id as u32);
+            }
+        }
+
+        Ok(user_ids)
+    }
+
+    /// Returns the osu! user ids of the given discord users that linked their
+    /// account
+    pub async fn select_osu_ids_by_discord_ids(&self, discord_ids: &[i64]) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  DISTINCT osu_id
+FROM
+  user_configs
+WHERE
+  discord_id = ANY($1)
+  AND osu_id IS NOT NULL"#,
+            discord_ids,
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| row.osu_id)
+            .map(|user_id| user_id as u32)
+            .collect())
+    }
+
     pub async fn select_all_skins(&self) -> Result<Vec<SkinEntry>> {
         let query = sqlx::query_as!(
             DbSkinEntry,
@@ -262,6 +317,8 @@ FROM
             timezone,
             render_button,
             score_data,
+            card_theme,
+            card_layout,
         } = config;
 
         let query = sqlx::query!(
@@ -269,10 +326,14 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   retries, score_embed, list_size, 
-  timezone_seconds, render_button, score_data
+  timezone_seconds, render_button, score_data, 
+  card_theme, card_layout
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 
+    $12
+  ) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -283,7 +344,9 @@ SET
   list_size = $7, 
   timezone_seconds = $8, 
   render_button = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  card_theme = $11, 
+  card_layout = $12"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -294,6 +357,8 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             score_data.map(i16::from),
+            card_theme.map(i16::from),
+            card_layout.map(i16::from),
         );
 
         query
@@ -10,19 +10,27 @@ use crate::{
 };
 
 impl Database {
-    pub async fn increment_bggame_scores(&self, user_ids: &[i64], amounts: &[i32]) -> Result<()> {
+    pub async fn increment_bggame_scores(
+        &self,
+        user_ids: &[i64],
+        amounts: &[i32],
+        variant: i16,
+    ) -> Result<()> {
         let query = sqlx::query!(
             r#"
-INSERT INTO bggame_scores (discord_id, score) 
+INSERT INTO bggame_scores (discord_id, variant, score) 
 SELECT
-  *
+  discord_id,
+  $3::INT2,
+  score
 FROM
-  UNNEST($1::INT8[], $2::INT4[]) ON CONFLICT (discord_id) DO 
+  UNNEST($1::INT8[], $2::INT4[]) AS scores(discord_id, score) ON CONFLICT (discord_id, variant) DO 
 UPDATE 
 SET 
   score = bggame_scores.score + excluded.score"#,
             user_ids,
             amounts,
+            variant,
         );
 
         query
@@ -33,7 +41,7 @@ SET
         Ok(())
     }
 
-    pub async fn select_bggame_scores(&self) -> Result<Vec<BgGameScore>> {
+    pub async fn select_bggame_scores_by_variant(&self, variant: i16) -> Result<Vec<BgGameScore>> {
         let query = sqlx::query_as!(
             DbBgGameScore,
             r#"
@@ -41,7 +49,10 @@ SELECT
   discord_id, 
   score 
 FROM 
-  bggame_scores"#
+  bggame_scores 
+WHERE 
+  variant = $1"#,
+            variant
         );
 
         let scores = query
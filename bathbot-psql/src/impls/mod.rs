@@ -1,3 +1,4 @@
+mod api_tokens;
 mod bookmarks;
 mod configs;
 mod games;
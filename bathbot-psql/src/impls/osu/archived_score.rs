@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+
+use bathbot_model::{RankingEntries, RankingEntry, ScoreStatsColumn};
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{CountryCode, GameMod, GameMode, Score};
+use time::OffsetDateTime;
+
+use crate::{
+    Database,
+    model::osu::{DbArchivedScore, DbScoreStatsEntry},
+};
+
+impl Database {
+    pub async fn insert_archived_scores(&self, scores: &[Score]) -> Result<()> {
+        let mut vec_score_id = Vec::with_capacity(scores.len());
+        let mut vec_user_id = Vec::with_capacity(scores.len());
+        let mut vec_map_id = Vec::with_capacity(scores.len());
+        let mut vec_mapset_id = Vec::with_capacity(scores.len());
+        let mut vec_gamemode = Vec::with_capacity(scores.len());
+        let mut vec_mods = Vec::with_capacity(scores.len());
+        let mut vec_score = Vec::with_capacity(scores.len());
+        let mut vec_accuracy = Vec::with_capacity(scores.len());
+        let mut vec_max_combo = Vec::with_capacity(scores.len());
+        let mut vec_grade = Vec::with_capacity(scores.len());
+        let mut vec_pp = Vec::with_capacity(scores.len());
+        let mut vec_ended_at = Vec::with_capacity(scores.len());
+
+        for score in scores {
+            let mapset_id = score
+                .mapset
+                .as_ref()
+                .map(|mapset| mapset.mapset_id)
+                .or_else(|| score.map.as_ref().map(|map| map.mapset_id));
+
+            vec_score_id.push(score.id as i64);
+            vec_user_id.push(score.user_id as i32);
+            vec_map_id.push(score.map_id as i32);
+            vec_mapset_id.push(mapset_id.map(|mapset_id| mapset_id as i32));
+            vec_gamemode.push(score.mode as i16);
+            let acronyms = score.mods.iter().map(GameMod::acronym).fold(
+                String::with_capacity(score.mods.len() * 2),
+                |mut acronyms, acronym| {
+                    acronyms.push_str(acronym.as_str());
+
+                    acronyms
+                },
+            );
+
+            vec_mods.push(acronyms);
+            vec_score.push(score.score as i64);
+            vec_accuracy.push(score.accuracy);
+            vec_max_combo.push(score.max_combo as i32);
+            vec_grade.push(score.grade as i16);
+            vec_pp.push(score.pp);
+            vec_ended_at.push(score.ended_at);
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_archived_scores (
+  score_id, user_id, map_id, mapset_id,
+  gamemode, mods, score, accuracy, max_combo,
+  grade, pp, ended_at
+)
+SELECT
+  *
+FROM
+  UNNEST(
+    $1::INT8[], $2::INT4[], $3::INT4[], $4::INT4[],
+    $5::INT2[], $6::VARCHAR[], $7::INT8[], $8::FLOAT4[],
+    $9::INT4[], $10::INT2[], $11::FLOAT4[], $12::TIMESTAMPTZ[]
+  ) ON CONFLICT (score_id) DO NOTHING"#,
+            &vec_score_id,
+            &vec_user_id,
+            &vec_map_id,
+            &vec_mapset_id as _,
+            &vec_gamemode,
+            &vec_mods,
+            &vec_score,
+            &vec_accuracy,
+            &vec_max_combo,
+            &vec_grade,
+            &vec_pp as _,
+            &vec_ended_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns the highest pp scores of the linked discord users that were set
+    /// since the given date.
+    pub async fn select_archived_top_scores(
+        &self,
+        discord_ids: &[i64],
+        mode: GameMode,
+        since: OffsetDateTime,
+        limit: usize,
+    ) -> Result<Vec<DbArchivedScore>> {
+        let query = sqlx::query_as!(
+            DbArchivedScore,
+            r#"
+SELECT
+  scores.score_id,
+  scores.user_id,
+  scores.map_id,
+  scores.mapset_id,
+  scores.gamemode,
+  scores.mods,
+  scores.score,
+  scores.accuracy,
+  scores.max_combo,
+  scores.grade,
+  scores.pp,
+  scores.ended_at
+FROM
+  (
+    SELECT
+      DISTINCT osu_id
+    FROM
+      user_configs
+    WHERE
+      discord_id = ANY($1)
+      AND osu_id IS NOT NULL
+  ) AS configs
+  JOIN osu_archived_scores AS scores ON configs.osu_id = scores.user_id
+WHERE
+  scores.gamemode = $2
+  AND scores.ended_at >= $3
+  AND scores.pp IS NOT NULL
+ORDER BY
+  scores.pp DESC
+LIMIT
+  $4"#,
+            discord_ids,
+            mode as i16,
+            since,
+            limit as i64,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Leaderboard of the linked discord users based on their archived scores
+    /// that were set since the given date.
+    ///
+    /// Ranked score gained sums up, for each map that awards pp, by how much
+    /// the best score within the period improves the previous best score.
+    /// Previous scores are only known if they were archived too.
+    pub async fn select_archived_score_stats(
+        &self,
+        discord_ids: &[i64],
+        mode: GameMode,
+        column: ScoreStatsColumn,
+        since: OffsetDateTime,
+    ) -> Result<RankingEntries> {
+        fn query(value: &str, scores: &str) -> String {
+            format!(
+                r#"
+SELECT 
+  username, 
+  country_code, 
+  {value} AS value 
+FROM 
+  (
+    SELECT 
+      DISTINCT osu_id 
+    FROM 
+      user_configs 
+    WHERE 
+      discord_id = ANY($1) 
+      AND osu_id IS NOT NULL
+  ) AS configs 
+  JOIN osu_user_names AS names ON configs.osu_id = names.user_id 
+  LEFT JOIN osu_user_stats AS stats ON names.user_id = stats.user_id 
+  JOIN ({scores}) AS scores ON names.user_id = scores.user_id 
+GROUP BY 
+  username, 
+  country_code"#
+            )
+        }
+
+        const SCORES: &str = r#"
+    SELECT 
+      user_id, 
+      score, 
+      pp 
+    FROM 
+      osu_archived_scores 
+    WHERE 
+      gamemode = $2 
+      AND ended_at >= $3"#;
+
+        const SCORE_GAIN_PER_MAP: &str = r#"
+    SELECT 
+      curr.user_id, 
+      GREATEST(
+        MAX(curr.score) - COALESCE(
+          (
+            SELECT 
+              MAX(prev.score) 
+            FROM 
+              osu_archived_scores AS prev 
+            WHERE 
+              prev.user_id = curr.user_id 
+              AND prev.map_id = curr.map_id 
+              AND prev.gamemode = $2 
+              AND prev.ended_at < $3 
+              AND prev.pp IS NOT NULL
+          ), 
+          0
+        ), 
+        0
+      ) AS score 
+    FROM 
+      osu_archived_scores AS curr 
+    WHERE 
+      curr.gamemode = $2 
+      AND curr.ended_at >= $3 
+      AND curr.pp IS NOT NULL 
+    GROUP BY 
+      curr.user_id, 
+      curr.map_id"#;
+
+        match column {
+            ScoreStatsColumn::BestPlay => {
+                let query = query("MAX(scores.pp)", SCORES);
+
+                let mut entries: Vec<DbScoreStatsEntry<Option<f32>>> = sqlx::query_as(&query)
+                    .bind(discord_ids)
+                    .bind(mode as i16)
+                    .bind(since)
+                    .fetch_all(self)
+                    .await
+                    .wrap_err("Failed to fetch all")?;
+
+                entries.retain(|entry| entry.value.is_some());
+
+                entries.sort_unstable_by(|a, b| {
+                    b.value
+                        .partial_cmp(&a.value)
+                        .unwrap_or(Ordering::Equal)
+                        .then_with(|| a.name.cmp(&b.name))
+                });
+
+                let entries = entries
+                    .into_iter()
+                    .map(|entry| RankingEntry {
+                        country: entry.country.as_deref().map(CountryCode::from),
+                        name: entry.name.into(),
+                        value: entry.value.unwrap_or(0.0),
+                    })
+                    .enumerate()
+                    .collect();
+
+                Ok(RankingEntries::PpF32(entries))
+            }
+            ScoreStatsColumn::RankedScore | ScoreStatsColumn::Playcount => {
+                let query = if matches!(column, ScoreStatsColumn::RankedScore) {
+                    query("SUM(scores.score)::INT8", SCORE_GAIN_PER_MAP)
+                } else {
+                    query("COUNT(*)::INT8", SCORES)
+                };
+
+                let mut entries: Vec<DbScoreStatsEntry<i64>> = sqlx::query_as(&query)
+                    .bind(discord_ids)
+                    .bind(mode as i16)
+                    .bind(since)
+                    .fetch_all(self)
+                    .await
+                    .wrap_err("Failed to fetch all")?;
+
+                entries.sort_unstable_by(|a, b| {
+                    b.value.cmp(&a.value).then_with(|| a.name.cmp(&b.name))
+                });
+
+                let entries = entries
+                    .into_iter()
+                    .map(|entry| RankingEntry {
+                        country: entry.country.as_deref().map(CountryCode::from),
+                        name: entry.name.into(),
+                        value: entry.value as u64,
+                    })
+                    .enumerate()
+                    .collect();
+
+                Ok(RankingEntries::Amount(entries))
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+
+use crate::{Database, model::osu::DbCardSkills};
+
+impl Database {
+    /// Returns the two most recent card skills of a user that were stored
+    /// strictly before `until`, the most recent one first.
+    pub async fn select_osu_user_card_skills(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        until: OffsetDateTime,
+    ) -> Result<Vec<DbCardSkills>> {
+        let query = sqlx::query_as!(
+            DbCardSkills,
+            r#"
+SELECT
+  acc,
+  aim,
+  speed,
+  strain,
+  movement,
+  created_at
+FROM
+  osu_user_card_skills
+WHERE
+  user_id = $1
+  AND gamemode = $2
+  AND created_at < $3
+ORDER BY
+  created_at DESC
+LIMIT
+  2"#,
+            user_id as i32,
+            mode as i16,
+            until,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn insert_osu_user_card_skills(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        skills: &DbCardSkills,
+    ) -> Result<()> {
+        let DbCardSkills {
+            acc,
+            aim,
+            speed,
+            strain,
+            movement,
+            created_at,
+        } = skills;
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_user_card_skills (
+  user_id, gamemode, acc, aim, speed,
+  strain, movement, created_at
+)
+VALUES
+  ($1, $2, $3, $4, $5, $6, $7, $8)
+ON CONFLICT
+  (user_id, gamemode, created_at)
+DO
+  NOTHING"#,
+            user_id as i32,
+            mode as i16,
+            *acc,
+            *aim,
+            *speed,
+            *strain,
+            *movement,
+            *created_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
+
+use crate::{Database, model::osu::DbGuildChallenge};
+
+impl Database {
+    /// Returns `None` if the guild already has a challenge whose results have
+    /// not been announced yet.
+    pub async fn insert_guild_challenge(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        map_id: u32,
+        mode: GameMode,
+        mods: Option<&str>,
+        ends_at: OffsetDateTime,
+    ) -> Result<Option<DbGuildChallenge>> {
+        let query = sqlx::query_as!(
+            DbGuildChallenge,
+            r#"
+INSERT INTO guild_challenges (
+  guild_id, channel_id, map_id, gamemode, 
+  mods, ends_at
+) 
+VALUES 
+  ($1, $2, $3, $4, $5, $6) ON CONFLICT (guild_id) 
+WHERE 
+  NOT announced DO NOTHING RETURNING challenge_id, 
+  guild_id, 
+  channel_id, 
+  map_id, 
+  gamemode, 
+  mods, 
+  starts_at, 
+  ends_at"#,
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            map_id as i32,
+            mode as i16,
+            mods,
+            ends_at,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Returns the guild's challenge whose results have not been announced
+    /// yet.
+    pub async fn select_active_guild_challenge(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<DbGuildChallenge>> {
+        let query = sqlx::query_as!(
+            DbGuildChallenge,
+            r#"
+SELECT 
+  challenge_id, 
+  guild_id, 
+  channel_id, 
+  map_id, 
+  gamemode, 
+  mods, 
+  starts_at, 
+  ends_at 
+FROM 
+  guild_challenges 
+WHERE 
+  guild_id = $1 
+  AND NOT announced 
+ORDER BY 
+  ends_at DESC 
+LIMIT 
+  1"#,
+            guild_id.get() as i64,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    pub async fn select_latest_guild_challenge(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<DbGuildChallenge>> {
+        let query = sqlx::query_as!(
+            DbGuildChallenge,
+            r#"
+SELECT 
+  challenge_id, 
+  guild_id, 
+  channel_id, 
+  map_id, 
+  gamemode, 
+  mods, 
+  starts_at, 
+  ends_at 
+FROM 
+  guild_challenges 
+WHERE 
+  guild_id = $1 
+ORDER BY 
+  starts_at DESC 
+LIMIT 
+  1"#,
+            guild_id.get() as i64,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Returns all challenges that ended but whose results have not been
+    /// announced yet.
+    pub async fn select_due_guild_challenges(&self) -> Result<Vec<DbGuildChallenge>> {
+        let query = sqlx::query_as!(
+            DbGuildChallenge,
+            r#"
+SELECT 
+  challenge_id, 
+  guild_id, 
+  channel_id, 
+  map_id, 
+  gamemode, 
+  mods, 
+  starts_at, 
+  ends_at 
+FROM 
+  guild_challenges 
+WHERE 
+  NOT announced 
+  AND ends_at <= NOW()"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Ends the guild's ongoing challenge right away.
+    ///
+    /// Returns `false` if there was no ongoing challenge.
+    pub async fn update_guild_challenge_end(&self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  guild_challenges 
+SET 
+  ends_at = NOW() 
+WHERE 
+  guild_id = $1 
+  AND NOT announced 
+  AND ends_at > NOW()"#,
+            guild_id.get() as i64,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn update_guild_challenge_announced(&self, challenge_id: i32) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  guild_challenges 
+SET 
+  announced = TRUE 
+WHERE 
+  challenge_id = $1"#,
+            challenge_id,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
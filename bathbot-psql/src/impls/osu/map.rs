@@ -7,7 +7,7 @@ use sqlx::{Postgres, Transaction};
 
 use crate::{
     Database,
-    model::osu::{DbBeatmap, DbBeatmapset, DbMapContent, MapVersion},
+    model::osu::{DbBeatmap, DbBeatmapset, DbMapAttributes, DbMapContent, MapVersion},
 };
 
 impl Database {
@@ -221,6 +221,46 @@ FROM
         Ok(maps)
     }
 
+    pub async fn select_osu_map_attributes(&self, map_id: u32) -> Result<Option<DbMapAttributes>> {
+        let query = sqlx::query_as!(
+            DbMapAttributes,
+            r#"
+SELECT
+  map.map_id,
+  map.mapset_id,
+  map.user_id AS mapper_id,
+  map.checksum,
+  map.map_version,
+  map.seconds_total,
+  map.seconds_drain,
+  map.count_circles,
+  map.count_sliders,
+  map.count_spinners,
+  map.hp,
+  map.cs,
+  map.od,
+  map.ar,
+  map.bpm,
+  map.gamemode,
+  mapset.artist,
+  mapset.title,
+  mapset.creator,
+  mapset.rank_status,
+  mapset.ranked_date
+FROM
+  osu_maps AS map
+  JOIN osu_mapsets AS mapset ON map.mapset_id = mapset.mapset_id
+WHERE
+  map.map_id = $1"#,
+            map_id as i32
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
     pub async fn select_beatmap_file_content(&self, map_id: u32) -> Result<Option<Vec<u8>>> {
         let query = sqlx::query!(
             r#"
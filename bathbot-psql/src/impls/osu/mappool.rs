@@ -0,0 +1,132 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{Database, model::osu::DbMappoolSlot};
+
+impl Database {
+    pub async fn select_guild_mappool(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<Vec<DbMappoolSlot>> {
+        let query = sqlx::query_as!(
+            DbMappoolSlot,
+            r#"
+SELECT
+  slot,
+  map_id
+FROM
+  guild_mappools
+WHERE
+  guild_id = $1
+  AND name = $2"#,
+            guild_id.get() as i64,
+            name,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_guild_mappool_names(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<String>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  DISTINCT name
+FROM
+  guild_mappools
+WHERE
+  guild_id = $1
+ORDER BY
+  name"#,
+            guild_id.get() as i64,
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.name).collect())
+    }
+
+    /// Stores the mappool, replacing the guild's previous pool of the same
+    /// name.
+    pub async fn upsert_guild_mappool(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        slots: &[String],
+        map_ids: &[i32],
+    ) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  guild_mappools
+WHERE
+  guild_id = $1
+  AND name = $2"#,
+            guild_id.get() as i64,
+            name,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute delete query")?;
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_mappools (guild_id, name, slot, map_id)
+SELECT
+  $1::INT8,
+  $2::VARCHAR,
+  slot,
+  map_id
+FROM
+  UNNEST($3::VARCHAR[], $4::INT4[]) AS slots(slot, map_id)"#,
+            guild_id.get() as i64,
+            name,
+            slots as _,
+            map_ids,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute insert query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    /// Returns whether the mappool existed
+    pub async fn delete_guild_mappool(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  guild_mappools
+WHERE
+  guild_id = $1
+  AND name = $2"#,
+            guild_id.get() as i64,
+            name,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,192 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, MessageMarker},
+};
+
+use crate::{Database, model::osu::DbMatchLiveChannel};
+
+impl Database {
+    /// Returns all channels that track a match alongside the match's state
+    pub async fn select_match_live_channels(&self) -> Result<Vec<DbMatchLiveChannel>> {
+        let query = sqlx::query_as!(
+            DbMatchLiveChannel,
+            r#"
+SELECT
+  matches.match_id,
+  matches.last_event_id,
+  matches.mappool,
+  channels.channel_id,
+  channels.msg_id
+FROM
+  match_live_matches AS matches
+  JOIN match_live_channels AS channels ON matches.match_id = channels.match_id"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_match_live_match(
+        &self,
+        match_id: u32,
+        last_event_id: u64,
+        mappool: Option<&str>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO match_live_matches (match_id, last_event_id, mappool)
+VALUES
+  ($1, $2, $3) ON CONFLICT (match_id) DO
+UPDATE
+SET
+  last_event_id = $2,
+  mappool = $3"#,
+            match_id as i32,
+            last_event_id as i64,
+            mappool,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn update_match_live_event(&self, match_id: u32, last_event_id: u64) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  match_live_matches
+SET
+  last_event_id = $2
+WHERE
+  match_id = $1"#,
+            match_id as i32,
+            last_event_id as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_match_live_channel(
+        &self,
+        match_id: u32,
+        channel: Id<ChannelMarker>,
+        msg: Id<MessageMarker>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO match_live_channels (match_id, channel_id, msg_id)
+VALUES
+  ($1, $2, $3) ON CONFLICT (match_id, channel_id) DO
+UPDATE
+SET
+  msg_id = $3"#,
+            match_id as i32,
+            channel.get() as i64,
+            msg.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Removes the channel's track of the match and the match itself if no
+    /// other channel tracks it anymore
+    pub async fn delete_match_live_channel(
+        &self,
+        match_id: u32,
+        channel: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  match_live_channels
+WHERE
+  match_id = $1
+  AND channel_id = $2"#,
+            match_id as i32,
+            channel.get() as i64,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute channel query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  match_live_matches
+WHERE
+  match_id = $1
+  AND NOT EXISTS (
+    SELECT
+      1
+    FROM
+      match_live_channels
+    WHERE
+      match_id = $1
+  )"#,
+            match_id as i32,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute match query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_match_live_match(&self, match_id: u32) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  match_live_channels
+WHERE
+  match_id = $1"#,
+            match_id as i32,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute channels query")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  match_live_matches
+WHERE
+  match_id = $1"#,
+            match_id as i32,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute match query")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+}
@@ -1,8 +1,15 @@
+pub mod archived_score;
+pub mod card_skills;
+pub mod challenge;
 pub mod map;
+pub mod mappool;
 pub mod mapset;
+pub mod match_live;
 pub mod name;
 pub mod rank_pp;
 pub mod render;
 pub mod score;
+pub mod tracked_digests;
 pub mod tracked_users;
 pub mod user;
+pub mod user_snapshot;
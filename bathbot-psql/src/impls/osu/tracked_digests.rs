@@ -0,0 +1,218 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+
+use crate::{
+    Database,
+    model::osu::{DbTrackedOsuDigest, DbTrackedOsuDigestScore},
+};
+
+impl Database {
+    pub async fn select_tracked_osu_digests(&self) -> Result<Vec<DbTrackedOsuDigest>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuDigest,
+            r#"
+SELECT
+  channel_id,
+  interval,
+  next_post
+FROM
+  tracked_osu_digests"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracked_osu_digest(
+        &self,
+        channel_id: u64,
+        interval: i16,
+        next_post: OffsetDateTime,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_digests (channel_id, interval, next_post)
+VALUES
+  ($1, $2, $3)
+ON CONFLICT
+  (channel_id)
+DO
+  UPDATE
+SET
+  interval = $2,
+  next_post = $3"#,
+            channel_id as i64,
+            interval,
+            next_post,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Removes the digest of a channel alongside all of its buffered scores.
+    pub async fn delete_tracked_osu_digest(&self, channel_id: u64) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("Failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_digests
+WHERE
+  channel_id = $1"#,
+            channel_id as i64,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to delete digest")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_digest_scores
+WHERE
+  channel_id = $1"#,
+            channel_id as i64,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to delete digest scores")?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    /// Removes the buffered scores of a channel for the given mode.
+    pub async fn delete_tracked_osu_digest_scores(
+        &self,
+        channel_id: u64,
+        mode: GameMode,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_digest_scores
+WHERE
+  channel_id = $1
+  AND gamemode = $2"#,
+            channel_id as i64,
+            mode as i16,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn insert_tracked_osu_digest_score(
+        &self,
+        channel_id: u64,
+        score: &DbTrackedOsuDigestScore,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_digest_scores (
+  channel_id, score_id, user_id, username, gamemode, map_id,
+  map_title, mods, pp, top_index, pp_before, pp_after,
+  ended_at
+)
+VALUES
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13
+  )
+ON CONFLICT
+  (channel_id, score_id)
+DO
+  NOTHING"#,
+            channel_id as i64,
+            score.score_id,
+            score.user_id,
+            score.username,
+            score.gamemode,
+            score.map_id,
+            score.map_title,
+            score.mods,
+            score.pp,
+            score.top_index,
+            score.pp_before,
+            score.pp_after,
+            score.ended_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// All buffered scores of a channel.
+    pub async fn select_tracked_osu_digest_scores(
+        &self,
+        channel_id: u64,
+    ) -> Result<Vec<DbTrackedOsuDigestScore>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuDigestScore,
+            r#"
+SELECT
+  score_id,
+  user_id,
+  username,
+  gamemode,
+  map_id,
+  map_title,
+  mods,
+  pp,
+  top_index,
+  pp_before,
+  pp_after,
+  ended_at
+FROM
+  tracked_osu_digest_scores
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Removes the given buffered scores of a channel once they've been
+    /// posted.
+    pub async fn delete_posted_tracked_osu_digest_scores(
+        &self,
+        channel_id: u64,
+        score_ids: &[i64],
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracked_osu_digest_scores
+WHERE
+  channel_id = $1
+  AND score_id = ANY($2)"#,
+            channel_id as i64,
+            score_ids,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -4,7 +4,11 @@ use time::OffsetDateTime;
 
 use crate::{
     Database,
-    model::osu::{DbTrackedOsuUser, DbTrackedOsuUserInChannel},
+    model::osu::{
+        DbTrackedOsuLeaderboardUser, DbTrackedOsuLeaderboardUserInChannel,
+        DbTrackedOsuMilestoneUser, DbTrackedOsuMilestoneUserInChannel, DbTrackedOsuUser,
+        DbTrackedOsuUserInChannel,
+    },
 };
 
 impl Database {
@@ -50,7 +54,12 @@ SELECT
   min_pp,
   max_pp,
   min_combo_percent,
-  max_combo_percent
+  max_combo_percent,
+  mods,
+  mods_kind,
+  min_stars,
+  max_stars,
+  map_statuses
 FROM
   tracked_osu_users
 WHERE
@@ -70,10 +79,13 @@ WHERE
             r#"
 INSERT INTO tracked_osu_users (
   user_id, gamemode, channel_id, min_index, max_index,
-  min_pp, max_pp, min_combo_percent, max_combo_percent
+  min_pp, max_pp, min_combo_percent, max_combo_percent,
+  mods, mods_kind, min_stars, max_stars, map_statuses
 )
 VALUES
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14
+  )
 ON CONFLICT
   (user_id, gamemode, channel_id)
 DO
@@ -84,7 +96,12 @@ SET
     min_pp = $6,
     max_pp = $7,
     min_combo_percent = $8,
-    max_combo_percent = $9"#,
+    max_combo_percent = $9,
+    mods = $10,
+    mods_kind = $11,
+    min_stars = $12,
+    max_stars = $13,
+    map_statuses = $14"#,
             user.user_id,
             user.gamemode,
             channel_id as i64,
@@ -94,6 +111,11 @@ SET
             user.max_pp,
             user.min_combo_percent,
             user.max_combo_percent,
+            user.mods,
+            user.mods_kind,
+            user.min_stars,
+            user.max_stars,
+            user.map_statuses,
         );
 
         query
@@ -194,4 +216,260 @@ WHERE
 
         Ok(())
     }
+
+    pub async fn select_tracked_osu_leaderboard_users(
+        &self,
+    ) -> Result<Vec<DbTrackedOsuLeaderboardUser>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuLeaderboardUser,
+            r#"
+SELECT
+  user_id,
+  gamemode,
+  channel_id,
+  max_global_rank,
+  max_country_rank
+FROM
+  tracked_osu_leaderboard_users"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_tracked_osu_leaderboard_users_channel(
+        &self,
+        channel_id: u64,
+    ) -> Result<Vec<DbTrackedOsuLeaderboardUserInChannel>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuLeaderboardUserInChannel,
+            r#"
+SELECT
+  user_id,
+  gamemode,
+  max_global_rank,
+  max_country_rank
+FROM
+  tracked_osu_leaderboard_users
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracked_osu_leaderboard_user(
+        &self,
+        user: &DbTrackedOsuLeaderboardUserInChannel,
+        channel_id: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_leaderboard_users (
+  user_id, gamemode, channel_id, max_global_rank, max_country_rank
+)
+VALUES
+  ($1, $2, $3, $4, $5)
+ON CONFLICT
+  (user_id, gamemode, channel_id)
+DO
+  UPDATE
+SET
+    max_global_rank = $4,
+    max_country_rank = $5"#,
+            user.user_id,
+            user.gamemode,
+            channel_id as i64,
+            user.max_global_rank,
+            user.max_country_rank,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_leaderboard_user(
+        &self,
+        user_id: u32,
+        mode: Option<GameMode>,
+        channel_id: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_leaderboard_users
+WHERE
+  user_id = $1
+  AND ($2::INT2 is NULL OR gamemode = $2)
+  AND channel_id = $3"#,
+            user_id as i32,
+            mode.map(|mode| mode as i16),
+            channel_id as i64
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_leaderboard_channel(
+        &self,
+        channel_id: u64,
+        mode: Option<GameMode>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_leaderboard_users
+WHERE 
+  channel_id = $1
+  AND ($2::INT2 IS NULL OR gamemode = $2)"#,
+            channel_id as i64,
+            mode.map(|mode| mode as i16) as _,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_tracked_osu_milestone_users(
+        &self,
+    ) -> Result<Vec<DbTrackedOsuMilestoneUser>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuMilestoneUser,
+            r#"
+SELECT
+  user_id,
+  gamemode,
+  channel_id,
+  ranks,
+  pp_step,
+  playcount_step
+FROM
+  tracked_osu_milestone_users"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_tracked_osu_milestone_users_channel(
+        &self,
+        channel_id: u64,
+    ) -> Result<Vec<DbTrackedOsuMilestoneUserInChannel>> {
+        let query = sqlx::query_as!(
+            DbTrackedOsuMilestoneUserInChannel,
+            r#"
+SELECT
+  user_id,
+  gamemode,
+  ranks,
+  pp_step,
+  playcount_step
+FROM
+  tracked_osu_milestone_users
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracked_osu_milestone_user(
+        &self,
+        user: &DbTrackedOsuMilestoneUserInChannel,
+        channel_id: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_milestone_users (
+  user_id, gamemode, channel_id, ranks, pp_step, playcount_step
+)
+VALUES
+  ($1, $2, $3, $4, $5, $6)
+ON CONFLICT
+  (user_id, gamemode, channel_id)
+DO
+  UPDATE
+SET
+    ranks = $4,
+    pp_step = $5,
+    playcount_step = $6"#,
+            user.user_id,
+            user.gamemode,
+            channel_id as i64,
+            &user.ranks,
+            user.pp_step,
+            user.playcount_step,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_milestone_user(
+        &self,
+        user_id: u32,
+        mode: Option<GameMode>,
+        channel_id: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_milestone_users
+WHERE
+  user_id = $1
+  AND ($2::INT2 is NULL OR gamemode = $2)
+  AND channel_id = $3"#,
+            user_id as i32,
+            mode.map(|mode| mode as i16),
+            channel_id as i64
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracked_osu_milestone_channel(
+        &self,
+        channel_id: u64,
+        mode: Option<GameMode>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_milestone_users
+WHERE 
+  channel_id = $1
+  AND ($2::INT2 IS NULL OR gamemode = $2)"#,
+            channel_id as i64,
+            mode.map(|mode| mode as i16) as _,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
 }
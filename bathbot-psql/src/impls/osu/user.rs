@@ -8,7 +8,7 @@ use time::OffsetDateTime;
 
 use crate::{
     Database,
-    model::osu::{DbUserStatsEntry, OsuUserStatsColumnName},
+    model::osu::{DbUserModeSnapshot, DbUserStatsEntry, OsuUserStatsColumnName},
 };
 
 fn convert_entries<V>(entries: Vec<DbUserStatsEntry<V>>) -> Vec<UserStatsEntry<V>> {
@@ -684,7 +684,13 @@ WHERE
         Ok(names)
     }
 
-    pub async fn upsert_osu_user(&self, user: &UserExtended, mode: GameMode) -> Result<()> {
+    /// Stores the user and returns the mode statistics that were previously
+    /// stored for the user, if any.
+    pub async fn upsert_osu_user(
+        &self,
+        user: &UserExtended,
+        mode: GameMode,
+    ) -> Result<Option<DbUserModeSnapshot>> {
         let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
 
         let query = sqlx::query!(
@@ -760,7 +766,31 @@ SET
             .await
             .wrap_err("failed to execute osu_user_stats query")?;
 
+        let mut prev_snapshot = None;
+
         if let Some(ref stats) = user.statistics {
+            let query = sqlx::query_as!(
+                DbUserModeSnapshot,
+                r#"
+SELECT 
+  pp, 
+  global_rank, 
+  playcount 
+FROM 
+  osu_user_mode_stats 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2 
+FOR UPDATE"#,
+                user.user_id as i32,
+                mode as i16,
+            );
+
+            prev_snapshot = query
+                .fetch_optional(&mut *tx)
+                .await
+                .wrap_err("failed to fetch previous osu_user_mode_stats")?;
+
             let query = sqlx::query!(
                 r#"
 INSERT INTO osu_user_mode_stats (
@@ -824,11 +854,64 @@ SET
                 .execute(&mut *tx)
                 .await
                 .wrap_err("failed to execute osu_user_mode_stats query")?;
+
+            // Only extend the history if the stats changed since the latest snapshot
+            let query = sqlx::query!(
+                r#"
+INSERT INTO osu_user_mode_snapshots (
+  user_id, gamemode, pp, global_rank, 
+  country_rank, accuracy, playcount, 
+  ranked_score, total_hits
+) 
+SELECT 
+  $1, $2, $3, $4, $5, $6, $7, $8, $9 
+WHERE 
+  NOT EXISTS (
+    SELECT 
+      1 
+    FROM 
+      (
+        SELECT 
+          pp, 
+          global_rank, 
+          accuracy, 
+          playcount 
+        FROM 
+          osu_user_mode_snapshots 
+        WHERE 
+          user_id = $1 
+          AND gamemode = $2 
+        ORDER BY 
+          snapshot_at DESC 
+        LIMIT 
+          1
+      ) AS latest 
+    WHERE 
+      latest.pp = $3 
+      AND latest.global_rank = $4 
+      AND latest.accuracy = $6 
+      AND latest.playcount = $7
+  ) ON CONFLICT (user_id, gamemode, snapshot_at) DO NOTHING"#,
+                user.user_id as i32,
+                mode as i16,
+                stats.pp,
+                stats.global_rank.unwrap_or(0) as i32,
+                stats.country_rank.unwrap_or(0) as i32,
+                stats.accuracy,
+                stats.playcount as i32,
+                stats.ranked_score as i64,
+                stats.total_hits as i64,
+            );
+
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("failed to execute osu_user_mode_snapshots query")?;
         }
 
         tx.commit().await.wrap_err("failed to commit transaction")?;
 
-        Ok(())
+        Ok(prev_snapshot)
     }
 
     pub async fn delete_osu_user_stats(&self, user_id: u32) -> Result<()> {
@@ -0,0 +1,287 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::{Date, Duration, OffsetDateTime, UtcOffset};
+
+use crate::{Database, model::osu::DbUserStatsHistoryEntry};
+
+impl Database {
+    /// Returns the latest snapshot up to the given date or, if there is none,
+    /// the earliest snapshot after it.
+    pub async fn select_osu_user_snapshot(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        at: OffsetDateTime,
+    ) -> Result<Option<DbUserStatsHistoryEntry>> {
+        let query = sqlx::query_as!(
+            DbUserStatsHistoryEntry,
+            r#"
+SELECT 
+  pp, 
+  global_rank, 
+  country_rank, 
+  accuracy, 
+  playcount, 
+  ranked_score, 
+  total_hits, 
+  snapshot_at 
+FROM 
+  osu_user_mode_snapshots 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2 
+ORDER BY 
+  snapshot_at > $3, 
+  ABS(
+    EXTRACT(
+      EPOCH 
+      FROM 
+        snapshot_at - $3
+    )
+  ) 
+LIMIT 
+  1"#,
+            user_id as i32,
+            mode as i16,
+            at,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    /// Returns all snapshots since the given date, oldest first.
+    pub async fn select_osu_user_snapshots(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        since: OffsetDateTime,
+    ) -> Result<Vec<DbUserStatsHistoryEntry>> {
+        let query = sqlx::query_as!(
+            DbUserStatsHistoryEntry,
+            r#"
+SELECT 
+  pp, 
+  global_rank, 
+  country_rank, 
+  accuracy, 
+  playcount, 
+  ranked_score, 
+  total_hits, 
+  snapshot_at 
+FROM 
+  osu_user_mode_snapshots 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2 
+  AND snapshot_at >= $3 
+ORDER BY 
+  snapshot_at"#,
+            user_id as i32,
+            mode as i16,
+            since,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    /// Thins out old snapshots so the table does not grow indefinitely.
+    ///
+    /// - Snapshots of the last day are all kept
+    /// - Up to 90 days, only the last snapshot of each day is kept
+    /// - Up to two years, only the last snapshot of each week is kept
+    /// - Anything older is removed
+    ///
+    /// Returns the amount of removed snapshots.
+    pub async fn downsample_osu_user_snapshots(&self) -> Result<u64> {
+        let now = OffsetDateTime::now_utc();
+
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  user_id, 
+  gamemode, 
+  snapshot_at 
+FROM 
+  osu_user_mode_snapshots 
+WHERE 
+  snapshot_at < $1 
+ORDER BY 
+  user_id, 
+  gamemode, 
+  snapshot_at"#,
+            now - Duration::DAY,
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        let mut user_ids = Vec::new();
+        let mut gamemodes = Vec::new();
+        let mut snapshot_ats = Vec::new();
+
+        let same_user_mode =
+            rows.chunk_by(|a, b| a.user_id == b.user_id && a.gamemode == b.gamemode);
+
+        for snapshots in same_user_mode {
+            for (i, snapshot) in snapshots.iter().enumerate() {
+                let next = snapshots.get(i + 1).map(|next| next.snapshot_at);
+
+                if is_expendable(now, snapshot.snapshot_at, next) {
+                    user_ids.push(snapshot.user_id);
+                    gamemodes.push(snapshot.gamemode);
+                    snapshot_ats.push(snapshot.snapshot_at);
+                }
+            }
+        }
+
+        if user_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  osu_user_mode_snapshots AS snapshots USING UNNEST(
+    $1::INT4[], $2::INT2[], $3::TIMESTAMPTZ[]
+  ) AS expendable(user_id, gamemode, snapshot_at) 
+WHERE 
+  snapshots.user_id = expendable.user_id 
+  AND snapshots.gamemode = expendable.gamemode 
+  AND snapshots.snapshot_at = expendable.snapshot_at"#,
+            &user_ids,
+            &gamemodes,
+            &snapshot_ats,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+}
+
+/// The period that a snapshot represents after downsampling.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SnapshotBucket {
+    /// Snapshot of the last day
+    Recent,
+    /// Snapshot of the last 90 days, grouped by day
+    Day(Date),
+    /// Snapshot of the last two years, grouped by the week's monday
+    Week(Date),
+}
+
+impl SnapshotBucket {
+    /// Returns `None` if the snapshot is too old to be kept.
+    fn new(now: OffsetDateTime, snapshot_at: OffsetDateTime) -> Option<Self> {
+        let age = now - snapshot_at;
+        let date = snapshot_at.to_offset(UtcOffset::UTC).date();
+
+        if age < Duration::DAY {
+            Some(Self::Recent)
+        } else if age <= Duration::days(90) {
+            Some(Self::Day(date))
+        } else if age <= Duration::days(2 * 365) {
+            let monday = date - Duration::days(date.weekday().number_days_from_monday() as i64);
+
+            Some(Self::Week(monday))
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether a snapshot can be removed because it's either too old or the next
+/// newer snapshot of the same user and mode falls into the same bucket.
+fn is_expendable(
+    now: OffsetDateTime,
+    snapshot_at: OffsetDateTime,
+    next: Option<OffsetDateTime>,
+) -> bool {
+    match SnapshotBucket::new(now, snapshot_at) {
+        None => true,
+        Some(SnapshotBucket::Recent) => false,
+        bucket @ Some(_) => next.is_some_and(|next| SnapshotBucket::new(now, next) == bucket),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+
+    use super::is_expendable;
+
+    // Wednesday, 2025-01-01 12:00 UTC
+    fn now() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_735_732_800).unwrap()
+    }
+
+    #[test]
+    fn keeps_all_recent_snapshots() {
+        let now = now();
+        let at = now - Duration::hours(3);
+        let next = now - Duration::hours(2);
+
+        assert!(!is_expendable(now, at, Some(next)));
+        assert!(!is_expendable(now, next, None));
+    }
+
+    #[test]
+    fn keeps_last_snapshot_per_day() {
+        let now = now();
+        let morning = now - Duration::days(10) - Duration::hours(4);
+        let evening = now - Duration::days(10) + Duration::hours(8);
+        let next_day = now - Duration::days(9);
+
+        assert!(is_expendable(now, morning, Some(evening)));
+        assert!(!is_expendable(now, evening, Some(next_day)));
+        assert!(!is_expendable(now, next_day, None));
+    }
+
+    #[test]
+    fn keeps_last_snapshot_of_day_before_recent() {
+        let now = now();
+        let at = now - Duration::hours(30);
+        let recent = now - Duration::hours(20);
+
+        assert!(!is_expendable(now, at, Some(recent)));
+    }
+
+    #[test]
+    fn keeps_last_snapshot_per_week() {
+        let now = now();
+        // Monday and Saturday of the same week, over 90 days ago
+        let monday = now - Duration::days(100);
+        let saturday = monday + Duration::days(5);
+        let next_monday = monday + Duration::days(7);
+
+        assert!(is_expendable(now, monday, Some(saturday)));
+        assert!(!is_expendable(now, saturday, Some(next_monday)));
+        assert!(!is_expendable(now, next_monday, None));
+    }
+
+    #[test]
+    fn keeps_daily_snapshot_next_to_weekly_one() {
+        let now = now();
+        let weekly = now - Duration::days(91);
+        let daily = now - Duration::days(89);
+
+        assert!(!is_expendable(now, weekly, Some(daily)));
+    }
+
+    #[test]
+    fn removes_snapshots_older_than_two_years() {
+        let now = now();
+        let at = now - Duration::days(2 * 365 + 1);
+
+        assert!(is_expendable(now, at, None));
+        assert!(is_expendable(now, at, Some(now - Duration::days(300))));
+    }
+}
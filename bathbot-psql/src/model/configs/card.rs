@@ -0,0 +1,60 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum CardTheme {
+    #[default]
+    #[option(name = "Classic", value = "classic")]
+    Classic = 0,
+    #[option(name = "Gamemode colors", value = "mode")]
+    Mode = 1,
+    #[option(name = "Midnight", value = "midnight")]
+    Midnight = 2,
+}
+
+impl From<CardTheme> for i16 {
+    fn from(theme: CardTheme) -> Self {
+        theme as Self
+    }
+}
+
+impl TryFrom<i16> for CardTheme {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Classic),
+            1 => Ok(Self::Mode),
+            2 => Ok(Self::Midnight),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum CardLayout {
+    #[default]
+    #[option(name = "Full", value = "full")]
+    Full = 0,
+    #[option(name = "Banner", value = "banner")]
+    Banner = 1,
+}
+
+impl From<CardLayout> for i16 {
+    fn from(layout: CardLayout) -> Self {
+        layout as Self
+    }
+}
+
+impl TryFrom<i16> for CardLayout {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Full),
+            1 => Ok(Self::Banner),
+            _ => Err(()),
+        }
+    }
+}
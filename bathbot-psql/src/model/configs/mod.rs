@@ -1,5 +1,6 @@
 pub use self::{
     authorities::{Authorities, Authority},
+    card::{CardLayout, CardTheme},
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
     list_size::ListSize,
@@ -10,6 +11,7 @@ pub use self::{
 };
 
 mod authorities;
+mod card;
 mod guild;
 mod hide_solutions;
 mod list_size;
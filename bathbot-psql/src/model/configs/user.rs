@@ -3,7 +3,7 @@ use rosu_v2::prelude::{GameMode, Username};
 use sqlx::types::Json;
 use time::UtcOffset;
 
-use super::{Retries, ScoreData, list_size::ListSize};
+use super::{CardLayout, CardTheme, Retries, ScoreData, list_size::ListSize};
 
 pub struct DbUserConfig {
     pub list_size: Option<i16>,
@@ -15,6 +15,8 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub score_data: Option<i16>,
+    pub card_theme: Option<i16>,
+    pub card_layout: Option<i16>,
 }
 
 pub trait OsuId {
@@ -46,6 +48,8 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub score_data: Option<ScoreData>,
+    pub card_theme: Option<CardTheme>,
+    pub card_layout: Option<CardLayout>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -61,6 +65,8 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             score_data: None,
+            card_theme: None,
+            card_layout: None,
         }
     }
 }
@@ -78,6 +84,8 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             score_data,
+            card_theme,
+            card_layout,
         } = config;
 
         Self {
@@ -92,6 +100,8 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            card_theme: card_theme.map(CardTheme::try_from).and_then(Result::ok),
+            card_layout: card_layout.map(CardLayout::try_from).and_then(Result::ok),
         }
     }
 }
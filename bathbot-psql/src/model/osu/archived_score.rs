@@ -0,0 +1,17 @@
+use time::OffsetDateTime;
+
+pub struct DbArchivedScore {
+    pub score_id: i64,
+    pub user_id: i32,
+    pub map_id: i32,
+    pub mapset_id: Option<i32>,
+    pub gamemode: i16,
+    /// Concatenated acronyms
+    pub mods: String,
+    pub score: i64,
+    pub accuracy: f32,
+    pub max_combo: i32,
+    pub grade: i16,
+    pub pp: Option<f32>,
+    pub ended_at: OffsetDateTime,
+}
@@ -0,0 +1,13 @@
+use time::OffsetDateTime;
+
+/// Skill values of a user card.
+///
+/// Only the values that are relevant for the gamemode are set.
+pub struct DbCardSkills {
+    pub acc: f64,
+    pub aim: Option<f64>,
+    pub speed: Option<f64>,
+    pub strain: Option<f64>,
+    pub movement: Option<f64>,
+    pub created_at: OffsetDateTime,
+}
@@ -0,0 +1,12 @@
+use time::OffsetDateTime;
+
+pub struct DbGuildChallenge {
+    pub challenge_id: i32,
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub map_id: i32,
+    pub gamemode: i16,
+    pub mods: Option<String>,
+    pub starts_at: OffsetDateTime,
+    pub ends_at: OffsetDateTime,
+}
@@ -3,6 +3,7 @@ use bathbot_util::{
     query::{FilterCriteria, RegularCriteria, Searchable},
 };
 use rkyv::{Archive, Deserialize, Serialize};
+use time::OffsetDateTime;
 
 #[derive(Clone)]
 pub struct DbBeatmap {
@@ -34,6 +35,31 @@ impl Searchable<RegularCriteria<'_>> for DbBeatmap {
     }
 }
 
+/// Stored metadata and attributes of a map without its file content.
+pub struct DbMapAttributes {
+    pub map_id: i32,
+    pub mapset_id: i32,
+    pub mapper_id: i32,
+    pub checksum: String,
+    pub map_version: String,
+    pub seconds_total: i32,
+    pub seconds_drain: i32,
+    pub count_circles: i32,
+    pub count_sliders: i32,
+    pub count_spinners: i32,
+    pub hp: f32,
+    pub cs: f32,
+    pub od: f32,
+    pub ar: f32,
+    pub bpm: f32,
+    pub gamemode: i16,
+    pub artist: String,
+    pub title: String,
+    pub creator: String,
+    pub rank_status: i16,
+    pub ranked_date: Option<OffsetDateTime>,
+}
+
 #[derive(Debug)]
 pub enum DbMapContent {
     Present(Vec<u8>),
@@ -0,0 +1,4 @@
+pub struct DbMappoolSlot {
+    pub slot: String,
+    pub map_id: i32,
+}
@@ -0,0 +1,7 @@
+pub struct DbMatchLiveChannel {
+    pub match_id: i32,
+    pub last_event_id: i64,
+    pub mappool: Option<String>,
+    pub channel_id: i64,
+    pub msg_id: i64,
+}
@@ -1,7 +1,16 @@
-pub use self::{bookmark::*, map::*, mapset::*, tracked_user::*, user::*};
+pub use self::{
+    archived_score::*, bookmark::*, card_skills::*, challenge::*, map::*, mappool::*, mapset::*,
+    match_live::*, tracked_digest::*, tracked_user::*, user::*,
+};
 
+mod archived_score;
 mod bookmark;
+mod card_skills;
+mod challenge;
 mod map;
+mod mappool;
 mod mapset;
+mod match_live;
+mod tracked_digest;
 mod tracked_user;
 mod user;
@@ -0,0 +1,22 @@
+use time::OffsetDateTime;
+
+pub struct DbTrackedOsuDigest {
+    pub channel_id: i64,
+    pub interval: i16,
+    pub next_post: OffsetDateTime,
+}
+
+pub struct DbTrackedOsuDigestScore {
+    pub score_id: i64,
+    pub user_id: i32,
+    pub username: String,
+    pub gamemode: i16,
+    pub map_id: i32,
+    pub map_title: String,
+    pub mods: String,
+    pub pp: f32,
+    pub top_index: i16,
+    pub pp_before: f32,
+    pub pp_after: f32,
+    pub ended_at: OffsetDateTime,
+}
@@ -10,6 +10,11 @@ pub struct DbTrackedOsuUser {
     pub max_pp: Option<f32>,
     pub min_combo_percent: Option<f32>,
     pub max_combo_percent: Option<f32>,
+    pub mods: Option<String>,
+    pub mods_kind: Option<i16>,
+    pub min_stars: Option<f32>,
+    pub max_stars: Option<f32>,
+    pub map_statuses: Option<i16>,
     pub last_pp: f32,
     pub last_updated: OffsetDateTime,
 }
@@ -23,4 +28,41 @@ pub struct DbTrackedOsuUserInChannel {
     pub max_pp: Option<f32>,
     pub min_combo_percent: Option<f32>,
     pub max_combo_percent: Option<f32>,
+    pub mods: Option<String>,
+    pub mods_kind: Option<i16>,
+    pub min_stars: Option<f32>,
+    pub max_stars: Option<f32>,
+    pub map_statuses: Option<i16>,
+}
+
+pub struct DbTrackedOsuLeaderboardUser {
+    pub user_id: i32,
+    pub gamemode: i16,
+    pub channel_id: i64,
+    pub max_global_rank: Option<i16>,
+    pub max_country_rank: Option<i16>,
+}
+
+pub struct DbTrackedOsuLeaderboardUserInChannel {
+    pub user_id: i32,
+    pub gamemode: i16,
+    pub max_global_rank: Option<i16>,
+    pub max_country_rank: Option<i16>,
+}
+
+pub struct DbTrackedOsuMilestoneUser {
+    pub user_id: i32,
+    pub gamemode: i16,
+    pub channel_id: i64,
+    pub ranks: Vec<i32>,
+    pub pp_step: Option<i32>,
+    pub playcount_step: Option<i32>,
+}
+
+pub struct DbTrackedOsuMilestoneUserInChannel {
+    pub user_id: i32,
+    pub gamemode: i16,
+    pub ranks: Vec<i32>,
+    pub pp_step: Option<i32>,
+    pub playcount_step: Option<i32>,
 }
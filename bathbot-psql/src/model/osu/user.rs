@@ -1,5 +1,6 @@
 use bathbot_model::{UserModeStatsColumn, UserStatsColumn};
 use sqlx::{Database, Decode, FromRow, Postgres, Type, error::BoxDynError, postgres::PgTypeInfo};
+use time::OffsetDateTime;
 
 struct DbCountryCode {
     inner: [u8; 2],
@@ -42,6 +43,36 @@ pub struct DbUserStatsEntry<V> {
     pub value: V,
 }
 
+/// Same as [`DbUserStatsEntry`] but the country is missing if the user's
+/// stats were not stored.
+#[derive(FromRow)]
+pub struct DbScoreStatsEntry<V> {
+    #[sqlx(rename = "country_code")]
+    pub country: Option<String>,
+    #[sqlx(rename = "username")]
+    pub name: String,
+    pub value: V,
+}
+
+/// Mode statistics of a user as they were stored before an update.
+pub struct DbUserModeSnapshot {
+    pub pp: f32,
+    pub global_rank: i32,
+    pub playcount: i32,
+}
+
+/// Entry of the append-only history of a user's mode statistics.
+pub struct DbUserStatsHistoryEntry {
+    pub pp: f32,
+    pub global_rank: i32,
+    pub country_rank: i32,
+    pub accuracy: f32,
+    pub playcount: i32,
+    pub ranked_score: i64,
+    pub total_hits: i64,
+    pub snapshot_at: OffsetDateTime,
+}
+
 pub trait OsuUserStatsColumn {
     type Stats;
     type Value;
@@ -10,5 +10,5 @@ mod state;
 pub use self::{
     server::Server,
     standby::{AuthenticationStandby, AuthenticationStandbyError},
-    state::AppStateBuilder,
+    state::{AppStateBuilder, ChannelAccess, ChannelAccessCheck},
 };
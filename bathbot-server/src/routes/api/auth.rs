@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use axum::{body::Body, extract::State, middleware::Next, response::Response};
+use hyper::{Request, header::AUTHORIZATION};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::error::ApiError;
+use crate::state::AppState;
+
+/// Discord user that authenticated the current request.
+#[derive(Copy, Clone)]
+pub struct ApiUser(pub Id<UserMarker>);
+
+/// Rejects requests without a valid `Authorization: Bearer <token>` header
+/// and makes the token's owner available as [`ApiUser`] extension.
+pub async fn authenticate(
+    State(state): State<Arc<AppState>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let token = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or(ApiError::MissingToken)?;
+
+    let user_id = state
+        .psql
+        .select_api_token_owner(token)
+        .await
+        .map_err(ApiError::Database)?
+        .ok_or(ApiError::InvalidToken)?;
+
+    req.extensions_mut().insert(ApiUser(user_id));
+
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::State};
+use bathbot_psql::model::osu::MapBookmark;
+use serde::Serialize;
+
+use super::{auth::ApiUser, error::ApiError};
+use crate::state::AppState;
+
+pub async fn get_bookmarks(
+    State(state): State<Arc<AppState>>,
+    Extension(ApiUser(user_id)): Extension<ApiUser>,
+) -> Result<Json<Vec<Bookmark>>, ApiError> {
+    let bookmarks = state
+        .psql
+        .select_user_bookmarks(user_id)
+        .await
+        .map_err(ApiError::Database)?;
+
+    Ok(Json(bookmarks.into_iter().map(Bookmark::from).collect()))
+}
+
+#[derive(Serialize)]
+pub struct Bookmark {
+    inserted_at: i64,
+    map_id: u32,
+    mapset_id: u32,
+    mapper_id: u32,
+    creator_id: u32,
+    creator_name: Box<str>,
+    artist: Box<str>,
+    title: Box<str>,
+    version: Box<str>,
+    mode: u8,
+    hp: f32,
+    cs: f32,
+    od: f32,
+    ar: f32,
+    bpm: f32,
+    count_circles: u32,
+    count_sliders: u32,
+    count_spinners: u32,
+    seconds_drain: u32,
+    seconds_total: u32,
+    status: i8,
+    ranked_at: Option<i64>,
+    genre: u8,
+    language: u8,
+    cover_url: Box<str>,
+}
+
+impl From<MapBookmark> for Bookmark {
+    fn from(bookmark: MapBookmark) -> Self {
+        Self {
+            inserted_at: bookmark.insert_date.unix_timestamp(),
+            map_id: bookmark.map_id,
+            mapset_id: bookmark.mapset_id,
+            mapper_id: bookmark.mapper_id,
+            creator_id: bookmark.creator_id,
+            creator_name: bookmark.creator_name,
+            artist: bookmark.artist,
+            title: bookmark.title,
+            version: bookmark.version,
+            mode: bookmark.mode as u8,
+            hp: bookmark.hp,
+            cs: bookmark.cs,
+            od: bookmark.od,
+            ar: bookmark.ar,
+            bpm: bookmark.bpm,
+            count_circles: bookmark.count_circles,
+            count_sliders: bookmark.count_sliders,
+            count_spinners: bookmark.count_spinners,
+            seconds_drain: bookmark.seconds_drain,
+            seconds_total: bookmark.seconds_total,
+            status: bookmark.status as i8,
+            ranked_at: bookmark.ranked_date.map(|date| date.unix_timestamp()),
+            genre: bookmark.genre as u8,
+            language: bookmark.language as u8,
+            cover_url: bookmark.cover_url,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use eyre::Report;
+use hyper::StatusCode;
+use serde::Serialize;
+
+#[derive(Debug, thiserror::Error)]
+#[error("api error")]
+pub enum ApiError {
+    #[error("missing bearer token")]
+    MissingToken,
+    #[error("unknown token")]
+    InvalidToken,
+    #[error("invalid channel id")]
+    InvalidChannel,
+    #[error("unknown bg game variant")]
+    UnknownVariant,
+    #[error("unknown higherlower version")]
+    UnknownVersion,
+    #[error("map not found")]
+    MapNotFound,
+    #[error("unknown channel")]
+    UnknownChannel,
+    #[error("missing access to channel")]
+    MissingAccess,
+    #[error("database error")]
+    Database(Report),
+    #[error("failed to check channel access")]
+    ChannelAccess(Report),
+}
+
+impl ApiError {
+    pub fn response(&self) -> (StatusCode, &'static str) {
+        match self {
+            Self::MissingToken => (StatusCode::UNAUTHORIZED, "Missing bearer token"),
+            Self::InvalidToken => (StatusCode::UNAUTHORIZED, "Invalid token"),
+            Self::InvalidChannel => (StatusCode::BAD_REQUEST, "Invalid channel id"),
+            Self::UnknownVariant => (StatusCode::BAD_REQUEST, "Unknown variant"),
+            Self::UnknownVersion => (StatusCode::BAD_REQUEST, "Unknown version"),
+            Self::MapNotFound => (StatusCode::NOT_FOUND, "Map not found"),
+            Self::UnknownChannel => (StatusCode::NOT_FOUND, "Unknown channel"),
+            Self::MissingAccess => (StatusCode::FORBIDDEN, "Missing access to channel"),
+            Self::Database(_) | Self::ChannelAccess(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
+            }
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        if let Self::Database(ref err) | Self::ChannelAccess(ref err) = self {
+            error!(?err, "Failed to handle api request");
+        }
+
+        let (status_code, error) = self.response();
+
+        (status_code, Json(ErrorBody { error })).into_response()
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: &'static str,
+}
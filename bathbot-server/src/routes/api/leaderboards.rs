@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use bathbot_model::{BgGameVariant, HlVersion};
+use serde::{Deserialize, Serialize};
+
+use super::error::ApiError;
+use crate::state::AppState;
+
+#[derive(Deserialize)]
+pub struct BgParams {
+    variant: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct HlParams {
+    version: Option<String>,
+}
+
+pub async fn get_bg_leaderboard(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<BgParams>,
+) -> Result<Json<Vec<LeaderboardEntry>>, ApiError> {
+    let variant = match params.variant {
+        Some(variant) => variant
+            .parse::<BgGameVariant>()
+            .map_err(|_| ApiError::UnknownVariant)?,
+        None => BgGameVariant::default(),
+    };
+
+    let scores = state
+        .psql
+        .select_bggame_scores_by_variant(variant as i16)
+        .await
+        .map_err(ApiError::Database)?;
+
+    let entries = scores
+        .into_iter()
+        .map(|score| LeaderboardEntry::new(score.discord_id, score.score))
+        .collect();
+
+    Ok(Json(LeaderboardEntry::sort(entries)))
+}
+
+pub async fn get_hl_leaderboard(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<HlParams>,
+) -> Result<Json<Vec<LeaderboardEntry>>, ApiError> {
+    let version = match params.version {
+        Some(version) => version
+            .parse::<HlVersion>()
+            .map_err(|_| ApiError::UnknownVersion)?,
+        None => HlVersion::ScorePp,
+    };
+
+    let scores = state
+        .psql
+        .select_higherlower_scores_by_version(version as i16)
+        .await
+        .map_err(ApiError::Database)?;
+
+    let entries = scores
+        .into_iter()
+        .map(|score| LeaderboardEntry::new(score.discord_id, score.highscore))
+        .collect();
+
+    Ok(Json(LeaderboardEntry::sort(entries)))
+}
+
+#[derive(Serialize)]
+pub struct LeaderboardEntry {
+    /// Discord user id as string since it may exceed javascript's integer
+    /// precision
+    discord_id: String,
+    score: u32,
+}
+
+impl LeaderboardEntry {
+    fn new(discord_id: i64, score: i32) -> Self {
+        Self {
+            discord_id: discord_id.to_string(),
+            score: score as u32,
+        }
+    }
+
+    fn sort(mut entries: Vec<Self>) -> Vec<Self> {
+        entries.sort_unstable_by(|a, b| b.score.cmp(&a.score));
+
+        entries
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use bathbot_psql::model::osu::DbMapAttributes;
+use serde::Serialize;
+
+use super::error::ApiError;
+use crate::state::AppState;
+
+pub async fn get_map(
+    State(state): State<Arc<AppState>>,
+    Path(map_id): Path<u32>,
+) -> Result<Json<MapAttributes>, ApiError> {
+    state
+        .psql
+        .select_osu_map_attributes(map_id)
+        .await
+        .map_err(ApiError::Database)?
+        .map(MapAttributes::from)
+        .map(Json)
+        .ok_or(ApiError::MapNotFound)
+}
+
+#[derive(Serialize)]
+pub struct MapAttributes {
+    map_id: u32,
+    mapset_id: u32,
+    mapper_id: u32,
+    checksum: String,
+    version: String,
+    artist: String,
+    title: String,
+    creator: String,
+    mode: u8,
+    status: i8,
+    ranked_at: Option<i64>,
+    seconds_total: u32,
+    seconds_drain: u32,
+    count_circles: u32,
+    count_sliders: u32,
+    count_spinners: u32,
+    hp: f32,
+    cs: f32,
+    od: f32,
+    ar: f32,
+    bpm: f32,
+}
+
+impl From<DbMapAttributes> for MapAttributes {
+    fn from(map: DbMapAttributes) -> Self {
+        Self {
+            map_id: map.map_id as u32,
+            mapset_id: map.mapset_id as u32,
+            mapper_id: map.mapper_id as u32,
+            checksum: map.checksum,
+            version: map.map_version,
+            artist: map.artist,
+            title: map.title,
+            creator: map.creator,
+            mode: map.gamemode as u8,
+            status: map.rank_status as i8,
+            ranked_at: map.ranked_date.map(|date| date.unix_timestamp()),
+            seconds_total: map.seconds_total as u32,
+            seconds_drain: map.seconds_drain as u32,
+            count_circles: map.count_circles as u32,
+            count_sliders: map.count_sliders as u32,
+            count_spinners: map.count_spinners as u32,
+            hp: map.hp,
+            cs: map.cs,
+            od: map.od,
+            ar: map.ar,
+            bpm: map.bpm,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+pub mod auth;
+pub mod bookmarks;
+pub mod error;
+pub mod leaderboards;
+pub mod maps;
+pub mod tracked;
+
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Arc};
+
+    use axum::{
+        Router,
+        body::{self, Body},
+    };
+    use bathbot_psql::Database;
+    use bathbot_util::MetricsReader;
+    use handlebars::Handlebars;
+    use hyper::{Request, StatusCode, header::AUTHORIZATION};
+    use metrics_exporter_prometheus::PrometheusBuilder;
+    use serde_json::Value;
+    use tower::ServiceExt;
+    use twilight_model::id::Id;
+
+    use crate::{
+        server::Server,
+        standby::AuthenticationStandby,
+        state::{AppState, ChannelAccess, https_client},
+    };
+
+    /// Requires a migrated database at `DATABASE_URL`.
+    fn state() -> Arc<AppState> {
+        let _ = dotenvy::dotenv();
+        let database_url = env::var("DATABASE_URL").expect("missing DATABASE_URL");
+
+        Arc::new(AppState {
+            client: https_client().unwrap(),
+            handlebars: Handlebars::new(),
+            prometheus: PrometheusBuilder::new().build_recorder().handle(),
+            metrics_reader: MetricsReader::new(),
+            osu_client_id: 0,
+            osu_client_secret: Box::default(),
+            twitch_client_id: Box::default(),
+            twitch_token: Box::default(),
+            redirect_base: Box::default(),
+            standby: Arc::new(AuthenticationStandby::new()),
+            psql: Database::new(&database_url).unwrap(),
+            channel_access: Box::new(|_, channel| {
+                let access = match channel.get() {
+                    1 => ChannelAccess::Granted,
+                    2 => ChannelAccess::Denied,
+                    _ => ChannelAccess::UnknownChannel,
+                };
+
+                Box::pin(async move { Ok(access) })
+            }),
+        })
+    }
+
+    fn app(state: &Arc<AppState>) -> Router {
+        Router::new()
+            .nest("/api/v1", Server::api_app(Arc::clone(state)))
+            .with_state(Arc::clone(state))
+    }
+
+    async fn get(state: &Arc<AppState>, uri: &str, token: Option<&str>) -> (StatusCode, Value) {
+        let mut req = Request::builder().uri(uri);
+
+        if let Some(token) = token {
+            req = req.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+
+        let res = app(state)
+            .oneshot(req.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = res.status();
+        let bytes = body::to_bytes(res.into_body(), usize::MAX).await.unwrap();
+
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database at DATABASE_URL"]
+    async fn missing_token() {
+        let state = state();
+        let (status, body) = get(&state, "/api/v1/leaderboards/bg", None).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["error"], "Missing bearer token");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database at DATABASE_URL"]
+    async fn unknown_token() {
+        let state = state();
+        let (status, _) = get(&state, "/api/v1/bookmarks", Some("not-a-token")).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database at DATABASE_URL"]
+    async fn authenticated_endpoints() {
+        const TOKEN: &str = "bathbot-server-test-token";
+
+        let state = state();
+        let user_id = Id::new(1);
+        state.psql.upsert_api_token(user_id, TOKEN).await.unwrap();
+
+        // Gather all responses first so that the token is removed again even
+        // if an assertion fails
+        let bookmarks = get(&state, "/api/v1/bookmarks", Some(TOKEN)).await;
+        let tracked = get(&state, "/api/v1/channels/1/tracked", Some(TOKEN)).await;
+        let tracked_denied = get(&state, "/api/v1/channels/2/tracked", Some(TOKEN)).await;
+        let tracked_unknown = get(&state, "/api/v1/channels/3/tracked", Some(TOKEN)).await;
+        let tracked_invalid = get(&state, "/api/v1/channels/0/tracked", Some(TOKEN)).await;
+        let bg = get(
+            &state,
+            "/api/v1/leaderboards/bg?variant=mapper",
+            Some(TOKEN),
+        )
+        .await;
+        let bg_unknown = get(
+            &state,
+            "/api/v1/leaderboards/bg?variant=unknown",
+            Some(TOKEN),
+        )
+        .await;
+        let hl = get(
+            &state,
+            "/api/v1/leaderboards/hl?version=medal_rarity",
+            Some(TOKEN),
+        )
+        .await;
+        let map = get(&state, "/api/v1/maps/0", Some(TOKEN)).await;
+
+        let deleted = state.psql.delete_api_token(user_id).await.unwrap();
+        let (status, _) = get(&state, "/api/v1/bookmarks", Some(TOKEN)).await;
+
+        assert!(deleted);
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        assert_eq!(bookmarks.0, StatusCode::OK);
+        assert!(bookmarks.1.is_array());
+
+        assert_eq!(tracked.0, StatusCode::OK);
+        assert!(tracked.1.is_array());
+
+        assert_eq!(tracked_denied.0, StatusCode::FORBIDDEN);
+        assert_eq!(tracked_denied.1["error"], "Missing access to channel");
+        assert_eq!(tracked_unknown.0, StatusCode::NOT_FOUND);
+        assert_eq!(tracked_invalid.0, StatusCode::BAD_REQUEST);
+
+        assert_eq!(bg.0, StatusCode::OK);
+
+        let scores: Vec<_> =
+            bg.1.as_array()
+                .unwrap()
+                .iter()
+                .map(|entry| entry["score"].as_u64().unwrap())
+                .collect();
+        assert!(scores.is_sorted_by(|a, b| a >= b));
+
+        assert_eq!(bg_unknown.0, StatusCode::BAD_REQUEST);
+        assert_eq!(hl.0, StatusCode::OK);
+
+        assert_eq!(map.0, StatusCode::NOT_FOUND);
+        assert_eq!(map.1["error"], "Map not found");
+    }
+}
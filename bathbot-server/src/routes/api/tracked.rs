@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use bathbot_psql::model::osu::DbTrackedOsuUserInChannel;
+use serde::Serialize;
+use twilight_model::id::Id;
+
+use super::{auth::ApiUser, error::ApiError};
+use crate::state::{AppState, ChannelAccess};
+
+pub async fn get_tracked_users(
+    State(state): State<Arc<AppState>>,
+    Extension(ApiUser(user_id)): Extension<ApiUser>,
+    Path(channel_id): Path<u64>,
+) -> Result<Json<Vec<TrackedUser>>, ApiError> {
+    let Some(channel) = Id::new_checked(channel_id) else {
+        return Err(ApiError::InvalidChannel);
+    };
+
+    let access = (state.channel_access)(user_id, channel)
+        .await
+        .map_err(ApiError::ChannelAccess)?;
+
+    match access {
+        ChannelAccess::Granted => {}
+        ChannelAccess::Denied => return Err(ApiError::MissingAccess),
+        ChannelAccess::UnknownChannel => return Err(ApiError::UnknownChannel),
+    }
+
+    let users = state
+        .psql
+        .select_tracked_osu_users_channel(channel_id)
+        .await
+        .map_err(ApiError::Database)?;
+
+    Ok(Json(users.into_iter().map(TrackedUser::from).collect()))
+}
+
+#[derive(Serialize)]
+pub struct TrackedUser {
+    user_id: u32,
+    mode: u8,
+    min_index: Option<u8>,
+    max_index: Option<u8>,
+    min_pp: Option<f32>,
+    max_pp: Option<f32>,
+    min_combo_percent: Option<f32>,
+    max_combo_percent: Option<f32>,
+    mods_include: Option<u32>,
+    mods_exclude: Option<u32>,
+    min_stars: Option<f32>,
+    max_stars: Option<f32>,
+    map_statuses: Option<u8>,
+}
+
+impl From<DbTrackedOsuUserInChannel> for TrackedUser {
+    fn from(user: DbTrackedOsuUserInChannel) -> Self {
+        Self {
+            user_id: user.user_id as u32,
+            mode: user.gamemode as u8,
+            min_index: user.min_index.map(|idx| idx as u8),
+            max_index: user.max_index.map(|idx| idx as u8),
+            min_pp: user.min_pp,
+            max_pp: user.max_pp,
+            min_combo_percent: user.min_combo_percent,
+            max_combo_percent: user.max_combo_percent,
+            mods_include: user.mods_include.map(|mods| mods as u32),
+            mods_exclude: user.mods_exclude.map(|mods| mods as u32),
+            min_stars: user.min_stars,
+            max_stars: user.max_stars,
+            map_statuses: user.map_statuses.map(|statuses| statuses as u8),
+        }
+    }
+}
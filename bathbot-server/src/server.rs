@@ -20,6 +20,13 @@ use crate::{
     AppStateBuilder,
     middleware::metrics::track_metrics,
     routes::{
+        api::{
+            auth::authenticate,
+            bookmarks::get_bookmarks,
+            leaderboards::{get_bg_leaderboard, get_hl_leaderboard},
+            maps::get_map,
+            tracked::get_tracked_users,
+        },
         auth::{osu::auth_osu, twitch::auth_twitch},
         guild_count::get_guild_count,
         metrics::get_metrics,
@@ -95,12 +102,24 @@ impl Server {
             .route("/metrics", get(get_metrics))
             .route("/guild_count", get(get_guild_count))
             .nest("/auth", Self::auth_app(website_path))
+            .nest("/api/v1", Self::api_app(Arc::clone(&state)))
             .route("/osudirect/{mapset_id}", get(redirect_osudirect))
             .layer(CorsLayer::permissive())
             .layer(middleware::from_fn_with_state(state, track_metrics))
             .layer(trace)
     }
 
+    /// Read-only endpoints that require a token issued through discord.
+    pub(crate) fn api_app(state: Arc<AppState>) -> Router<Arc<AppState>> {
+        Router::new()
+            .route("/bookmarks", get(get_bookmarks))
+            .route("/channels/{channel_id}/tracked", get(get_tracked_users))
+            .route("/leaderboards/bg", get(get_bg_leaderboard))
+            .route("/leaderboards/hl", get(get_hl_leaderboard))
+            .route("/maps/{map_id}", get(get_map))
+            .route_layer(middleware::from_fn_with_state(state, authenticate))
+    }
+
     fn auth_app(website_path: PathBuf) -> Router<Arc<AppState>> {
         let mut auth_assets = website_path;
         auth_assets.push("assets/auth");
@@ -1,6 +1,7 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{future::Future, path::PathBuf, pin::Pin, sync::Arc};
 
 use axum::body::Bytes;
+use bathbot_psql::Database;
 use bathbot_util::MetricsReader;
 use eyre::{Result, WrapErr};
 use handlebars::Handlebars;
@@ -12,6 +13,10 @@ use hyper_util::{
 };
 use metrics::describe_histogram;
 use metrics_exporter_prometheus::PrometheusHandle;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
 
 use crate::standby::AuthenticationStandby;
 
@@ -26,8 +31,32 @@ pub struct AppState {
     pub twitch_token: Box<str>,
     pub redirect_base: Box<str>,
     pub standby: Arc<AuthenticationStandby>,
+    pub psql: Database,
+    pub channel_access: ChannelAccessCheck,
+}
+
+/// Whether a discord user is allowed to view a channel.
+pub enum ChannelAccess {
+    Granted,
+    Denied,
+    /// The channel does not exist, is not a guild channel, or is not visible
+    /// to the bot.
+    UnknownChannel,
 }
 
+/// Checks whether the user is a member of the channel's guild and has
+/// permission to view the channel.
+///
+/// Provided by the bot since the server itself has no access to discord.
+pub type ChannelAccessCheck = Box<
+    dyn Fn(
+            Id<UserMarker>,
+            Id<ChannelMarker>,
+        ) -> Pin<Box<dyn Future<Output = Result<ChannelAccess>> + Send>>
+        + Send
+        + Sync,
+>;
+
 pub struct AppStateBuilder {
     pub website_path: PathBuf,
     pub prometheus: PrometheusHandle,
@@ -37,6 +66,8 @@ pub struct AppStateBuilder {
     pub twitch_client_id: String,
     pub twitch_token: String,
     pub redirect_base: String,
+    pub database_url: String,
+    pub channel_access: ChannelAccessCheck,
 }
 
 impl AppStateBuilder {
@@ -50,20 +81,12 @@ impl AppStateBuilder {
             twitch_client_id,
             twitch_token,
             redirect_base,
+            database_url,
+            channel_access,
         } = self;
 
-        let crypto_provider = rustls::crypto::ring::default_provider();
-
-        let https = HttpsConnectorBuilder::new()
-            .with_provider_and_webpki_roots(crypto_provider)
-            .wrap_err("Failed to configure https connector")?
-            .https_only()
-            .enable_http2()
-            .build();
-
-        let client = Builder::new(TokioExecutor::new())
-            .http2_only(true)
-            .build(https);
+        let client = https_client()?;
+        let psql = Database::new(&database_url).wrap_err("Failed to create database client")?;
 
         let mut handlebars = Handlebars::new();
         let mut path = website_path.clone();
@@ -91,8 +114,27 @@ impl AppStateBuilder {
             twitch_token: twitch_token.into_boxed_str(),
             redirect_base: redirect_base.into_boxed_str(),
             standby,
+            psql,
+            channel_access,
         };
 
         Ok((state, website_path))
     }
 }
+
+pub(crate) fn https_client() -> Result<HyperClient<HttpsConnector<HttpConnector>, Empty<Bytes>>> {
+    let crypto_provider = rustls::crypto::ring::default_provider();
+
+    let https = HttpsConnectorBuilder::new()
+        .with_provider_and_webpki_roots(crypto_provider)
+        .wrap_err("Failed to configure https connector")?
+        .https_only()
+        .enable_http2()
+        .build();
+
+    let client = Builder::new(TokioExecutor::new())
+        .http2_only(true)
+        .build(https);
+
+    Ok(client)
+}
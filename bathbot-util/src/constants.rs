@@ -30,6 +30,7 @@ pub const TWITCH_OAUTH: &str = "https://id.twitch.tv/oauth2/token";
 pub const GENERAL_ISSUE: &str = "Something went wrong, blame bade";
 pub const OSU_API_ISSUE: &str = "Some issue with the osu api, blame bade";
 pub const ORDR_ISSUE: &str = "Some issue with the o!rdr api, blame bade";
+pub const DANSER_ISSUE: &str = "Some issue with the danser renderer, blame bade";
 pub const OSEKAI_ISSUE: &str = "Some issue with the osekai api, blame bade";
 pub const OSUSTATS_API_ISSUE: &str = "Some issue with the osustats api, blame bade";
 pub const TWITCH_API_ISSUE: &str = "Some issue with the twitch api, blame bade";
@@ -206,15 +206,23 @@ impl ModSelection {
 
     /// Returns `true` if the score's mods coincide with this [`ModSelection`]
     pub fn filter_score(&self, score: &Score) -> bool {
+        self.filter_mods(&score.mods)
+    }
+
+    /// Returns `true` if the mods coincide with this [`ModSelection`]
+    pub fn filter_mods(&self, mods: &GameMods) -> bool {
         match self {
-            ModSelection::Include(mods) | ModSelection::Exact(mods) if mods.is_empty() => {
-                Self::filter_empty(&score.mods)
-            }
-            ModSelection::Include(mods) => Self::filter_include(mods, &score.mods),
-            &ModSelection::Exclude { ref mods, nomod } => {
-                Self::filter_exclude(mods, nomod, &score.mods)
+            ModSelection::Include(selection) | ModSelection::Exact(selection)
+                if selection.is_empty() =>
+            {
+                Self::filter_empty(mods)
             }
-            ModSelection::Exact(mods) => Self::filter_exact(mods, &score.mods),
+            ModSelection::Include(selection) => Self::filter_include(selection, mods),
+            &ModSelection::Exclude {
+                mods: ref selection,
+                nomod,
+            } => Self::filter_exclude(selection, nomod, mods),
+            ModSelection::Exact(selection) => Self::filter_exact(selection, mods),
         }
     }
 
@@ -1,8 +1,12 @@
-use std::{collections::VecDeque, sync::RwLock};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::RwLock,
+    time::{Duration, Instant},
+};
 
-use bathbot_model::Effects;
+use bathbot_model::{BgGameVariant, Effects};
 use bathbot_psql::model::games::MapsetTagsEntries;
-use bathbot_util::{CowUtils, constants::OSU_BASE};
+use bathbot_util::{CowUtils, IntHasher, constants::OSU_BASE};
 use eyre::{Result, WrapErr};
 use image::{
     GenericImageView,
@@ -22,20 +26,27 @@ use crate::{Context, commands::fun::GameDifficulty, core::BotConfig, util::Chann
 
 pub struct Game {
     pub mapset: GameMapset,
+    variant: BgGameVariant,
     difficulty: f32,
     hints: RwLock<Hints>,
     reveal: RwLock<ImageReveal>,
+    /// When each user last guessed the star rating
+    star_guesses: RwLock<HashMap<Id<UserMarker>, Instant, IntHasher>>,
 }
 
 impl Game {
+    /// How long a user has to wait before guessing the star rating again.
+    const STAR_GUESS_COOLDOWN: Duration = Duration::from_secs(5);
+
     pub async fn new(
         entries: &MapsetTagsEntries,
         previous_ids: &mut VecDeque<i32>,
         effects: Effects,
         difficulty: GameDifficulty,
+        variant: BgGameVariant,
     ) -> (Self, Vec<u8>) {
         loop {
-            match Game::new_(entries, previous_ids, effects, difficulty).await {
+            match Game::new_(entries, previous_ids, effects, difficulty, variant).await {
                 Ok(game) => {
                     let sub_image_result = { game.reveal.read().unwrap().sub_image() };
 
@@ -62,6 +73,7 @@ impl Game {
         previous_ids: &mut VecDeque<i32>,
         effects: Effects,
         difficulty: GameDifficulty,
+        variant: BgGameVariant,
     ) -> Result<Self> {
         let mut path = BotConfig::get().paths.backgrounds.clone();
 
@@ -118,34 +130,75 @@ impl Game {
             Ok(img)
         };
 
-        let (mapset_, img) = tokio::try_join!(GameMapset::new(mapset.mapset_id as u32), img_fut)?;
+        let mapset_fut = GameMapset::new(mapset.mapset_id as u32, entries.mode, variant);
+        let (mapset_, img) = tokio::try_join!(mapset_fut, img_fut)?;
+
+        let hints = match variant {
+            BgGameVariant::Mapper => {
+                let Some(creator) = mapset_.creator() else {
+                    bail!("missing creator for mapset {mapset_id}");
+                };
+
+                Hints::new(creator)
+            }
+            BgGameVariant::Stars => {
+                if mapset_.star_bracket().is_none() {
+                    bail!("missing star rating for mapset {mapset_id}");
+                }
+
+                Hints::new(mapset_.title())
+            }
+            BgGameVariant::Title | BgGameVariant::Blurred | BgGameVariant::Pixelated => {
+                Hints::new(mapset_.title())
+            }
+        };
 
         Ok(Self {
-            hints: RwLock::new(Hints::new(mapset_.title())),
+            hints: RwLock::new(hints),
+            variant,
             difficulty: difficulty.factor(),
             mapset: mapset_,
-            reveal: RwLock::new(ImageReveal::new(img)),
+            reveal: RwLock::new(ImageReveal::new(img, variant)),
+            star_guesses: RwLock::new(HashMap::default()),
         })
     }
 
     pub fn sub_image(&self) -> Result<Vec<u8>> {
         let mut reveal = self.reveal.write().unwrap();
-        reveal.increase_radius();
+        reveal.enhance();
 
         reveal.sub_image()
     }
 
     pub fn hint(&self) -> String {
         let mut hints = self.hints.write().unwrap();
+        let mapset = &self.mapset;
 
-        hints.get(self.mapset.title(), self.mapset.artist())
+        match (self.variant, mapset.creator(), mapset.star_bracket()) {
+            (BgGameVariant::Mapper, Some(creator), _) => hints.get_mapper(creator),
+            (BgGameVariant::Stars, _, Some(bracket)) => {
+                hints.get_stars(mapset.artist(), mapset.title(), bracket)
+            }
+            _ => hints.get(mapset.title(), mapset.artist()),
+        }
     }
 
     pub fn mapset_id(&self) -> u32 {
         self.mapset.mapset_id
     }
 
-    fn check_msg_content(&self, content: &str) -> ContentResult {
+    fn check_msg_content(&self, content: &str, author: Id<UserMarker>) -> ContentResult {
+        match self.variant {
+            BgGameVariant::Mapper => {
+                return match self.mapset.matches_creator(content, self.difficulty) {
+                    Some(exact) => ContentResult::Mapper(exact),
+                    None => ContentResult::None,
+                };
+            }
+            BgGameVariant::Stars => return self.check_stars(content, author),
+            BgGameVariant::Title | BgGameVariant::Blurred | BgGameVariant::Pixelated => {}
+        }
+
         match self.mapset.matches_title(content, self.difficulty) {
             Some(true) => return ContentResult::Title(true),
             Some(false) => return ContentResult::Title(false),
@@ -162,6 +215,50 @@ impl Game {
 
         ContentResult::None
     }
+
+    /// Guesses of a user within [`Game::STAR_GUESS_COOLDOWN`] of their
+    /// previous guess are ignored.
+    fn check_stars(&self, content: &str, author: Id<UserMarker>) -> ContentResult {
+        let Some(bracket) = self.mapset.star_bracket() else {
+            return ContentResult::None;
+        };
+
+        let guess = content
+            .trim()
+            .trim_end_matches(['*', '★'])
+            .trim_end_matches("stars")
+            .trim()
+            .parse::<f32>();
+
+        let Ok(guess) = guess else {
+            return ContentResult::None;
+        };
+
+        if !guess.is_finite() || guess < 0.0 {
+            return ContentResult::None;
+        }
+
+        {
+            let mut star_guesses = self.star_guesses.write().unwrap();
+            let now = Instant::now();
+
+            let on_cooldown = star_guesses.get(&author).is_some_and(|last_guess| {
+                now.duration_since(*last_guess) < Self::STAR_GUESS_COOLDOWN
+            });
+
+            if on_cooldown {
+                return ContentResult::None;
+            }
+
+            star_guesses.insert(author, now);
+        }
+
+        if guess as u32 == bracket {
+            ContentResult::Stars(bracket)
+        } else {
+            ContentResult::None
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -181,7 +278,7 @@ pub async fn game_loop(
         let game = game_locked.read().await;
         let content = msg.content.cow_to_ascii_lowercase();
 
-        match game.check_msg_content(content.as_ref()) {
+        match game.check_msg_content(content.as_ref(), msg.author.id) {
             // Title correct?
             ContentResult::Title(exact) => {
                 let content = format!(
@@ -228,6 +325,42 @@ pub async fn game_loop(
                     warn!(?err, "Error while sending msg for correct artist");
                 }
             }
+            // Mapper correct?
+            ContentResult::Mapper(exact) => {
+                let content = format!(
+                    "{} \\:)\n\
+                    Mapper: `{creator}`\n\
+                    Mapset: {OSU_BASE}beatmapsets/{mapset_id}",
+                    if exact {
+                        format!("Gratz {}, you guessed it", msg.author.name)
+                    } else {
+                        format!("You were close enough {}, gratz", msg.author.name)
+                    },
+                    creator = game.mapset.creator().unwrap_or_default(),
+                    mapset_id = game.mapset.mapset_id
+                );
+
+                if let Err(err) = channel.plain_message(&content).await {
+                    warn!(?err, "Error while sending msg for winner");
+                }
+
+                return LoopResult::Winner(msg.author.id);
+            }
+            // Star rating correct?
+            ContentResult::Stars(bracket) => {
+                let content = format!(
+                    "Gratz {}, the hardest difficulty has {bracket}.xx stars \\:)\n\
+                    Mapset: {OSU_BASE}beatmapsets/{mapset_id}",
+                    msg.author.name,
+                    mapset_id = game.mapset.mapset_id
+                );
+
+                if let Err(err) = channel.plain_message(&content).await {
+                    warn!(?err, "Error while sending msg for winner");
+                }
+
+                return LoopResult::Winner(msg.author.id);
+            }
             ContentResult::None => {}
         }
     }
@@ -239,5 +372,8 @@ pub async fn game_loop(
 enum ContentResult {
     Title(bool),
     Artist(bool),
+    Mapper(bool),
+    /// Correctly guessed star rating bracket
+    Stars(u32),
     None,
 }
@@ -4,7 +4,7 @@ use std::{
     sync::Arc,
 };
 
-use bathbot_model::Effects;
+use bathbot_model::{BgGameVariant, Effects};
 use bathbot_psql::model::games::MapsetTagsEntries;
 use bathbot_util::{IntHasher, MessageBuilder, constants::OSU_BASE};
 use eyre::Result;
@@ -37,6 +37,7 @@ impl BackgroundGame {
         entries: MapsetTagsEntries,
         effects: Effects,
         difficulty: GameDifficulty,
+        variant: BgGameVariant,
     ) -> Self {
         let (tx, mut rx) = mpsc::unbounded_channel();
 
@@ -47,7 +48,8 @@ impl BackgroundGame {
         let mut scores = HashMap::with_hasher(IntHasher);
 
         // Initialize game
-        let (game, mut img) = Game::new(&entries, &mut previous_ids, effects, difficulty).await;
+        let (game, mut img) =
+            Game::new(&entries, &mut previous_ids, effects, difficulty, variant).await;
         let game = Arc::new(RwLock::new(game));
         let game_clone = Arc::clone(&game);
 
@@ -100,7 +102,10 @@ impl BackgroundGame {
                         }
 
                         // Store score for winners
-                        if let Err(err) = Context::games().bggame_increment_scores(&scores).await {
+                        if let Err(err) = Context::games()
+                            .bggame_increment_scores(&scores, variant)
+                            .await
+                        {
                             warn!("{err:?}");
                         }
 
@@ -117,7 +122,7 @@ impl BackgroundGame {
 
                 // Initialize next game
                 let (game, img_) =
-                    Game::new(&entries, &mut previous_ids, effects, difficulty).await;
+                    Game::new(&entries, &mut previous_ids, effects, difficulty, variant).await;
                 img = img_;
                 *game_clone.write().await = game;
             }
@@ -5,14 +5,15 @@ use rand::seq::SliceRandom;
 pub struct Hints {
     pub artist_guessed: bool,
     hint_level: u8,
-    title_mask: Box<[bool]>,
+    mask: Box<[bool]>,
     indices: Vec<usize>,
 }
 
 impl Hints {
-    pub fn new(title: &str) -> Self {
+    /// `answer` is the string whose characters will be revealed over time.
+    pub fn new(answer: &str) -> Self {
         // Indices of chars that still need to be revealed
-        let mut indices: Vec<_> = title
+        let mut indices: Vec<_> = answer
             .chars()
             .enumerate()
             .skip(1) // first char revealed immediatly
@@ -23,14 +24,14 @@ impl Hints {
         let mut rng = rand::thread_rng();
         indices.shuffle(&mut rng);
 
-        let title_mask = iter::once(true)
-            .chain(title.chars().skip(1).map(|c| c == ' '))
+        let mask = iter::once(true)
+            .chain(answer.chars().skip(1).map(|c| c == ' '))
             .collect();
 
         Self {
             artist_guessed: false,
             hint_level: 0,
-            title_mask,
+            mask,
             indices,
         }
     }
@@ -62,23 +63,58 @@ impl Hints {
             artist_hint.push('`');
 
             artist_hint
-        } else if let Some(i) = self.indices.pop() {
-            self.title_mask[i] = true;
-
-            let mut title_hint = "Slowly constructing the title: `".to_owned();
+        } else if let Some(masked) = self.reveal_char(title) {
+            format!("Slowly constructing the title: `{masked}`")
+        } else {
+            format!("Bruh the title is literally `{title}` xd")
+        }
+    }
 
-            let title_iter = self
-                .title_mask
-                .iter()
-                .zip(title.chars())
-                .map(|(mask, c)| if *mask { c } else { '▢' });
+    pub fn get_mapper(&mut self, creator: &str) -> String {
+        self.hint_level = self.hint_level.saturating_add(1);
 
-            title_hint.extend(title_iter);
-            title_hint.push('`');
+        if self.hint_level == 1 {
+            let len = creator.chars().count();
 
-            title_hint
+            format!(
+                "Let me give you a hint: The mapper's name has {len} \
+                characters and the starting letter is `{first}`",
+                first = creator.chars().next().unwrap(),
+            )
+        } else if let Some(masked) = self.reveal_char(creator) {
+            format!("Slowly constructing the mapper's name: `{masked}`")
         } else {
-            format!("Bruh the title is literally `{title}` xd")
+            format!("Bruh the mapper is literally `{creator}` xd")
         }
     }
+
+    pub fn get_stars(&mut self, artist: &str, title: &str, bracket: u32) -> String {
+        self.hint_level = self.hint_level.saturating_add(1);
+
+        match self.hint_level {
+            1 => format!("Let me give you a hint: The mapset is `{artist} - {title}`"),
+            2 => format!(
+                "Here's my second hint: The hardest difficulty has \
+                between {min} and {max} stars",
+                min = bracket.saturating_sub(1),
+                max = bracket + 2,
+            ),
+            _ => format!("Bruh it's literally {bracket}.xx stars xd"),
+        }
+    }
+
+    /// Reveals another char of `answer` and returns the masked answer.
+    fn reveal_char(&mut self, answer: &str) -> Option<String> {
+        let i = self.indices.pop()?;
+        self.mask[i] = true;
+
+        let masked = self
+            .mask
+            .iter()
+            .zip(answer.chars())
+            .map(|(mask, c)| if *mask { c } else { '▢' })
+            .collect();
+
+        Some(masked)
+    }
 }
@@ -1,45 +1,78 @@
 use std::io::Cursor;
 
+use bathbot_model::BgGameVariant;
 use eyre::{Result, WrapErr};
-use image::{DynamicImage, GenericImageView, ImageOutputFormat::Png};
+use image::{DynamicImage, GenericImageView, ImageOutputFormat::Png, imageops::FilterType};
 use rand::RngCore;
 
 pub struct ImageReveal {
     dim: (u32, u32),
     original: DynamicImage,
-    x: u32,
-    y: u32,
-    radius: u32,
+    kind: RevealKind,
+}
+
+enum RevealKind {
+    /// Show a square around a random center
+    Crop { x: u32, y: u32, radius: u32 },
+    /// Show the whole image but blurred
+    Blur { sigma: f32 },
+    /// Show the whole image but with a lower resolution
+    Pixelate { size: u32 },
 }
 
 impl ImageReveal {
-    pub fn new(original: DynamicImage) -> Self {
+    pub fn new(original: DynamicImage, variant: BgGameVariant) -> Self {
         let (w, h) = original.dimensions();
-        let radius = 100;
-        let mut rng = rand::thread_rng();
-        let x = radius + rng.next_u32() % (w - 2 * radius);
-        let y = radius + rng.next_u32() % (h - 2 * radius);
+
+        let kind = match variant {
+            BgGameVariant::Blurred => RevealKind::Blur { sigma: 20.0 },
+            BgGameVariant::Pixelated => RevealKind::Pixelate { size: 48 },
+            BgGameVariant::Title | BgGameVariant::Mapper | BgGameVariant::Stars => {
+                let radius = 100;
+                let mut rng = rand::thread_rng();
+                let x = radius + rng.next_u32() % (w - 2 * radius);
+                let y = radius + rng.next_u32() % (h - 2 * radius);
+
+                RevealKind::Crop { x, y, radius }
+            }
+        };
 
         Self {
             dim: (w, h),
             original,
-            x,
-            y,
-            radius,
+            kind,
         }
     }
 
-    pub fn increase_radius(&mut self) {
-        self.radius += 75;
+    /// Make the next image show a little more.
+    pub fn enhance(&mut self) {
+        match self.kind {
+            RevealKind::Crop { ref mut radius, .. } => *radius += 75,
+            RevealKind::Blur { ref mut sigma } => *sigma = (*sigma - 4.0).max(1.0),
+            RevealKind::Pixelate { ref mut size } => *size = (*size * 2 / 3).max(2),
+        }
     }
 
     pub fn sub_image(&self) -> Result<Vec<u8>> {
-        let cx = self.x.saturating_sub(self.radius);
-        let cy = self.y.saturating_sub(self.radius);
         let (w, h) = self.dim;
-        let w = (self.x + self.radius).min(w) - cx;
-        let h = (self.y + self.radius).min(h) - cy;
-        let sub_image = self.original.crop_imm(cx, cy, w, h);
+
+        let sub_image = match self.kind {
+            RevealKind::Crop { x, y, radius } => {
+                let cx = x.saturating_sub(radius);
+                let cy = y.saturating_sub(radius);
+                let w = (x + radius).min(w) - cx;
+                let h = (y + radius).min(h) - cy;
+
+                self.original.crop_imm(cx, cy, w, h)
+            }
+            RevealKind::Blur { sigma } => self.original.blur(sigma),
+            RevealKind::Pixelate { size } => self
+                .original
+                .resize_exact((w / size).max(1), (h / size).max(1), FilterType::Triangle)
+                .resize_exact(w, h, FilterType::Nearest),
+        };
+
+        let (w, h) = sub_image.dimensions();
         let png_bytes: Vec<u8> = Vec::with_capacity((w * h) as usize);
 
         let mut cursor = Cursor::new(png_bytes);
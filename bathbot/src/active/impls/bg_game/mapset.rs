@@ -1,6 +1,8 @@
+use bathbot_model::BgGameVariant;
 use bathbot_psql::model::osu::ArtistTitle;
 use bathbot_util::string_cmp::{gestalt_pattern_matching, levenshtein_similarity};
 use eyre::{Report, Result};
+use rosu_v2::prelude::GameMode;
 
 use crate::core::Context;
 
@@ -9,20 +11,52 @@ pub struct GameMapset {
     artist: Box<str>,
     title: Box<str>,
     title_adjusted: Option<Box<str>>,
+    /// Only available for variants that need to guess the mapper
+    creator: Option<Box<str>>,
+    /// Star rating of the hardest difficulty; only available for variants
+    /// that need to guess the star rating
+    stars: Option<f32>,
 }
 
 impl GameMapset {
-    pub async fn new(mapset_id: u32) -> Result<Self> {
-        let ArtistTitle { artist, title } = match Context::osu_map().artist_title(mapset_id).await {
-            Ok(mut artist_title) => {
-                artist_title.title.make_ascii_lowercase();
-                artist_title.artist.make_ascii_lowercase();
+    pub async fn new(mapset_id: u32, mode: GameMode, variant: BgGameVariant) -> Result<Self> {
+        let mut creator = None;
+        let mut stars = None;
 
-                artist_title
+        let artist_title = if variant.guess_title() {
+            Context::osu_map()
+                .artist_title(mapset_id)
+                .await
+                .map_err(|err| Report::new(err).wrap_err("failed to get artist and title"))?
+        } else {
+            let mapset = Context::osu_map()
+                .retrieve_mapset(mapset_id)
+                .await
+                .map_err(|err| Report::new(err).wrap_err("failed to get mapset"))?;
+
+            creator = Some(mapset.creator_name.to_ascii_lowercase().into_boxed_str());
+
+            stars = mapset.maps.as_deref().and_then(|maps| {
+                maps.iter()
+                    .filter(|map| map.mode == mode)
+                    .map(|map| map.stars)
+                    .max_by(f32::total_cmp)
+            });
+
+            ArtistTitle {
+                artist: mapset.artist,
+                title: mapset.title,
             }
-            Err(err) => return Err(Report::new(err).wrap_err("failed to get artist and title")),
         };
 
+        let ArtistTitle {
+            mut artist,
+            mut title,
+        } = artist_title;
+
+        title.make_ascii_lowercase();
+        artist.make_ascii_lowercase();
+
         let title_adjusted = if let (Some(open), Some(close)) = (title.find('('), title.rfind(')'))
         {
             let mut title_ = title.clone();
@@ -51,6 +85,8 @@ impl GameMapset {
             artist: artist.into_boxed_str(),
             title: title.into_boxed_str(),
             title_adjusted: title_adjusted.map(String::into_boxed_str),
+            creator,
+            stars,
         };
 
         Ok(mapset)
@@ -67,6 +103,15 @@ impl GameMapset {
         self.artist.as_ref()
     }
 
+    pub fn creator(&self) -> Option<&str> {
+        self.creator.as_deref()
+    }
+
+    /// The integer part of the hardest difficulty's star rating.
+    pub fn star_bracket(&self) -> Option<u32> {
+        self.stars.map(|stars| stars as u32)
+    }
+
     pub fn matches_title(&self, content: &str, difficulty: f32) -> Option<bool> {
         self.title_adjusted
             .as_deref()
@@ -78,6 +123,12 @@ impl GameMapset {
         Self::matches(self.artist.as_ref(), content, difficulty)
     }
 
+    pub fn matches_creator(&self, content: &str, difficulty: f32) -> Option<bool> {
+        self.creator
+            .as_deref()
+            .and_then(|creator| Self::matches(creator, content, difficulty))
+    }
+
     fn matches(src: &str, content: &str, difficulty: f32) -> Option<bool> {
         if src == content {
             Some(true)
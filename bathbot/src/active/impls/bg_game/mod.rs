@@ -1,4 +1,4 @@
-use bathbot_model::{Effects, MapsetTags};
+use bathbot_model::{BgGameVariant, Effects, MapsetTags};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{
     Authored, EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields,
@@ -33,6 +33,7 @@ mod util;
 
 pub struct BackgroundGameSetup {
     difficulty: GameDifficulty,
+    variant: BgGameVariant,
     effects: Effects,
     excluded: MapsetTags,
     included: MapsetTags,
@@ -337,9 +338,14 @@ impl IActiveMessage for BackgroundGameSetup {
 }
 
 impl BackgroundGameSetup {
-    pub fn new(difficulty: GameDifficulty, msg_owner: Id<UserMarker>) -> Self {
+    pub fn new(
+        difficulty: GameDifficulty,
+        variant: BgGameVariant,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
         Self {
             difficulty,
+            variant,
             msg_owner,
             effects: Effects::empty(),
             excluded: MapsetTags::empty(),
@@ -396,7 +402,10 @@ impl BackgroundGameSetup {
             "Effects", effects_value, true;
         ];
 
-        let footer = FooterBuilder::new(format!("Difficulty: {:?}", self.difficulty));
+        let footer = FooterBuilder::new(format!(
+            "Difficulty: {:?} • Variant: {}",
+            self.difficulty, self.variant
+        ));
         let title = format!("Selected tags ({} backgrounds)", entries.tags.len());
 
         let embed = EmbedBuilder::new()
@@ -412,10 +421,17 @@ impl BackgroundGameSetup {
             info!(
                 included = self.included.join(','),
                 excluded = self.excluded.join(','),
+                variant = %self.variant,
                 "Starting game"
             );
 
-            let game_fut = BackgroundGame::new(channel, entries, self.effects, self.difficulty);
+            let game_fut = BackgroundGame::new(
+                channel,
+                entries,
+                self.effects,
+                self.difficulty,
+                self.variant,
+            );
 
             let game = game_fut.await;
             Context::bg_games().own(channel).await.insert(game);
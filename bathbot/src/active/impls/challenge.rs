@@ -0,0 +1,154 @@
+use std::mem;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE};
+use eyre::{Report, Result};
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ActionRow, Button, ButtonStyle},
+    },
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    core::Context,
+    manager::{ChallengeEntry, ChallengeStandings, GuildChallenge, OsuMap},
+    util::{
+        ComponentExt,
+        interaction::{InteractionComponent, InteractionModal},
+    },
+};
+
+#[derive(PaginationBuilder)]
+pub struct ChallengeLeaderboardPagination {
+    map: OsuMap,
+    challenge: GuildChallenge,
+    #[pagination(per_page = 10)]
+    entries: Vec<ChallengeEntry>,
+    /// Amount of members whose scores could not be retrieved.
+    failed: usize,
+    defer_next: bool,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl ChallengeLeaderboardPagination {
+    /// Anyone may refresh, not just the message owner.
+    async fn handle_refresh(&mut self, component: &InteractionComponent) -> ComponentResult {
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        let ChallengeStandings { entries, failed } =
+            match Context::challenges().leaderboard(&self.challenge).await {
+                Ok(standings) => standings,
+                Err(err) => return ComponentResult::Err(err),
+            };
+
+        self.entries = entries;
+        self.failed = failed;
+
+        self.pages = Pages::new(10, self.entries.len());
+        self.defer_next = true;
+
+        ComponentResult::BuildPage
+    }
+}
+
+impl IActiveMessage for ChallengeLeaderboardPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let defer = mem::replace(&mut self.defer_next, false);
+
+        let idx = self.pages.index();
+        let mut description = String::with_capacity(1024);
+
+        for (entry, i) in self.entries.iter().skip(idx).take(10).zip(idx + 1..) {
+            entry.write(&mut description, i);
+        }
+
+        if self.entries.is_empty() {
+            description.push_str("No scores yet");
+        }
+
+        let ends_at = self.challenge.ends_at.unix_timestamp();
+
+        let status = if self.challenge.is_ongoing() {
+            format!("Ends <t:{ends_at}:R>")
+        } else {
+            format!("Ended <t:{ends_at}:R>")
+        };
+
+        let mods = match self.challenge.mods {
+            Some(ref mods) => format!(" • Mods: `{mods}`"),
+            None => String::new(),
+        };
+
+        let description = format!("{status}{mods}\n\n{description}");
+
+        let title = format!(
+            "Challenge: {} - {} [{}]",
+            self.map.artist().cow_escape_markdown(),
+            self.map.title().cow_escape_markdown(),
+            self.map.version().cow_escape_markdown()
+        );
+
+        let mut footer = format!(
+            "Page {}/{} • {} participant{}",
+            self.pages.curr_page(),
+            self.pages.last_page(),
+            self.entries.len(),
+            if self.entries.len() == 1 { "" } else { "s" }
+        );
+
+        ChallengeStandings::write_failed(self.failed, &mut footer);
+
+        let embed = EmbedBuilder::new()
+            .title(title)
+            .url(format!("{OSU_BASE}b/{}", self.challenge.map_id))
+            .description(description)
+            .thumbnail(self.map.thumbnail())
+            .footer(FooterBuilder::new(footer));
+
+        Ok(BuildPage::new(embed, defer))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        let mut components = self.pages.components();
+
+        if self.challenge.is_ongoing() {
+            let refresh = Button {
+                custom_id: Some("challenge_refresh".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Refresh".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+                sku_id: None,
+            };
+
+            components.push(Component::ActionRow(ActionRow {
+                components: vec![Component::Button(refresh)],
+            }));
+        }
+
+        components
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        match component.data.custom_id.as_str() {
+            "challenge_refresh" => self.handle_refresh(component).await,
+            _ => {
+                handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
+            }
+        }
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
+    }
+}
@@ -0,0 +1,118 @@
+use bathbot_util::{EmbedBuilder, constants::OSU_BASE, numbers::WithComma};
+use eyre::{ContextCompat, Result, WrapErr};
+use rand::Rng;
+use rosu_v2::prelude::{CountryCode, GameMode, Username};
+use twilight_model::channel::message::embed::EmbedField;
+
+use super::state::HlImage;
+use crate::core::Context;
+
+pub(super) struct GlobalRank {
+    pub rank: u32,
+    avatar_url: Box<str>,
+    country_code: CountryCode,
+    user_id: u32,
+    username: Username,
+}
+
+impl GlobalRank {
+    pub(super) async fn random(
+        mode: GameMode,
+        prev: Option<&Self>,
+        curr_score: u32,
+    ) -> Result<Self> {
+        let max_rank = 5000 - (mode != GameMode::Osu) as u32 * 1000;
+
+        // The higher the current score, the closer the ranks will be
+        let spread = (max_rank >> curr_score.min(6)).max(50);
+
+        let rank = {
+            let mut rng = rand::thread_rng();
+
+            match prev {
+                Some(prev) => {
+                    let min = prev.rank.saturating_sub(spread).max(1);
+                    let max = (prev.rank + spread).min(max_rank);
+
+                    rng.gen_range(min..=max)
+                }
+                None => rng.gen_range(1..=max_rank),
+            }
+        };
+
+        let page = ((rank - 1) / 50) + 1;
+        let idx = ((rank - 1) % 50) as usize;
+
+        let ranking = Context::redis()
+            .pp_ranking(mode, page, None)
+            .await
+            .wrap_err("Failed to get cached pp ranking")?;
+
+        let user = ranking
+            .ranking
+            .get(idx)
+            .wrap_err("Missing user in pp ranking")?;
+
+        let global_rank = user
+            .statistics
+            .as_ref()
+            .map_or(0, |stats| stats.global_rank.to_native());
+
+        Ok(Self {
+            rank: if global_rank > 0 { global_rank } else { rank },
+            avatar_url: user.avatar_url.as_ref().into(),
+            country_code: user.country_code.as_str().into(),
+            user_id: user.user_id.to_native(),
+            username: user.username.as_str().into(),
+        })
+    }
+
+    pub(super) fn image(&self) -> HlImage {
+        HlImage::Avatar(self.avatar_url.clone())
+    }
+
+    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("__Previous:__ {}", previous.player_string()),
+                value: previous.rank_string(true),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("__Next:__ {}", next.player_string()),
+                value: next.rank_string(revealed),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+
+    fn player_string(&self) -> String {
+        format!(
+            ":flag_{country_code}: {username}",
+            country_code = self.country_code.to_lowercase(),
+            username = self.username,
+        )
+    }
+
+    fn rank_string(&self, rank_visible: bool) -> String {
+        let profile = format!("[Profile]({OSU_BASE}u/{})", self.user_id);
+
+        if rank_visible {
+            format!(
+                "Global rank: **#{}** • {profile}",
+                WithComma::new(self.rank)
+            )
+        } else {
+            "Global rank: **#???**".to_owned()
+        }
+    }
+}
+
+impl PartialEq for GlobalRank {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
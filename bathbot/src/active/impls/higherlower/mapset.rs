@@ -0,0 +1,145 @@
+use bathbot_psql::model::games::DbMapTagsParams;
+use bathbot_util::{EmbedBuilder, constants::OSU_BASE, numbers::WithComma};
+use eyre::{ContextCompat, Report, Result, WrapErr};
+use rand::{Rng, seq::SliceRandom};
+use rosu_v2::prelude::{GameMode, Username};
+use twilight_model::channel::message::embed::EmbedField;
+
+use super::state::HlImage;
+use crate::core::Context;
+
+/// Which value of a [`HlMapset`] is compared.
+#[derive(Copy, Clone)]
+pub(super) enum MapsetValue {
+    /// Playcount of a difficulty
+    Playcount,
+    /// Favourite count of the mapset
+    Favourites,
+}
+
+pub(super) struct HlMapset {
+    map_id: u32,
+    mapset_id: u32,
+    artist: Box<str>,
+    title: Box<str>,
+    version: Box<str>,
+    creator: Username,
+    playcount: u32,
+    favourites: u32,
+}
+
+impl HlMapset {
+    /// Mapsets are picked among those that are available for the background
+    /// game.
+    pub(super) async fn mapset_ids() -> Result<Box<[u32]>> {
+        let entries = Context::games()
+            .bggame_tags(DbMapTagsParams::new(GameMode::Osu))
+            .await
+            .wrap_err("Failed to get mapset ids")?;
+
+        if entries.tags.len() < 2 {
+            bail!("Not enough mapsets available");
+        }
+
+        Ok(entries
+            .tags
+            .into_iter()
+            .map(|entry| entry.mapset_id as u32)
+            .collect())
+    }
+
+    pub(super) async fn random(mapset_ids: &[u32]) -> Result<Self> {
+        let mapset_id = *mapset_ids
+            .choose(&mut rand::thread_rng())
+            .wrap_err("No mapset ids available")?;
+
+        let mapset = Context::osu_map()
+            .retrieve_mapset(mapset_id)
+            .await
+            .map_err(|err| Report::new(err).wrap_err("Failed to get mapset"))?;
+
+        let maps = mapset.maps.as_deref().unwrap_or_default();
+
+        let map = match maps.len() {
+            0 => bail!("Mapset {mapset_id} contains no maps"),
+            len => &maps[rand::thread_rng().gen_range(0..len)],
+        };
+
+        Ok(Self {
+            map_id: map.map_id,
+            mapset_id,
+            artist: mapset.artist.as_str().into(),
+            title: mapset.title.as_str().into(),
+            version: map.version.as_str().into(),
+            creator: mapset.creator_name.clone(),
+            playcount: map.playcount,
+            favourites: mapset.favourite_count,
+        })
+    }
+
+    pub(super) fn value(&self, value: MapsetValue) -> u32 {
+        match value {
+            MapsetValue::Playcount => self.playcount,
+            MapsetValue::Favourites => self.favourites,
+        }
+    }
+
+    pub(super) fn image(&self) -> HlImage {
+        HlImage::Cover(self.mapset_id)
+    }
+
+    pub(super) fn to_embed(
+        previous: &Self,
+        next: &Self,
+        value: MapsetValue,
+        revealed: bool,
+    ) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("__Previous:__ Mapset by {}", previous.creator),
+                value: previous.mapset_string(value, true),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("__Next:__ Mapset by {}", next.creator),
+                value: next.mapset_string(value, revealed),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+
+    fn mapset_string(&self, value: MapsetValue, value_visible: bool) -> String {
+        let amount = if value_visible {
+            WithComma::new(self.value(value)).to_string()
+        } else {
+            "???".to_owned()
+        };
+
+        match value {
+            MapsetValue::Playcount => format!(
+                "**[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})**\n\
+                Playcount: **{amount}**",
+                artist = self.artist,
+                title = self.title,
+                version = self.version,
+                map_id = self.map_id,
+            ),
+            MapsetValue::Favourites => format!(
+                "**[{artist} - {title}]({OSU_BASE}s/{mapset_id})**\n\
+                Favourites: **{amount}**",
+                artist = self.artist,
+                title = self.title,
+                mapset_id = self.mapset_id,
+            ),
+        }
+    }
+}
+
+impl PartialEq for HlMapset {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.mapset_id == other.mapset_id
+    }
+}
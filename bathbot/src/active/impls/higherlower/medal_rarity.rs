@@ -0,0 +1,82 @@
+use bathbot_util::EmbedBuilder;
+use eyre::{ContextCompat, Result, WrapErr};
+use rand::seq::IteratorRandom;
+use rkyv::rend::f32_le;
+use twilight_model::channel::message::embed::EmbedField;
+
+use super::state::HlImage;
+use crate::core::Context;
+
+pub(super) struct MedalRarity {
+    /// Percentage of users that own the medal
+    pub rarity: f32,
+    medal_id: u32,
+    name: Box<str>,
+    description: Box<str>,
+    icon_url: String,
+}
+
+impl MedalRarity {
+    pub(super) async fn random() -> Result<Self> {
+        let medals = Context::redis()
+            .medals()
+            .await
+            .wrap_err("Failed to get cached medals")?;
+
+        let (medal, rarity) = medals
+            .iter()
+            .filter_map(|medal| {
+                let rarity = medal.rarity.as_ref().copied().map(f32_le::to_native)?;
+
+                Some((medal, rarity))
+            })
+            .choose(&mut rand::thread_rng())
+            .wrap_err("No medals with rarity available")?;
+
+        Ok(Self {
+            rarity,
+            medal_id: medal.medal_id.to_native(),
+            name: medal.name.as_str().into(),
+            description: medal.description.as_str().into(),
+            icon_url: medal.icon_url().to_string(),
+        })
+    }
+
+    pub(super) fn image(&self) -> HlImage {
+        HlImage::MedalIcon(self.icon_url.clone())
+    }
+
+    pub(super) fn to_embed(previous: &Self, next: &Self, revealed: bool) -> EmbedBuilder {
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("__Previous:__ {}", previous.name),
+                value: previous.medal_string(true),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("__Next:__ {}", next.name),
+                value: next.medal_string(revealed),
+            },
+        ];
+
+        EmbedBuilder::new().fields(fields)
+    }
+
+    fn medal_string(&self, rarity_visible: bool) -> String {
+        let rarity = if rarity_visible {
+            format!("{:.2}%", self.rarity)
+        } else {
+            "???".to_owned()
+        };
+
+        format!("*{}*\nOwned by **{rarity}** of players", self.description)
+    }
+}
+
+impl PartialEq for MedalRarity {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.medal_id == other.medal_id
+    }
+}
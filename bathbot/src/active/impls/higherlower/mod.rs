@@ -25,6 +25,9 @@ use crate::{
     util::{ComponentExt, Emote, interaction::InteractionComponent},
 };
 
+mod global_rank;
+mod mapset;
+mod medal_rarity;
 mod score_pp;
 mod state;
 
@@ -189,9 +192,15 @@ impl IActiveMessage for HigherLowerGame {
 }
 
 impl HigherLowerGame {
-    pub async fn new_score_pp(mode: GameMode, msg_owner: Id<UserMarker>) -> Result<Self> {
-        let game_fut = HigherLowerState::start_score_pp(mode);
-        let highscore_fut = Context::games().higherlower_highscore(msg_owner, HlVersion::ScorePp);
+    /// The mode is only relevant for the [`HlVersion::ScorePp`] and
+    /// [`HlVersion::GlobalRank`] versions.
+    pub async fn new(
+        version: HlVersion,
+        mode: GameMode,
+        msg_owner: Id<UserMarker>,
+    ) -> Result<Self> {
+        let game_fut = HigherLowerState::start(version, mode);
+        let highscore_fut = Context::games().higherlower_highscore(msg_owner, version);
 
         let ((state, rx), highscore) = tokio::try_join!(game_fut, highscore_fut)?;
 
@@ -2,13 +2,27 @@ use std::mem;
 
 use bathbot_model::HlVersion;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
+use bytes::Bytes;
 use eyre::{ContextCompat, Result, WrapErr};
-use image::{ColorType, ImageEncoder, codecs::png::PngEncoder};
+use image::{
+    ColorType, DynamicImage, ImageBuffer, ImageEncoder,
+    codecs::png::PngEncoder,
+    imageops::{self, FilterType},
+};
 use rosu_v2::prelude::GameMode;
 use tokio::sync::oneshot::{self, Receiver};
 
-use super::{HlGuess, score_pp::ScorePp};
-use crate::{core::BotConfig, util::ChannelExt};
+use super::{
+    HlGuess,
+    global_rank::GlobalRank,
+    mapset::{HlMapset, MapsetValue},
+    medal_rarity::MedalRarity,
+    score_pp::ScorePp,
+};
+use crate::{
+    core::{BotConfig, Context},
+    util::ChannelExt,
+};
 
 pub(super) const W: u32 = 900;
 pub(super) const H: u32 = 250;
@@ -33,10 +47,38 @@ pub(super) enum HigherLowerState {
         previous: ScorePp,
         next: ScorePp,
     },
+    GlobalRank {
+        mode: GameMode,
+        previous: GlobalRank,
+        next: GlobalRank,
+    },
+    Mapset {
+        value: MapsetValue,
+        mapset_ids: Box<[u32]>,
+        previous: HlMapset,
+        next: HlMapset,
+    },
+    MedalRarity {
+        previous: MedalRarity,
+        next: MedalRarity,
+    },
 }
 
 impl HigherLowerState {
-    pub(super) async fn start_score_pp(mode: GameMode) -> Result<(Self, Receiver<String>)> {
+    pub(super) async fn start(
+        version: HlVersion,
+        mode: GameMode,
+    ) -> Result<(Self, Receiver<String>)> {
+        match version {
+            HlVersion::ScorePp => Self::start_score_pp(mode).await,
+            HlVersion::GlobalRank => Self::start_global_rank(mode).await,
+            HlVersion::MapPlaycount => Self::start_mapset(MapsetValue::Playcount, None).await,
+            HlVersion::MapsetFavourites => Self::start_mapset(MapsetValue::Favourites, None).await,
+            HlVersion::MedalRarity => Self::start_medal_rarity().await,
+        }
+    }
+
+    async fn start_score_pp(mode: GameMode) -> Result<(Self, Receiver<String>)> {
         let (previous, mut next) = tokio::try_join!(
             ScorePp::random(mode, None, 0),
             ScorePp::random(mode, None, 0)
@@ -79,9 +121,85 @@ impl HigherLowerState {
         Ok((inner, rx))
     }
 
+    async fn start_global_rank(mode: GameMode) -> Result<(Self, Receiver<String>)> {
+        let (previous, mut next) = tokio::try_join!(
+            GlobalRank::random(mode, None, 0),
+            GlobalRank::random(mode, None, 0)
+        )
+        .wrap_err("Failed to create global rank entry")?;
+
+        while next == previous {
+            next = GlobalRank::random(mode, None, 0)
+                .await
+                .wrap_err("Failed to create global rank entry")?;
+        }
+
+        let rx = spawn_image(previous.image(), next.image());
+
+        let inner = Self::GlobalRank {
+            mode,
+            previous,
+            next,
+        };
+
+        Ok((inner, rx))
+    }
+
+    /// Mapset ids will be fetched if they're not provided.
+    async fn start_mapset(
+        value: MapsetValue,
+        mapset_ids: Option<Box<[u32]>>,
+    ) -> Result<(Self, Receiver<String>)> {
+        let mapset_ids = match mapset_ids {
+            Some(mapset_ids) => mapset_ids,
+            None => HlMapset::mapset_ids().await?,
+        };
+
+        let (previous, mut next) =
+            tokio::try_join!(HlMapset::random(&mapset_ids), HlMapset::random(&mapset_ids))
+                .wrap_err("Failed to create mapset entry")?;
+
+        while next == previous {
+            next = HlMapset::random(&mapset_ids)
+                .await
+                .wrap_err("Failed to create mapset entry")?;
+        }
+
+        let rx = spawn_image(previous.image(), next.image());
+
+        let inner = Self::Mapset {
+            value,
+            mapset_ids,
+            previous,
+            next,
+        };
+
+        Ok((inner, rx))
+    }
+
+    async fn start_medal_rarity() -> Result<(Self, Receiver<String>)> {
+        let (previous, mut next) = tokio::try_join!(MedalRarity::random(), MedalRarity::random())
+            .wrap_err("Failed to create medal entry")?;
+
+        while next == previous {
+            next = MedalRarity::random()
+                .await
+                .wrap_err("Failed to create medal entry")?;
+        }
+
+        let rx = spawn_image(previous.image(), next.image());
+
+        Ok((Self::MedalRarity { previous, next }, rx))
+    }
+
     pub(super) async fn restart(&mut self) -> Result<(Self, Receiver<String>)> {
         match self {
             Self::ScorePp { mode, .. } => Self::start_score_pp(*mode).await,
+            Self::GlobalRank { mode, .. } => Self::start_global_rank(*mode).await,
+            Self::Mapset {
+                value, mapset_ids, ..
+            } => Self::start_mapset(*value, Some(mapset_ids.clone())).await,
+            Self::MedalRarity { .. } => Self::start_medal_rarity().await,
         }
     }
 
@@ -133,6 +251,61 @@ impl HigherLowerState {
 
                 rx
             }
+            Self::GlobalRank {
+                mode,
+                previous,
+                next,
+            } => {
+                let mode = *mode;
+                mem::swap(previous, next);
+
+                *next = GlobalRank::random(mode, Some(&*previous), curr_score)
+                    .await
+                    .wrap_err("Failed to create global rank entry")?;
+
+                while previous == next {
+                    *next = GlobalRank::random(mode, Some(&*previous), curr_score)
+                        .await
+                        .wrap_err("Failed to create global rank entry")?;
+                }
+
+                spawn_image(previous.image(), next.image())
+            }
+            Self::Mapset {
+                mapset_ids,
+                previous,
+                next,
+                ..
+            } => {
+                mem::swap(previous, next);
+
+                *next = HlMapset::random(mapset_ids)
+                    .await
+                    .wrap_err("Failed to create mapset entry")?;
+
+                while previous == next {
+                    *next = HlMapset::random(mapset_ids)
+                        .await
+                        .wrap_err("Failed to create mapset entry")?;
+                }
+
+                spawn_image(previous.image(), next.image())
+            }
+            Self::MedalRarity { previous, next } => {
+                mem::swap(previous, next);
+
+                *next = MedalRarity::random()
+                    .await
+                    .wrap_err("Failed to create medal entry")?;
+
+                while previous == next {
+                    *next = MedalRarity::random()
+                        .await
+                        .wrap_err("Failed to create medal entry")?;
+                }
+
+                spawn_image(previous.image(), next.image())
+            }
         };
 
         Ok(rx)
@@ -166,42 +339,69 @@ impl HigherLowerState {
     }
 
     pub(super) fn to_embed(&self, revealed: bool) -> EmbedBuilder {
-        let mut title = "Higher or Lower: ".to_owned();
+        let mut title = format!("Higher or Lower: {}", self.version());
 
-        let builder = match self {
-            HigherLowerState::ScorePp {
-                mode,
-                previous,
-                next,
-            } => {
-                title.push_str("Score PP");
+        let mode = match self {
+            Self::ScorePp { mode, .. } | Self::GlobalRank { mode, .. } => Some(*mode),
+            Self::Mapset { .. } | Self::MedalRarity { .. } => None,
+        };
 
-                match mode {
-                    GameMode::Osu => {}
-                    GameMode::Taiko => title.push_str(" (taiko)"),
-                    GameMode::Catch => title.push_str(" (ctb)"),
-                    GameMode::Mania => title.push_str(" (mania)"),
-                }
+        match mode {
+            Some(GameMode::Osu) | None => {}
+            Some(GameMode::Taiko) => title.push_str(" (taiko)"),
+            Some(GameMode::Catch) => title.push_str(" (ctb)"),
+            Some(GameMode::Mania) => title.push_str(" (mania)"),
+        }
 
-                ScorePp::to_embed(previous, next, revealed)
+        let builder = match self {
+            Self::ScorePp { previous, next, .. } => ScorePp::to_embed(previous, next, revealed),
+            Self::GlobalRank { previous, next, .. } => {
+                GlobalRank::to_embed(previous, next, revealed)
             }
+            Self::Mapset {
+                value,
+                previous,
+                next,
+                ..
+            } => HlMapset::to_embed(previous, next, *value, revealed),
+            Self::MedalRarity { previous, next } => MedalRarity::to_embed(previous, next, revealed),
         };
 
         builder.title(title)
     }
 
     pub(super) fn check_guess(&self, guess: HlGuess) -> bool {
-        match self {
-            Self::ScorePp { previous, next, .. } => match guess {
-                HlGuess::Higher => next.pp >= previous.pp,
-                HlGuess::Lower => next.pp <= previous.pp,
-            },
+        let (previous, next) = match self {
+            Self::ScorePp { previous, next, .. } => (previous.pp, next.pp),
+            Self::GlobalRank { previous, next, .. } => (previous.rank as f32, next.rank as f32),
+            Self::Mapset {
+                value,
+                previous,
+                next,
+                ..
+            } => (previous.value(*value) as f32, next.value(*value) as f32),
+            Self::MedalRarity { previous, next } => (previous.rarity, next.rarity),
+        };
+
+        match guess {
+            HlGuess::Higher => next >= previous,
+            HlGuess::Lower => next <= previous,
         }
     }
 
     pub(super) fn version(&self) -> HlVersion {
         match self {
             Self::ScorePp { .. } => HlVersion::ScorePp,
+            Self::GlobalRank { .. } => HlVersion::GlobalRank,
+            Self::Mapset {
+                value: MapsetValue::Playcount,
+                ..
+            } => HlVersion::MapPlaycount,
+            Self::Mapset {
+                value: MapsetValue::Favourites,
+                ..
+            } => HlVersion::MapsetFavourites,
+            Self::MedalRarity { .. } => HlVersion::MedalRarity,
         }
     }
 }
@@ -209,3 +409,92 @@ impl HigherLowerState {
 pub(super) fn mapset_cover(mapset_id: u32) -> String {
     format!("https://assets.ppy.sh/beatmaps/{mapset_id}/covers/cover.jpg")
 }
+
+/// Image of a higherlower entry which will be shown on one half of the
+/// combined image.
+pub(super) enum HlImage {
+    Avatar(Box<str>),
+    Cover(u32),
+    MedalIcon(String),
+}
+
+impl HlImage {
+    async fn fetch(&self) -> Result<Bytes> {
+        let client = Context::client();
+
+        match self {
+            Self::Avatar(url) => client.get_avatar(url).await,
+            Self::Cover(mapset_id) => client.get_mapset_cover(&mapset_cover(*mapset_id)).await,
+            Self::MedalIcon(url) => client.get_medal_icon(url).await,
+        }
+    }
+
+    fn content(&self) -> String {
+        match self {
+            Self::Avatar(url) => url.to_string(),
+            Self::Cover(mapset_id) => mapset_id.to_string(),
+            Self::MedalIcon(url) => url.clone(),
+        }
+    }
+
+    /// Whether the image should fill its half, potentially cropping it,
+    /// instead of being fit into it.
+    fn fill(&self) -> bool {
+        matches!(self, Self::Cover(_))
+    }
+}
+
+/// Creates the combined image in the background so it's available when
+/// needed later.
+fn spawn_image(left: HlImage, right: HlImage) -> Receiver<String> {
+    let (tx, rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let url = match combine_images(&left, &right).await {
+            Ok(url) => url,
+            Err(err) => {
+                warn!(?err, "Failed to create image");
+
+                String::new()
+            }
+        };
+
+        let _ = tx.send(url);
+    });
+
+    rx
+}
+
+/// Places both images next to each other and uploads the result.
+async fn combine_images(left: &HlImage, right: &HlImage) -> Result<String> {
+    let (left_bytes, right_bytes) =
+        tokio::try_join!(left.fetch(), right.fetch()).wrap_err("Failed to retrieve some image")?;
+
+    let left_img =
+        image::load_from_memory(&left_bytes).wrap_err("Failed to load left image from memory")?;
+
+    let right_img =
+        image::load_from_memory(&right_bytes).wrap_err("Failed to load right image from memory")?;
+
+    let mut combined = ImageBuffer::new(W, H);
+
+    for (img, kind, offset) in [(left_img, left, 0), (right_img, right, W / 2)] {
+        let img = resize_half(img, kind.fill());
+        let x = offset + (W / 2 - img.width()) / 2;
+        let y = (H - img.height()) / 2;
+
+        imageops::overlay(&mut combined, &img.to_rgba8(), x as i64, y as i64);
+    }
+
+    let content = format!("{} ~ {}", left.content(), right.content());
+
+    HigherLowerState::upload_image(combined.as_raw(), content).await
+}
+
+fn resize_half(img: DynamicImage, fill: bool) -> DynamicImage {
+    if fill {
+        img.resize_to_fill(W / 2, H, FilterType::Triangle)
+    } else {
+        img.resize(W / 2, H, FilterType::Triangle)
+    }
+}
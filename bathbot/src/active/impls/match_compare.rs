@@ -23,6 +23,7 @@ use crate::{
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::MatchCompareComparison,
+    manager::Mappool,
     util::{
         interaction::{InteractionComponent, InteractionModal},
         osu::grade_emote,
@@ -60,6 +61,7 @@ impl MatchComparePagination {
         match1: &mut OsuMatch,
         match2: &mut OsuMatch,
         comparison: MatchCompareComparison,
+        mappool: Option<&Mappool>,
         msg_owner: Id<UserMarker>,
     ) -> Self {
         let users: HashMap<_, _, IntHasher> = match1
@@ -90,7 +92,7 @@ impl MatchComparePagination {
 
         for mut game_1 in match1.drain_games() {
             let (map_id, map) = match game_1.map.as_ref().filter(|_| game_1.end_time.is_some()) {
-                Some(map) => (map.map_id, map_name(map)),
+                Some(map) => (map.map_id, map_name(map, mappool)),
                 None => continue,
             };
 
@@ -118,7 +120,7 @@ impl MatchComparePagination {
 
         for game in match2.drain_games() {
             let (map_id, map) = match game.map.as_ref().filter(|_| game.end_time.is_some()) {
-                Some(map) => (map.map_id, map_name(map)),
+                Some(map) => (map.map_id, map_name(map, mappool)),
                 None => continue,
             };
 
@@ -505,9 +507,14 @@ impl UniqueMap {
     }
 }
 
-fn map_name(map: &Beatmap) -> Box<str> {
+/// Prefixes the name with the map's slot if the map is part of the mappool
+fn map_name(map: &Beatmap, mappool: Option<&Mappool>) -> Box<str> {
     let mut name = String::new();
 
+    if let Some(slot) = mappool.and_then(|mappool| mappool.slot(map.map_id)) {
+        let _ = write!(name, "{slot} • ");
+    }
+
     if let Some(ref mapset) = map.mapset {
         name.push_str(mapset.title.cow_escape_markdown().as_ref());
     } else {
@@ -17,61 +17,31 @@ use crate::{
         BuildPage, ComponentResult, IActiveMessage,
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
-    commands::osu::{MatchCostDisplay, MatchResult, TeamResult, UserMatchCostEntry},
+    commands::osu::{
+        MatchCostDisplay, MatchCostFormulaKind, MatchResult, PoolBreakdown, TeamResult,
+        UserMatchCostEntry,
+    },
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
 pub struct MatchCostPagination {
-    result: MatchResult,
-    osu_match: OsuMatch,
-    display: MatchCostDisplay,
+    data: MatchCostData,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
 
+struct MatchCostData {
+    result: MatchResult,
+    osu_match: OsuMatch,
+    display: MatchCostDisplay,
+    formula: MatchCostFormulaKind,
+    breakdown: Box<[PoolBreakdown]>,
+}
+
 impl IActiveMessage for MatchCostPagination {
     async fn build_page(&mut self) -> Result<BuildPage> {
-        let match_id = self.osu_match.match_id;
-        let mut title = self.osu_match.name.clone();
-        title.retain(|c| c != '(' && c != ')');
-
-        let mut embed = EmbedBuilder::new()
-            .title(title)
-            .url(format!("{OSU_BASE}community/matches/{match_id}"));
-
-        embed = match &self.result {
-            MatchResult::TeamVS {
-                blue,
-                red,
-                mvp_avatar_url,
-            } => {
-                let mut description = self.description_team_vs(blue, red);
-
-                validate_description_len(&mut description);
-
-                embed = match self.display {
-                    MatchCostDisplay::Compact => embed.thumbnail(mvp_avatar_url.as_ref()),
-                    MatchCostDisplay::Full => embed.footer(FooterBuilder::new(FULL_FOOTER)),
-                };
-
-                embed.description(description)
-            }
-            MatchResult::HeadToHead {
-                players,
-                mvp_avatar_url,
-            } => {
-                let description = self.description_head_to_head(players);
-
-                embed = match self.display {
-                    MatchCostDisplay::Compact => embed.thumbnail(mvp_avatar_url.as_ref()),
-                    MatchCostDisplay::Full => embed.footer(FooterBuilder::new(FULL_FOOTER)),
-                };
-
-                embed.description(description)
-            }
-            MatchResult::NoGames { description } => embed.description(description.as_str()),
-        };
+        let embed = self.data.embed(&self.pages);
 
         Ok(BuildPage::new(embed, false).content(self.content.clone()))
     }
@@ -89,7 +59,7 @@ impl IActiveMessage for MatchCostPagination {
     }
 
     fn until_timeout(&self) -> Option<Duration> {
-        match self.result {
+        match self.data.result {
             MatchResult::TeamVS { .. } | MatchResult::NoGames { .. } => None,
             MatchResult::HeadToHead { ref players, .. } => {
                 (players.len() > self.pages.per_page()).then_some(Duration::from_secs(60))
@@ -103,6 +73,8 @@ pub struct MatchCostPaginationBuilder {
     result: Option<MatchResult>,
     osu_match: Option<OsuMatch>,
     display: Option<MatchCostDisplay>,
+    formula: Option<MatchCostFormulaKind>,
+    breakdown: Option<Box<[PoolBreakdown]>>,
     content: Option<Box<str>>,
     msg_owner: Option<Id<UserMarker>>,
 }
@@ -112,6 +84,8 @@ impl MatchCostPaginationBuilder {
         let result = self.result.expect("missing result");
         let osu_match = self.osu_match.expect("missing osu match");
         let display = self.display.expect("missing display");
+        let formula = self.formula.unwrap_or_default();
+        let breakdown = self.breakdown.unwrap_or_default();
         let content = self.content.expect("missing content");
         let msg_owner = self.msg_owner.expect("missing msg owner");
 
@@ -121,16 +95,50 @@ impl MatchCostPaginationBuilder {
             MatchResult::NoGames { .. } => Pages::new(1, 1),
         };
 
-        MatchCostPagination {
+        let data = MatchCostData {
             result,
             osu_match,
             display,
+            formula,
+            breakdown,
+        };
+
+        MatchCostPagination {
+            data,
             content,
             msg_owner,
             pages,
         }
     }
 
+    /// Builds a single embed containing all players instead of a pagination.
+    ///
+    /// Neither content nor message owner are required.
+    pub fn build_embed(self) -> EmbedBuilder {
+        let result = self.result.expect("missing result");
+        let osu_match = self.osu_match.expect("missing osu match");
+        let display = self.display.unwrap_or_default();
+        let formula = self.formula.unwrap_or_default();
+        let breakdown = self.breakdown.unwrap_or_default();
+
+        let pages = match result {
+            MatchResult::HeadToHead { ref players, .. } => {
+                Pages::new(players.len().max(1), players.len())
+            }
+            MatchResult::TeamVS { .. } | MatchResult::NoGames { .. } => Pages::new(1, 1),
+        };
+
+        let data = MatchCostData {
+            result,
+            osu_match,
+            display,
+            formula,
+            breakdown,
+        };
+
+        data.embed(&pages)
+    }
+
     pub fn result(mut self, result: MatchResult) -> Self {
         self.result = Some(result);
 
@@ -149,6 +157,18 @@ impl MatchCostPaginationBuilder {
         self
     }
 
+    pub fn formula(mut self, formula: MatchCostFormulaKind) -> Self {
+        self.formula = Some(formula);
+
+        self
+    }
+
+    pub fn breakdown(mut self, breakdown: Box<[PoolBreakdown]>) -> Self {
+        self.breakdown = Some(breakdown);
+
+        self
+    }
+
     pub fn content(mut self, content: Box<str>) -> Self {
         self.content = Some(content);
 
@@ -166,6 +186,70 @@ impl MatchCostPagination {
     pub fn builder() -> MatchCostPaginationBuilder {
         MatchCostPaginationBuilder::default()
     }
+}
+
+impl MatchCostData {
+    fn embed(&self, pages: &Pages) -> EmbedBuilder {
+        let match_id = self.osu_match.match_id;
+        let mut title = self.osu_match.name.clone();
+        title.retain(|c| c != '(' && c != ')');
+
+        let mut embed = EmbedBuilder::new()
+            .title(title)
+            .url(format!("{OSU_BASE}community/matches/{match_id}"));
+
+        match &self.result {
+            MatchResult::TeamVS {
+                blue,
+                red,
+                mvp_avatar_url,
+            } => {
+                let mut description = self.description_team_vs(blue, red);
+                self.description_breakdown(&mut description, true);
+
+                validate_description_len(&mut description);
+
+                embed = self.apply_display(embed, mvp_avatar_url);
+
+                embed.description(description)
+            }
+            MatchResult::HeadToHead {
+                players,
+                mvp_avatar_url,
+            } => {
+                let mut description = self.description_head_to_head(players, pages);
+
+                if pages.index() == 0 {
+                    self.description_breakdown(&mut description, false);
+                }
+
+                validate_description_len(&mut description);
+
+                embed = self.apply_display(embed, mvp_avatar_url);
+
+                embed.description(description)
+            }
+            MatchResult::NoGames { description } => embed.description(description.as_str()),
+        }
+    }
+
+    fn apply_display(&self, embed: EmbedBuilder, mvp_avatar_url: &str) -> EmbedBuilder {
+        match self.display {
+            MatchCostDisplay::Compact => {
+                let embed = embed.thumbnail(mvp_avatar_url);
+
+                match self.formula {
+                    MatchCostFormulaKind::Bathbot => embed,
+                    formula => {
+                        embed.footer(FooterBuilder::new(format!("Formula: {}", formula.name())))
+                    }
+                }
+            }
+            MatchCostDisplay::Full => {
+                embed.footer(FooterBuilder::new(self.formula.formula().footer()))
+            }
+        }
+    }
 
     fn description_team_vs(&self, blue: &TeamResult, red: &TeamResult) -> String {
         let mut description = String::new();
@@ -244,13 +328,49 @@ impl MatchCostPagination {
         description
     }
 
-    fn description_head_to_head(&self, players: &[UserMatchCostEntry]) -> String {
+    fn description_breakdown(&self, description: &mut String, team_vs: bool) {
+        if self.breakdown.is_empty() {
+            return;
+        }
+
+        description.push_str("\n**Mod pools**\n");
+
+        for entry in self.breakdown.iter() {
+            let _ = write!(
+                description,
+                "`{pool}` {slots}",
+                pool = entry.pool.abbreviation(),
+                slots = entry.slots.join(" "),
+            );
+
+            if team_vs {
+                let _ = write!(
+                    description,
+                    " • :large_blue_diamond: {} - {} :small_red_triangle:",
+                    entry.blue_wins, entry.red_wins
+                );
+            }
+
+            if let Some((user_id, ratio)) = entry.best() {
+                let name = self.osu_match.users.get(&user_id).map_or_else(
+                    || format!("<user {user_id}>").into(),
+                    |user| Cow::Borrowed(user.username.as_str()),
+                );
+
+                let _ = write!(description, " • Best: `{name}` ({ratio:.2}x)");
+            }
+
+            description.push('\n');
+        }
+    }
+
+    fn description_head_to_head(&self, players: &[UserMatchCostEntry], pages: &Pages) -> String {
         let mut description = String::new();
 
         let lengths = Lengths::default().update(players, &self.osu_match.users);
 
-        let idx = self.pages.index();
-        let per_page = self.pages.per_page();
+        let idx = pages.index();
+        let per_page = pages.per_page();
         let entries = &players[idx..cmp::min(players.len(), idx + per_page)];
 
         match self.display {
@@ -285,9 +405,6 @@ impl MatchCostPagination {
     }
 }
 
-const FULL_FOOTER: &str =
-    "matchcost = (performance * participation * mods) + tiebreaker | average score";
-
 #[derive(Default)]
 struct Lengths {
     index: usize,
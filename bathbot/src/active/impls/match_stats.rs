@@ -0,0 +1,148 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE};
+use eyre::Result;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    commands::osu::{MapPerformance, MatchCostFormulaKind, MatchStatsPlayer, MatchStatsTeam},
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+/// Maximum amount of teams on the first page
+const TEAMS_LIMIT: usize = 8;
+
+#[derive(PaginationBuilder)]
+pub struct MatchStatsPagination {
+    #[pagination(per_page = 5)]
+    players: Box<[MatchStatsPlayer]>,
+    teams: Box<[MatchStatsTeam]>,
+    matches: usize,
+    maps: usize,
+    formula: MatchCostFormulaKind,
+    content: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for MatchStatsPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let end_idx = self.players.len().min(idx + pages.per_page());
+
+        let mut description = String::with_capacity(2048);
+
+        if idx == 0 && !self.teams.is_empty() {
+            description.push_str("__Teams__\n");
+
+            for team in self.teams.iter().take(TEAMS_LIMIT) {
+                let _ = writeln!(
+                    description,
+                    "**{name}** • Matches `{matches_won}/{matches}` • \
+                    Maps `{maps_won}/{maps}` • Avg MC `{mc:.2}`\n{pools}",
+                    name = team.name.cow_escape_markdown(),
+                    matches_won = team.matches_won,
+                    matches = team.matches,
+                    maps_won = team.pools.won(),
+                    maps = team.maps,
+                    mc = team.avg_match_cost(),
+                    pools = team.pools,
+                );
+            }
+
+            if self.teams.len() > TEAMS_LIMIT {
+                let remaining = self.teams.len() - TEAMS_LIMIT;
+                let _ = writeln!(description, "_... and {remaining} more teams_");
+            }
+
+            description.push_str("\n__Players__\n");
+        }
+
+        for (player, i) in self.players[idx..end_idx].iter().zip(idx + 1..) {
+            let _ = write!(
+                description,
+                "**#{i} [{name}]({OSU_BASE}u/{user_id})**",
+                name = player.username.cow_escape_markdown(),
+                user_id = player.user_id,
+            );
+
+            if let Some(ref team) = player.team {
+                let _ = write!(description, " ({})", team.cow_escape_markdown());
+            }
+
+            let _ = writeln!(
+                description,
+                "\nAvg MC `{mc:.2}` • {maps} maps in {matches} matches\n{pools}",
+                mc = player.avg_match_cost(),
+                maps = player.maps,
+                matches = player.matches,
+                pools = player.pools,
+            );
+
+            if let (Some(best), Some(worst)) = (&player.best, &player.worst) {
+                description.push_str("Best: ");
+                write_map(&mut description, best);
+                description.push_str("\nWorst: ");
+                write_map(&mut description, worst);
+                description.push('\n');
+            }
+        }
+
+        if self.players.is_empty() {
+            description.push_str("No games played");
+        }
+
+        let footer_text = format!(
+            "Page {page}/{pages} • Formula: {formula} • Pool records: won/played",
+            page = pages.curr_page(),
+            pages = pages.last_page(),
+            formula = self.formula.name(),
+        );
+
+        let title = format!(
+            "Stats across {matches} match{plural} ({maps} maps)",
+            matches = self.matches,
+            maps = self.maps,
+            plural = if self.matches == 1 { "" } else { "es" },
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title(title);
+
+        Ok(BuildPage::new(embed, false).content(self.content.clone()))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
+    }
+}
+
+fn write_map(description: &mut String, map: &MapPerformance) {
+    let _ = write!(
+        description,
+        "[{name}]({OSU_BASE}b/{map_id}) `{ratio:.2}x` ([match]({OSU_BASE}mp/{match_id}))",
+        name = map.map.cow_escape_markdown(),
+        map_id = map.map_id,
+        ratio = map.ratio,
+        match_id = map.match_id,
+    );
+}
@@ -5,6 +5,7 @@ pub use self::{
     badges::BadgesPagination,
     bg_game::{BackgroundGame, BackgroundGameSetup},
     bookmarks::BookmarksPagination,
+    challenge::ChallengeLeaderboardPagination,
     changelog::ChangelogPagination,
     compare::{CompareMostPlayedPagination, CompareScoresPagination, CompareTopPagination},
     embed_builder::ScoreEmbedBuilderActive,
@@ -15,6 +16,7 @@ pub use self::{
     map_search::MapSearchPagination,
     match_compare::MatchComparePagination,
     match_costs::MatchCostPagination,
+    match_stats::MatchStatsPagination,
     medals::{
         MedalsCommonPagination, MedalsListPagination, MedalsMissingPagination,
         MedalsRecentPagination,
@@ -35,12 +37,14 @@ pub use self::{
     snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
     top::TopPagination,
     top_if::TopIfPagination,
+    track_digest::TrackDigestPagination,
     track_list::TrackListPagination,
 };
 
 mod badges;
 mod bg_game;
 mod bookmarks;
+mod challenge;
 mod changelog;
 mod compare;
 mod embed_builder;
@@ -51,6 +55,7 @@ mod map;
 mod map_search;
 mod match_compare;
 mod match_costs;
+mod match_stats;
 mod medals;
 mod most_played;
 mod nochoke;
@@ -69,4 +74,5 @@ mod slash_commands;
 mod snipe;
 mod top;
 mod top_if;
+mod track_digest;
 mod track_list;
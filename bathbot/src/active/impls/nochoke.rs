@@ -14,6 +14,7 @@ use twilight_model::{
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
+        impls::TopOldVersion,
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::NochokeEntry,
@@ -30,8 +31,10 @@ pub struct NoChokePagination {
     user: CachedUser,
     #[pagination(per_page = 5)]
     entries: Box<[NochokeEntry]>,
+    pre_pp: f32,
     unchoked_pp: f32,
     rank: Option<u32>,
+    pp_version: Option<TopOldVersion>,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
@@ -43,13 +46,7 @@ impl IActiveMessage for NoChokePagination {
         let end_idx = self.entries.len().min(pages.index() + pages.per_page());
         let entries = &self.entries[pages.index()..end_idx];
 
-        let pp_raw = self
-            .user
-            .statistics
-            .as_ref()
-            .expect("missing stats")
-            .pp
-            .to_native();
+        let pp_raw = self.pre_pp;
 
         let pp_diff = (100.0 * (self.unchoked_pp - pp_raw)).round() / 100.0;
         let mut description = String::with_capacity(512);
@@ -116,6 +113,10 @@ impl IActiveMessage for NoChokePagination {
             );
         }
 
+        if let Some(version) = self.pp_version {
+            let _ = write!(footer_text, " • {version}");
+        }
+
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
             .description(description)
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Write};
 
 use bathbot_model::RankAccPeaks;
+use bathbot_psql::model::osu::DbUserStatsHistoryEntry;
 use bathbot_util::{
     Authored, EmbedBuilder, FooterBuilder, MessageOrigin,
     constants::OSU_BASE,
@@ -53,6 +54,7 @@ pub struct ProfileMenu {
     scores: Availability<Box<[Score]>>,
     score_rank: Availability<ScoreData>,
     osutrack_peaks: Option<RankAccPeaks>,
+    snapshot: Option<DbUserStatsHistoryEntry>,
     top100stats: Option<Top100Stats>,
     mapper_names: Availability<MapperNames>,
     kind: ProfileKind,
@@ -175,6 +177,7 @@ impl ProfileMenu {
         discord_id: Option<Id<UserMarker>>,
         tz: Option<UtcOffset>,
         osutrack_peaks: Option<RankAccPeaks>,
+        snapshot: Option<DbUserStatsHistoryEntry>,
         legacy_scores: bool,
         kind: ProfileKind,
         origin: MessageOrigin,
@@ -185,6 +188,7 @@ impl ProfileMenu {
             discord_id,
             tz,
             osutrack_peaks,
+            snapshot,
             legacy_scores,
             kind,
             msg_owner,
@@ -253,6 +257,8 @@ impl ProfileMenu {
             );
         }
 
+        self.write_snapshot_delta(&mut description);
+
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(true))
             .description(description)
@@ -354,6 +360,7 @@ impl ProfileMenu {
             stats.total_hits.to_native() as f32 / stats.playcount.to_native() as f32;
 
         description.push_str(":**__");
+        self.write_snapshot_delta(&mut description);
 
         self.consider_osutrack_peaks(&mut highest_rank);
 
@@ -925,6 +932,38 @@ impl ProfileMenu {
         }
     }
 
+    /// Writes the stat changes since the stored snapshot into the description.
+    fn write_snapshot_delta(&self, description: &mut String) {
+        let (Some(snapshot), Some(stats)) = (self.snapshot.as_ref(), self.user.statistics.as_ref())
+        else {
+            return;
+        };
+
+        let _ = write!(
+            description,
+            "\nSince <t:{timestamp}:d>: PP `{pp:+.2}`",
+            timestamp = snapshot.snapshot_at.unix_timestamp(),
+            pp = stats.pp.to_native() - snapshot.pp,
+        );
+
+        let rank = stats.global_rank.to_native();
+
+        if rank > 0 && snapshot.global_rank > 0 {
+            let _ = write!(
+                description,
+                " • Rank `{:+}`",
+                snapshot.global_rank as i64 - rank as i64
+            );
+        }
+
+        let _ = write!(
+            description,
+            " • Accuracy `{acc:+.2}%` • Playcount `{playcount:+}`",
+            acc = stats.accuracy.to_native() - snapshot.accuracy,
+            playcount = stats.playcount.to_native() as i64 - snapshot.playcount as i64,
+        );
+    }
+
     fn footer(&self) -> FooterBuilder {
         let mut join_date = self.user.join_date.try_deserialize::<Panic>().always_ok();
 
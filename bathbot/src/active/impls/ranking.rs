@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{
         BTreeMap,
         btree_map::{Entry, Range},
@@ -7,9 +8,13 @@ use std::{
 };
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_model::{BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind};
+use bathbot_model::{
+    BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind, ServerTopPlay,
+};
 use bathbot_util::{
-    EmbedBuilder,
+    CowUtils, EmbedBuilder,
+    constants::OSU_BASE,
+    datetime::HowLongAgoDynamic,
     numbers::{WithComma, round},
 };
 use eyre::{Result, WrapErr};
@@ -90,6 +95,9 @@ impl IActiveMessage for RankingPagination {
             RankingEntries::Rank(ref entries) => {
                 Self::finalize::<_, Rank<'_>>(&mut buf, &mut description, entries, idx)
             }
+            RankingEntries::TopPlays(ref entries) => {
+                Self::finalize_top_plays(&mut description, entries, idx)
+            }
         };
 
         let page = self.pages.curr_page();
@@ -183,6 +191,41 @@ impl RankingPagination {
         }
     }
 
+    fn finalize_top_plays(
+        description: &mut String,
+        entries: &BTreeMap<usize, RankingEntry<ServerTopPlay>>,
+        idx: usize,
+    ) {
+        // Keeps a full page of entries within the description limit
+        const MAP_LEN: usize = 45;
+
+        for (i, entry) in entries.range(idx..idx + 20) {
+            let play = &entry.value;
+
+            let map = match play.map {
+                Some(ref map) => match map.char_indices().nth(MAP_LEN) {
+                    Some((end, _)) => Cow::Owned(format!("{}...", &map[..end])),
+                    None => Cow::Borrowed(map.as_str()),
+                },
+                None => Cow::Owned(format!("Map {}", play.map_id)),
+            };
+
+            let _ = writeln!(
+                description,
+                "**#{idx}** {name} • **{pp:.2}pp**\n\
+                [{map}]({OSU_BASE}b/{map_id}) +{mods} • {acc:.2}% • {ago}",
+                idx = i + 1,
+                name = entry.name.cow_escape_markdown(),
+                pp = play.pp,
+                map = map.cow_escape_markdown(),
+                map_id = play.map_id,
+                mods = play.mods,
+                acc = play.accuracy,
+                ago = HowLongAgoDynamic::new(&play.ended_at),
+            );
+        }
+    }
+
     async fn assure_present_users(&mut self, page: usize) -> Result<()> {
         let pages = &self.pages;
         let range = pages.index()..pages.index() + pages.per_page();
@@ -2,10 +2,9 @@ use std::{fmt::Write, mem};
 
 use bathbot_util::{
     Authored, BucketName, EmbedBuilder, MessageBuilder,
-    constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE},
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
 };
 use eyre::{ContextCompat, Report, Result, WrapErr};
-use rosu_render::{ClientError as OrdrError, client::error::ApiError as OrdrApiError};
 use rosu_v2::error::OsuError;
 use twilight_model::{
     channel::message::{
@@ -17,9 +16,10 @@ use twilight_model::{
 
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage, response::ActiveResponse},
-    commands::osu::{OngoingRender, RENDERER_NAME, RenderStatus, RenderStatusInner},
+    commands::osu::{OngoingRender, RenderStatus, RenderStatusInner},
     core::Context,
     manager::ReplayError,
+    tracking::{IRenderBackend, RenderError, RenderSource},
     util::{ComponentExt, MessageExt, interaction::InteractionComponent},
 };
 
@@ -158,26 +158,16 @@ impl CachedRender {
             "Commissioning render"
         );
 
-        let render_fut = Context::ordr()
-            .client()
-            .render_with_replay_file(&replay, RENDERER_NAME, &skin.skin)
-            .options(settings.options());
+        let render = Context::render();
+        let source = RenderSource::File(&replay);
+        let render_fut = render.commission(source, &skin.skin, settings.options());
 
-        let render = match render_fut.await {
-            Ok(render) => render,
+        let render_id = match render_fut.await {
+            Ok(render_id) => render_id,
             Err(err) => {
                 let (content, err) = match err {
-                    OrdrError::Response {
-                        error:
-                            OrdrApiError {
-                                code: Some(code), ..
-                            },
-                        ..
-                    } => (
-                        format!("Error code {int} from o!rdr: {code}", int = code.to_u8()),
-                        None,
-                    ),
-                    err => (ORDR_ISSUE.to_owned(), Some(err)),
+                    RenderError::Rejected(content) => (content, None),
+                    RenderError::Report(err) => (render.issue().to_owned(), Some(err)),
                 };
 
                 let embed = EmbedBuilder::new().color_red().description(content);
@@ -185,19 +175,14 @@ impl CachedRender {
                 let _ = component.update(builder).await;
 
                 return match err {
-                    Some(err) => Err(Report::new(err).wrap_err("Failed to commission render")),
+                    Some(err) => Err(err),
                     None => return Ok(()),
                 };
             }
         };
 
-        let ongoing_fut = OngoingRender::new(
-            render.render_id,
-            &*component,
-            status,
-            Some(self.score_id),
-            owner,
-        );
+        let ongoing_fut =
+            OngoingRender::new(render_id, &*component, status, Some(self.score_id), owner);
 
         tokio::spawn(ongoing_fut.await.await_render_url());
 
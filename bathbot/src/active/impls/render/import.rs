@@ -22,6 +22,7 @@ use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage},
     core::Context,
     manager::ReplaySettings,
+    tracking::Ordr,
     util::{
         ModalExt,
         interaction::{InteractionComponent, InteractionModal},
@@ -239,7 +240,11 @@ impl IActiveMessage for SettingsImport {
         };
 
         let user = modal.user_id()?;
-        let ordr = Context::ordr().client();
+        let Some(ordr) = Context::try_ordr().map(Ordr::client) else {
+            self.import_result = ImportResult::Err(eyre!("Cannot validate skins without o!rdr"));
+
+            return Ok(());
+        };
 
         let settings = match skin {
             RenderSkinOption::Official { ref name } => {
@@ -465,20 +465,23 @@ impl IActiveMessage for RenderSettingsActive {
 
                 // We're not simply propagating errors because the modal must be deferred
                 // already so we need to respond properly
-                match Context::ordr().client().skin_list().search(input).await {
-                    Ok(mut skin_list) => {
-                        let skin_opt =
-                            (!skin_list.skins.is_empty()).then(|| skin_list.skins.swap_remove(0));
+                match Context::try_ordr() {
+                    Some(ordr) => match ordr.client().skin_list().search(input).await {
+                        Ok(mut skin_list) => {
+                            let skin_opt = (!skin_list.skins.is_empty())
+                                .then(|| skin_list.skins.swap_remove(0));
 
-                        match skin_opt {
-                            Some(skin) => self.settings.official_skin(skin),
-                            None => self.skin_status = SkinStatus::NotFoundName,
+                            match skin_opt {
+                                Some(skin) => self.settings.official_skin(skin),
+                                None => self.skin_status = SkinStatus::NotFoundName,
+                            }
                         }
-                    }
-                    Err(err) => {
-                        warn!(?err, "Failed to search for official skin `{input}`");
-                        self.skin_status = SkinStatus::Err;
-                    }
+                        Err(err) => {
+                            warn!(?err, "Failed to search for official skin `{input}`");
+                            self.skin_status = SkinStatus::Err;
+                        }
+                    },
+                    None => self.skin_status = SkinStatus::Unavailable,
                 }
             }
             "custom_skin" => match input.trim() {
@@ -491,12 +494,15 @@ impl IActiveMessage for RenderSettingsActive {
                     modal.defer().await.wrap_err("Failed to defer modal")?;
                     deferred = true;
 
-                    match Context::ordr().client().custom_skin_info(id).await {
-                        Ok(info) => self.settings.custom_skin(id, info),
-                        Err(err) => {
-                            warn!(?err, "Failed to search for custom skin `{input}`");
-                            self.skin_status = SkinStatus::NotFoundId;
-                        }
+                    match Context::try_ordr() {
+                        Some(ordr) => match ordr.client().custom_skin_info(id).await {
+                            Ok(info) => self.settings.custom_skin(id, info),
+                            Err(err) => {
+                                warn!(?err, "Failed to search for custom skin `{input}`");
+                                self.skin_status = SkinStatus::NotFoundId;
+                            }
+                        },
+                        None => self.skin_status = SkinStatus::Unavailable,
                     }
                 }
             },
@@ -1140,6 +1146,7 @@ enum SkinStatus {
     NotFoundName,
     Err,
     NotFoundId,
+    Unavailable,
 }
 
 impl SkinStatus {
@@ -1155,6 +1162,9 @@ impl Display for SkinStatus {
             SkinStatus::NotFoundName => f.write_str("⚠️ No official skin fits the name you gave\n"),
             SkinStatus::NotFoundId => f.write_str("⚠️ No custom skin found for the ID you gave\n"),
             SkinStatus::Err => f.write_str("⚠️ Failed to validate skin, maybe try again later\n"),
+            SkinStatus::Unavailable => {
+                f.write_str("⚠️ Skins can only be changed when rendering through o!rdr\n")
+            }
         }
     }
 }
@@ -8,6 +8,27 @@ pub struct SimulateAttributes {
     pub od: Option<f32>,
 }
 
+impl SimulateAttributes {
+    /// Overwrites the map's attributes with all specified values
+    pub fn apply(self, map: &mut Beatmap) {
+        if let Some(ar) = self.ar {
+            map.ar = ar;
+        }
+
+        if let Some(cs) = self.cs {
+            map.cs = cs;
+        }
+
+        if let Some(hp) = self.hp {
+            map.hp = hp;
+        }
+
+        if let Some(od) = self.od {
+            map.od = od;
+        }
+    }
+}
+
 impl From<&Beatmap> for SimulateAttributes {
     #[inline]
     fn from(map: &Beatmap) -> Self {
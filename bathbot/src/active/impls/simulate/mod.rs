@@ -68,25 +68,7 @@ pub struct SimulateComponents {
 
 impl IActiveMessage for SimulateComponents {
     async fn build_page(&mut self) -> Result<BuildPage> {
-        {
-            let pp_map = self.map.pp_map_mut();
-
-            if let Some(ar) = self.data.attrs.ar {
-                pp_map.ar = ar;
-            }
-
-            if let Some(cs) = self.data.attrs.cs {
-                pp_map.cs = cs;
-            }
-
-            if let Some(hp) = self.data.attrs.hp {
-                pp_map.hp = hp;
-            }
-
-            if let Some(od) = self.data.attrs.od {
-                pp_map.od = od;
-            }
-        }
+        self.data.attrs.apply(self.map.pp_map_mut());
 
         let mut title = match self.map {
             SimulateMap::Full(ref map) => {
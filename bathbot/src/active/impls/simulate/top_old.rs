@@ -4,6 +4,7 @@ use rosu_pp::{
     Beatmap, Performance, any::HitResultPriority, catch::CatchScoreState, mania::ManiaScoreState,
     osu::OsuScoreState, taiko::TaikoScoreState,
 };
+use rosu_v2::prelude::GameMode;
 use twilight_model::channel::message::{
     Component,
     component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption, SelectMenuType},
@@ -23,6 +24,29 @@ pub enum TopOldVersion {
 }
 
 impl TopOldVersion {
+    /// The pp version that is currently live for the mode
+    pub fn current(mode: GameMode) -> Self {
+        match mode {
+            GameMode::Osu => Self::Osu(TopOldOsuVersion::March25Now),
+            GameMode::Taiko => Self::Taiko(TopOldTaikoVersion::March25Now),
+            GameMode::Catch => Self::Catch(TopOldCatchVersion::October24Now),
+            GameMode::Mania => Self::Mania(TopOldManiaVersion::October24Now),
+        }
+    }
+
+    pub fn mode(self) -> GameMode {
+        match self {
+            Self::Osu(_) => GameMode::Osu,
+            Self::Taiko(_) => GameMode::Taiko,
+            Self::Catch(_) => GameMode::Catch,
+            Self::Mania(_) => GameMode::Mania,
+        }
+    }
+
+    pub fn is_current(self) -> bool {
+        self == Self::current(self.mode())
+    }
+
     pub fn from_menu_str(s: &str) -> Option<Self> {
         let version = match s {
             "sim_osu_march25_now" => Self::Osu(TopOldOsuVersion::March25Now),
@@ -13,14 +13,13 @@ use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     AuthorBuilder, Authored, BucketName, CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
     ModsFormatter, attachment,
-    constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
     datetime::{HowLongAgoDynamic, HowLongAgoText, SHORT_NAIVE_DATETIME_FORMAT, SecToMinSec},
     fields,
     numbers::round,
 };
 use eyre::{Report, Result};
 use rosu_pp::model::beatmap::BeatmapAttributes;
-use rosu_render::{ClientError as OrdrError, client::error::ApiError as OrdrApiError};
 use rosu_v2::{
     error::OsuError,
     model::{GameMode, Grade},
@@ -42,16 +41,17 @@ use twilight_model::{
 use crate::{
     active::{
         ActiveMessages, BuildPage, ComponentResult, IActiveMessage,
-        impls::{CachedRender, embed_builder::ValueKind},
+        impls::{CachedRender, TopOldVersion, embed_builder::ValueKind},
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::{
-        osu::{OngoingRender, RENDERER_NAME, RenderStatus, RenderStatusInner},
+        osu::{OngoingRender, RenderStatus, RenderStatusInner},
         utility::{ScoreEmbedData, ScoreEmbedDataWrap},
     },
     core::Context,
     embeds::HitResultFormatter,
     manager::{ReplayError, redis::osu::CachedUser},
+    tracking::{IRenderBackend, RenderError, RenderSource},
     util::{
         CachedUserExt, Emote, MessageExt,
         interaction::{InteractionComponent, InteractionModal},
@@ -65,6 +65,7 @@ pub struct SingleScorePagination {
     score_data: ScoreData,
     msg_owner: Id<UserMarker>,
     pages: Pages,
+    pp_version: Option<TopOldVersion>,
 
     author: AuthorBuilder,
     content: SingleScoreContent,
@@ -91,6 +92,7 @@ impl SingleScorePagination {
             score_data,
             msg_owner,
             pages,
+            pp_version: None,
             author: user.author_builder(false),
             content,
         }
@@ -100,13 +102,24 @@ impl SingleScorePagination {
         self.pages.set_index(idx);
     }
 
+    /// Recalculate the pp of all scores with a previous pp version
+    pub fn set_pp_version(&mut self, pp_version: Option<TopOldVersion>) {
+        self.pp_version = pp_version;
+    }
+
     // refactored into a pub method so it's usable from elsewhere
     pub async fn async_build_page(
         &mut self,
         content: Box<str>,
         mark_idx: MarkIndex,
     ) -> Result<BuildPage> {
-        let score = &*self.scores[self.pages.index()].get_mut().await?;
+        let score = self.scores[self.pages.index()].get_mut().await?;
+
+        if let Some(version) = self.pp_version {
+            score.apply_pp_version(version).await;
+        }
+
+        let score = &*score;
 
         let embed = Self::apply_settings(&self.settings, score, self.score_data, mark_idx);
 
@@ -381,26 +394,19 @@ impl SingleScorePagination {
 
         debug!(score_id, discord = owner.get(), "Commissioning render");
 
-        let render_fut = Context::ordr()
-            .client()
-            .render_with_replay_file(&replay, RENDERER_NAME, &skin.skin)
-            .options(settings.options());
+        let render = Context::render();
+        let source = RenderSource::File(&replay);
+        let render_fut = render.commission(source, &skin.skin, settings.options());
 
-        let render = match render_fut.await {
-            Ok(render) => render,
+        let render_id = match render_fut.await {
+            Ok(render_id) => render_id,
             Err(err) => {
                 let content = match err {
-                    OrdrError::Response {
-                        error:
-                            OrdrApiError {
-                                code: Some(code), ..
-                            },
-                        ..
-                    } => format!("Error code {int} from o!rdr: {code}", int = code.to_u8()),
-                    err => {
+                    RenderError::Rejected(content) => content,
+                    RenderError::Report(err) => {
                         error!(?err, "Failed to commission render");
 
-                        ORDR_ISSUE.to_owned()
+                        render.issue().to_owned()
                     }
                 };
 
@@ -415,13 +421,8 @@ impl SingleScorePagination {
             }
         };
 
-        let ongoing_fut = OngoingRender::new(
-            render.render_id,
-            (msg, permissions),
-            status,
-            Some(score_id),
-            owner,
-        );
+        let ongoing_fut =
+            OngoingRender::new(render_id, (msg, permissions), status, Some(score_id), owner);
 
         ongoing_fut.await.await_render_url().await;
     }
@@ -967,6 +968,14 @@ fn apply_settings(
         SettingsImage::Hide => {}
     }
 
+    if let Some(version) = data.pp_version {
+        if !footer_text.is_empty() {
+            footer_text.push_str(SEP_VALUE);
+        }
+
+        let _ = write!(footer_text, "{version}");
+    }
+
     if !footer_text.is_empty() {
         let emote = Emote::from(data.score.mode).url();
         let footer = FooterBuilder::new(footer_text).icon_url(emote);
@@ -17,6 +17,7 @@ use twilight_model::{
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
+        impls::TopOldVersion,
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::TopIfEntry,
@@ -38,6 +39,7 @@ pub struct TopIfPagination {
     pre_pp: f32,
     post_pp: f32,
     rank: Option<u32>,
+    pp_version: Option<TopOldVersion>,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
@@ -95,6 +97,10 @@ impl IActiveMessage for TopIfPagination {
             );
         }
 
+        if let Some(version) = self.pp_version {
+            let _ = write!(footer_text, " • {version}");
+        }
+
         let title = format!(
             "Total pp: {pre_pp} → **{post_pp}pp** ({pp_diff:+})",
             pre_pp = self.pre_pp,
@@ -0,0 +1,115 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder,
+    constants::OSU_BASE,
+    numbers::{WithComma, round},
+};
+use eyre::Result;
+use twilight_model::channel::message::Component;
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    tracking::{TrackDigestInterval, TrackDigestUserEntry},
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct TrackDigestPagination {
+    #[pagination(per_page = 5)]
+    entries: Box<[TrackDigestUserEntry]>,
+    interval: TrackDigestInterval,
+    pages: Pages,
+}
+
+impl IActiveMessage for TrackDigestPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let pages = &self.pages;
+        let end_idx = self.entries.len().min(pages.index() + pages.per_page());
+        let entries = &self.entries[pages.index()..end_idx];
+
+        let mut description = String::with_capacity(entries.len() * 300);
+
+        for entry in entries {
+            let TrackDigestUserEntry {
+                user_id,
+                username,
+                mode: _,
+                pp_gained,
+                rank,
+                score_count,
+                scores,
+            } = entry;
+
+            let _ = write!(
+                description,
+                "**[{username}]({OSU_BASE}u/{user_id})**: `{sign}{pp_gained}pp`",
+                sign = if *pp_gained >= 0.0 { "+" } else { "" },
+                pp_gained = round(*pp_gained),
+            );
+
+            if let Some(rank) = rank {
+                let _ = write!(description, " • `#{}`", WithComma::new(*rank));
+            }
+
+            let _ = writeln!(
+                description,
+                " • {score_count} new top play{plural}",
+                plural = if *score_count == 1 { "" } else { "s" },
+            );
+
+            for score in scores.iter() {
+                let _ = writeln!(
+                    description,
+                    "- **#{idx}** [{title}]({OSU_BASE}b/{map_id}) +{mods} • **{pp}pp**",
+                    idx = score.top_index,
+                    title = score.map_title,
+                    map_id = score.map_id,
+                    mods = score.mods,
+                    pp = round(score.pp),
+                );
+            }
+
+            description.push('\n');
+        }
+
+        let page = pages.curr_page();
+        let last_page = pages.last_page();
+
+        let footer_text = format!(
+            "Page {page}/{last_page} • Tracked users with new top plays: {}",
+            self.entries.len()
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title(format!("Tracking digest ({})", self.interval));
+
+        Ok(BuildPage::new(embed, false))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        // Digests are not owned by anyone so everyone may paginate
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        handle_pagination_component(component, user_id, false, &mut self.pages).await
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        let user_id = modal.user_id()?;
+
+        handle_pagination_modal(modal, user_id, false, &mut self.pages).await
+    }
+}
@@ -13,7 +13,7 @@ use crate::{
         BuildPage, ComponentResult, IActiveMessage,
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
-    commands::tracking::TracklistUserEntry,
+    commands::tracking::{TracklistParams, TracklistUserEntry},
     util::{
         Emote,
         interaction::{InteractionComponent, InteractionModal},
@@ -44,15 +44,33 @@ impl IActiveMessage for TrackListPagination {
                 params,
             } = entry;
 
-            let _ = writeln!(
+            let _ = write!(
                 description,
-                "[`{name}`]({OSU_BASE}u/{user_id}) {mode}: \
-                `Index: {index}` • `PP: {pp}` • `Combo percent: {combo_percent}%`",
+                "[`{name}`]({OSU_BASE}u/{user_id}) {mode}: ",
                 mode = Emote::from(*mode),
-                index = params.index(),
-                pp = params.pp(),
-                combo_percent = params.combo_percent(),
             );
+
+            match params {
+                TracklistParams::TopScores(params) => {
+                    let _ = write!(
+                        description,
+                        "`Index: {index}` • `PP: {pp}` • `Combo percent: {combo_percent}%`",
+                        index = params.index(),
+                        pp = params.pp(),
+                        combo_percent = params.combo_percent(),
+                    );
+
+                    params.write_extra_filters(&mut description, " • ");
+                }
+                TracklistParams::Leaderboard(params) => {
+                    let _ = write!(description, "`Leaderboard` • {params}");
+                }
+                TracklistParams::Milestones(params) => {
+                    let _ = write!(description, "`Milestones` • {params}");
+                }
+            }
+
+            description.push('\n');
         }
 
         if description.is_empty() {
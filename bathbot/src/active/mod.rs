@@ -16,17 +16,18 @@ use self::{
     builder::ActiveMessagesBuilder,
     impls::{
         BackgroundGameSetup, BadgesPagination, BookmarksPagination, CachedRender,
-        ChangelogPagination, CompareMostPlayedPagination, CompareScoresPagination,
-        CompareTopPagination, HelpInteractionCommand, HelpPrefixMenu, HigherLowerGame,
-        LeaderboardPagination, MapPagination, MapSearchPagination, MatchComparePagination,
-        MatchCostPagination, MedalCountPagination, MedalRarityPagination, MedalsCommonPagination,
-        MedalsListPagination, MedalsMissingPagination, MedalsRecentPagination,
-        MostPlayedPagination, NoChokePagination, OsuStatsBestPagination, OsuStatsPlayersPagination,
-        OsuStatsScoresPagination, ProfileMenu, RankingCountriesPagination, RankingPagination,
-        RecentListPagination, RenderSettingsActive, ScoreEmbedBuilderActive, SettingsImport,
-        SimulateComponents, SingleScorePagination, SkinsPagination, SlashCommandsPagination,
-        SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination,
-        TopIfPagination, TopPagination, TrackListPagination,
+        ChallengeLeaderboardPagination, ChangelogPagination, CompareMostPlayedPagination,
+        CompareScoresPagination, CompareTopPagination, HelpInteractionCommand, HelpPrefixMenu,
+        HigherLowerGame, LeaderboardPagination, MapPagination, MapSearchPagination,
+        MatchComparePagination, MatchCostPagination, MatchStatsPagination, MedalCountPagination,
+        MedalRarityPagination, MedalsCommonPagination, MedalsListPagination,
+        MedalsMissingPagination, MedalsRecentPagination, MostPlayedPagination, NoChokePagination,
+        OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination, ProfileMenu,
+        RankingCountriesPagination, RankingPagination, RecentListPagination, RenderSettingsActive,
+        ScoreEmbedBuilderActive, SettingsImport, SimulateComponents, SingleScorePagination,
+        SkinsPagination, SlashCommandsPagination, SnipeCountryListPagination,
+        SnipeDifferencePagination, SnipePlayerListPagination, TopIfPagination, TopPagination,
+        TrackDigestPagination, TrackListPagination,
     },
     response::ActiveResponse,
 };
@@ -51,6 +52,7 @@ pub enum ActiveMessage {
     BadgesPagination,
     BookmarksPagination,
     CachedRender,
+    ChallengeLeaderboardPagination,
     ChangelogPagination,
     CompareMostPlayedPagination,
     CompareScoresPagination,
@@ -63,6 +65,7 @@ pub enum ActiveMessage {
     MapSearchPagination,
     MatchComparePagination,
     MatchCostPagination,
+    MatchStatsPagination,
     MedalCountPagination,
     MedalRarityPagination,
     MedalsCommonPagination,
@@ -91,6 +94,7 @@ pub enum ActiveMessage {
     SnipePlayerListPagination,
     TopPagination,
     TopIfPagination,
+    TrackDigestPagination,
     TrackListPagination,
 }
 
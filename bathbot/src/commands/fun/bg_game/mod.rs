@@ -1,7 +1,7 @@
 use std::ops::ControlFlow;
 
 use bathbot_macros::{SlashCommand, command};
-use bathbot_model::{Effects, command_fields::ThreadChannel};
+use bathbot_model::{BgGameVariant, Effects, command_fields::ThreadChannel};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{
     Authored, CowUtils, MessageBuilder,
@@ -24,7 +24,7 @@ use crate::{
         ActiveMessages,
         impls::{BackgroundGame, BackgroundGameSetup},
     },
-    core::commands::interaction::InteractionCommands,
+    core::commands::{CommandOrigin, interaction::InteractionCommands},
     util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -58,9 +58,11 @@ pub async fn prefix_backgroundgame(
                 • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
                 • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
                 • `<bg stop`: Resolve the current background and stop the game.
-                • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
-                amount of correct guesses. If `server` or `s` is added at the end, \
-                I will only show members of this server.";
+                • `<bg l[eaderboard] s[erver] [variant]`: Check out the global leaderboard for \
+                amount of correct guesses. If `server` or `s` is added, \
+                I will only show members of this server. Add `mapper`, `stars`, `blurred` \
+                or `pixelated` to show the leaderboard of that game variant. \
+                The same is available through `/bgleaderboard`.";
 
             let builder = MessageBuilder::new().embed(content);
             msg.create_message(builder, permissions).await?;
@@ -72,12 +74,29 @@ pub async fn prefix_backgroundgame(
         Some("b" | "bigger" | "enhance") => bigger(msg, permissions).await,
         Some("stop" | "end" | "quit") => stop(msg).await,
         Some("l" | "lb" | "leaderboard") => {
-            let arg = args.next();
-
-            match arg.as_ref().map(|arg| arg.as_ref()) {
-                Some("s" | "server") => leaderboard(msg, false).await,
-                _ => leaderboard(msg, true).await,
+            let mut global = true;
+            let mut variant = BgGameVariant::default();
+
+            for arg in args {
+                match arg.as_ref() {
+                    "s" | "server" => global = false,
+                    other => match other.parse() {
+                        Ok(parsed) => variant = parsed,
+                        Err(_) => {
+                            let content = format!(
+                                "Unknown variant `{other}`, must be either `title`, \
+                                `mapper`, `stars`, `blurred`, or `pixelated`"
+                            );
+
+                            msg.error(content).await?;
+
+                            return Ok(());
+                        }
+                    },
+                }
             }
+
+            leaderboard(CommandOrigin::from_msg(msg, permissions), global, variant).await
         }
         _ => {
             let prefix = Context::guild_config().first_prefix(msg.guild_id).await;
@@ -105,14 +124,32 @@ pub async fn prefix_backgroundgame(
     • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
     • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
     • `<bg stop`: Resolve the current background and stop the game.
-    • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
-    amount of correct guesses. If `server` or `s` is added at the end, \
-    I will only show members of this server."
+    • `<bg l[eaderboard] s[erver] [variant]`: Check out the global leaderboard for \
+    amount of correct guesses. If `server` or `s` is added, \
+    I will only show members of this server. Add `mapper`, `stars`, `blurred` \
+    or `pixelated` to show the leaderboard of that game variant. \
+    The same is available through `/bgleaderboard`.\n\n\
+    Instead of the title, the `variant` option lets you guess the mapper or \
+    the star rating of the hardest difficulty. \
+    Blurred and pixelated variants show the whole background which gets clearer with `<bg bigger`.\n\
+    For the star rating, guess the whole number of stars e.g. `5` for 5.00 to 5.99 stars. \
+    Each user can guess once every 5 seconds."
 )]
 #[flags(SKIP_DEFER)]
 pub struct Bg {
     #[command(desc = "Specify a gamemode")]
     mode: Option<BgGameMode>,
+    #[command(
+        desc = "Specify what to guess and how the background is shown",
+        help = "Specify what to guess and how the background is shown.\n\
+        - `Title`: Guess the title from a part of the background (default)\n\
+        - `Mapper`: Guess the mapper from a part of the background\n\
+        - `Star rating`: Guess the star rating of the hardest difficulty, e.g. `5` for 5.00 to 5.99 stars. \
+        Each user can guess once every 5 seconds.\n\
+        - `Blurred title`: Guess the title from the whole but blurred background\n\
+        - `Pixelated title`: Guess the title from the whole but pixelated background"
+    )]
+    variant: Option<BgGameVariant>,
     #[command(
         desc = "Increase difficulty by requiring better guessing",
         help = "Increase the difficulty.\n\
@@ -255,6 +292,7 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
     let Bg {
         difficulty,
         mode,
+        variant,
         thread,
     } = Bg::from_interaction(command.input_data())?;
 
@@ -362,10 +400,11 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
     }
 
     let difficulty = difficulty.unwrap_or_default();
+    let variant = variant.unwrap_or_default();
 
     match mode {
         Some(BgGameMode::Osu) | None => {
-            let setup = BackgroundGameSetup::new(difficulty, author);
+            let setup = BackgroundGameSetup::new(difficulty, variant, author);
 
             if matches!(thread, Some(ThreadChannel::Thread)) {
                 let res_builder = MessageBuilder::new().embed("Starting new thread...");
@@ -389,7 +428,7 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
             };
 
             let content = format!(
-                "Starting mania background guessing game with {} different backgrounds",
+                "Starting mania background guessing game ({variant}) with {} different backgrounds",
                 entries.tags.len()
             );
 
@@ -404,7 +443,8 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                 command.callback(builder, false).await?;
             }
 
-            let game_fut = BackgroundGame::new(channel, entries, Effects::empty(), difficulty);
+            let game_fut =
+                BackgroundGame::new(channel, entries, Effects::empty(), difficulty, variant);
 
             Context::bg_games()
                 .own(channel)
@@ -1,29 +1,56 @@
 use std::collections::{BTreeMap, HashSet};
 
-use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
+use bathbot_macros::SlashCommand;
+use bathbot_model::{BgGameVariant, RankingEntries, RankingEntry, RankingKind};
 use bathbot_util::{IntHasher, constants::GENERAL_ISSUE};
 use eyre::Result;
-use twilight_model::{channel::Message, id::Id};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::Id;
 
 use crate::{
     Context,
     active::{ActiveMessages, impls::RankingPagination},
-    util::ChannelExt,
+    core::commands::CommandOrigin,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
-pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "bgleaderboard",
+    desc = "Check out the leaderboard for correct background game guesses"
+)]
+pub struct BgLeaderboard {
+    #[command(desc = "Specify the game variant, defaults to title")]
+    variant: Option<BgGameVariant>,
+    #[command(desc = "Only show members of this server")]
+    server: Option<bool>,
+}
+
+async fn slash_bgleaderboard(mut command: InteractionCommand) -> Result<()> {
+    let args = BgLeaderboard::from_interaction(command.input_data())?;
+    let global = !args.server.unwrap_or(false);
+    let variant = args.variant.unwrap_or_default();
+
+    leaderboard((&mut command).into(), global, variant).await
+}
+
+pub async fn leaderboard(
+    orig: CommandOrigin<'_>,
+    global: bool,
+    variant: BgGameVariant,
+) -> Result<()> {
     let cache = Context::cache();
 
-    let mut scores = match Context::games().bggame_leaderboard().await {
+    let mut scores = match Context::games().bggame_leaderboard(variant).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let _ = orig.error(GENERAL_ISSUE).await;
 
             return Err(err.wrap_err("failed to get bggame scores"));
         }
     };
 
-    let guild = msg.guild_id;
+    let guild = orig.guild_id();
 
     if let Some(guild) = guild.filter(|_| !global) {
         let members: HashSet<_, IntHasher> = cache
@@ -36,7 +63,8 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
         scores.retain(|row| members.contains(&row.discord_id));
     }
 
-    let author = msg.author.id.get() as i64;
+    let owner = orig.user_id()?;
+    let author = owner.get() as i64;
 
     scores.sort_unstable_by(|a, b| b.score.cmp(&a.score));
     let author_idx = scores.iter().position(|row| row.discord_id == author);
@@ -81,7 +109,11 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
     // Prepare initial page
     let total = scores.len();
     let global = guild.is_none() || global;
-    let data = RankingKind::BgScores { global, scores };
+    let data = RankingKind::BgScores {
+        global,
+        scores,
+        variant,
+    };
 
     let pagination = RankingPagination::builder()
         .entries(entries)
@@ -89,8 +121,11 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
         .author_idx(author_idx)
         .kind(data)
         .defer(false)
-        .msg_owner(msg.author.id)
+        .msg_owner(owner)
         .build();
 
-    ActiveMessages::builder(pagination).begin(msg).await
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
 }
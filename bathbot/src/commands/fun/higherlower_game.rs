@@ -24,6 +24,14 @@ use crate::{
 pub enum HigherLower {
     #[command(name = "pp")]
     ScorePp(HigherLowerScorePp),
+    #[command(name = "rank")]
+    GlobalRank(HigherLowerGlobalRank),
+    #[command(name = "playcount")]
+    MapPlaycount(HigherLowerMapPlaycount),
+    #[command(name = "favourites")]
+    MapsetFavourites(HigherLowerMapsetFavourites),
+    #[command(name = "medals")]
+    MedalRarity(HigherLowerMedalRarity),
     #[command(name = "leaderboard")]
     Leaderboard(HigherLowerLeaderboard),
 }
@@ -42,34 +50,77 @@ pub struct HigherLowerScorePp {
     mode: Option<GameModeOption>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "rank",
+    desc = "Is the player's global rank higher or lower?",
+    help = "Is the player's global rank higher or lower?\n\
+    Higher means a larger number i.e. a worse rank.\n\
+    The players are chosen randomly from the top 5,000 but the higher the \
+    current score is, the closer the ranks of the players will be."
+)]
+pub struct HigherLowerGlobalRank {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "playcount", desc = "Is the map's playcount higher or lower?")]
+pub struct HigherLowerMapPlaycount;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "favourites",
+    desc = "Is the mapset's favourite count higher or lower?"
+)]
+pub struct HigherLowerMapsetFavourites;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "medals",
+    desc = "Is the medal's rarity higher or lower?",
+    help = "Is the medal's rarity higher or lower?\n\
+    The rarity is the percentage of players that own the medal as provided by [osekai](https://osekai.net/)."
+)]
+pub struct HigherLowerMedalRarity;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "leaderboard",
     desc = "Get the server leaderboard for higherlower highscores"
 )]
-pub struct HigherLowerLeaderboard;
+pub struct HigherLowerLeaderboard {
+    #[command(desc = "Specify the game version, defaults to score pp")]
+    version: Option<HlVersion>,
+}
 
 async fn slash_higherlower(mut command: InteractionCommand) -> Result<()> {
     let args = HigherLower::from_interaction(command.input_data())?;
     let user = command.user_id()?;
 
-    let game_res = match args {
-        HigherLower::ScorePp(args) => {
-            let mode = match args.mode.map(GameMode::from) {
-                Some(mode) => mode,
-                None => Context::user_config()
-                    .mode(user)
-                    .await?
-                    .unwrap_or(GameMode::Osu),
-            };
-
-            HigherLowerGame::new_score_pp(mode, user).await
-        }
-        HigherLower::Leaderboard(_) => {
-            return higherlower_leaderboard(command, HlVersion::ScorePp).await;
+    let (version, mode) = match args {
+        HigherLower::ScorePp(args) => (HlVersion::ScorePp, args.mode),
+        HigherLower::GlobalRank(args) => (HlVersion::GlobalRank, args.mode),
+        HigherLower::MapPlaycount(_) => (HlVersion::MapPlaycount, None),
+        HigherLower::MapsetFavourites(_) => (HlVersion::MapsetFavourites, None),
+        HigherLower::MedalRarity(_) => (HlVersion::MedalRarity, None),
+        HigherLower::Leaderboard(args) => {
+            let version = args.version.unwrap_or(HlVersion::ScorePp);
+
+            return higherlower_leaderboard(command, version).await;
         }
     };
 
+    let mode = match mode.map(GameMode::from) {
+        Some(mode) => mode,
+        None => Context::user_config()
+            .mode(user)
+            .await?
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let game_res = HigherLowerGame::new(version, mode, user).await;
+
     match game_res {
         Ok(game) => {
             ActiveMessages::builder(game)
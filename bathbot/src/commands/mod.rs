@@ -14,3 +14,9 @@ const DISCORD_OPTION_DESC: &str = "Specify a linked discord user";
 const DISCORD_OPTION_HELP: &str = "Instead of specifying an osu! username with \
 the `name` option, you can use this option to choose a discord user.\nOnly \
 works on users who have used the `/link` command.";
+
+const PP_VERSION_DESC: &str = "Calculate pp with a previous pp system";
+
+const PP_VERSION_HELP: &str = "Calculate pp with the pp system of a previous \
+rework instead of the current one.\nThe chosen version must match the mode of the \
+command.\nCheck `/topold` for an overview of all pp versions.";
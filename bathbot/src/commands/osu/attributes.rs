@@ -8,8 +8,10 @@ use bathbot_util::{
 use eyre::Result;
 use rosu_v2::{model::mods::GameModsIntermode, prelude::GameMode};
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
 
 use crate::{
+    commands::osu::parsed_map::AttachedSimulateMap,
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{AttributesEmbed, EmbedData},
     util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
@@ -20,7 +22,6 @@ use crate::{
     name = "attributes",
     desc = "Check how mods influence the AR, OD, HP, or CS attributes"
 )]
-#[flags(SKIP_DEFER)]
 pub enum Attributes<'a> {
     #[command(name = "ar")]
     Ar(AttributesAr<'a>),
@@ -47,24 +48,28 @@ impl<'a> Attributes<'a> {
 
         let this = match kind {
             AttributeKind::Ar => Self::Ar(AttributesAr {
-                number: number.clamp(AR_MIN, AR_MAX),
+                number: Some(number.clamp(AR_MIN, AR_MAX)),
                 mods,
                 clock_rate: None,
+                file: None,
             }),
             AttributeKind::Cs => Self::Cs(AttributesCs {
-                number: number.clamp(CS_MIN, CS_MAX),
+                number: Some(number.clamp(CS_MIN, CS_MAX)),
                 mods,
                 clock_rate: None,
+                file: None,
             }),
             AttributeKind::Hp => Self::Hp(AttributesHp {
-                number: number.clamp(HP_MIN, HP_MAX),
+                number: Some(number.clamp(HP_MIN, HP_MAX)),
                 mods,
                 clock_rate: None,
+                file: None,
             }),
             AttributeKind::Od => Self::Od(AttributesOd {
-                number: number.clamp(OD_MIN, OD_MAX),
+                number: Some(number.clamp(OD_MIN, OD_MAX)),
                 mods,
                 clock_rate: None,
+                file: None,
             }),
         };
 
@@ -72,6 +77,12 @@ impl<'a> Attributes<'a> {
     }
 }
 
+const ATTRIBUTES_FILE_DESC: &str = "Specify a .osu file to use its value";
+const ATTRIBUTES_FILE_HELP: &str = "Specify a .osu file to use its value.\n\
+    This way unsubmitted difficulties can be checked as well. \
+    The file's value takes precedence over the `value` option. \
+    The file is only used for this command and is not stored.";
+
 const AR_DESC: &str = "Check how mods influence the approach rate attribute";
 const AR_MIN: f32 = -15.0;
 const AR_MAX: f32 = 13.0;
@@ -79,21 +90,23 @@ const AR_MAX: f32 = 13.0;
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "ar", desc = AR_DESC)]
 pub struct AttributesAr<'a> {
-    #[command(
-        rename = "value",
-        min_value = AR_MIN = f32,
-        max_value = AR_MAX = f32,
-        desc = "Specify an AR value"
-    )]
-    number: f32,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
         e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Cow<'a, str>,
+    #[command(
+        rename = "value",
+        min_value = AR_MIN = f32,
+        max_value = AR_MAX = f32,
+        desc = "Specify an AR value"
+    )]
+    number: Option<f32>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = ATTRIBUTES_FILE_DESC, help = ATTRIBUTES_FILE_HELP)]
+    file: Option<Attachment>,
 }
 
 const CS_DESC: &str = "Check how mods influence the circle size attribute";
@@ -103,21 +116,23 @@ const CS_MAX: f32 = 20.0;
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "cs", desc = CS_DESC)]
 pub struct AttributesCs<'a> {
-    #[command(
-        rename = "value",
-        min_value = CS_MIN = f32,
-        max_value = CS_MAX = f32,
-        desc = "Specify a CS value"
-    )]
-    number: f32,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
         e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Cow<'a, str>,
+    #[command(
+        rename = "value",
+        min_value = CS_MIN = f32,
+        max_value = CS_MAX = f32,
+        desc = "Specify a CS value"
+    )]
+    number: Option<f32>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = ATTRIBUTES_FILE_DESC, help = ATTRIBUTES_FILE_HELP)]
+    file: Option<Attachment>,
 }
 
 const HP_DESC: &str = "Check how mods influence the drain rate attribute";
@@ -127,21 +142,23 @@ const HP_MAX: f32 = 20.0;
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "hp", desc = HP_DESC)]
 pub struct AttributesHp<'a> {
-    #[command(
-        rename = "value",
-        min_value = HP_MIN = f32,
-        max_value = HP_MAX = f32,
-        desc = "Specify an HP value"
-    )]
-    number: f32,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
         e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Cow<'a, str>,
+    #[command(
+        rename = "value",
+        min_value = HP_MIN = f32,
+        max_value = HP_MAX = f32,
+        desc = "Specify an HP value"
+    )]
+    number: Option<f32>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = ATTRIBUTES_FILE_DESC, help = ATTRIBUTES_FILE_HELP)]
+    file: Option<Attachment>,
 }
 
 const OD_DESC: &str = "Check how mods influence the overall difficulty attribute";
@@ -151,21 +168,23 @@ const OD_MAX: f32 = 13.33;
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "od", desc = OD_DESC)]
 pub struct AttributesOd<'a> {
-    #[command(
-        rename = "value",
-        min_value = OD_MIN = f32,
-        max_value = OD_MAX = f32,
-        desc = "Specify an OD value"
-    )]
-    number: f32,
     #[command(
         desc = "Specify mods e.g. hdhr or nm",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
         e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Cow<'a, str>,
+    #[command(
+        rename = "value",
+        min_value = OD_MIN = f32,
+        max_value = OD_MAX = f32,
+        desc = "Specify an OD value"
+    )]
+    number: Option<f32>,
     #[command(desc = "Specify a custom clock rate that overwrites mods")]
     clock_rate: Option<f32>,
+    #[command(desc = ATTRIBUTES_FILE_DESC, help = ATTRIBUTES_FILE_HELP)]
+    file: Option<Attachment>,
 }
 
 async fn slash_attributes(mut command: InteractionCommand) -> Result<()> {
@@ -243,11 +262,35 @@ async fn prefix_od(msg: &Message, args: Args<'_>) -> Result<()> {
 }
 
 async fn attributes(orig: CommandOrigin<'_>, args: Attributes<'_>) -> Result<()> {
-    let (kind, value, mods, clock_rate) = match args {
-        Attributes::Ar(args) => (AttributeKind::Ar, args.number, args.mods, args.clock_rate),
-        Attributes::Cs(args) => (AttributeKind::Cs, args.number, args.mods, args.clock_rate),
-        Attributes::Hp(args) => (AttributeKind::Hp, args.number, args.mods, args.clock_rate),
-        Attributes::Od(args) => (AttributeKind::Od, args.number, args.mods, args.clock_rate),
+    let (kind, number, mods, clock_rate, file) = match args {
+        Attributes::Ar(args) => (
+            AttributeKind::Ar,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.file,
+        ),
+        Attributes::Cs(args) => (
+            AttributeKind::Cs,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.file,
+        ),
+        Attributes::Hp(args) => (
+            AttributeKind::Hp,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.file,
+        ),
+        Attributes::Od(args) => (
+            AttributeKind::Od,
+            args.number,
+            args.mods,
+            args.clock_rate,
+            args.file,
+        ),
     };
 
     let mods = if let Some(mods) = GameModsIntermode::try_from_acronyms(&mods) {
@@ -258,15 +301,13 @@ async fn attributes(orig: CommandOrigin<'_>, args: Attributes<'_>) -> Result<()>
             None => {
                 let content =
                     "Failed to parse mods. Be sure to specify a valid mod combination e.g. `hrdt`.";
-                orig.error_callback(content).await?;
 
-                return Ok(());
+                return orig.error(content).await;
             }
             Some(ModSelection::Exclude { .. }) => {
                 let content = "Excluding mods does not work for this command";
-                orig.error_callback(content).await?;
 
-                return Ok(());
+                return orig.error(content).await;
             }
         }
     };
@@ -283,14 +324,36 @@ async fn attributes(orig: CommandOrigin<'_>, args: Attributes<'_>) -> Result<()>
     if !valid_mods {
         let content = "Looks like either some of these mods are incompatible with each other \
             or those mods don't fit to any gamemode.";
-        orig.error_callback(content).await?;
 
-        return Ok(());
+        return orig.error(content).await;
     }
 
-    let embed = AttributesEmbed::new(kind, value, mods, clock_rate).build();
+    let (value, filename) = match (file, number) {
+        (Some(file), _) => {
+            let Some(map) = AttachedSimulateMap::new(&orig, Box::new(file), None).await? else {
+                return Ok(());
+            };
+
+            let value = match kind {
+                AttributeKind::Ar => map.pp_map.ar,
+                AttributeKind::Cs => map.pp_map.cs,
+                AttributeKind::Hp => map.pp_map.hp,
+                AttributeKind::Od => map.pp_map.od,
+            };
+
+            (value, Some(map.filename))
+        }
+        (None, Some(number)) => (number, None),
+        (None, None) => {
+            let content = "You must specify either a value or a .osu file";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let embed = AttributesEmbed::new(kind, value, mods, clock_rate, filename.as_deref()).build();
     let builder = MessageBuilder::new().embed(embed);
-    orig.callback(builder).await?;
+    orig.create_message(builder).await?;
 
     Ok(())
 }
@@ -1,20 +1,20 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
 
-use bathbot_cards::{BathbotCard, RequiredAttributes};
+use bathbot_cards::{BathbotCard, RequiredAttributes, Skills};
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_psql::model::configs::{CardLayout, CardTheme, ScoreData};
 use bathbot_util::{
     EmbedBuilder, IntHasher, MessageBuilder, attachment,
     constants::{GENERAL_ISSUE, OSEKAI_ISSUE},
-    datetime::DATE_FORMAT,
+    datetime::{DATE_FORMAT, HowLongAgoDynamic},
     matcher,
     osu::flag_url_size,
 };
 use eyre::{Report, Result, WrapErr};
 use futures::{TryStreamExt, stream::FuturesUnordered};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
 
@@ -25,7 +25,10 @@ use crate::{
         BotConfig, Context,
         commands::{CommandOrigin, prefix::Args},
     },
-    manager::redis::osu::{UserArgs, UserArgsError},
+    manager::{
+        SkillsSnapshot,
+        redis::osu::{UserArgs, UserArgsError},
+    },
     util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -74,7 +77,10 @@ values to each other:\n  \
 - taiko, catch, and mania:\n    \
 - All skills are roughly the same: `Gamer`\n    \
 - High accuracy but low strain: `Rhythm Enjoyer`\n    \
-- High strain but low accuracy: `Masher` / `Droplet Dodger`";
+- High strain but low accuracy: `Masher` / `Droplet Dodger`\n\n\
+The `theme` and `layout` options are remembered so they only need to be specified once.\n\
+Skill values are compared to the previous card of the user unless a date is specified \
+through the `since` option in which case the latest card before that date is used.";
 
 #[derive(CommandModel, CreateCommand, SlashCommand, HasName)]
 #[command(name = "card", desc = "Create a user card", help = CARD_HELP)]
@@ -85,6 +91,12 @@ pub struct Card<'a> {
     name: Option<Cow<'a, str>>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the colors and background of the card")]
+    theme: Option<CardTheme>,
+    #[command(desc = "Specify whether to draw a full card or a compact banner")]
+    layout: Option<CardLayout>,
+    #[command(desc = "Compare skills to the latest card before this date (YYYY-MM-DD)")]
+    since: Option<Cow<'a, str>>,
 }
 
 impl<'m> Card<'m> {
@@ -104,6 +116,9 @@ impl<'m> Card<'m> {
             mode,
             name,
             discord,
+            theme: None,
+            layout: None,
+            since: None,
         }
     }
 }
@@ -167,7 +182,34 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
 
 async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
     let owner = orig.user_id()?;
-    let config = Context::user_config().with_osu_id(owner).await?;
+    let mut config = Context::user_config().with_osu_id(owner).await?;
+
+    let theme_changed = args.theme.is_some() && args.theme != config.card_theme;
+    let layout_changed = args.layout.is_some() && args.layout != config.card_layout;
+
+    if theme_changed || layout_changed {
+        config.card_theme = args.theme.or(config.card_theme);
+        config.card_layout = args.layout.or(config.card_layout);
+
+        if let Err(err) = Context::user_config().store(owner, &config).await {
+            warn!(?err, "Failed to store card theme");
+        }
+    }
+
+    let theme = config.card_theme.unwrap_or_default();
+    let layout = config.card_layout.unwrap_or_default();
+
+    let since = match args.since.as_deref() {
+        Some(date) => match Date::parse(date, DATE_FORMAT) {
+            Ok(date) => Some(date.midnight().assume_utc()),
+            Err(_) => {
+                let content = "Failed to parse date. Be sure to specify it as `YYYY-MM-DD`.";
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -283,7 +325,11 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
         .format(DATE_FORMAT)
         .unwrap();
 
-    let card_res = BathbotCard::new(mode, &scores, maps, legacy_scores)
+    let mut card = BathbotCard::new(mode, &scores, maps, legacy_scores);
+    let skills = *card.skills();
+    let previous = previous_skills(user.user_id.to_native(), &skills, since).await;
+
+    let card = card
         .user(user.username.as_str(), stats.level.float())
         .ranks(
             stats.global_rank.to_native(),
@@ -293,7 +339,14 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
         .bytes(&pfp, &flag)
         .date(&today)
         .assets(BotConfig::get().paths.assets.clone())
-        .draw();
+        .theme(card_theme(theme))
+        .layout(card_layout(layout));
+
+    if let Some((previous, _)) = previous {
+        card.previous_skills(previous);
+    }
+
+    let card_res = card.draw();
 
     let bytes = match card_res {
         Ok(bytes) => bytes,
@@ -304,10 +357,14 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
         }
     };
 
-    let embed = EmbedBuilder::new()
+    let mut embed = EmbedBuilder::new()
         .author(user.author_builder(false))
         .image(attachment("card.png"));
 
+    if let Some((previous, created_at)) = previous {
+        embed = embed.description(skill_deltas(&skills, &previous, &created_at));
+    }
+
     let builder = MessageBuilder::new()
         .attachment("card.png", bytes)
         .embed(embed);
@@ -316,3 +373,190 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
 
     Ok(())
 }
+
+/// Returns the skills to compare against alongside their timestamp.
+///
+/// Also stores the current skills if they changed since the last card.
+async fn previous_skills(
+    user_id: u32,
+    skills: &Skills,
+    since: Option<OffsetDateTime>,
+) -> Option<(Skills, OffsetDateTime)> {
+    let card_skills = Context::card_skills();
+    let mode = skills.mode();
+    let fetched_at = OffsetDateTime::now_utc();
+
+    let history = match card_skills.history(user_id, mode, fetched_at).await {
+        Ok(history) => history,
+        Err(err) => {
+            warn!(?err, "Failed to get card skills history");
+
+            return None;
+        }
+    };
+
+    let changed = history
+        .first()
+        .is_none_or(|snapshot| snapshot.skills != *skills);
+
+    if changed {
+        if let Err(err) = card_skills.store(user_id, skills, fetched_at).await {
+            warn!(?err, "Failed to store card skills");
+        }
+    }
+
+    let until = previous_until(fetched_at, since);
+
+    let history = match since {
+        Some(_) => match card_skills.history(user_id, mode, until).await {
+            Ok(history) => history,
+            Err(err) => {
+                warn!(?err, "Failed to get card skills history");
+
+                return None;
+            }
+        },
+        None => history,
+    };
+
+    select_previous(history, skills, until, since.is_some())
+        .map(|snapshot| (snapshot.skills, snapshot.created_at))
+}
+
+/// Snapshots to compare against must have been stored before the returned
+/// point in time so that the snapshot of the current fetch is never used.
+fn previous_until(fetched_at: OffsetDateTime, since: Option<OffsetDateTime>) -> OffsetDateTime {
+    since.map_or(fetched_at, |since| since.min(fetched_at))
+}
+
+/// Picks the snapshot to compare the current skills against.
+///
+/// `history` must be sorted by most recent first.
+fn select_previous(
+    history: Vec<SkillsSnapshot>,
+    skills: &Skills,
+    until: OffsetDateTime,
+    since: bool,
+) -> Option<SkillsSnapshot> {
+    let mut history = history
+        .into_iter()
+        .filter(|snapshot| snapshot.created_at < until)
+        .peekable();
+
+    // Without a date, the latest snapshot may hold the current skills already
+    // in which case the one before is more interesting
+    if !since {
+        history.next_if(|snapshot| snapshot.skills == *skills);
+    }
+
+    history.next()
+}
+
+fn skill_deltas(skills: &Skills, previous: &Skills, created_at: &OffsetDateTime) -> String {
+    let mut description = format!(
+        "Skill changes since {}:",
+        HowLongAgoDynamic::new(created_at)
+    );
+
+    let deltas = skills.deltas(previous).unwrap_or_default();
+
+    for ((name, value), delta) in skills.named_values().into_iter().zip(deltas) {
+        let _ = write!(description, "\n`{name}`: **{value:.2}** ({delta:+.2})");
+    }
+
+    description
+}
+
+fn card_theme(theme: CardTheme) -> bathbot_cards::CardTheme {
+    match theme {
+        CardTheme::Classic => bathbot_cards::CardTheme::Classic,
+        CardTheme::Mode => bathbot_cards::CardTheme::Mode,
+        CardTheme::Midnight => bathbot_cards::CardTheme::Midnight,
+    }
+}
+
+fn card_layout(layout: CardLayout) -> bathbot_cards::CardLayout {
+    match layout {
+        CardLayout::Full => bathbot_cards::CardLayout::Full,
+        CardLayout::Banner => bathbot_cards::CardLayout::Banner,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURRENT: Skills = Skills::Osu {
+        acc: 5.0,
+        aim: 5.0,
+        speed: 5.0,
+    };
+
+    fn at(hours: i64) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_735_689_600 + hours * 3600).unwrap()
+    }
+
+    fn snapshot(hours: i64, acc: f64) -> SkillsSnapshot {
+        SkillsSnapshot {
+            skills: Skills::Osu {
+                acc,
+                aim: 5.0,
+                speed: 5.0,
+            },
+            created_at: at(hours),
+        }
+    }
+
+    fn selected(
+        history: Vec<SkillsSnapshot>,
+        fetched_at: OffsetDateTime,
+        since: Option<OffsetDateTime>,
+    ) -> Option<OffsetDateTime> {
+        let until = previous_until(fetched_at, since);
+
+        select_previous(history, &CURRENT, until, since.is_some())
+            .map(|snapshot| snapshot.created_at)
+    }
+
+    #[test]
+    fn previous_card() {
+        let history = vec![snapshot(-1, 4.0), snapshot(-2, 3.0)];
+        assert_eq!(selected(history, at(0), None), Some(at(-1)));
+    }
+
+    #[test]
+    fn previous_card_without_changes() {
+        // The latest snapshot already holds the current skills
+        let history = vec![snapshot(-1, 5.0), snapshot(-2, 3.0)];
+        assert_eq!(selected(history, at(0), None), Some(at(-2)));
+    }
+
+    #[test]
+    fn excludes_current_fetch() {
+        // Snapshot that was stored for this very fetch
+        let history = vec![snapshot(0, 5.0), snapshot(-1, 4.0)];
+        assert_eq!(selected(history, at(0), None), Some(at(-1)));
+
+        let history = vec![snapshot(0, 5.0), snapshot(-1, 4.0)];
+        assert_eq!(selected(history, at(0), Some(at(24))), Some(at(-1)));
+    }
+
+    #[test]
+    fn since_start_of_day() {
+        // Only snapshots before the start of the given day count
+        let history = vec![snapshot(-1, 4.0), snapshot(-30, 3.0)];
+        assert_eq!(selected(history, at(12), Some(at(-24))), Some(at(-30)));
+
+        // Unchanged skills still count when a date is given
+        let history = vec![snapshot(-30, 5.0)];
+        assert_eq!(selected(history, at(12), Some(at(-24))), Some(at(-30)));
+    }
+
+    #[test]
+    fn no_previous_card() {
+        assert_eq!(selected(Vec::new(), at(0), None), None);
+
+        let history = vec![snapshot(-1, 5.0)];
+        assert_eq!(selected(history, at(0), None), None);
+    }
+}
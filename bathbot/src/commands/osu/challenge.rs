@@ -0,0 +1,311 @@
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+    osu::{ModSelection, ModsResult},
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::GameMode;
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{
+    active::{ActiveMessages, impls::ChallengeLeaderboardPagination},
+    core::{Context, commands::checks::check_authority},
+    manager::MapError,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "challenge",
+    dm_permission = false,
+    desc = "Host a challenge on a map for this server",
+    help = "Host a challenge on a map for this server.\n\
+    While a challenge runs, the best score of each linked server member on the map \
+    is collected as long as it was set within the time window and fits the mods.\n\
+    Once the time window closes, the results are announced in the channel \
+    in which the challenge was started.\n\
+    Only one challenge can run at a time. \
+    Starting and ending challenges requires authority status."
+)]
+#[flags(ONLY_GUILDS)]
+pub enum Challenge {
+    #[command(name = "start")]
+    Start(ChallengeStart),
+    #[command(name = "leaderboard")]
+    Leaderboard(ChallengeLeaderboard),
+    #[command(name = "end")]
+    End(ChallengeEnd),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "start", desc = "Start a challenge on a map")]
+pub struct ChallengeStart {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(
+        min_value = 1,
+        max_value = 336,
+        desc = "Specify for how many hours the challenge should run"
+    )]
+    hours: i64,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
+        e.g. `hdhr` or `+hdhr!`. Only scores that match those mods will count."
+    )]
+    mods: Option<String>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "leaderboard",
+    desc = "Show the leaderboard of the current or latest challenge"
+)]
+pub struct ChallengeLeaderboard;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "end", desc = "End the current challenge early")]
+pub struct ChallengeEnd;
+
+async fn slash_challenge(mut command: InteractionCommand) -> Result<()> {
+    let Some(guild_id) = command.guild_id else {
+        return Ok(());
+    };
+
+    let args = Challenge::from_interaction(command.input_data())?;
+
+    if matches!(args, Challenge::Start(_) | Challenge::End(_)) {
+        if let Some(content) = check_authority(command.user_id()?, Some(guild_id)).await? {
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    }
+
+    match args {
+        Challenge::Start(args) => start(command, guild_id, args).await,
+        Challenge::Leaderboard(_) => leaderboard(command, guild_id).await,
+        Challenge::End(_) => end(command, guild_id).await,
+    }
+}
+
+async fn start(
+    command: InteractionCommand,
+    guild_id: Id<GuildMarker>,
+    args: ChallengeStart,
+) -> Result<()> {
+    let manager = Context::challenges();
+
+    match manager.active(guild_id).await {
+        Ok(Some(_)) => {
+            let content = "There is already a challenge in this server, \
+                end it first with `/challenge end`";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Ok(None) => {}
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    }
+
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        let content = "Failed to parse map. Be sure to specify a map id or map url.";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let mods = args
+        .mods
+        .as_deref()
+        .map(str::trim)
+        .filter(|mods| !mods.is_empty());
+
+    let mods = match ModSelection::parse(mods) {
+        ModsResult::Mods(_) => mods,
+        ModsResult::None => None,
+        ModsResult::Invalid => {
+            let content = "Failed to parse mods.\n\
+            If you want included mods, specify it e.g. as `+hrdt`.\n\
+            If you want exact mods, specify it e.g. as `+hdhr!`.\n\
+            And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = args.mode.map_or(map.mode(), GameMode::from);
+
+    if mode != map.mode() && map.mode() != GameMode::Osu {
+        let content = format!("Cannot convert a {:?} map to {mode:?}", map.mode());
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let map = map.convert(mode);
+    let ends_at = OffsetDateTime::now_utc() + Duration::hours(args.hours);
+
+    let challenge_res = manager
+        .create(guild_id, command.channel_id, map_id, mode, mods, ends_at)
+        .await;
+
+    match challenge_res {
+        Ok(Some(_)) => {}
+        // Another challenge was started in the meantime
+        Ok(None) => {
+            let content = "There is already a challenge in this server, \
+                end it first with `/challenge end`";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    }
+
+    let mods = match mods {
+        Some(mods) => format!("Mods: `{mods}`\n"),
+        None => String::new(),
+    };
+
+    let ends_at = ends_at.unix_timestamp();
+
+    let description = format!(
+        "{mods}Ends <t:{ends_at}:f> (<t:{ends_at}:R>)\n\n\
+        Set your best score on the map before then! \
+        Check the standings with `/challenge leaderboard`."
+    );
+
+    let title = format!(
+        "Challenge started: {} - {} [{}]",
+        map.artist().cow_escape_markdown(),
+        map.title().cow_escape_markdown(),
+        map.version().cow_escape_markdown()
+    );
+
+    let embed = EmbedBuilder::new()
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"))
+        .description(description)
+        .image(map.cover());
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn leaderboard(mut command: InteractionCommand, guild_id: Id<GuildMarker>) -> Result<()> {
+    let manager = Context::challenges();
+
+    let challenge_res = match manager.active(guild_id).await {
+        Ok(Some(challenge)) => Ok(Some(challenge)),
+        Ok(None) => manager.latest(guild_id).await,
+        Err(err) => Err(err),
+    };
+
+    let challenge = match challenge_res {
+        Ok(Some(challenge)) => challenge,
+        Ok(None) => {
+            let content = "There has not been a challenge in this server yet, \
+                start one with `/challenge start`";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let map_fut = Context::osu_map().map(challenge.map_id, None);
+    let entries_fut = manager.leaderboard(&challenge);
+
+    let (map, standings) = match tokio::join!(map_fut, entries_fut) {
+        (Ok(map), Ok(standings)) => (map.convert(challenge.mode), standings),
+        (Err(err), _) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get challenge map"));
+        }
+        (_, Err(err)) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let pagination = ChallengeLeaderboardPagination::builder()
+        .map(map)
+        .challenge(challenge)
+        .entries(standings.entries)
+        .failed(standings.failed)
+        .defer_next(false)
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(&mut command)
+        .await
+}
+
+async fn end(command: InteractionCommand, guild_id: Id<GuildMarker>) -> Result<()> {
+    let content = match Context::challenges().end(guild_id).await {
+        Ok(true) => "Ended the challenge, the results will be announced shortly",
+        Ok(false) => {
+            command
+                .error("There is no ongoing challenge in this server")
+                .await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let embed = EmbedBuilder::new().description(content);
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
@@ -171,6 +171,8 @@ pub struct CompareProfile<'a> {
     discord1: Option<Id<UserMarker>>,
     #[command(desc = "Specify a linked discord user")]
     discord2: Option<Id<UserMarker>>,
+    #[command(desc = "Also compare stat gains since this date (YYYY-MM-DD)")]
+    since: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, Default)]
@@ -2,9 +2,11 @@ use std::{borrow::Cow, io::Cursor};
 
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::{RankAccPeaks, RespektiveUser, command_fields::GameModeOption};
+use bathbot_psql::model::osu::DbUserStatsHistoryEntry;
 use bathbot_util::{
     MessageBuilder,
     constants::GENERAL_ISSUE,
+    datetime::DATE_FORMAT,
     matcher,
     numbers::MinMaxAvg,
     osu::{BonusPP, UserStats},
@@ -20,6 +22,7 @@ use rosu_v2::{
     prelude::{GameMode, OsuError, Score},
     request::UserId,
 };
+use time::Date;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     guild::Permissions,
@@ -62,6 +65,8 @@ pub struct Pc<'a> {
     discord1: Option<Id<UserMarker>>,
     #[command(desc = "Specify a linked discord user")]
     discord2: Option<Id<UserMarker>>,
+    #[command(desc = "Also compare stat gains since this date (YYYY-MM-DD)")]
+    since: Option<Cow<'a, str>>,
 }
 
 async fn slash_pc(mut command: InteractionCommand) -> Result<()> {
@@ -126,6 +131,18 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
         return orig.error("Give two different names").await;
     }
 
+    let since = match args.since.as_deref() {
+        Some(date) => match Date::parse(date, DATE_FORMAT) {
+            Ok(date) => Some(date.midnight().assume_utc()),
+            Err(_) => {
+                let content = "Failed to parse date. Be sure to specify it as `YYYY-MM-DD`.";
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
+
     let mode = match args.mode {
         Some(mode) => mode.into(),
         None => match Context::user_config().mode(orig.user_id()?).await {
@@ -195,8 +212,25 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
     let osutrack_fut1 = client.osu_user_rank_acc_peak(user1.user_id.to_native(), mode);
     let osutrack_fut2 = client.osu_user_rank_acc_peak(user2.user_id.to_native(), mode);
 
-    let (thumbnail_res, score_ranks_res, osutrack_res1, osutrack_res2) =
-        tokio::join!(thumbnail_fut, score_ranks_fut, osutrack_fut1, osutrack_fut2);
+    let snapshots_fut = async {
+        let Some(since) = since else {
+            return Ok((None, None));
+        };
+
+        let osu_user = Context::osu_user();
+        let fut1 = osu_user.snapshot(user1.user_id.to_native(), mode, since);
+        let fut2 = osu_user.snapshot(user2.user_id.to_native(), mode, since);
+
+        tokio::try_join!(fut1, fut2)
+    };
+
+    let (thumbnail_res, score_ranks_res, osutrack_res1, osutrack_res2, snapshots_res) = tokio::join!(
+        thumbnail_fut,
+        score_ranks_fut,
+        osutrack_fut1,
+        osutrack_fut2,
+        snapshots_fut
+    );
 
     // Create the thumbnail
     let thumbnail = match thumbnail_res {
@@ -250,22 +284,39 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
         }
     };
 
+    let (snapshot1, snapshot2) = match snapshots_res {
+        Ok(snapshots) => snapshots,
+        Err(err) => {
+            warn!(?err, "Failed to get user snapshots");
+
+            (None, None)
+        }
+    };
+
     let profile_result1 = CompareResult::calc(
         &scores1,
         user1.statistics.as_ref().expect("missing stats"),
         score_rank_data1,
         osutrack_peaks1,
+        snapshot1,
     );
     let profile_result2 = CompareResult::calc(
         &scores2,
         user2.statistics.as_ref().expect("missing stats"),
         score_rank_data2,
         osutrack_peaks2,
+        snapshot2,
     );
 
     // Creating the embed
-    let embed_data =
-        ProfileCompareEmbed::new(mode, &user1, &user2, profile_result1, profile_result2);
+    let embed_data = ProfileCompareEmbed::new(
+        mode,
+        &user1,
+        &user2,
+        profile_result1,
+        profile_result2,
+        since,
+    );
     let embed = embed_data.build();
     let mut builder = MessageBuilder::new().embed(embed);
 
@@ -377,6 +428,7 @@ pub struct CompareResult {
     pub top1pp: f32,
     pub score_rank_data: Option<RespektiveUser>,
     pub osutrack_peaks: Option<RankAccPeaks>,
+    pub snapshot: Option<DbUserStatsHistoryEntry>,
     pub hits: u32,
     pub misses: u32,
 }
@@ -387,12 +439,14 @@ impl CompareResult {
         stats: &impl UserStats,
         score_rank_data: Option<RespektiveUser>,
         osutrack_peaks: Option<RankAccPeaks>,
+        snapshot: Option<DbUserStatsHistoryEntry>,
     ) -> Self {
         // Nested body to reduce function size w.r.t. the generic argument.
         fn inner(
             scores: &[Score],
             score_rank_data: Option<RespektiveUser>,
             osutrack_peaks: Option<RankAccPeaks>,
+            snapshot: Option<DbUserStatsHistoryEntry>,
         ) -> (CompareResult, BonusPP) {
             let mut pp = MinMaxAvg::new();
             let mut map_len = MinMaxAvg::new();
@@ -431,6 +485,7 @@ impl CompareResult {
                 top1pp: scores.first().and_then(|score| score.pp).unwrap_or(0.0),
                 score_rank_data,
                 osutrack_peaks,
+                snapshot,
                 hits,
                 misses,
             };
@@ -438,7 +493,7 @@ impl CompareResult {
             (res, bonus_pp)
         }
 
-        let (mut this, bonus_pp) = inner(scores, score_rank_data, osutrack_peaks);
+        let (mut this, bonus_pp) = inner(scores, score_rank_data, osutrack_peaks, snapshot);
         this.bonus_pp = bonus_pp.calculate(stats);
 
         this
@@ -499,6 +554,7 @@ impl<'m> CompareProfile<'m> {
             name2,
             discord1,
             discord2,
+            since: None,
         }
     }
 }
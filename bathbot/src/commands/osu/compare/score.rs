@@ -811,6 +811,7 @@ async fn process_scores(
             pb_idx,
             global_idx,
             if_fc_pp,
+            pp_version: None,
             #[cfg(feature = "twitch")]
             twitch: None,
         };
@@ -1047,6 +1048,7 @@ async fn compare_from_score(
         pb_idx,
         global_idx,
         if_fc_pp,
+        pp_version: None,
         #[cfg(feature = "twitch")]
         twitch: None,
     };
@@ -1120,7 +1122,7 @@ async fn prepare_graph(entry: &ScoreEmbedData) -> Option<(String, Vec<u8>)> {
     let fut = map_strains_graph(
         &entry.map.pp_map,
         entry.score.mods.clone(),
-        entry.map.cover(),
+        Some(entry.map.cover()),
         SingleScorePagination::IMAGE_W,
         SingleScorePagination::IMAGE_H,
     );
@@ -63,7 +63,11 @@ impl<'m> GraphMapBpm<'m> {
             }
         }
 
-        Ok(Self { map, mods })
+        Ok(Self {
+            map,
+            mods,
+            file: None,
+        })
     }
 }
 
@@ -88,7 +92,11 @@ async fn prefix_graphbpm(msg: &Message, args: Args<'_>, perms: Option<Permission
     super::graph(orig, Graph::MapBpm(args)).await
 }
 
-pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Result<Vec<u8>> {
+pub async fn map_bpm_graph(
+    map: &Beatmap,
+    mods: GameMods,
+    cover_url: Option<&str>,
+) -> Result<Vec<u8>> {
     let mut start_timestamp = map
         .hit_objects
         .first()
@@ -169,7 +177,10 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
     let lower_limit = min_bpm - bpm_range * 0.2;
     let upper_limit = max_bpm + bpm_range * 0.2;
 
-    let cover_res = get_map_cover(cover_url, W, H).await;
+    let cover_res = match cover_url {
+        Some(url) => get_map_cover(url, W, H).await.map(Some),
+        None => Ok(None),
+    };
 
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
@@ -180,7 +191,7 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
 
         // Add background
         match cover_res {
-            Ok(background) => {
+            Ok(Some(background)) => {
                 let background = background.blur(2.0);
                 let elem = BitMapElement::new(background, (0, 0));
                 root.draw(&elem).wrap_err("Failed to draw background")?;
@@ -189,8 +200,10 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
                 root.draw(&rect)
                     .wrap_err("Failed to draw darkening rectangle")?;
             }
-            Err(err) => {
-                warn!(?err, "Failed to get mapset cover");
+            res => {
+                if let Err(err) = res {
+                    warn!(?err, "Failed to get mapset cover");
+                }
 
                 root.fill(&RGBColor(19, 43, 33))
                     .wrap_err("Failed to fill background")?;
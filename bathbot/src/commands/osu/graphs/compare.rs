@@ -0,0 +1,153 @@
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, attachment, constants::GENERAL_ISSUE, matcher,
+};
+use eyre::{Report, Result};
+use plotters::style::RGBColor;
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+
+use crate::{
+    commands::osu::user_not_found,
+    core::{Context, commands::CommandOrigin},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+/// Maximum amount of users in one comparison graph.
+pub const MAX_USERS: usize = 5;
+
+/// Line colors of the compared users, in the order they were specified.
+pub const COLORS: [RGBColor; MAX_USERS] = [
+    RGBColor(0, 208, 138),
+    RGBColor(2, 139, 250),
+    RGBColor(244, 67, 54),
+    RGBColor(255, 193, 7),
+    RGBColor(186, 104, 200),
+];
+
+pub const COMPARE_DESC: &str = "Specify up to four more usernames to compare with";
+pub const COMPARE_HELP: &str = "Specify up to four more usernames to draw into the same graph.\n\
+    Names can be separated by spaces or, if a name contains a space, by commas. \
+    Discord mentions of linked users work too.";
+
+/// Combines the main user with the users of the `compare` option.
+///
+/// The outer result is an internal error, the inner one a message for the
+/// user.
+pub async fn compare_user_ids(
+    user_id: UserId,
+    compare: &str,
+) -> Result<Result<Vec<UserId>, String>> {
+    let args: Vec<_> = if compare.contains(',') {
+        compare
+            .split(',')
+            .map(str::trim)
+            .filter(|arg| !arg.is_empty())
+            .collect()
+    } else {
+        compare.split_whitespace().collect()
+    };
+
+    if args.len() >= MAX_USERS {
+        let content = format!("Can only compare up to {MAX_USERS} users in one graph");
+
+        return Ok(Err(content));
+    }
+
+    let mut user_ids = Vec::with_capacity(args.len() + 1);
+    user_ids.push(user_id);
+
+    for arg in args {
+        let Some(discord_id) = matcher::get_mention_user(arg) else {
+            user_ids.push(UserId::Name(arg.into()));
+
+            continue;
+        };
+
+        match Context::user_config().osu_id(discord_id).await? {
+            Some(user_id) => user_ids.push(UserId::Id(user_id)),
+            None => {
+                let content = format!("<@{discord_id}> is not linked to an osu!profile");
+
+                return Ok(Err(content));
+            }
+        }
+    }
+
+    Ok(Ok(user_ids))
+}
+
+/// Retrieves all users, responding with an error message if one of them was
+/// not found or if there are less than two distinct users.
+pub async fn compare_users(
+    orig: &CommandOrigin<'_>,
+    user_ids: Vec<UserId>,
+    mode: GameMode,
+) -> Result<Option<Vec<CachedUser>>> {
+    let mut users: Vec<CachedUser> = Vec::with_capacity(user_ids.len());
+
+    for user_id in user_ids {
+        let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+        let user = match Context::redis().osu_user(user_args).await {
+            Ok(user) => user,
+            Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                let content = user_not_found(user_id).await;
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user");
+
+                return Err(err);
+            }
+        };
+
+        if users
+            .iter()
+            .all(|prev| prev.user_id.to_native() != user.user_id.to_native())
+        {
+            users.push(user);
+        }
+    }
+
+    if users.len() < 2 {
+        orig.error("Specify at least two different users to compare")
+            .await?;
+
+        return Ok(None);
+    }
+
+    Ok(Some(users))
+}
+
+pub async fn send_compare_graph(
+    orig: &CommandOrigin<'_>,
+    tuple_option: Option<(Vec<CachedUser>, Vec<u8>)>,
+    footer: Option<FooterBuilder>,
+) -> Result<()> {
+    let Some((users, graph)) = tuple_option else {
+        return Ok(());
+    };
+
+    let names: Vec<_> = users.iter().map(|user| user.username.as_str()).collect();
+
+    let mut embed = EmbedBuilder::new()
+        .title(format!("Comparing {}", names.join(", ")))
+        .image(attachment("graph.png"));
+
+    if let Some(footer) = footer {
+        embed = embed.footer(footer);
+    }
+
+    let builder = MessageBuilder::new()
+        .embed(embed)
+        .attachment("graph.png", graph);
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use bathbot_psql::model::osu::DbUserStatsHistoryEntry;
+use bathbot_util::{constants::GENERAL_ISSUE, datetime::DATE_FORMAT, numbers::WithComma};
+use eyre::{Report, Result, WrapErr};
+use plotters::{
+    chart::{ChartBuilder, SeriesLabelPosition},
+    prelude::{IntoDrawingArea, PathElement},
+    series::LineSeries,
+    style::{Color, RGBColor, TextStyle, WHITE},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+use skia_safe::{EncodedImageFormat, surfaces};
+use time::OffsetDateTime;
+
+use super::{H, W};
+use crate::{
+    commands::osu::user_not_found,
+    core::{Context, commands::CommandOrigin},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+pub async fn history_graph(
+    orig: &CommandOrigin<'_>,
+    user_id: UserId,
+    mode: GameMode,
+    since: Option<OffsetDateTime>,
+) -> Result<Option<(CachedUser, Vec<u8>)>> {
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let since = since.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+    let snapshots = match Context::osu_user()
+        .snapshots(user.user_id.to_native(), mode, since)
+        .await
+    {
+        Ok(snapshots) => snapshots,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if snapshots.len() < 2 {
+        let content = format!(
+            "There is not enough stat history for `{name}` yet.\n\
+            Stats are stored whenever the bot retrieves a user so check back later.",
+            name = user.username.as_str()
+        );
+
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let bytes = match draw_graph(&snapshots) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw history graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user, bytes)))
+}
+
+fn draw_graph(snapshots: &[DbUserStatsHistoryEntry]) -> Result<Vec<u8>> {
+    let mut min_rank = i32::MAX;
+    let mut max_rank = 0;
+
+    let mut min_pp = f32::MAX;
+    let mut max_pp = 0.0_f32;
+
+    for snapshot in snapshots {
+        if snapshot.global_rank > 0 {
+            min_rank = min_rank.min(snapshot.global_rank);
+            max_rank = max_rank.max(snapshot.global_rank);
+        }
+
+        min_pp = min_pp.min(snapshot.pp);
+        max_pp = max_pp.max(snapshot.pp);
+    }
+
+    if min_rank > max_rank {
+        min_rank = 1;
+        max_rank = 1;
+    }
+
+    // Padding so that flat lines are not drawn onto the border
+    let (min_rank, max_rank) = (-(max_rank + 1), -(min_rank - 1));
+    let (min_pp, max_pp) = (min_pp - 1.0, max_pp + 1.0);
+
+    // The caller already checked that there are at least two snapshots
+    let first = &snapshots[0];
+    let last = &snapshots[snapshots.len() - 1];
+
+    let start = first.snapshot_at.unix_timestamp();
+    let end = last.snapshot_at.unix_timestamp();
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let title = format!(
+            "Rank and PP since {}",
+            first.snapshot_at.format(DATE_FORMAT).unwrap()
+        );
+
+        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        root = root
+            .titled(&title, title_style)
+            .wrap_err("Failed to draw title")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(30)
+            .y_label_area_size(90)
+            .right_y_label_area_size(90)
+            .margin(9)
+            .build_cartesian_2d(start..end, min_rank..max_rank)
+            .wrap_err("Failed to build chart")?
+            .set_secondary_coord(start..end, min_pp..max_pp);
+
+        // Mesh and axes
+        let label_style = ("sans-serif", 20_i32, &WHITE);
+        let axis_style = RGBColor(7, 18, 14);
+        let axis_desc_style = ("sans-serif", 20_i32, FontStyle::Bold, &WHITE);
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(8)
+            .x_label_formatter(&|timestamp| {
+                OffsetDateTime::from_unix_timestamp(*timestamp)
+                    .ok()
+                    .and_then(|datetime| datetime.format(DATE_FORMAT).ok())
+                    .unwrap_or_default()
+            })
+            .bold_line_style(WHITE.mix(0.3))
+            .light_line_style(WHITE.mix(0.0)) // hide
+            .y_desc("Rank")
+            .y_label_formatter(&|y| if *y == 0 { 1 } else { -*y }.to_string())
+            .label_style(label_style)
+            .axis_style(axis_style)
+            .axis_desc_style(axis_desc_style)
+            .draw()
+            .wrap_err("Failed to draw primary mesh")?;
+
+        chart
+            .configure_secondary_axes()
+            .y_desc("PP")
+            .y_label_formatter(&|pp| format!("{pp:.0}"))
+            .label_style(label_style)
+            .axis_style(axis_style)
+            .axis_desc_style(axis_desc_style)
+            .draw()
+            .wrap_err("Failed to draw secondary mesh")?;
+
+        // Series
+        let rank_data = snapshots
+            .iter()
+            .filter(|snapshot| snapshot.global_rank > 0)
+            .map(|snapshot| (snapshot.snapshot_at.unix_timestamp(), -snapshot.global_rank));
+
+        let rank_style = RGBColor(0, 116, 193).stroke_width(2);
+        let rank_series = LineSeries::new(rank_data, rank_style);
+
+        let rank_label = if first.global_rank > 0 && last.global_rank > 0 {
+            format!(
+                "Rank (#{} → #{})",
+                WithComma::new(first.global_rank as u32),
+                WithComma::new(last.global_rank as u32)
+            )
+        } else {
+            "Rank".to_owned()
+        };
+
+        chart
+            .draw_series(rank_series)
+            .wrap_err("Failed to draw rank series")?
+            .label(rank_label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], rank_style));
+
+        let pp_data = snapshots
+            .iter()
+            .map(|snapshot| (snapshot.snapshot_at.unix_timestamp(), snapshot.pp));
+
+        let pp_style = RGBColor(0, 246, 193).stroke_width(2);
+        let pp_series = LineSeries::new(pp_data, pp_style);
+
+        chart
+            .draw_secondary_series(pp_series)
+            .wrap_err("Failed to draw pp series")?
+            .label(format!("PP ({:+.2})", last.pp - first.pp))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], pp_style));
+
+        // Legend
+        chart
+            .configure_series_labels()
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::UpperLeft)
+            .legend_area_size(45_i32)
+            .label_font(("sans-serif", 20_i32, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
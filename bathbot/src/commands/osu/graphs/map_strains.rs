@@ -62,7 +62,12 @@ impl<'m> GraphMapStrains<'m> {
             }
         }
 
-        Ok(Self { map, mods, mode })
+        Ok(Self {
+            map,
+            mods,
+            mode,
+            file: None,
+        })
     }
 }
 
@@ -163,12 +168,15 @@ const LEGEND_H: u32 = 25;
 pub async fn map_strains_graph(
     map: &Beatmap,
     mods: GameMods,
-    cover_url: &str,
+    cover_url: Option<&str>,
     w: u32,
     h: u32,
 ) -> Result<Vec<u8>> {
     let strains = GraphStrains::new(map, mods)?;
-    let cover_res = get_map_cover(cover_url, w, h).await;
+    let cover_res = match cover_url {
+        Some(url) => get_map_cover(url, w, h).await.map(Some),
+        None => Ok(None),
+    };
 
     let last_timestamp = ((NEW_STRAIN_COUNT - 2) as f64
         * strains.strains.section_len()
@@ -225,7 +233,7 @@ pub async fn map_strains_graph(
 
         // Add background
         match cover_res {
-            Ok(background) => {
+            Ok(Some(background)) => {
                 let background = background.blur(2.0);
                 let elem = BitMapElement::new(background, (0, 0));
                 root.draw(&elem).wrap_err("Failed to draw background")?;
@@ -234,8 +242,10 @@ pub async fn map_strains_graph(
                 root.draw(&rect)
                     .wrap_err("Failed to draw darkening rectangle")?;
             }
-            Err(err) => {
-                warn!(?err, "Failed to get mapset cover");
+            res => {
+                if let Err(err) = res {
+                    warn!(?err, "Failed to get mapset cover");
+                }
 
                 root.fill(&RGBColor(19, 43, 33))
                     .wrap_err("Failed to fill background")?;
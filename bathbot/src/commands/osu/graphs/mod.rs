@@ -9,6 +9,7 @@ use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, attachment,
     constants::{GENERAL_ISSUE, OSU_BASE},
+    datetime::DATE_FORMAT,
     matcher,
     osu::{MapIdType, ModSelection, ModsResult},
 };
@@ -21,42 +22,50 @@ use rosu_v2::{
     prelude::{GameMode, GameMods, OsuError},
     request::UserId,
 };
-use time::UtcOffset;
+use time::{Date, UtcOffset};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
-use twilight_model::id::{
-    Id,
-    marker::{ChannelMarker, UserMarker},
+use twilight_model::{
+    channel::Attachment,
+    id::{
+        Id,
+        marker::{ChannelMarker, UserMarker},
+    },
 };
 
 pub use self::map_strains::map_strains_graph;
 use self::{
     bpm::map_bpm_graph,
+    compare::{COMPARE_DESC, COMPARE_HELP, compare_user_ids, compare_users, send_compare_graph},
+    history::history_graph,
     medals::medals_graph,
-    osutrack::osutrack_graph,
+    osutrack::{osutrack_graph, osutrack_graph_compare},
     playcount_replays::{ProfileGraphFlags, playcount_replays_graph},
-    rank::rank_graph,
+    rank::{rank_graph, rank_graph_compare},
     score_rank::score_rank_graph,
     snipe_count::snipe_count_graph,
     sniped::sniped_graph,
-    top_date::top_graph_date,
+    top_date::{top_graph_date, top_graph_date_compare},
     top_index::top_graph_index,
     top_time::{top_graph_time_day, top_graph_time_hour},
 };
 use super::{SnipeGameMode, UserIdResult, require_link, user_not_found};
 use crate::{
+    active::impls::SimulateMap,
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{HasMods, HasName as HasNameTrait},
+        osu::{HasMods, HasName as HasNameTrait, parsed_map::AttachedSimulateMap},
     },
     core::{Context, commands::CommandOrigin},
     manager::{
-        MapError, OsuMap,
-        redis::osu::{CachedUser, UserArgs, UserArgsError},
+        MapError,
+        redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim},
     },
     util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
 mod bpm;
+mod compare;
+mod history;
 mod map_strains;
 mod medals;
 mod osutrack;
@@ -72,6 +81,8 @@ mod top_time;
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "graph", desc = "Display graphs about some user data")]
 pub enum Graph<'a> {
+    #[command(name = "history")]
+    History(GraphHistory<'a>),
     #[command(name = "bpm")]
     MapBpm(GraphMapBpm<'a>),
     #[command(name = "strains")]
@@ -94,8 +105,35 @@ pub enum Graph<'a> {
     Top(GraphTop),
 }
 
+const GRAPH_HISTORY_DESC: &str = "Display a user's pp and rank based on stats stored by the bot";
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "history",
+    desc = GRAPH_HISTORY_DESC,
+    help = "Display a user's pp and rank over time.\n\
+    Unlike the `osutrack` graphs, this uses the user stats that the bot stores \
+    whenever it retrieves a user so the history only covers times in which \
+    the user was requested in some way."
+)]
+pub struct GraphHistory<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = "Only show stats since this date (YYYY-MM-DD)")]
+    since: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
 const GRAPH_BPM_DESC: &str = "Display a map's bpm over time";
 
+const GRAPH_FILE_DESC: &str = "Specify a .osu file instead of a map";
+const GRAPH_FILE_HELP: &str = "Specify a .osu file instead of a map.\n\
+    This way unsubmitted difficulties can be checked as well. \
+    The file is only used for this command and is not stored.";
+
 #[derive(CommandModel, CreateCommand, HasMods)]
 #[command(name = "bpm", desc = GRAPH_BPM_DESC)]
 pub struct GraphMapBpm<'a> {
@@ -111,6 +149,8 @@ pub struct GraphMapBpm<'a> {
         help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Option<Cow<'a, str>>,
+    #[command(desc = GRAPH_FILE_DESC, help = GRAPH_FILE_HELP)]
+    file: Option<Attachment>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods)]
@@ -130,6 +170,8 @@ pub struct GraphMapStrains<'a> {
     mods: Option<Cow<'a, str>>,
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
+    #[command(desc = GRAPH_FILE_DESC, help = GRAPH_FILE_HELP)]
+    file: Option<Attachment>,
 }
 
 const GRAPH_MEDALS_DESC: &str = "Display a user's medal progress over time";
@@ -160,6 +202,19 @@ pub enum GraphOsuTrack {
     Grades(GraphOsuTrackGrades),
 }
 
+impl GraphOsuTrack {
+    fn compare(&self) -> Option<&str> {
+        match self {
+            GraphOsuTrack::PpRank(args) => args.compare.as_deref(),
+            GraphOsuTrack::Accuracy(args) => args.compare.as_deref(),
+            GraphOsuTrack::Score(_)
+            | GraphOsuTrack::HitRatios(_)
+            | GraphOsuTrack::Playcount(_)
+            | GraphOsuTrack::Grades(_) => None,
+        }
+    }
+}
+
 #[derive(CommandModel, CreateCommand, HasName)]
 #[command(
     name = "pp_rank",
@@ -172,6 +227,8 @@ pub struct GraphOsuTrackPpRank {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = COMPARE_DESC, help = COMPARE_HELP)]
+    compare: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -225,6 +282,8 @@ pub struct GraphOsuTrackAccuracy {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = COMPARE_DESC, help = COMPARE_HELP)]
+    compare: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -270,6 +329,8 @@ pub struct GraphRank<'a> {
     until: Option<u8>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = COMPARE_DESC, help = COMPARE_HELP)]
+    compare: Option<Cow<'a, str>>,
 }
 
 const GRAPH_SCORE_RANK_DESC: &str = "Display a user's score rank progression over time";
@@ -320,7 +381,8 @@ pub struct GraphSnipeCount<'a> {
     name = "top",
     desc = "Display a user's top scores pp",
     help = "Display a user's top scores pp.\n\
-    The timezone option is only relevant for the `Time` order."
+    The timezone option is only relevant for the `Time` order \
+    and comparing users is only supported for the `Date` order."
 )]
 pub struct GraphTop {
     #[command(desc = "Choose by which order the scores should be sorted, defaults to index")]
@@ -333,6 +395,8 @@ pub struct GraphTop {
     timezone: Option<TimezoneOption>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = COMPARE_DESC, help = COMPARE_HELP)]
+    compare: Option<String>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -359,6 +423,26 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
     let mut footer = None;
 
     let tuple_option = match args {
+        Graph::History(args) => {
+            let since = match args.since.as_deref() {
+                Some(date) => match Date::parse(date, DATE_FORMAT) {
+                    Ok(date) => Some(date.midnight().assume_utc()),
+                    Err(_) => {
+                        let content =
+                            "Failed to parse date. Be sure to specify it as `YYYY-MM-DD`.";
+
+                        return orig.error(content).await;
+                    }
+                },
+                None => None,
+            };
+
+            let (user_id, mode) = user_id_mode!(orig, args);
+
+            history_graph(&orig, user_id, mode, since)
+                .await
+                .wrap_err("Failed to create history graph")?
+        }
         Graph::MapBpm(args) => {
             return match map_bpm(&orig, args).await {
                 Ok(ControlFlow::Continue(map)) => {
@@ -453,6 +537,19 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
 
             footer = Some(FooterBuilder::new("Data provided by ameobea.me/osutrack"));
 
+            if let Some(compare) = args.compare() {
+                let user_ids = match compare_user_ids(user_id, compare).await? {
+                    Ok(user_ids) => user_ids,
+                    Err(content) => return orig.error(content).await,
+                };
+
+                let tuple_option = osutrack_graph_compare(&orig, user_ids, mode, args)
+                    .await
+                    .wrap_err("Failed to create osutrack comparison graph")?;
+
+                return send_compare_graph(&orig, tuple_option, footer).await;
+            }
+
             osutrack_graph(&orig, user_id, mode, args)
                 .await
                 .wrap_err("Failed to create osutrack graph")?
@@ -495,6 +592,20 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
         }
         Graph::Rank(args) => {
             let (user_id, mode) = user_id_mode!(orig, args);
+
+            if let Some(compare) = args.compare.as_deref() {
+                let user_ids = match compare_user_ids(user_id, compare).await? {
+                    Ok(user_ids) => user_ids,
+                    Err(content) => return orig.error(content).await,
+                };
+
+                let tuple_option = rank_graph_compare(&orig, user_ids, mode, args.from, args.until)
+                    .await
+                    .wrap_err("Failed to create rank comparison graph")?;
+
+                return send_compare_graph(&orig, tuple_option, None).await;
+            }
+
             let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
             rank_graph(&orig, user_id, user_args, args.from, args.until)
@@ -584,6 +695,25 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 },
             };
 
+            if let Some(compare) = args.compare.as_deref() {
+                if !matches!(args.order, GraphTopOrder::Date) {
+                    let content = "Comparing users is only supported for the `Date` order";
+
+                    return orig.error(content).await;
+                }
+
+                let user_ids = match compare_user_ids(user_id, compare).await? {
+                    Ok(user_ids) => user_ids,
+                    Err(content) => return orig.error(content).await,
+                };
+
+                let tuple_option = top_graph_compare(&orig, user_ids, mode, legacy_scores)
+                    .await
+                    .wrap_err("failed to create top comparison graph")?;
+
+                return send_compare_graph(&orig, tuple_option, None).await;
+            }
+
             top_graph(&orig, user_id, user_args, args.order, tz, legacy_scores)
                 .await
                 .wrap_err("failed to create top graph")?
@@ -617,30 +747,92 @@ const H: u32 = 711;
 struct MapResult {
     bytes: Vec<u8>,
     title: String,
-    url: String,
+    url: Option<String>,
 }
 
 impl MapResult {
-    fn new(map: &OsuMap, bytes: Vec<u8>) -> Self {
-        Self {
-            bytes,
-            title: format!("{} - {} [{}]", map.artist(), map.title(), map.version()),
-            url: format!("{OSU_BASE}b/{}", map.map_id()),
+    fn new(map: &SimulateMap, bytes: Vec<u8>) -> Self {
+        match map {
+            SimulateMap::Full(map) => Self {
+                bytes,
+                title: format!("{} - {} [{}]", map.artist(), map.title(), map.version()),
+                url: Some(format!("{OSU_BASE}b/{}", map.map_id())),
+            },
+            SimulateMap::Attached(map) => Self {
+                bytes,
+                title: map.filename.as_ref().to_owned(),
+                url: None,
+            },
         }
     }
 }
 
 impl From<MapResult> for MessageBuilder<'_> {
     fn from(map: MapResult) -> Self {
-        let embed = EmbedBuilder::new()
+        let mut embed = EmbedBuilder::new()
             .image(attachment("graph.png"))
-            .title(map.title)
-            .url(map.url);
+            .title(map.title);
+
+        if let Some(url) = map.url {
+            embed = embed.url(url);
+        }
 
         Self::new().embed(embed).attachment("graph.png", map.bytes)
     }
 }
 
+/// Resolves the map of a map graph which is either the attached .osu file or
+/// the map given by id, url, or the channel history.
+async fn graph_map(
+    orig: &CommandOrigin<'_>,
+    map: Option<&str>,
+    file: Option<Attachment>,
+    mode: Option<GameMode>,
+) -> Result<ControlFlow<(), SimulateMap>> {
+    if let Some(file) = file {
+        let map = AttachedSimulateMap::new(orig, Box::new(file), mode).await?;
+
+        return Ok(map.map_or(ControlFlow::Break(()), |map| {
+            ControlFlow::Continue(SimulateMap::Attached(map))
+        }));
+    }
+
+    let map_id = match get_map_id(map, orig.channel_id()).await {
+        Ok(map_id) => map_id,
+        Err(content) => return orig.error(content).await.map(ControlFlow::Break),
+    };
+
+    match Context::osu_map().map(map_id, None).await {
+        Ok(mut map) => {
+            if let Some(mode) = mode {
+                map.convert_mut(mode);
+            }
+
+            Ok(ControlFlow::Continue(SimulateMap::Full(map)))
+        }
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            orig.error(content).await.map(ControlFlow::Break)
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            Err(err)
+        }
+    }
+}
+
+fn graph_cover(map: &SimulateMap) -> Option<&str> {
+    match map {
+        SimulateMap::Full(map) => Some(map.cover()),
+        SimulateMap::Attached(_) => None,
+    }
+}
+
 async fn get_map_id(map: Option<&str>, channel_id: Id<ChannelMarker>) -> Result<u32, &'static str> {
     let map = match map.map(|arg| {
         matcher::get_osu_map_id(arg)
@@ -691,26 +883,9 @@ async fn map_bpm(
 ) -> Result<ControlFlow<(), MapResult>> {
     let mods_res = args.mods();
 
-    let map_id = match get_map_id(args.map.as_deref(), orig.channel_id()).await {
-        Ok(map_id) => map_id,
-        Err(content) => return orig.error(content).await.map(ControlFlow::Break),
-    };
-
-    let map = match Context::osu_map().map(map_id, None).await {
-        Ok(map) => map,
-        Err(MapError::NotFound) => {
-            let content = format!(
-                "Could not find beatmap with id `{map_id}`. \
-                Did you give me a mapset id instead of a map id?",
-            );
-
-            return orig.error(content).await.map(ControlFlow::Break);
-        }
-        Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-
-            return Err(err);
-        }
+    let map = match graph_map(orig, args.map.as_deref(), args.file, None).await? {
+        ControlFlow::Continue(map) => map,
+        ControlFlow::Break(()) => return Ok(ControlFlow::Break(())),
     };
 
     let mods = match mods_res {
@@ -748,7 +923,7 @@ async fn map_bpm(
         }
     };
 
-    let bytes = map_bpm_graph(&map.pp_map, mods, map.cover()).await?;
+    let bytes = map_bpm_graph(map.pp_map(), mods, graph_cover(&map)).await?;
 
     Ok(ControlFlow::Continue(MapResult::new(&map, bytes)))
 }
@@ -758,35 +933,11 @@ async fn map_strains(
     args: GraphMapStrains<'_>,
 ) -> Result<ControlFlow<(), MapResult>> {
     let mods_res = args.mods();
-
-    let map_id = match get_map_id(args.map.as_deref(), orig.channel_id()).await {
-        Ok(map_id) => map_id,
-        Err(content) => return orig.error(content).await.map(ControlFlow::Break),
-    };
-
     let mode = args.mode.map(GameMode::from);
 
-    let map = match Context::osu_map().map(map_id, None).await {
-        Ok(mut map) => {
-            if let Some(mode) = mode {
-                map.convert_mut(mode);
-            }
-
-            map
-        }
-        Err(MapError::NotFound) => {
-            let content = format!(
-                "Could not find beatmap with id `{map_id}`. \
-                        Did you give me a mapset id instead of a map id?",
-            );
-
-            return orig.error(content).await.map(ControlFlow::Break);
-        }
-        Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-
-            return Err(err);
-        }
+    let map = match graph_map(orig, args.map.as_deref(), args.file, mode).await? {
+        ControlFlow::Continue(map) => map,
+        ControlFlow::Break(()) => return Ok(ControlFlow::Break(())),
     };
 
     let mode = mode.unwrap_or(map.mode());
@@ -821,7 +972,7 @@ async fn map_strains(
         }
     };
 
-    let bytes = map_strains_graph(&map.pp_map, mods, map.cover(), W, H).await?;
+    let bytes = map_strains_graph(map.pp_map(), mods, graph_cover(&map), W, H).await?;
 
     Ok(ControlFlow::Continue(MapResult::new(&map, bytes)))
 }
@@ -906,6 +1057,69 @@ async fn top_graph(
     Ok(Some((user, bytes)))
 }
 
+async fn top_graph_compare(
+    orig: &CommandOrigin<'_>,
+    user_ids: Vec<UserId>,
+    mode: GameMode,
+    legacy_scores: bool,
+) -> Result<Option<(Vec<CachedUser>, Vec<u8>)>> {
+    let Some(users) = compare_users(orig, user_ids, mode).await? else {
+        return Ok(None);
+    };
+
+    let mut entries = Vec::with_capacity(users.len());
+
+    for user in users.iter() {
+        let user_args = UserArgsSlim::user_id(user.user_id.to_native()).mode(mode);
+
+        let scores_fut = Context::osu_scores()
+            .top(200, legacy_scores)
+            .exec(user_args);
+
+        match scores_fut.await {
+            Ok(scores) if scores.is_empty() => {
+                let content = format!(
+                    "`{name}`'s top scores are empty",
+                    name = user.username.as_str()
+                );
+
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
+            Ok(scores) => entries.push((user.username.as_str(), scores)),
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get scores");
+
+                return Err(err);
+            }
+        }
+    }
+
+    let caption = format!(
+        "{mode}top200 comparison",
+        mode = match mode {
+            GameMode::Osu => "",
+            GameMode::Taiko => "taiko ",
+            GameMode::Catch => "ctb ",
+            GameMode::Mania => "mania ",
+        }
+    );
+
+    let bytes = match top_graph_date_compare(caption, &entries).await {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to create top date comparison graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((users, bytes)))
+}
+
 async fn get_map_cover(url: &str, w: u32, h: u32) -> Result<DynamicImage> {
     let bytes = Context::client().get_mapset_cover(url).await?;
 
@@ -4,8 +4,8 @@ use bathbot_model::ArchivedOsuTrackHistoryEntry;
 use eyre::{ContextCompat, Result, WrapErr};
 use plotters::{
     chart::{ChartBuilder, SeriesLabelPosition},
-    prelude::{Circle, IntoDrawingArea},
-    series::AreaSeries,
+    prelude::{Circle, IntoDrawingArea, PathElement},
+    series::{AreaSeries, LineSeries},
     style::{Color, GREEN, RED, RGBColor, TextStyle, WHITE},
 };
 use plotters_backend::FontStyle;
@@ -13,6 +13,7 @@ use plotters_skia::SkiaBackend;
 use skia_safe::{EncodedImageFormat, surfaces};
 use time::OffsetDateTime;
 
+use super::CompareEntry;
 use crate::{
     commands::osu::graphs::{H, W},
     util::Monthly,
@@ -125,3 +126,89 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
 
     Ok(png_bytes)
 }
+
+pub(super) fn graph_compare(entries: &[CompareEntry<'_>]) -> Result<Vec<u8>> {
+    let mut min_acc: f32 = 100.0;
+    let mut max_acc: f32 = 0.0;
+
+    for entry in entries.iter().flat_map(|entry| entry.history) {
+        let acc = entry.accuracy.to_native();
+
+        min_acc = min_acc.min(acc);
+        max_acc = max_acc.max(acc);
+    }
+
+    let (start, end) = CompareEntry::time_range(entries);
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        root = root
+            .titled("Accuracy", title_style)
+            .wrap_err("Failed to draw title")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(20)
+            .y_label_area_size(60)
+            .margin(9)
+            .build_cartesian_2d(Monthly(start..end), min_acc..max_acc)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .bold_line_style(WHITE.mix(0.3))
+            .light_line_style(WHITE.mix(0.0)) // hide
+            .y_label_formatter(&f32::to_string)
+            .label_style(("sans-serif", 20_i32, &WHITE))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        for entry in entries {
+            let history = entry.history;
+
+            let data = history
+                .iter()
+                .map(|entry| (entry.timestamp(), entry.accuracy.to_native()));
+
+            let style = entry.color.stroke_width(2);
+
+            // The caller already checked that the history is not empty
+            let last = history[history.len() - 1].accuracy.to_native();
+
+            chart
+                .draw_series(LineSeries::new(data, style))
+                .wrap_err("Failed to draw accuracy series")?
+                .label(format!("{name} ({last:.2}%)", name = entry.name))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+
+        // Legend
+        chart
+            .configure_series_labels()
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::LowerRight)
+            .legend_area_size(45_i32)
+            .label_font(("sans-serif", 20_i32, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
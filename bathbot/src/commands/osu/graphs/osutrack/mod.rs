@@ -1,8 +1,14 @@
+use bathbot_model::ArchivedOsuTrackHistoryEntry;
 use bathbot_util::constants::GENERAL_ISSUE;
 use eyre::{Report, Result};
+use plotters::style::RGBColor;
 use rosu_v2::{error::OsuError, model::GameMode, request::UserId};
+use time::OffsetDateTime;
 
-use super::GraphOsuTrack;
+use super::{
+    GraphOsuTrack,
+    compare::{COLORS, compare_users},
+};
 use crate::{
     commands::osu::user_not_found,
     core::{Context, commands::CommandOrigin},
@@ -73,3 +79,105 @@ pub async fn osutrack_graph(
 
     Ok(Some((user, res?)))
 }
+
+pub async fn osutrack_graph_compare(
+    orig: &CommandOrigin<'_>,
+    user_ids: Vec<UserId>,
+    mode: GameMode,
+    args: GraphOsuTrack,
+) -> Result<Option<(Vec<CachedUser>, Vec<u8>)>> {
+    let graph_fn: fn(&[CompareEntry<'_>]) -> Result<Vec<u8>> = match args {
+        GraphOsuTrack::PpRank(_) => pp_rank::graph_compare,
+        GraphOsuTrack::Accuracy(_) => accuracy::graph_compare,
+        GraphOsuTrack::Score(_)
+        | GraphOsuTrack::HitRatios(_)
+        | GraphOsuTrack::Playcount(_)
+        | GraphOsuTrack::Grades(_) => {
+            let content = "Comparing users is not supported for this graph";
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+    };
+
+    let Some(users) = compare_users(orig, user_ids, mode).await? else {
+        return Ok(None);
+    };
+
+    let mut histories = Vec::with_capacity(users.len());
+
+    for user in users.iter() {
+        let user_id = user.user_id.to_native();
+
+        match Context::redis().osutrack_history(user_id, mode).await {
+            Ok(history) if history.is_empty() => {
+                let content = format!(
+                    "`{name}` has no osutrack history :(",
+                    name = user.username.as_str()
+                );
+
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
+            Ok(history) => histories.push(history),
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get osutrack history");
+
+                return Err(err);
+            }
+        }
+    }
+
+    let entries: Vec<_> = users
+        .iter()
+        .zip(histories.iter())
+        .zip(COLORS)
+        .map(|((user, history), color)| CompareEntry {
+            name: user.username.as_str(),
+            history,
+            color,
+        })
+        .collect();
+
+    let bytes = match graph_fn(&entries) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw osutrack comparison graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((users, bytes)))
+}
+
+/// A user's osutrack history within a comparison graph.
+struct CompareEntry<'a> {
+    name: &'a str,
+    history: &'a [ArchivedOsuTrackHistoryEntry],
+    color: RGBColor,
+}
+
+impl CompareEntry<'_> {
+    /// The time range covered by all entries.
+    ///
+    /// The caller already checked that no history is empty.
+    fn time_range(entries: &[Self]) -> (OffsetDateTime, OffsetDateTime) {
+        let start = entries
+            .iter()
+            .map(|entry| entry.history[0].timestamp())
+            .min()
+            .unwrap_or_else(OffsetDateTime::now_utc);
+
+        let end = entries
+            .iter()
+            .map(|entry| entry.history[entry.history.len() - 1].timestamp())
+            .max()
+            .unwrap_or_else(OffsetDateTime::now_utc);
+
+        (start, end)
+    }
+}
@@ -5,17 +5,23 @@ use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{constants::GENERAL_ISSUE, matcher, numbers::WithComma};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::{
-    prelude::{ChartBuilder, Circle, IntoDrawingArea, SeriesLabelPosition},
-    series::AreaSeries,
+    prelude::{ChartBuilder, Circle, IntoDrawingArea, PathElement, SeriesLabelPosition},
+    series::{AreaSeries, LineSeries},
     style::{BLACK, Color, GREEN, RED, RGBColor, ShapeStyle, WHITE},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
-use rosu_v2::{prelude::OsuError, request::UserId};
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
 use skia_safe::{EncodedImageFormat, surfaces};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphRank};
+use super::{
+    Graph, GraphRank,
+    compare::{COLORS, compare_users},
+};
 use crate::{
     commands::osu::{
         graphs::{GRAPH_RANK_DESC, H, W},
@@ -32,9 +38,12 @@ impl<'m> GraphRank<'m> {
     fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Self {
         let mut name = None;
         let mut discord = None;
+        let mut compare = Vec::new();
 
         for arg in args {
-            if let Some(id) = matcher::get_mention_user(arg) {
+            if name.is_some() || discord.is_some() {
+                compare.push(arg);
+            } else if let Some(id) = matcher::get_mention_user(arg) {
                 discord = Some(id);
             } else {
                 name = Some(arg.into());
@@ -47,14 +56,15 @@ impl<'m> GraphRank<'m> {
             discord,
             from: None,
             until: None,
+            compare: (!compare.is_empty()).then(|| compare.join(",").into()),
         }
     }
 }
 
 #[command]
 #[desc(GRAPH_RANK_DESC)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [usernames to compare]")]
+#[examples("peppy", "peppy \"Cookiezi\" mrekk")]
 #[group(Osu)]
 async fn prefix_graphrank(msg: &Message, args: Args<'_>, perms: Option<Permissions>) -> Result<()> {
     let args = GraphRank::args(None, args);
@@ -65,8 +75,8 @@ async fn prefix_graphrank(msg: &Message, args: Args<'_>, perms: Option<Permissio
 
 #[command]
 #[desc(GRAPH_RANK_DESC)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [usernames to compare]")]
+#[examples("peppy", "peppy \"Cookiezi\" mrekk")]
 #[group(Taiko)]
 async fn prefix_graphranktaiko(
     msg: &Message,
@@ -81,8 +91,8 @@ async fn prefix_graphranktaiko(
 
 #[command]
 #[desc(GRAPH_RANK_DESC)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [usernames to compare]")]
+#[examples("peppy", "peppy \"Cookiezi\" mrekk")]
 #[aliases("graphrankcatch")]
 #[group(Catch)]
 async fn prefix_graphrankctb(
@@ -98,8 +108,8 @@ async fn prefix_graphrankctb(
 
 #[command]
 #[desc(GRAPH_RANK_DESC)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [usernames to compare]")]
+#[examples("peppy", "peppy \"Cookiezi\" mrekk")]
 #[group(Mania)]
 async fn prefix_graphrankmania(
     msg: &Message,
@@ -153,21 +163,7 @@ pub async fn rank_graph(
             }
         }
 
-        let y_label_area_size = if max > 1_000_000 {
-            85
-        } else if max > 100_000 {
-            80
-        } else if max > 10_000 {
-            75
-        } else if max > 1000 {
-            70
-        } else if max > 100 {
-            65
-        } else if max > 10 {
-            60
-        } else {
-            50
-        };
+        let y_label_area_size = y_label_area_size(max);
 
         let (min, max) = (-(max as i32), -(min as i32));
 
@@ -311,3 +307,170 @@ pub async fn rank_graph(
 
     Ok(Some((user, bytes)))
 }
+
+pub async fn rank_graph_compare(
+    orig: &CommandOrigin<'_>,
+    user_ids: Vec<UserId>,
+    mode: GameMode,
+    from: Option<u8>,
+    until: Option<u8>,
+) -> Result<Option<(Vec<CachedUser>, Vec<u8>)>> {
+    let Some(users) = compare_users(orig, user_ids, mode).await? else {
+        return Ok(None);
+    };
+
+    let from_unwrapped = from.unwrap_or(0);
+    let until_unwrapped = u8::max(until.unwrap_or(90), u8::min(from_unwrapped + 2, 90));
+
+    let missing_data = users.iter().find(|user| {
+        user.rank_history.len() < 90 - from_unwrapped as usize
+            || user.rank_history[90 - until_unwrapped as usize..90 - from_unwrapped as usize]
+                .iter()
+                .all(|rank| rank.to_native() == 0)
+    });
+
+    if let Some(user) = missing_data {
+        let mut content = format!(
+            "`{name}` has no available rank data",
+            name = user.username.as_str()
+        );
+
+        if from.is_some() || until.is_some() {
+            content.push_str(" for this time range");
+        }
+
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let bytes = match draw_compare_graph(&users, from_unwrapped, until_unwrapped) {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw rank comparison graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((users, bytes)))
+}
+
+/// The caller must ensure that each user has rank data within the range.
+fn draw_compare_graph(users: &[CachedUser], from: u8, until: u8) -> Result<Vec<u8>> {
+    let mut min = u32::MAX;
+    let mut max = 0;
+
+    for user in users {
+        let history = &user.rank_history[90 - until as usize..90 - from as usize];
+
+        for rank in history.iter().map(|rank| rank.to_native()) {
+            if rank != 0 {
+                min = min.min(rank);
+                max = max.max(rank);
+            }
+        }
+    }
+
+    let y_label_area_size = y_label_area_size(max);
+    let (min, max) = (-(max as i32), -(min as i32));
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40)
+            .y_label_area_size(y_label_area_size)
+            .margin(10)
+            .margin_left(6)
+            .build_cartesian_2d(from as u32..(until as u32).saturating_sub(1), min..max)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .x_labels(20)
+            .x_desc("Days ago")
+            .x_label_formatter(&|x| format!("{}", (until + from) as u32 - *x))
+            .y_label_formatter(&|y| format!("{}", -*y))
+            .y_desc("Rank")
+            .label_style(("sans-serif", 15, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        for (user, color) in users.iter().zip(COLORS) {
+            let history = &user.rank_history[90 - until as usize..90 - from as usize];
+
+            let data = (from as u32..)
+                .zip(history.iter().map(|rank| -(rank.to_native() as i32)))
+                .skip_while(|(_, rank)| *rank == 0)
+                .take_while(|(_, rank)| *rank != 0);
+
+            let peak = history
+                .iter()
+                .map(|rank| rank.to_native())
+                .filter(|&rank| rank != 0)
+                .min()
+                .unwrap_or(0);
+
+            let style = color.stroke_width(3);
+
+            chart
+                .draw_series(LineSeries::new(data, style))
+                .wrap_err("Failed to draw rank series")?
+                .label(format!(
+                    "{name} (peak #{peak})",
+                    name = user.username.as_str(),
+                    peak = WithComma::new(peak)
+                ))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+
+        chart
+            .configure_series_labels()
+            .border_style(WHITE.mix(0.6).stroke_width(1))
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::LowerLeft)
+            .legend_area_size(25)
+            .label_font(("sans-serif", 15, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
+
+fn y_label_area_size(max: u32) -> i32 {
+    if max > 1_000_000 {
+        85
+    } else if max > 100_000 {
+        80
+    } else if max > 10_000 {
+        75
+    } else if max > 1000 {
+        70
+    } else if max > 100 {
+        65
+    } else if max > 10 {
+        60
+    } else {
+        50
+    }
+}
@@ -9,7 +9,7 @@ use plotters_skia::SkiaBackend;
 use rosu_v2::prelude::Score;
 use skia_safe::{EncodedImageFormat, surfaces};
 
-use super::{H, W};
+use super::{H, W, compare::COLORS};
 use crate::util::Monthly;
 
 pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec<u8>> {
@@ -104,3 +104,99 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
 
     Ok(png_bytes)
 }
+
+/// Each entry consists of a username and that user's top scores.
+pub async fn top_graph_date_compare(
+    caption: String,
+    entries: &[(&str, Vec<Score>)],
+) -> Result<Vec<u8>> {
+    let pps = || {
+        entries
+            .iter()
+            .flat_map(|(_, scores)| scores)
+            .filter_map(|score| score.pp)
+    };
+
+    let max_adj = pps().fold(0.0_f32, f32::max) + 5.0;
+    let min_adj = (pps().fold(f32::MAX, f32::min) - 5.0).max(0.0);
+
+    let dates = || {
+        entries
+            .iter()
+            .flat_map(|(_, scores)| scores)
+            .map(|s| s.ended_at)
+    };
+
+    let first = dates().min().wrap_err("no scores")?;
+    let last = dates().max().wrap_err("no scores")?;
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("failed to fill background")?;
+
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &WHITE);
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40_i32)
+            .y_label_area_size(60_i32)
+            .margin_top(5_i32)
+            .margin_right(15_i32)
+            .caption(caption, caption_style)
+            .build_cartesian_2d(Monthly(first..last), min_adj..max_adj)
+            .wrap_err("failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .y_label_formatter(&|pp| format!("{pp:.0}pp"))
+            .x_label_formatter(&|datetime| datetime.date().to_string())
+            .label_style(("sans-serif", 16_i32, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw mesh")?;
+
+        for ((name, scores), color) in entries.iter().zip(COLORS) {
+            let point_style = color.mix(0.7).filled();
+            let max = scores.iter().filter_map(|s| s.pp).fold(0.0_f32, f32::max);
+
+            let iter = scores.iter().filter_map(|s| Some((s.ended_at, s.pp?)));
+
+            let series =
+                PointSeries::of_element(iter, 3_i32, point_style, &|coord, size, style| {
+                    EmptyElement::at(coord) + Circle::new((0, 0), size, style)
+                });
+
+            chart
+                .draw_series(series)
+                .wrap_err("failed to draw points")?
+                .label(format!("{name} (max {max:.2}pp)"))
+                .legend(move |(x, y)| Circle::new((x, y), 5_i32, point_style));
+        }
+
+        chart
+            .configure_series_labels()
+            .border_style(WHITE.mix(0.6).stroke_width(1))
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::MiddleLeft)
+            .legend_area_size(15_i32)
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
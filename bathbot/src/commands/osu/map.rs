@@ -351,7 +351,7 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
             let w = SingleScorePagination::IMAGE_W;
             let h = SingleScorePagination::IMAGE_H;
 
-            match map_strains_graph(&map, mods_with_mode, &mapset.covers.cover, w, h).await {
+            match map_strains_graph(&map, mods_with_mode, Some(&mapset.covers.cover), w, h).await {
                 Ok(graph) => Some(graph),
                 Err(err) => {
                     warn!(?err, "Failed to create graph");
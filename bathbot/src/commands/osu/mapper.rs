@@ -295,7 +295,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
-        && Context::ordr_available();
+        && Context::render_available();
 
     let entries =
         match process_scores(scores, mapper_id, args.sort, with_render, legacy_scores).await {
@@ -367,7 +367,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
                     let fut = map_strains_graph(
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
-                        entry.map.cover(),
+                        Some(entry.map.cover()),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
                     );
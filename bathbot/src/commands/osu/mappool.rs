@@ -0,0 +1,225 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{
+    core::{Context, commands::checks::check_authority},
+    manager::Mappool,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "mappool",
+    dm_permission = false,
+    desc = "Manage tournament mappools of this server",
+    help = "Manage tournament mappools of this server.\n\
+    A mappool assigns a slot like `NM1`, `HD2`, or `TB` to each map. \
+    Commands such as `/matchcost`, `/matchcompare`, `/matchstats`, and `/matchlive` \
+    accept either the name of a stored mappool or the slot-map pairs directly \
+    so that games are labeled with their slot and stats are broken down per mod pool.\n\
+    Saving and deleting mappools requires authority status."
+)]
+#[flags(ONLY_GUILDS)]
+pub enum Mappools {
+    #[command(name = "save")]
+    Save(MappoolSave),
+    #[command(name = "show")]
+    Show(MappoolShow),
+    #[command(name = "list")]
+    List(MappoolList),
+    #[command(name = "delete")]
+    Delete(MappoolDelete),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "save",
+    desc = "Save a mappool, replacing a previous pool of the same name"
+)]
+pub struct MappoolSave {
+    #[command(max_length = 32, desc = "Specify the name of the mappool")]
+    name: String,
+    #[command(
+        desc = "Specify slot-map pairs e.g. `NM1:1234 NM2:5678 HD1:9012`",
+        help = "Specify slot-map pairs e.g. `NM1:1234 NM2:5678 HD1:9012 TB:3456`.\n\
+        Pairs can be separated by spaces or commas, slot and map by `:` or `=`. \
+        Maps can be given by id or url.\n\
+        The letters of a slot determine its mod pool: \
+        `NM`, `HD`, `HR`, `DT`, `FM`, and `TB`; anything else counts as other."
+    )]
+    slots: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "show", desc = "Show a mappool of this server")]
+pub struct MappoolShow {
+    #[command(desc = "Specify the name of the mappool")]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all mappools of this server")]
+pub struct MappoolList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "delete", desc = "Delete a mappool of this server")]
+pub struct MappoolDelete {
+    #[command(desc = "Specify the name of the mappool")]
+    name: String,
+}
+
+async fn slash_mappools(mut command: InteractionCommand) -> Result<()> {
+    let Some(guild_id) = command.guild_id else {
+        return Ok(());
+    };
+
+    let args = Mappools::from_interaction(command.input_data())?;
+
+    if matches!(args, Mappools::Save(_) | Mappools::Delete(_)) {
+        if let Some(content) = check_authority(command.user_id()?, Some(guild_id)).await? {
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    }
+
+    let manager = Context::mappools();
+
+    let (title, description) = match args {
+        Mappools::Save(args) => {
+            let name = args.name.trim();
+
+            let mappool = match Mappool::parse(&args.slots) {
+                Ok(mappool) => mappool,
+                Err(content) => {
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+            };
+
+            if let Err(err) = manager.store(guild_id, name, &mappool).await {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+
+            (format!("Saved mappool {name}"), slots_description(&mappool))
+        }
+        Mappools::Show(args) => {
+            let name = args.name.trim();
+
+            match manager.get(guild_id, name).await {
+                Ok(Some(mappool)) => (format!("Mappool {name}"), slots_description(&mappool)),
+                Ok(None) => return no_mappool(&command, name).await,
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err);
+                }
+            }
+        }
+        Mappools::List(_) => match manager.names(guild_id).await {
+            Ok(names) => {
+                let mut description = String::with_capacity(names.len() * 16);
+
+                for name in names.iter() {
+                    let _ = writeln!(description, "- `{name}`");
+                }
+
+                if names.is_empty() {
+                    description.push_str("No mappools saved yet, use `/mappool save` to add one");
+                }
+
+                ("Mappools of this server".to_owned(), description)
+            }
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        Mappools::Delete(args) => {
+            let name = args.name.trim();
+
+            match manager.delete(guild_id, name).await {
+                Ok(true) => (
+                    "Mappools of this server".to_owned(),
+                    format!("Deleted mappool `{name}`"),
+                ),
+                Ok(false) => return no_mappool(&command, name).await,
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err);
+                }
+            }
+        }
+    };
+
+    let embed = EmbedBuilder::new().title(title).description(description);
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn no_mappool(command: &InteractionCommand, name: &str) -> Result<()> {
+    let content = format!(
+        "There is no mappool `{}` in this server",
+        name.cow_escape_markdown()
+    );
+
+    command.error(content).await?;
+
+    Ok(())
+}
+
+fn slots_description(mappool: &Mappool) -> String {
+    let mut description = String::with_capacity(mappool.slots().len() * 48);
+
+    for (label, map_id) in mappool.slots() {
+        let _ = writeln!(description, "`{label:<4}` {OSU_BASE}b/{map_id}");
+    }
+
+    description
+}
+
+/// Resolves the `mappool` option of a command which is either the name of a
+/// mappool stored for the guild or slot-map pairs.
+///
+/// The outer result is an internal error, the inner one a message for the
+/// user.
+pub async fn resolve_mappool(
+    guild_id: Option<Id<GuildMarker>>,
+    input: &str,
+) -> Result<Result<Mappool, String>> {
+    if input.contains([':', '=']) {
+        return Ok(Mappool::parse(input));
+    }
+
+    let name = input.trim();
+
+    let Some(guild_id) = guild_id else {
+        let content = "Stored mappools are only available in servers, \
+            specify slot-map pairs like `NM1:1234 HD1:5678` instead";
+
+        return Ok(Err(content.to_owned()));
+    };
+
+    match Context::mappools().get(guild_id, name).await? {
+        Some(mappool) => Ok(Ok(mappool)),
+        None => Ok(Err(format!(
+            "There is no mappool `{}` in this server, check `/mappool list`",
+            name.cow_escape_markdown()
+        ))),
+    }
+}
@@ -1,13 +1,17 @@
 use std::time::Duration;
 
 use bathbot_macros::SlashCommand;
-use bathbot_util::{Authored, MessageBuilder, constants::OSU_API_ISSUE, matcher};
+use bathbot_util::{
+    Authored, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use tokio::time::interval;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 
-use super::retrieve_previous;
+use super::{resolve_mappool, retrieve_previous};
 use crate::{
     active::{ActiveMessages, impls::MatchComparePagination},
     core::Context,
@@ -26,6 +30,13 @@ pub struct MatchCompare {
     output: Option<MatchCompareOutput>,
     #[command(desc = "Specify if it should show comparisons between players or teams")]
     comparison: Option<MatchCompareComparison>,
+    #[command(
+        desc = "Specify a mappool name of this server or slot-map pairs",
+        help = "Specify the name of a mappool that was saved via `/mappool save` \
+        or slot-map pairs like `NM1:1234 NM2:5678 HD1:9012`.\n\
+        Maps will then be labeled with their slot."
+    )]
+    mappool: Option<String>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -70,6 +81,7 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
         match_url_2,
         output,
         comparison,
+        mappool,
     } = args;
 
     let match_id1 = match matcher::get_osu_match_id(&match_url_1) {
@@ -101,6 +113,23 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
         return Ok(());
     }
 
+    let mappool = match mappool {
+        Some(ref mappool) => match resolve_mappool(command.guild_id, mappool).await {
+            Ok(Ok(mappool)) => Some(mappool),
+            Ok(Err(content)) => {
+                command.error(content).await?;
+
+                return Ok(());
+            }
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
     let match_fut1 = Context::osu().osu_match(match_id1);
     let match_fut2 = Context::osu().osu_match(match_id2);
 
@@ -122,7 +151,13 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
 
             let owner = command.user_id()?;
 
-            MatchComparePagination::new(&mut match1, &mut match2, comparison, owner)
+            MatchComparePagination::new(
+                &mut match1,
+                &mut match2,
+                comparison,
+                mappool.as_ref(),
+                owner,
+            )
         }
         Err(OsuError::NotFound) => {
             let content = "At least one of the two given matches was not found";
@@ -0,0 +1,401 @@
+use std::collections::{HashMap, HashSet};
+
+use bathbot_util::IntHasher;
+use rosu_v2::prelude::{GameMod, GameModIntermode, GameMods, GameModsIntermode, MatchGame};
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// Rates the performance of each player across the games of a match.
+pub trait MatchCostFormula {
+    /// Calculates the rating of every user that played in at least one of
+    /// the games.
+    ///
+    /// `tiebreaker` is the last game if it decided the match.
+    fn match_costs(
+        &self,
+        games: &[MatchGame],
+        tiebreaker: Option<&MatchGame>,
+    ) -> HashMap<u32, MatchCostEntry, IntHasher>;
+
+    /// Explains how the values of the full display come together.
+    fn footer(&self) -> &'static str;
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum MatchCostFormulaKind {
+    #[default]
+    #[option(name = "Bathbot", value = "bathbot")]
+    Bathbot,
+    #[option(name = "Bathbot legacy", value = "legacy")]
+    BathbotLegacy,
+    #[option(name = "Flashlight", value = "flashlight")]
+    Flashlight,
+    #[option(name = "Z-score", value = "zscore")]
+    ZScore,
+}
+
+impl MatchCostFormulaKind {
+    pub fn formula(self) -> &'static dyn MatchCostFormula {
+        match self {
+            Self::Bathbot => &BATHBOT,
+            Self::BathbotLegacy => &BATHBOT_LEGACY,
+            Self::Flashlight => &Flashlight,
+            Self::ZScore => &ZScore,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bathbot => "Bathbot",
+            Self::BathbotLegacy => "Bathbot legacy",
+            Self::Flashlight => "Flashlight",
+            Self::ZScore => "Z-score",
+        }
+    }
+}
+
+pub struct MatchCostEntry {
+    pub performance_cost: f32,
+    pub participation_bonus_factor: f32,
+    pub mods_bonus_factor: f32,
+    pub tiebreaker_bonus: f32,
+    pub avg_score: u32,
+}
+
+impl MatchCostEntry {
+    pub fn match_cost(&self) -> f32 {
+        (self.performance_cost * self.participation_bonus_factor * self.mods_bonus_factor)
+            + self.tiebreaker_bonus
+    }
+}
+
+/// Score relative to the average score of the game, rewarding participation,
+/// mod variety, and tiebreaker performance.
+pub struct Bathbot {
+    /// Flat additive performance cost bonus for each player
+    flat_bonus: f32,
+    /// Exponent base; maximum participation bonus for playing each game
+    base_participation_bonus: f32,
+    /// Exponent; curve to reach the maximum participation bonus.
+    ///
+    /// <0.85: fast up then slow down; >0.85: slow up then speed up
+    exp_participation_bonus: f32,
+    /// Multiplier bonus per mod combination (if at least 3)
+    mod_bonus: f32,
+    tiebreaker: TiebreakerBonus,
+}
+
+enum TiebreakerBonus {
+    /// Performing average on the tiebreaker rewards `factor`; any better
+    /// performance is capped at `max`
+    Scaled { factor: f32, max: f32 },
+    /// Every tiebreaker player gets the same bonus
+    Flat(f32),
+}
+
+static BATHBOT: Bathbot = Bathbot {
+    flat_bonus: 0.5,
+    base_participation_bonus: 1.5,
+    exp_participation_bonus: 0.6,
+    mod_bonus: 0.02,
+    tiebreaker: TiebreakerBonus::Scaled {
+        factor: 0.25,
+        max: 0.5,
+    },
+};
+
+/// Formula from before the tiebreaker bonus depended on the performance.
+static BATHBOT_LEGACY: Bathbot = Bathbot {
+    flat_bonus: 0.5,
+    base_participation_bonus: 1.4,
+    exp_participation_bonus: 0.6,
+    mod_bonus: 0.02,
+    tiebreaker: TiebreakerBonus::Flat(0.25),
+};
+
+impl MatchCostFormula for Bathbot {
+    fn match_costs(
+        &self,
+        games: &[MatchGame],
+        tiebreaker: Option<&MatchGame>,
+    ) -> HashMap<u32, MatchCostEntry, IntHasher> {
+        let mut users_mods = UsersMods::default();
+        let mut users_performance_costs = UsersPerformanceCosts::default();
+
+        for game in games.iter() {
+            let score_sum = game.scores.iter().fold(0, |sum, score| sum + score.score);
+            let score_avg = score_sum as f32 / game.scores.len() as f32;
+
+            for score in game.scores.iter() {
+                users_mods.update(score.user_id, &score.mods);
+                users_performance_costs.update(
+                    score.user_id,
+                    score.score,
+                    score.score as f32 / score_avg,
+                );
+            }
+        }
+
+        let mut match_costs =
+            HashMap::with_capacity_and_hasher(users_performance_costs.entries.len(), IntHasher);
+
+        for (user_id, entries) in users_performance_costs.entries.iter() {
+            let (avg_performance_cost, avg_score) = averages(entries);
+            let performance_cost = avg_performance_cost + self.flat_bonus;
+            let scores_len = entries.len() as f32;
+
+            let mut tiebreaker_bonus = 0.0;
+
+            if let Some(game) = tiebreaker {
+                if game.scores.iter().any(|score| score.user_id == *user_id) {
+                    if let Some(entry) = entries.last() {
+                        tiebreaker_bonus = match self.tiebreaker {
+                            TiebreakerBonus::Scaled { factor, max } => {
+                                max.min(factor * entry.performance_cost)
+                            }
+                            TiebreakerBonus::Flat(bonus) => bonus,
+                        };
+                    }
+                }
+            }
+
+            let exp = if games.len() <= 1 {
+                0.0
+            } else {
+                (scores_len - 1.0) / (games.len() - 1) as f32
+            };
+
+            let participation_bonus_factor = self
+                .base_participation_bonus
+                .powf(exp.powf(self.exp_participation_bonus));
+
+            let mods_used = users_mods.get_count(*user_id).unwrap_or(0) as u32;
+
+            let mut mods_bonus_factor = 1.0;
+
+            if mods_used > 2 {
+                mods_bonus_factor += self.mod_bonus * (mods_used - 2) as f32;
+            }
+
+            let entry = MatchCostEntry {
+                performance_cost,
+                participation_bonus_factor,
+                mods_bonus_factor,
+                tiebreaker_bonus,
+                avg_score,
+            };
+
+            match_costs.insert(*user_id, entry);
+        }
+
+        match_costs
+    }
+
+    fn footer(&self) -> &'static str {
+        "matchcost = (performance * participation * mods) + tiebreaker | average score"
+    }
+}
+
+/// Score relative to the median score of the game, scaled by the cube root
+/// of the amount of played games relative to the median amount.
+pub struct Flashlight;
+
+impl MatchCostFormula for Flashlight {
+    fn match_costs(
+        &self,
+        games: &[MatchGame],
+        _: Option<&MatchGame>,
+    ) -> HashMap<u32, MatchCostEntry, IntHasher> {
+        let mut users_performance_costs = UsersPerformanceCosts::default();
+        let mut scores = Vec::new();
+
+        for game in games.iter() {
+            scores.clear();
+            scores.extend(game.scores.iter().map(|score| score.score as f32));
+            let score_median = median(&mut scores);
+
+            // Only empty games have a median of 0; like with the average in
+            // the other formulas, they don't contribute to anyone's rating.
+            if score_median <= 0.0 {
+                continue;
+            }
+
+            for score in game.scores.iter() {
+                users_performance_costs.update(
+                    score.user_id,
+                    score.score,
+                    score.score as f32 / score_median,
+                );
+            }
+        }
+
+        let mut games_played: Vec<_> = users_performance_costs
+            .entries
+            .values()
+            .map(|entries| entries.len() as f32)
+            .collect();
+
+        let games_played_median = median(&mut games_played);
+
+        users_performance_costs
+            .entries
+            .iter()
+            .map(|(user_id, entries)| {
+                let (performance_cost, avg_score) = averages(entries);
+
+                let entry = MatchCostEntry {
+                    performance_cost,
+                    participation_bonus_factor: (entries.len() as f32 / games_played_median).cbrt(),
+                    mods_bonus_factor: 1.0,
+                    tiebreaker_bonus: 0.0,
+                    avg_score,
+                };
+
+                (*user_id, entry)
+            })
+            .collect()
+    }
+
+    fn footer(&self) -> &'static str {
+        "matchcost = (performance * participation * mods) + tiebreaker | average score\n\
+        performance: score / median score • participation: ∛(games / median games)"
+    }
+}
+
+/// Standard score of each game, scaled by the square root of the ratio of
+/// played games so that few games pull the rating towards zero.
+pub struct ZScore;
+
+impl MatchCostFormula for ZScore {
+    fn match_costs(
+        &self,
+        games: &[MatchGame],
+        _: Option<&MatchGame>,
+    ) -> HashMap<u32, MatchCostEntry, IntHasher> {
+        let mut users_performance_costs = UsersPerformanceCosts::default();
+
+        for game in games.iter() {
+            let len = game.scores.len() as f32;
+            let mean = game
+                .scores
+                .iter()
+                .map(|score| score.score as f32)
+                .sum::<f32>()
+                / len;
+
+            let variance = game
+                .scores
+                .iter()
+                .map(|score| (score.score as f32 - mean).powi(2))
+                .sum::<f32>()
+                / len;
+
+            let std_dev = variance.sqrt();
+
+            for score in game.scores.iter() {
+                let z_score = if std_dev > 0.0 {
+                    (score.score as f32 - mean) / std_dev
+                } else {
+                    0.0
+                };
+
+                users_performance_costs.update(score.user_id, score.score, z_score);
+            }
+        }
+
+        users_performance_costs
+            .entries
+            .iter()
+            .map(|(user_id, entries)| {
+                let (performance_cost, avg_score) = averages(entries);
+
+                let entry = MatchCostEntry {
+                    performance_cost,
+                    participation_bonus_factor: (entries.len() as f32 / games.len() as f32).sqrt(),
+                    mods_bonus_factor: 1.0,
+                    tiebreaker_bonus: 0.0,
+                    avg_score,
+                };
+
+                (*user_id, entry)
+            })
+            .collect()
+    }
+
+    fn footer(&self) -> &'static str {
+        "matchcost = (performance * participation * mods) + tiebreaker | average score\n\
+        performance: average z-score • participation: √(games / total games)"
+    }
+}
+
+/// Keeps track of all mod combinations a user has played
+#[derive(Default)]
+struct UsersMods {
+    entries: HashMap<u32, HashSet<GameModsIntermode>, IntHasher>,
+}
+
+impl UsersMods {
+    fn update(&mut self, user_id: u32, mods: &GameMods) {
+        let mods: GameModsIntermode = mods.iter().map(GameMod::intermode).collect();
+
+        self.entries
+            .entry(user_id)
+            .or_default()
+            .insert(mods - GameModIntermode::NoFail);
+    }
+
+    fn get_count(&self, user_id: u32) -> Option<usize> {
+        self.entries.get(&user_id).map(HashSet::len)
+    }
+}
+
+/// For each user, store the performance cost of all their scores
+#[derive(Default)]
+struct UsersPerformanceCosts {
+    entries: HashMap<u32, Vec<PerformanceCost>, IntHasher>,
+}
+
+impl UsersPerformanceCosts {
+    fn update(&mut self, user_id: u32, score: u32, performance_cost: f32) {
+        let performance_cost = PerformanceCost {
+            score,
+            performance_cost,
+        };
+
+        self.entries
+            .entry(user_id)
+            .or_default()
+            .push(performance_cost);
+    }
+}
+
+struct PerformanceCost {
+    score: u32,
+    performance_cost: f32,
+}
+
+/// Average performance cost and average score
+fn averages(entries: &[PerformanceCost]) -> (f32, u32) {
+    let (performance_cost_sum, score_sum) =
+        entries
+            .iter()
+            .fold((0.0, 0), |(performance_cost_sum, score_sum), entry| {
+                (
+                    performance_cost_sum + entry.performance_cost,
+                    score_sum + entry.score,
+                )
+            });
+
+    let len = entries.len() as f32;
+
+    (performance_cost_sum / len, (score_sum as f32 / len) as u32)
+}
+
+fn median(values: &mut [f32]) -> f32 {
+    values.sort_unstable_by(f32::total_cmp);
+
+    match values.len() {
+        0 => 0.0,
+        len if len % 2 == 1 => values[len / 2],
+        len => (values[len / 2 - 1] + values[len / 2]) / 2.0,
+    }
+}
@@ -0,0 +1,1112 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    mem,
+};
+
+use bathbot_macros::{SlashCommand, command};
+use bathbot_util::{
+    IntHasher,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{
+    GameModIntermode, MatchGame, MatchTeam, Osu, OsuError, OsuMatch, OsuResult, TeamType, User,
+};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+
+pub use self::formula::{MatchCostFormula, MatchCostFormulaKind};
+use super::resolve_mappool;
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::MatchCostPagination},
+    core::commands::{
+        CommandOrigin,
+        prefix::{Args, ArgsNum},
+    },
+    manager::{Mappool, ModPool},
+    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+mod formula;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "matchcost",
+    desc = "Display performance ratings for a multiplayer match",
+    help = "Calculate a performance rating for each player in the given multiplayer match.\n\
+    Default formula: <https://i.imgur.com/zuii7Oj.png> ([desmos](https://www.desmos.com/calculator/mm4tins990))"
+)]
+pub struct MatchCost<'a> {
+    #[command(desc = "Specify a match url or match id")]
+    match_url: Cow<'a, str>,
+    #[command(
+        min_value = 0,
+        desc = "Specify the amount of warmups to ignore (defaults to 0)",
+        help = "Since warmup maps commonly want to be skipped for performance calculations, \
+        this option allows you to specify how many maps should be ignored in the beginning.\n\
+        If no value is specified, it defaults to 0."
+    )]
+    warmups: Option<usize>,
+    #[command(
+        max_value = 100.0,
+        desc = "Specify a multiplier for EZ scores",
+        help = "Specify a multiplier for EZ scores.\n\
+        The suggested multiplier range is 1.0-2.0"
+    )]
+    ez_mult: Option<f32>,
+    #[command(
+        max_value = 100.0,
+        desc = "Specify a multiplier for HD scores",
+        help = "Specify a multiplier for HD scores.\n\
+        The suggested multiplier range is 1.0-1.2"
+    )]
+    hd_mult: Option<f32>,
+    #[command(
+        max_value = 100.0,
+        desc = "Specify a multiplier for FL scores",
+        help = "Specify a multiplier for FL scores.\n\
+        The suggested multiplier range is 1.0-1.5"
+    )]
+    fl_mult: Option<f32>,
+    #[command(
+        min_value = 0,
+        desc = "Specify the amount of maps to ignore at the end (defaults to 0)",
+        help = "In case the last few maps were just for fun, \
+        this options allows to ignore them for the performance rating.\n\
+        Alternatively, in combination with the `warmups` option, \
+        you can check the rating for specific maps.\n\
+        If no value is specified, it defaults to 0."
+    )]
+    skip_last: Option<usize>,
+    #[command(
+        desc = "Specify the formula to rate players with",
+        help = "Specify the formula to rate players with.\n\
+        - `Bathbot`: Relative to the average score of each map with bonuses for \
+        participation, mod variety, and the tiebreaker\n\
+        - `Bathbot legacy`: Former version of the `Bathbot` formula with \
+        a flat tiebreaker bonus\n\
+        - `Flashlight`: Relative to the median score of each map, scaled by \
+        the amount of played maps\n\
+        - `Z-score`: Standard score of each map where 0 is average, \
+        shrunk towards 0 for few played maps"
+    )]
+    formula: Option<MatchCostFormulaKind>,
+    #[command(
+        desc = "Specify whether mod multipliers also apply to the mods of a map (defaults to false)",
+        help = "By default, mod multipliers only apply to scores that were set with the mod.\n\
+        If enabled, they also apply to all scores on a map that was played with the mod \
+        for everyone, e.g. an HD pick without freemod.\n\
+        This works the same for every formula."
+    )]
+    game_mods: Option<bool>,
+    #[command(
+        desc = "Specify a mappool name of this server or slot-map pairs",
+        help = "Specify the name of a mappool that was saved via `/mappool save` \
+        or slot-map pairs like `NM1:1234 NM2:5678 HD1:9012`.\n\
+        The result will then contain a breakdown per mod pool."
+    )]
+    mappool: Option<Cow<'a, str>>,
+    #[command(desc = "How the data should be displayed")]
+    display: Option<MatchCostDisplay>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum MatchCostDisplay {
+    #[default]
+    #[option(name = "Compact", value = "compact")]
+    Compact,
+    #[option(name = "Full", value = "full")]
+    Full,
+}
+
+impl<'m> MatchCost<'m> {
+    fn args(mut args: Args<'m>) -> Result<Self, &'static str> {
+        let match_url = match args.next() {
+            Some(arg) => arg.into(),
+            None => {
+                let content = "The first argument must be either a match \
+                    id or the multiplayer link to a match";
+
+                return Err(content);
+            }
+        };
+
+        let warmups = match args.num {
+            ArgsNum::Value(n) => Some(n as usize),
+            ArgsNum::Random | ArgsNum::None => args.next().and_then(|arg| arg.parse().ok()),
+        };
+
+        Ok(Self {
+            match_url,
+            warmups,
+            skip_last: None,
+            ez_mult: None,
+            hd_mult: None,
+            fl_mult: None,
+            formula: None,
+            game_mods: None,
+            mappool: None,
+            display: None,
+        })
+    }
+}
+
+#[command]
+#[desc("Display performance ratings for a multiplayer match")]
+#[help(
+    "Calculate a performance rating for each player \
+     in the given multiplayer match.\nThe optional second \
+     argument is the amount of played warmups, defaults to 0.\n\
+     Current formula: <https://i.imgur.com/zuii7Oj.png> ([desmos](https://www.desmos.com/calculator/mm4tins990))"
+)]
+#[usage("[match url / match id] [amount of warmups]")]
+#[examples("58320988 1", "https://osu.ppy.sh/community/matches/58320988")]
+#[aliases("mc", "matchcost")]
+#[group(AllModes)]
+async fn prefix_matchcosts(msg: &Message, args: Args<'_>) -> Result<()> {
+    match MatchCost::args(args) {
+        Ok(args) => matchcosts(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_matchcost(mut command: InteractionCommand) -> Result<()> {
+    let args = MatchCost::from_interaction(command.input_data())?;
+
+    matchcosts((&mut command).into(), args).await
+}
+
+async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let MatchCost {
+        match_url,
+        warmups,
+        skip_last,
+        ez_mult,
+        hd_mult,
+        fl_mult,
+        formula,
+        game_mods,
+        mappool,
+        display,
+    } = args;
+
+    let Some(match_id) = matcher::get_osu_match_id(&match_url) else {
+        let content = "Failed to parse match url.\n\
+            Be sure it's a valid mp url or a match id.";
+
+        return orig.error(content).await;
+    };
+
+    let mappool = match mappool {
+        Some(ref mappool) => match resolve_mappool(orig.guild_id(), mappool).await {
+            Ok(Ok(mappool)) => Some(mappool),
+            Ok(Err(content)) => return orig.error(content).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
+    let warmups = warmups.unwrap_or(0);
+    let skip_last = skip_last.unwrap_or(0);
+    let formula = formula.unwrap_or_default();
+
+    let multipliers = ModMultipliers::new(
+        ez_mult.unwrap_or(1.0),
+        hd_mult.unwrap_or(1.0),
+        fl_mult.unwrap_or(1.0),
+        game_mods.unwrap_or(false),
+    );
+
+    let osu = Context::osu();
+
+    // Retrieve the match
+    let (osu_match, games) = match osu.osu_match(match_id).await {
+        Ok(mut osu_match) => {
+            retrieve_previous(&mut osu_match, osu).await?;
+            let games = prepare_games(&mut osu_match, warmups, skip_last, multipliers);
+
+            (osu_match, games)
+        }
+        Err(OsuError::NotFound) => {
+            let content = format!("No match with id `{match_id}` was found");
+
+            return orig.error(content).await;
+        }
+        Err(OsuError::Response { status, .. }) if status == 401 => {
+            let content = "I can't access the match because it was set as private";
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get match");
+
+            return Err(err);
+        }
+    };
+
+    let breakdown = match mappool {
+        Some(ref mappool) => pool_breakdown(&games, mappool),
+        None => Vec::new(),
+    };
+
+    let match_result = if games.is_empty() {
+        let mut description = format!("No games played yet beyond the {warmups} warmup");
+
+        if warmups != 1 {
+            description.push('s');
+        }
+
+        MatchResult::NoGames { description }
+    } else {
+        process_match(
+            &games,
+            osu_match.end_time.is_some(),
+            &osu_match.users,
+            formula.formula(),
+        )
+    };
+
+    let mut content = String::new();
+
+    if warmups > 0 {
+        content.push_str("Ignoring the first ");
+
+        if warmups == 1 {
+            content.push_str("map");
+        } else {
+            let _ = write!(content, "{warmups} maps");
+        }
+
+        content.push_str(" as warmup");
+    }
+
+    if multipliers.is_neutral() {
+        if !content.is_empty() {
+            content.push(':');
+        }
+    } else {
+        let _ = if content.is_empty() {
+            write!(content, "Mod multipliers: {multipliers}")
+        } else {
+            write!(content, " (mod multipliers: {multipliers}):")
+        };
+    }
+
+    let pagination = MatchCostPagination::builder()
+        .osu_match(osu_match)
+        .content(content.into_boxed_str())
+        .display(display.unwrap_or_default())
+        .formula(formula)
+        .breakdown(breakdown.into_boxed_slice())
+        .msg_owner(owner)
+        .result(match_result)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+/// Prepends up to 500 previous events to the match.
+///
+/// Returns `false` if the match has even earlier events that were not
+/// retrieved.
+pub async fn retrieve_previous(osu_match: &mut OsuMatch, osu: &Osu) -> OsuResult<bool> {
+    let mut curr = &*osu_match;
+    let mut prev: Option<OsuMatch> = None;
+    let mut complete = false;
+
+    // Retrieve at most 500 previous events
+    for _ in 0..5 {
+        match curr.get_previous(osu).await {
+            Some(Ok(next_prev)) => {
+                let prev_opt = prev.take();
+                curr = &*prev.get_or_insert(next_prev);
+
+                if let Some(mut prev) = prev_opt {
+                    prev.events.append(&mut osu_match.events);
+                    mem::swap(&mut prev.events, &mut osu_match.events);
+                    osu_match.users.extend(prev.users);
+                }
+            }
+            Some(Err(err)) => return Err(err),
+            None => {
+                complete = true;
+
+                break;
+            }
+        }
+    }
+
+    if let Some(mut prev) = prev {
+        prev.events.append(&mut osu_match.events);
+        mem::swap(&mut prev.events, &mut osu_match.events);
+        osu_match.users.extend(prev.users);
+    }
+
+    Ok(complete)
+}
+
+/// Drains the finished games of the match and prepares them for the match
+/// cost calculation.
+pub fn prepare_games(
+    osu_match: &mut OsuMatch,
+    warmups: usize,
+    skip_last: usize,
+    multipliers: ModMultipliers,
+) -> Vec<MatchGame> {
+    let mut games: Vec<_> = osu_match
+        .drain_games()
+        .filter(|game| game.end_time.is_some())
+        .skip(warmups)
+        .map(|mut game| {
+            game.scores.retain(|score| score.score > 0);
+
+            if !multipliers.is_neutral() {
+                multipliers.apply(&mut game);
+            }
+
+            game
+        })
+        .collect();
+
+    games.truncate(games.len().saturating_sub(skip_last));
+
+    games
+}
+
+/// Score multipliers for maps that were played with specific mods
+#[derive(Copy, Clone)]
+pub struct ModMultipliers {
+    ez: f32,
+    hd: f32,
+    fl: f32,
+    /// Whether the mods of the game count in addition to the score's mods
+    game_mods: bool,
+}
+
+impl ModMultipliers {
+    pub const NEUTRAL: Self = Self {
+        ez: 1.0,
+        hd: 1.0,
+        fl: 1.0,
+        game_mods: false,
+    };
+
+    /// If `game_mods` is set, multipliers also apply to all scores of a game
+    /// whose mods were enforced for everyone.
+    pub const fn new(ez: f32, hd: f32, fl: f32, game_mods: bool) -> Self {
+        Self {
+            ez,
+            hd,
+            fl,
+            game_mods,
+        }
+    }
+
+    fn is_neutral(self) -> bool {
+        self.ez == 1.0 && self.hd == 1.0 && self.fl == 1.0
+    }
+
+    fn apply(self, game: &mut MatchGame) {
+        let mods = [
+            (GameModIntermode::Easy, self.ez),
+            (GameModIntermode::Hidden, self.hd),
+            (GameModIntermode::Flashlight, self.fl),
+        ];
+
+        for score in game.scores.iter_mut() {
+            let mult = mods
+                .iter()
+                .filter(|(gamemod, _)| {
+                    score.mods.contains_intermode(*gamemod)
+                        || (self.game_mods && game.mods.contains_intermode(*gamemod))
+                })
+                .fold(1.0, |mult, (_, factor)| mult * factor);
+
+            if mult != 1.0 {
+                score.score = (score.score as f32 * mult) as u32;
+            }
+        }
+    }
+}
+
+impl Display for ModMultipliers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mults = [("EZ", self.ez), ("HD", self.hd), ("FL", self.fl)];
+        let mut iter = mults.into_iter().filter(|(_, mult)| *mult != 1.0);
+
+        if let Some((name, mult)) = iter.next() {
+            write!(f, "{name} {mult:.2}")?;
+
+            for (name, mult) in iter {
+                write!(f, " • {name} {mult:.2}")?;
+            }
+
+            if self.game_mods {
+                f.write_str(" (incl. map mods)")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn process_match(
+    games: &[MatchGame],
+    finished: bool,
+    users: &HashMap<u32, User>,
+    formula: &dyn MatchCostFormula,
+) -> MatchResult {
+    let mut users_team = UsersTeam::default();
+    let mut teams_win_count = TeamsWinCount::default();
+
+    for game in games.iter() {
+        let mut teams_score = TeamsScore::default();
+
+        for score in game.scores.iter() {
+            users_team.update(score.user_id, score.info.team);
+            teams_score.update(score.info.team, score.score);
+        }
+
+        teams_win_count.add_win(teams_score.winner());
+    }
+
+    let tiebreaker_game = games
+        .last()
+        .filter(|_| finished && games.len() > 4 && teams_win_count.diff() == 1);
+
+    let match_costs = formula.match_costs(games, tiebreaker_game);
+
+    let mvp_avatar_url = match_costs
+        .iter()
+        .reduce(|(mvp_user_id, mvp_entry), (user_id, entry)| {
+            if entry.match_cost() > mvp_entry.match_cost() {
+                (user_id, entry)
+            } else {
+                (mvp_user_id, mvp_entry)
+            }
+        })
+        .and_then(|(user_id, _)| users.get(user_id))
+        .map_or_else(Box::default, |user| Box::from(user.avatar_url.as_str()));
+
+    if games[0].team_type == TeamType::TeamVS {
+        let mut blue = TeamResult::new(teams_win_count.get(MatchTeam::Blue));
+        let mut red = TeamResult::new(teams_win_count.get(MatchTeam::Red));
+
+        for (&user_id, entry) in match_costs.iter() {
+            let Some(team @ (MatchTeam::Blue | MatchTeam::Red)) = users_team.get(user_id) else {
+                continue;
+            };
+
+            let entry = UserMatchCostEntry {
+                user_id,
+                performance_cost: entry.performance_cost,
+                participation_bonus_factor: entry.participation_bonus_factor,
+                mods_bonus_factor: entry.mods_bonus_factor,
+                tiebreaker_bonus: entry.tiebreaker_bonus,
+                match_cost: entry.match_cost(),
+                avg_score: entry.avg_score,
+            };
+
+            match team {
+                MatchTeam::Blue => blue.players.push(entry),
+                MatchTeam::Red => red.players.push(entry),
+                MatchTeam::None => unreachable!(),
+            }
+        }
+
+        UserMatchCostEntry::sort(&mut blue.players);
+        UserMatchCostEntry::sort(&mut red.players);
+
+        MatchResult::TeamVS {
+            blue,
+            red,
+            mvp_avatar_url,
+        }
+    } else {
+        let mut players: Vec<_> = match_costs
+            .iter()
+            .map(|(user_id, entry)| UserMatchCostEntry {
+                user_id: *user_id,
+                performance_cost: entry.performance_cost,
+                participation_bonus_factor: entry.participation_bonus_factor,
+                mods_bonus_factor: entry.mods_bonus_factor,
+                tiebreaker_bonus: entry.tiebreaker_bonus,
+                match_cost: entry.match_cost(),
+                avg_score: entry.avg_score,
+            })
+            .collect();
+
+        // If it was a two person match, artificially assign them into
+        // teams
+        if let [blue_player, _] = players.as_slice() {
+            let mut teams_win_count = TeamsWinCount::default();
+
+            for game in games.iter() {
+                let mut teams_score = TeamsScore::default();
+
+                for score in game.scores.iter() {
+                    let team = if score.user_id == blue_player.user_id {
+                        MatchTeam::Blue
+                    } else {
+                        MatchTeam::Red
+                    };
+
+                    teams_score.update(team, score.score);
+                }
+
+                teams_win_count.add_win(teams_score.winner());
+            }
+
+            let red_players = players.split_off(1);
+
+            MatchResult::TeamVS {
+                blue: TeamResult {
+                    players,
+                    win_count: teams_win_count.get(MatchTeam::Blue),
+                },
+                red: TeamResult {
+                    players: red_players,
+                    win_count: teams_win_count.get(MatchTeam::Red),
+                },
+                mvp_avatar_url,
+            }
+        } else {
+            UserMatchCostEntry::sort(&mut players);
+
+            MatchResult::HeadToHead {
+                players,
+                mvp_avatar_url,
+            }
+        }
+    }
+}
+
+/// Groups the games by the mod pool of their slot and determines the
+/// winners of each pool.
+///
+/// Games whose map is not part of the mappool are skipped.
+pub fn pool_breakdown(games: &[MatchGame], mappool: &Mappool) -> Vec<PoolBreakdown> {
+    let mut breakdown: Vec<PoolBreakdown> = Vec::new();
+
+    for game in games.iter() {
+        let Some(label) = mappool.game_slot(game) else {
+            continue;
+        };
+
+        let idx = match breakdown
+            .iter()
+            .position(|entry| entry.pool == label.pool())
+        {
+            Some(idx) => idx,
+            None => {
+                breakdown.push(PoolBreakdown::new(label.pool()));
+
+                breakdown.len() - 1
+            }
+        };
+
+        let entry = &mut breakdown[idx];
+        entry.slots.push(label.as_str().into());
+
+        if game.team_type == TeamType::TeamVS {
+            let mut teams_score = TeamsScore::default();
+
+            for score in game.scores.iter() {
+                teams_score.update(score.info.team, score.score);
+            }
+
+            match teams_score.winner() {
+                MatchTeam::Blue => entry.blue_wins += 1,
+                MatchTeam::Red => entry.red_wins += 1,
+                MatchTeam::None => {}
+            }
+        }
+
+        if game.scores.is_empty() {
+            continue;
+        }
+
+        let score_sum = game.scores.iter().fold(0, |sum, score| sum + score.score);
+        let score_avg = score_sum as f32 / game.scores.len() as f32;
+
+        for score in game.scores.iter() {
+            let ratio = score.score as f32 / score_avg;
+
+            match entry
+                .ratios
+                .iter_mut()
+                .find(|(user_id, ..)| *user_id == score.user_id)
+            {
+                Some((_, sum, count)) => {
+                    *sum += ratio;
+                    *count += 1;
+                }
+                None => entry.ratios.push((score.user_id, ratio, 1)),
+            }
+        }
+    }
+
+    breakdown.sort_unstable_by_key(|entry| entry.pool);
+
+    breakdown
+}
+
+pub struct PoolBreakdown {
+    pub pool: ModPool,
+    /// Slots of all games in the pool
+    pub slots: Vec<Box<str>>,
+    pub blue_wins: u32,
+    pub red_wins: u32,
+    /// User id, sum of scores relative to the game average, and amount of
+    /// games for each user
+    ratios: Vec<(u32, f32, u32)>,
+}
+
+impl PoolBreakdown {
+    fn new(pool: ModPool) -> Self {
+        Self {
+            pool,
+            slots: Vec::new(),
+            blue_wins: 0,
+            red_wins: 0,
+            ratios: Vec::new(),
+        }
+    }
+
+    /// The user with the highest average score relative to the game average
+    /// and their average ratio
+    pub fn best(&self) -> Option<(u32, f32)> {
+        self.ratios
+            .iter()
+            .map(|(user_id, sum, count)| (*user_id, sum / *count as f32))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+}
+
+/// Store each user's team.
+///
+/// If a user has played in multiple teams, only the first one is stored.
+#[derive(Default)]
+struct UsersTeam {
+    entries: HashMap<u32, MatchTeam, IntHasher>,
+}
+
+impl UsersTeam {
+    fn update(&mut self, user_id: u32, team: MatchTeam) {
+        self.entries.entry(user_id).or_insert(team);
+    }
+
+    fn get(&self, user_id: u32) -> Option<MatchTeam> {
+        self.entries.get(&user_id).copied()
+    }
+}
+
+/// The score sum of all users in a team.
+#[derive(Default)]
+struct TeamsScore {
+    entries: HashMap<MatchTeam, u32, IntHasher>,
+}
+
+impl TeamsScore {
+    fn update(&mut self, team: MatchTeam, score: u32) {
+        *self.entries.entry(team).or_default() += score;
+    }
+
+    fn winner(&self) -> MatchTeam {
+        self.entries
+            .iter()
+            .max_by_key(|(_, score)| **score)
+            .map_or(MatchTeam::None, |(team, _)| *team)
+    }
+}
+
+/// The amount of games that a team won because it had more total score.
+#[derive(Default)]
+struct TeamsWinCount {
+    entries: HashMap<MatchTeam, u32, IntHasher>,
+}
+
+impl TeamsWinCount {
+    fn add_win(&mut self, team: MatchTeam) {
+        *self.entries.entry(team).or_default() += 1;
+    }
+
+    fn diff(&self) -> u32 {
+        if let (Some(blue), Some(red)) = (
+            self.entries.get(&MatchTeam::Blue),
+            self.entries.get(&MatchTeam::Red),
+        ) {
+            blue.abs_diff(*red)
+        } else {
+            0
+        }
+    }
+
+    fn get(&self, team: MatchTeam) -> u32 {
+        self.entries.get(&team).copied().unwrap_or(0)
+    }
+}
+
+pub struct UserMatchCostEntry {
+    pub user_id: u32,
+    pub performance_cost: f32,
+    pub participation_bonus_factor: f32,
+    pub mods_bonus_factor: f32,
+    pub tiebreaker_bonus: f32,
+    pub match_cost: f32,
+    pub avg_score: u32,
+}
+
+impl UserMatchCostEntry {
+    fn sort(entries: &mut [Self]) {
+        entries.sort_unstable_by(|a, b| b.match_cost.total_cmp(&a.match_cost));
+    }
+}
+
+pub struct TeamResult {
+    pub players: Vec<UserMatchCostEntry>,
+    pub win_count: u32,
+}
+
+impl TeamResult {
+    pub fn new(win_count: u32) -> Self {
+        Self {
+            players: Vec::new(),
+            win_count,
+        }
+    }
+}
+
+pub enum MatchResult {
+    TeamVS {
+        blue: TeamResult,
+        red: TeamResult,
+        mvp_avatar_url: Box<str>,
+    },
+    HeadToHead {
+        players: Vec<UserMatchCostEntry>,
+        mvp_avatar_url: Box<str>,
+    },
+    NoGames {
+        description: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEAM_VS: &str = include_str!("fixtures/team_vs.json");
+    const HEAD_TO_HEAD: &str = include_str!("fixtures/head_to_head.json");
+
+    // Expected values were calculated with a separate implementation of each
+    // formula's definition rather than with the code under test.
+
+    const SCORE_MODS: ModMultipliers = ModMultipliers::new(1.5, 1.05, 1.2, false);
+    const GAME_MODS: ModMultipliers = ModMultipliers::new(1.5, 1.05, 1.2, true);
+
+    fn match_costs(
+        fixture: &str,
+        multipliers: ModMultipliers,
+        formula: MatchCostFormulaKind,
+    ) -> Vec<(u32, f32)> {
+        let mut osu_match: OsuMatch = serde_json::from_str(fixture).unwrap();
+        let games = prepare_games(&mut osu_match, 0, 0, multipliers);
+        let finished = osu_match.end_time.is_some();
+
+        let players = match process_match(&games, finished, &osu_match.users, formula.formula()) {
+            MatchResult::TeamVS { blue, red, .. } => {
+                let mut players = blue.players;
+                players.extend(red.players);
+                UserMatchCostEntry::sort(&mut players);
+
+                players
+            }
+            MatchResult::HeadToHead { players, .. } => players,
+            MatchResult::NoGames { .. } => panic!("no games"),
+        };
+
+        players
+            .into_iter()
+            .map(|entry| (entry.user_id, entry.match_cost))
+            .collect()
+    }
+
+    #[track_caller]
+    fn assert_golden(actual: &[(u32, f32)], expected: &[(u32, f32)]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?}");
+
+        for ((user_id, match_cost), (expected_id, expected_cost)) in actual.iter().zip(expected) {
+            assert_eq!(user_id, expected_id, "{actual:?}");
+            assert!(
+                (match_cost - expected_cost).abs() < 0.001,
+                "user {user_id}: {match_cost} != {expected_cost}"
+            );
+        }
+    }
+
+    #[test]
+    fn team_vs_bathbot() {
+        let expected = [
+            (101, 2.4716),
+            (201, 2.3703),
+            (202, 2.3486),
+            (102, 2.3262),
+            (103, 1.9577),
+            (203, 1.8768),
+        ];
+
+        let actual = match_costs(
+            TEAM_VS,
+            ModMultipliers::NEUTRAL,
+            MatchCostFormulaKind::Bathbot,
+        );
+        assert_golden(&actual, &expected);
+
+        let expected = [
+            (101, 2.5728),
+            (201, 2.3875),
+            (202, 2.3396),
+            (102, 2.3162),
+            (103, 1.9104),
+            (203, 1.8075),
+        ];
+
+        let actual = match_costs(TEAM_VS, SCORE_MODS, MatchCostFormulaKind::Bathbot);
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn team_vs_bathbot_legacy() {
+        let expected = [
+            (101, 2.3426),
+            (201, 2.2673),
+            (202, 2.2412),
+            (102, 2.2172),
+            (103, 1.8706),
+            (203, 1.7933),
+        ];
+
+        let actual = match_costs(
+            TEAM_VS,
+            ModMultipliers::NEUTRAL,
+            MatchCostFormulaKind::BathbotLegacy,
+        );
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn team_vs_flashlight() {
+        let expected = [
+            (101, 1.0787),
+            (201, 1.0197),
+            (202, 1.0004),
+            (102, 0.982),
+            (103, 0.9005),
+            (203, 0.871),
+        ];
+
+        let actual = match_costs(
+            TEAM_VS,
+            ModMultipliers::NEUTRAL,
+            MatchCostFormulaKind::Flashlight,
+        );
+        assert_golden(&actual, &expected);
+
+        let expected = [
+            (101, 1.1752),
+            (201, 1.0451),
+            (202, 1.0024),
+            (102, 0.9834),
+            (103, 0.8842),
+            (203, 0.8359),
+        ];
+
+        let actual = match_costs(TEAM_VS, GAME_MODS, MatchCostFormulaKind::Flashlight);
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn team_vs_z_score() {
+        let expected = [
+            (101, 0.8374),
+            (201, 0.2885),
+            (202, 0.0364),
+            (102, -0.2948),
+            (203, -0.4204),
+            (103, -0.5495),
+        ];
+
+        let actual = match_costs(
+            TEAM_VS,
+            ModMultipliers::NEUTRAL,
+            MatchCostFormulaKind::ZScore,
+        );
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn head_to_head_bathbot() {
+        let expected = [
+            (303, 2.5357),
+            (301, 2.232),
+            (302, 2.1574),
+            (305, 2.0076),
+            (304, 1.6578),
+        ];
+
+        let actual = match_costs(
+            HEAD_TO_HEAD,
+            ModMultipliers::NEUTRAL,
+            MatchCostFormulaKind::Bathbot,
+        );
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn head_to_head_bathbot_legacy() {
+        let expected = [
+            (303, 2.3814),
+            (301, 2.2248),
+            (302, 1.9883),
+            (305, 1.8475),
+            (304, 1.5353),
+        ];
+
+        let actual = match_costs(
+            HEAD_TO_HEAD,
+            SCORE_MODS,
+            MatchCostFormulaKind::BathbotLegacy,
+        );
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn head_to_head_flashlight() {
+        let expected = [
+            (303, 1.1842),
+            (301, 1.0259),
+            (302, 1.0107),
+            (305, 0.8992),
+            (304, 0.67),
+        ];
+
+        let actual = match_costs(
+            HEAD_TO_HEAD,
+            ModMultipliers::NEUTRAL,
+            MatchCostFormulaKind::Flashlight,
+        );
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn head_to_head_z_score() {
+        let expected = [
+            (303, 0.7474),
+            (302, 0.4899),
+            (301, 0.2843),
+            (305, -0.5914),
+            (304, -1.1759),
+        ];
+
+        let actual = match_costs(
+            HEAD_TO_HEAD,
+            ModMultipliers::NEUTRAL,
+            MatchCostFormulaKind::ZScore,
+        );
+        assert_golden(&actual, &expected);
+
+        let expected = [
+            (301, 0.7183),
+            (303, 0.6152),
+            (302, 0.0975),
+            (305, -0.557),
+            (304, -1.0931),
+        ];
+
+        let actual = match_costs(HEAD_TO_HEAD, GAME_MODS, MatchCostFormulaKind::ZScore);
+        assert_golden(&actual, &expected);
+    }
+
+    #[test]
+    fn flashlight_skips_empty_games() {
+        let mut osu_match: OsuMatch = serde_json::from_str(TEAM_VS).unwrap();
+        let mut games = prepare_games(&mut osu_match, 0, 0, ModMultipliers::NEUTRAL);
+        games[0].scores.clear();
+
+        let match_costs = MatchCostFormulaKind::Flashlight
+            .formula()
+            .match_costs(&games, None);
+
+        assert_eq!(match_costs.len(), 6);
+        assert!(
+            match_costs
+                .values()
+                .all(|entry| entry.match_cost().is_finite())
+        );
+    }
+
+    #[test]
+    fn multipliers_game_mods() {
+        // First game of the fixture but played with HD for everyone
+        let mut fixture: serde_json::Value = serde_json::from_str(TEAM_VS).unwrap();
+
+        let game = fixture["events"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find_map(|event| event.get_mut("game"))
+            .unwrap();
+
+        game["mods"] = serde_json::json!(["HD", "NF"]);
+        let fixture = fixture.to_string();
+
+        let first_game_scores = |multipliers| {
+            let mut osu_match: OsuMatch = serde_json::from_str(&fixture).unwrap();
+            let games = prepare_games(&mut osu_match, 0, 0, multipliers);
+
+            games[0]
+                .scores
+                .iter()
+                .map(|score| score.score)
+                .collect::<Vec<_>>()
+        };
+
+        let score_mods_only = [612_345, 580_000, 640_000, 598_765];
+        let with_game_mods = [642_962, 609_000, 672_000, 628_703];
+
+        assert_eq!(first_game_scores(SCORE_MODS), score_mods_only);
+        assert_eq!(first_game_scores(GAME_MODS), with_game_mods);
+    }
+
+    #[test]
+    fn multipliers_display() {
+        let multipliers = ModMultipliers::new(1.5, 1.0, 1.25, false);
+        assert_eq!(multipliers.to_string(), "EZ 1.50 • FL 1.25");
+
+        let multipliers = ModMultipliers::new(1.5, 1.0, 1.25, true);
+        assert_eq!(
+            multipliers.to_string(),
+            "EZ 1.50 • FL 1.25 (incl. map mods)"
+        );
+
+        assert!(ModMultipliers::NEUTRAL.is_neutral());
+    }
+}
@@ -15,6 +15,7 @@ use twilight_http::{api_error::ApiError, error::ErrorType};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::{ChannelType, thread::AutoArchiveDuration};
 
+use super::resolve_mappool;
 use crate::{
     Context,
     core::commands::CommandOrigin,
@@ -44,6 +45,13 @@ pub struct MatchliveAdd<'a> {
     match_url: Cow<'a, str>,
     #[command(desc = "Choose if a new thread should be started")]
     thread: ThreadChannel,
+    #[command(
+        desc = "Specify a mappool name of this server or slot-map pairs",
+        help = "Specify the name of a mappool that was saved via `/mappool save` \
+        or slot-map pairs like `NM1:1234 NM2:5678 HD1:9012`.\n\
+        Games will then be labeled with their slot."
+    )]
+    mappool: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -80,6 +88,7 @@ async fn prefix_matchlive(msg: &Message, mut args: Args<'_>) -> Result<()> {
             let args = MatchliveAdd {
                 match_url: arg.into(),
                 thread: ThreadChannel::Channel,
+                mappool: None,
             };
 
             matchlive(msg.into(), args).await
@@ -136,13 +145,30 @@ fn parse_match_id(match_url: &str) -> Result<u32, &'static str> {
 }
 
 async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()> {
-    let MatchliveAdd { match_url, thread } = args;
+    let MatchliveAdd {
+        match_url,
+        thread,
+        mappool,
+    } = args;
 
     let match_id = match parse_match_id(&match_url) {
         Ok(id) => id,
         Err(content) => return orig.error(content).await,
     };
 
+    let mappool = match mappool {
+        Some(ref mappool) => match resolve_mappool(orig.guild_id(), mappool).await {
+            Ok(Ok(mappool)) => Some(mappool),
+            Ok(Err(content)) => return orig.error(content).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
     let mut channel = orig.channel_id();
 
     if let ThreadChannel::Thread = thread {
@@ -191,7 +217,7 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
         }
     }
 
-    let content: &str = match Context::add_match_track(channel, match_id).await {
+    let content: &str = match Context::add_match_track(channel, match_id, mappool).await {
         MatchTrackResult::Added => match orig {
             CommandOrigin::Message { .. } => return Ok(()),
             CommandOrigin::Interaction { command } => {
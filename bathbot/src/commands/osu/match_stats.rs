@@ -0,0 +1,892 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    IntHasher,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+};
+use eyre::{Report, Result};
+use futures::{StreamExt, stream};
+use rosu_v2::prelude::{MatchGame, MatchTeam, OsuError, TeamType, Username};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::{
+    MatchCostFormulaKind, MatchResult,
+    match_costs::{ModMultipliers, prepare_games, process_match},
+    resolve_mappool, retrieve_previous,
+};
+use crate::{
+    active::{ActiveMessages, impls::MatchStatsPagination},
+    core::Context,
+    manager::{Mappool, ModPool, SlotLabel},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const MAX_MATCHES: usize = 32;
+const CONCURRENT_REQUESTS: usize = 4;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "matchstats",
+    desc = "Aggregate stats across multiple multiplayer matches",
+    help = "Aggregate stats of players and teams across multiple multiplayer matches, \
+    e.g. all matches of a tournament bracket.\n\
+    For each player it shows the average match cost, the amount of played maps, \
+    the win rate per mod pool, and the maps with the best and worst score \
+    relative to the average score of the map.\n\
+    Teams are recognized through match names like `ABC: (Team A) vs (Team B)`."
+)]
+#[bucket(MatchCompare)]
+pub struct MatchStats {
+    #[command(desc = "Specify match urls or match ids, separated by spaces or commas")]
+    matches: String,
+    #[command(
+        min_value = 0,
+        desc = "Specify the amount of warmups to ignore in each match (defaults to 0)"
+    )]
+    warmups: Option<usize>,
+    #[command(desc = "Specify the formula to rate players with")]
+    formula: Option<MatchCostFormulaKind>,
+    #[command(
+        desc = "Specify a mappool name of this server or slot-map pairs",
+        help = "Specify the name of a mappool that was saved via `/mappool save` \
+        or slot-map pairs like `NM1:1234 NM2:5678 HD1:9012`.\n\
+        If specified, maps are assigned to mod pools based on their slot. \
+        Otherwise, the mod pool is guessed through the mods of each game."
+    )]
+    mappool: Option<String>,
+    #[command(desc = "Specify whether the stats should be attached as CSV file")]
+    csv: Option<bool>,
+}
+
+async fn slash_matchstats(mut command: InteractionCommand) -> Result<()> {
+    let args = MatchStats::from_interaction(command.input_data())?;
+
+    matchstats(command, args).await
+}
+
+async fn matchstats(mut command: InteractionCommand, args: MatchStats) -> Result<()> {
+    let MatchStats {
+        matches,
+        warmups,
+        formula,
+        mappool,
+        csv,
+    } = args;
+
+    let mut match_ids = Vec::new();
+
+    for arg in matches
+        .split([' ', ',', '\n'])
+        .filter(|arg| !arg.is_empty())
+    {
+        let Some(match_id) = matcher::get_osu_match_id(arg) else {
+            let content = format!(
+                "Failed to parse `{arg}`.\n\
+                Be sure it's a valid mp url or a match id."
+            );
+            command.error(content).await?;
+
+            return Ok(());
+        };
+
+        if !match_ids.contains(&match_id) {
+            match_ids.push(match_id);
+        }
+    }
+
+    if match_ids.is_empty() {
+        let content = "You must specify at least one match url or match id";
+        command.error(content).await?;
+
+        return Ok(());
+    } else if match_ids.len() > MAX_MATCHES {
+        let content = format!("You can specify at most {MAX_MATCHES} matches");
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let mappool = match mappool {
+        Some(ref mappool) => match resolve_mappool(command.guild_id, mappool).await {
+            Ok(Ok(mappool)) => Some(mappool),
+            Ok(Err(content)) => {
+                command.error(content).await?;
+
+                return Ok(());
+            }
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
+    let warmups = warmups.unwrap_or(0);
+    let formula = formula.unwrap_or_default();
+    let osu = Context::osu();
+
+    let mut osu_matches = Vec::with_capacity(match_ids.len());
+
+    let mut match_futs = stream::iter(match_ids.into_iter().enumerate())
+        .map(|(idx, match_id)| async move {
+            let res = match osu.osu_match(match_id).await {
+                Ok(mut osu_match) => retrieve_previous(&mut osu_match, osu)
+                    .await
+                    .map(|complete| (osu_match, complete)),
+                Err(err) => Err(err),
+            };
+
+            (idx, match_id, res)
+        })
+        .buffer_unordered(CONCURRENT_REQUESTS);
+
+    while let Some((idx, match_id, res)) = match_futs.next().await {
+        match res {
+            Ok((osu_match, complete)) => osu_matches.push((idx, osu_match, complete)),
+            Err(OsuError::NotFound) => {
+                let content = format!("No match with id `{match_id}` was found");
+                command.error(content).await?;
+
+                return Ok(());
+            }
+            Err(OsuError::Response { status, .. }) if status == 401 => {
+                let content =
+                    format!("I can't access match `{match_id}` because it was set as private");
+                command.error(content).await?;
+
+                return Ok(());
+            }
+            Err(err) => {
+                let _ = command.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get match or its history");
+
+                return Err(err);
+            }
+        }
+    }
+
+    // Process matches in the given order regardless of when they arrived
+    osu_matches.sort_unstable_by_key(|(idx, ..)| *idx);
+
+    let mut stats = MatchStatsBuilder::new(mappool.as_ref());
+    let mut truncated = Vec::new();
+
+    for (_, mut osu_match, complete) in osu_matches {
+        if !complete {
+            truncated.push(osu_match.match_id);
+        }
+
+        let games = prepare_games(&mut osu_match, warmups, 0, ModMultipliers::NEUTRAL);
+
+        let result = if games.is_empty() {
+            None
+        } else {
+            Some(process_match(
+                &games,
+                osu_match.end_time.is_some(),
+                &osu_match.users,
+                formula.formula(),
+            ))
+        };
+
+        for (user_id, user) in osu_match.users.drain() {
+            stats.usernames.entry(user_id).or_insert(user.username);
+        }
+
+        stats.push_match(osu_match.match_id, &osu_match.name, &games, result);
+    }
+
+    let MatchStatsBuilder {
+        mappool: _,
+        matches,
+        maps,
+        mut players,
+        teams,
+        usernames,
+    } = stats;
+
+    for player in players.values_mut() {
+        if let Some(name) = usernames.get(&player.user_id) {
+            player.username = name.clone();
+        }
+    }
+
+    let mut players: Vec<_> = players.into_values().collect();
+    players.sort_unstable_by(|a, b| b.avg_match_cost().total_cmp(&a.avg_match_cost()));
+
+    let mut teams: Vec<_> = teams.into_values().collect();
+    teams.sort_unstable_by(|a, b| {
+        b.matches_won
+            .cmp(&a.matches_won)
+            .then_with(|| b.win_rate().total_cmp(&a.win_rate()))
+    });
+
+    let attachment = csv
+        .unwrap_or(false)
+        .then(|| ("match_stats.csv".to_owned(), to_csv(&players, &teams)));
+
+    let content = if truncated.is_empty() {
+        String::new()
+    } else {
+        let mut content = String::from("Only the latest 500 events were considered for match");
+
+        if truncated.len() > 1 {
+            content.push_str("es");
+        }
+
+        for (i, match_id) in truncated.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            let _ = write!(content, "{sep}`{match_id}`");
+        }
+
+        content
+    };
+
+    let pagination = MatchStatsPagination::builder()
+        .players(players.into_boxed_slice())
+        .teams(teams.into_boxed_slice())
+        .matches(matches)
+        .maps(maps)
+        .formula(formula)
+        .content(content.into_boxed_str())
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .attachment(attachment)
+        .begin(&mut command)
+        .await
+}
+
+struct MatchStatsBuilder<'p> {
+    mappool: Option<&'p Mappool>,
+    matches: usize,
+    maps: usize,
+    players: HashMap<u32, MatchStatsPlayer, IntHasher>,
+    teams: HashMap<Box<str>, MatchStatsTeam>,
+    usernames: HashMap<u32, Username, IntHasher>,
+}
+
+impl<'p> MatchStatsBuilder<'p> {
+    fn new(mappool: Option<&'p Mappool>) -> Self {
+        Self {
+            mappool,
+            matches: 0,
+            maps: 0,
+            players: HashMap::default(),
+            teams: HashMap::default(),
+            usernames: HashMap::default(),
+        }
+    }
+
+    fn push_match(
+        &mut self,
+        match_id: u32,
+        name: &str,
+        games: &[MatchGame],
+        result: Option<MatchResult>,
+    ) {
+        self.matches += 1;
+        self.maps += games.len();
+
+        let mut match_costs = HashMap::with_hasher(IntHasher);
+
+        match result {
+            Some(MatchResult::TeamVS { blue, red, .. }) => {
+                for entry in blue.players.iter().chain(red.players.iter()) {
+                    match_costs.insert(entry.user_id, entry.match_cost);
+                }
+            }
+            Some(MatchResult::HeadToHead { players, .. }) => {
+                for entry in players.iter() {
+                    match_costs.insert(entry.user_id, entry.match_cost);
+                }
+            }
+            Some(MatchResult::NoGames { .. }) | None => {}
+        }
+
+        for (&user_id, &match_cost) in match_costs.iter() {
+            self.player(user_id).add_match_cost(match_cost);
+        }
+
+        let team_names = team_names(name);
+        let mut teams_won = [0_u32; 3];
+        let mut users_team = HashMap::with_hasher(IntHasher);
+
+        for game in games.iter() {
+            let pool = self
+                .mappool
+                .and_then(|mappool| mappool.game_slot(game))
+                .map_or_else(|| ModPool::from_game(game), SlotLabel::pool);
+
+            let score_sum: u32 = game.scores.iter().map(|score| score.score).sum();
+            let score_avg = score_sum as f32 / game.scores.len() as f32;
+
+            let is_team_vs = matches!(game.team_type, TeamType::TeamVS | TeamType::TagTeamVS);
+
+            let winner = if is_team_vs {
+                let mut team_scores = [0_u32; 3];
+
+                for score in game.scores.iter() {
+                    team_scores[score.info.team as usize] += score.score;
+                }
+
+                let blue = team_scores[MatchTeam::Blue as usize];
+                let red = team_scores[MatchTeam::Red as usize];
+
+                if blue > red {
+                    Winner::Team(MatchTeam::Blue)
+                } else if red > blue {
+                    Winner::Team(MatchTeam::Red)
+                } else {
+                    Winner::None
+                }
+            } else {
+                game.scores
+                    .iter()
+                    .max_by_key(|score| score.score)
+                    .map_or(Winner::None, |score| Winner::User(score.user_id))
+            };
+
+            if let Winner::Team(team) = winner {
+                teams_won[team as usize] += 1;
+            }
+
+            for score in game.scores.iter() {
+                let won = match winner {
+                    Winner::Team(team) => score.info.team == team,
+                    Winner::User(user_id) => score.user_id == user_id,
+                    Winner::None => false,
+                };
+
+                let team_name = team_names
+                    .filter(|_| is_team_vs)
+                    .and_then(|names| names.get(score.info.team));
+
+                let player = self.player(score.user_id);
+                player.maps += 1;
+                player.pools.add(pool, won);
+
+                if let Some(name) = team_name {
+                    player.team = Some(name.into());
+                    users_team.entry(score.user_id).or_insert(name);
+                }
+
+                let ratio = score.score as f32 / score_avg;
+                player.update_maps(game, match_id, ratio);
+            }
+
+            let Some(names) = team_names.filter(|_| is_team_vs) else {
+                continue;
+            };
+
+            for team in [MatchTeam::Blue, MatchTeam::Red] {
+                if !game.scores.iter().any(|score| score.info.team == team) {
+                    continue;
+                }
+
+                let Some(name) = names.get(team) else {
+                    continue;
+                };
+
+                let won = matches!(winner, Winner::Team(winner) if winner == team);
+                let stats = self.team(name);
+                stats.maps += 1;
+                stats.pools.add(pool, won);
+            }
+        }
+
+        let Some(names) = team_names else { return };
+
+        if teams_won == [0; 3] {
+            return;
+        }
+
+        let blue_won = teams_won[MatchTeam::Blue as usize];
+        let red_won = teams_won[MatchTeam::Red as usize];
+
+        for (team, won) in [
+            (MatchTeam::Blue, blue_won > red_won),
+            (MatchTeam::Red, red_won > blue_won),
+        ] {
+            let Some(name) = names.get(team) else {
+                continue;
+            };
+            let stats = self.team(name);
+            stats.matches += 1;
+            stats.matches_won += won as u32;
+        }
+
+        // Players' match costs count towards the team they played for
+        for (user_id, name) in users_team {
+            let Some(match_cost) = match_costs.get(&user_id) else {
+                continue;
+            };
+
+            if let Some(stats) = self.teams.get_mut(name) {
+                stats.match_cost_sum += match_cost;
+                stats.match_cost_count += 1;
+            }
+        }
+    }
+
+    fn player(&mut self, user_id: u32) -> &mut MatchStatsPlayer {
+        self.players
+            .entry(user_id)
+            .or_insert_with(|| MatchStatsPlayer::new(user_id))
+    }
+
+    fn team(&mut self, name: &str) -> &mut MatchStatsTeam {
+        if !self.teams.contains_key(name) {
+            self.teams.insert(name.into(), MatchStatsTeam::new(name));
+        }
+
+        self.teams.get_mut(name).expect("just inserted")
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Winner {
+    Team(MatchTeam),
+    User(u32),
+    None,
+}
+
+/// Team names of a match called e.g. `ABC: (Team A) vs (Team B)`
+#[derive(Copy, Clone)]
+struct TeamNames<'n> {
+    red: &'n str,
+    blue: &'n str,
+}
+
+impl<'n> TeamNames<'n> {
+    fn get(self, team: MatchTeam) -> Option<&'n str> {
+        match team {
+            MatchTeam::Blue => Some(self.blue),
+            MatchTeam::Red => Some(self.red),
+            MatchTeam::None => None,
+        }
+    }
+}
+
+/// The first team of a match name is the red team, the second one is blue.
+fn team_names(name: &str) -> Option<TeamNames<'_>> {
+    let (_, rest) = name.split_once('(')?;
+    let (red, rest) = rest.split_once(')')?;
+
+    let rest = rest.trim_start();
+    let rest = rest
+        .strip_prefix("vs")
+        .or_else(|| rest.strip_prefix("VS"))?
+        .trim_start()
+        .strip_prefix('(')?;
+
+    let (blue, _) = rest.rsplit_once(')')?;
+    let red = red.trim();
+    let blue = blue.trim();
+
+    if red.is_empty() || blue.is_empty() {
+        return None;
+    }
+
+    Some(TeamNames { red, blue })
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct PoolRecord {
+    pub played: u32,
+    pub won: u32,
+}
+
+/// Played and won maps for each [`ModPool`]
+#[derive(Default)]
+pub struct PoolRecords([PoolRecord; ModPool::ALL.len()]);
+
+impl PoolRecords {
+    fn add(&mut self, pool: ModPool, won: bool) {
+        let record = &mut self.0[pool as usize];
+        record.played += 1;
+        record.won += won as u32;
+    }
+
+    pub fn get(&self, pool: ModPool) -> PoolRecord {
+        self.0[pool as usize]
+    }
+
+    pub fn won(&self) -> u32 {
+        self.0.iter().map(|record| record.won).sum()
+    }
+}
+
+impl Display for PoolRecords {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut iter = ModPool::ALL
+            .into_iter()
+            .map(|pool| (pool, self.get(pool)))
+            .filter(|(_, record)| record.played > 0);
+
+        if let Some((pool, record)) = iter.next() {
+            write!(
+                f,
+                "{} {}/{}",
+                pool.abbreviation(),
+                record.won,
+                record.played
+            )?;
+
+            for (pool, record) in iter {
+                write!(
+                    f,
+                    " • {} {}/{}",
+                    pool.abbreviation(),
+                    record.won,
+                    record.played
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A map on which a player performed notably well or badly
+pub struct MapPerformance {
+    pub map_id: u32,
+    pub map: Box<str>,
+    pub match_id: u32,
+    /// Score relative to the average score of the game
+    pub ratio: f32,
+}
+
+impl MapPerformance {
+    fn new(game: &MatchGame, match_id: u32, ratio: f32) -> Self {
+        let name = match game.map {
+            Some(ref map) => {
+                let mut name = match map.mapset {
+                    Some(ref mapset) => format!("{} - {}", mapset.artist, mapset.title),
+                    None => "<unknown title>".to_owned(),
+                };
+
+                let _ = write!(name, " [{}]", map.version);
+
+                name
+            }
+            None => "<unknown map>".to_owned(),
+        };
+
+        Self {
+            map_id: game.map_id,
+            map: name.into_boxed_str(),
+            match_id,
+            ratio,
+        }
+    }
+}
+
+pub struct MatchStatsPlayer {
+    pub user_id: u32,
+    pub username: Username,
+    pub team: Option<Box<str>>,
+    pub matches: u32,
+    pub maps: u32,
+    pub pools: PoolRecords,
+    pub best: Option<MapPerformance>,
+    pub worst: Option<MapPerformance>,
+    match_cost_sum: f32,
+}
+
+impl MatchStatsPlayer {
+    fn new(user_id: u32) -> Self {
+        Self {
+            user_id,
+            username: format!("User id {user_id}").into(),
+            team: None,
+            matches: 0,
+            maps: 0,
+            pools: PoolRecords::default(),
+            best: None,
+            worst: None,
+            match_cost_sum: 0.0,
+        }
+    }
+
+    fn add_match_cost(&mut self, match_cost: f32) {
+        self.matches += 1;
+        self.match_cost_sum += match_cost;
+    }
+
+    fn update_maps(&mut self, game: &MatchGame, match_id: u32, ratio: f32) {
+        if self.best.as_ref().is_none_or(|best| ratio > best.ratio) {
+            self.best = Some(MapPerformance::new(game, match_id, ratio));
+        }
+
+        if self.worst.as_ref().is_none_or(|worst| ratio < worst.ratio) {
+            self.worst = Some(MapPerformance::new(game, match_id, ratio));
+        }
+    }
+
+    pub fn avg_match_cost(&self) -> f32 {
+        if self.matches == 0 {
+            0.0
+        } else {
+            self.match_cost_sum / self.matches as f32
+        }
+    }
+}
+
+pub struct MatchStatsTeam {
+    pub name: Box<str>,
+    pub matches: u32,
+    pub matches_won: u32,
+    pub maps: u32,
+    pub pools: PoolRecords,
+    match_cost_sum: f32,
+    match_cost_count: u32,
+}
+
+impl MatchStatsTeam {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            matches: 0,
+            matches_won: 0,
+            maps: 0,
+            pools: PoolRecords::default(),
+            match_cost_sum: 0.0,
+            match_cost_count: 0,
+        }
+    }
+
+    /// Average match cost of all players for each match they played
+    pub fn avg_match_cost(&self) -> f32 {
+        if self.match_cost_count == 0 {
+            0.0
+        } else {
+            self.match_cost_sum / self.match_cost_count as f32
+        }
+    }
+
+    pub fn win_rate(&self) -> f32 {
+        if self.maps == 0 {
+            0.0
+        } else {
+            self.pools.won() as f32 / self.maps as f32
+        }
+    }
+}
+
+fn to_csv(players: &[MatchStatsPlayer], teams: &[MatchStatsTeam]) -> Vec<u8> {
+    let mut csv =
+        String::from("kind,id,name,team,matches,matches_won,maps,maps_won,avg_match_cost");
+
+    for pool in ModPool::ALL {
+        let _ = write!(csv, ",{pool}_played,{pool}_won", pool = pool.abbreviation());
+    }
+
+    csv.push_str(",best_map_id,best_map,best_ratio,worst_map_id,worst_map,worst_ratio\n");
+
+    for player in players {
+        let _ = write!(
+            csv,
+            "player,{id},{name},{team},{matches},,{maps},{won},{mc:.4}",
+            id = player.user_id,
+            name = CsvField(&player.username),
+            team = CsvField(player.team.as_deref().unwrap_or_default()),
+            matches = player.matches,
+            maps = player.maps,
+            won = player.pools.won(),
+            mc = player.avg_match_cost(),
+        );
+
+        write_pools(&mut csv, &player.pools);
+
+        for map in [&player.best, &player.worst] {
+            match map {
+                Some(map) => {
+                    let _ = write!(
+                        csv,
+                        ",{},{},{:.4}",
+                        map.map_id,
+                        CsvField(&map.map),
+                        map.ratio
+                    );
+                }
+                None => csv.push_str(",,,"),
+            }
+        }
+
+        csv.push('\n');
+    }
+
+    for team in teams {
+        let _ = write!(
+            csv,
+            "team,,{name},{name},{matches},{matches_won},{maps},{won},{mc:.4}",
+            name = CsvField(&team.name),
+            matches = team.matches,
+            matches_won = team.matches_won,
+            maps = team.maps,
+            won = team.pools.won(),
+            mc = team.avg_match_cost(),
+        );
+
+        write_pools(&mut csv, &team.pools);
+        csv.push_str(",,,,,,\n");
+    }
+
+    csv.into_bytes()
+}
+
+fn write_pools(csv: &mut String, pools: &PoolRecords) {
+    for pool in ModPool::ALL {
+        let record = pools.get(pool);
+        let _ = write!(csv, ",{},{}", record.played, record.won);
+    }
+}
+
+/// Quotes the field if necessary
+struct CsvField<'s>(&'s str);
+
+impl Display for CsvField<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.0.contains([',', '"', '\n']) {
+            return f.write_str(self.0);
+        }
+
+        f.write_str("\"")?;
+
+        for (i, part) in self.0.split('"').enumerate() {
+            if i > 0 {
+                f.write_str("\"\"")?;
+            }
+
+            f.write_str(part)?;
+        }
+
+        f.write_str("\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::OsuMatch;
+
+    use super::*;
+
+    const TEAM_VS: &str = include_str!("match_costs/fixtures/team_vs.json");
+    const HEAD_TO_HEAD: &str = include_str!("match_costs/fixtures/head_to_head.json");
+
+    fn push_fixture(stats: &mut MatchStatsBuilder<'_>, fixture: &str) {
+        let mut osu_match: OsuMatch = serde_json::from_str(fixture).unwrap();
+        let games = prepare_games(&mut osu_match, 0, 0, ModMultipliers::NEUTRAL);
+        let formula = MatchCostFormulaKind::default().formula();
+        let result = process_match(&games, true, &osu_match.users, formula);
+
+        stats.push_match(osu_match.match_id, &osu_match.name, &games, Some(result));
+    }
+
+    #[track_caller]
+    fn assert_pool(records: &PoolRecords, pool: ModPool, played: u32, won: u32) {
+        let record = records.get(pool);
+        assert_eq!((record.played, record.won), (played, won), "{pool:?}");
+    }
+
+    #[track_caller]
+    fn assert_map(map: Option<&MapPerformance>, map_id: u32, match_id: u32, ratio: f32) {
+        let map = map.unwrap();
+        assert_eq!(map.map_id, map_id);
+        assert_eq!(map.match_id, match_id);
+        assert!(
+            (map.ratio - ratio).abs() < 0.0001,
+            "{} != {ratio}",
+            map.ratio
+        );
+    }
+
+    #[test]
+    fn team_vs_stats() {
+        let mut stats = MatchStatsBuilder::new(None);
+        push_fixture(&mut stats, TEAM_VS);
+
+        assert_eq!(stats.matches, 1);
+        assert_eq!(stats.maps, 7);
+
+        let player = &stats.players[&101];
+        assert_eq!(player.team.as_deref(), Some("United States"));
+        assert_eq!(player.matches, 1);
+        assert_eq!(player.maps, 5);
+        assert_pool(&player.pools, ModPool::NoMod, 1, 0);
+        assert_pool(&player.pools, ModPool::FreeMod, 3, 3);
+        assert_pool(&player.pools, ModPool::DoubleTime, 1, 1);
+        assert_map(player.best.as_ref(), 1005, 111000001, 1.19728);
+        assert_map(player.worst.as_ref(), 1001, 111000001, 1.00751);
+
+        // The first team in the match name is red
+        let blue = &stats.teams["United States"];
+        assert_eq!((blue.matches, blue.matches_won, blue.maps), (1, 1, 7));
+        assert_pool(&blue.pools, ModPool::NoMod, 1, 0);
+        assert_pool(&blue.pools, ModPool::FreeMod, 5, 3);
+        assert_pool(&blue.pools, ModPool::DoubleTime, 1, 1);
+
+        let red = &stats.teams["Australia"];
+        assert_eq!((red.matches, red.matches_won, red.maps), (1, 0, 7));
+        assert_pool(&red.pools, ModPool::NoMod, 1, 1);
+        assert_pool(&red.pools, ModPool::FreeMod, 5, 2);
+        assert_pool(&red.pools, ModPool::DoubleTime, 1, 0);
+    }
+
+    #[test]
+    fn head_to_head_stats() {
+        let mut stats = MatchStatsBuilder::new(None);
+        push_fixture(&mut stats, TEAM_VS);
+        push_fixture(&mut stats, HEAD_TO_HEAD);
+
+        assert_eq!(stats.matches, 2);
+        assert_eq!(stats.maps, 12);
+
+        // Team names of head-to-head matches don't count
+        assert_eq!(stats.teams.len(), 2);
+
+        let player = &stats.players[&303];
+        assert_eq!(player.team, None);
+        assert_eq!(player.matches, 1);
+        assert_eq!(player.maps, 5);
+        assert_pool(&player.pools, ModPool::NoMod, 1, 1);
+        assert_pool(&player.pools, ModPool::FreeMod, 3, 1);
+        assert_pool(&player.pools, ModPool::DoubleTime, 1, 1);
+
+        // Scores of zero are removed before calculating the game's average
+        assert_map(player.best.as_ref(), 2001, 111000002, 1.28049);
+        assert_map(player.worst.as_ref(), 2002, 111000002, 0.97642);
+    }
+
+    #[test]
+    fn parse_team_names() {
+        let names = team_names("OWC 2024: (Australia) vs (United States)").unwrap();
+        assert_eq!(names.red, "Australia");
+        assert_eq!(names.blue, "United States");
+
+        let names = team_names("ABC: (Red) VS ( Blue )").unwrap();
+        assert_eq!(names.red, "Red");
+        assert_eq!(names.blue, "Blue");
+
+        assert!(team_names("ABC: Australia vs United States").is_none());
+        assert!(team_names("ABC: (Australia) (United States)").is_none());
+        assert!(team_names("ABC: () vs (United States)").is_none());
+    }
+
+    #[test]
+    fn csv_fields() {
+        assert_eq!(CsvField("plain").to_string(), "plain");
+        assert_eq!(CsvField("a, b").to_string(), "\"a, b\"");
+        assert_eq!(CsvField("say \"hi\"").to_string(), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -71,8 +71,9 @@ use twilight_model::id::{Id, marker::UserMarker};
 
 pub use self::{
     badges::*, claim_name::*, compare::*, fix::*, graphs::*, leaderboard::*, map::*, map_search::*,
-    match_compare::*, match_costs::*, medals::*, nochoke::*, osustats::*, profile::*, recent::*,
-    render::*, simulate::*, snipe::*, top::*, whatif::*,
+    mappool::*, match_compare::*, match_costs::*, match_stats::*, medals::*, nochoke::*,
+    osustats::*, pp_version::*, profile::*, recent::*, render::*, simulate::*, snipe::*, top::*,
+    whatif::*,
 };
 use crate::{
     Context,
@@ -85,6 +86,7 @@ mod badges;
 mod bookmarks;
 mod bws;
 mod cards;
+mod challenge;
 mod claim_name;
 mod compare;
 mod daily_challenge;
@@ -96,8 +98,10 @@ mod link;
 mod map;
 mod map_search;
 mod mapper;
+mod mappool;
 mod match_compare;
 mod match_costs;
+mod match_stats;
 mod medals;
 mod most_played;
 mod nochoke;
@@ -105,6 +109,7 @@ mod osekai;
 mod osustats;
 mod pinned;
 mod pp;
+mod pp_version;
 mod profile;
 mod rank;
 mod ranking;
@@ -112,6 +117,7 @@ mod ratios;
 mod recent;
 pub(crate) mod relax;
 mod render;
+mod replay;
 mod serverleaderboard;
 mod simulate;
 mod snipe;
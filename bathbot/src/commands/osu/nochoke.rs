@@ -13,11 +13,16 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::{require_link, user_not_found};
+use super::{
+    PpVersion, VersionedAttrs, parse_pp_version, require_link, user_not_found, versioned_attrs,
+};
 use crate::{
     Context,
-    active::{ActiveMessages, impls::NoChokePagination},
-    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    active::{
+        ActiveMessages,
+        impls::{NoChokePagination, TopOldVersion},
+    },
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP, PP_VERSION_DESC, PP_VERSION_HELP},
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
         OsuMap,
@@ -58,6 +63,8 @@ pub struct Nochoke<'a> {
     filter: Option<NochokeFilter>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = PP_VERSION_DESC, help = PP_VERSION_HELP)]
+    pp_version: Option<PpVersion>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption)]
@@ -127,6 +134,7 @@ impl<'m> Nochoke<'m> {
             version: None,
             filter: None,
             discord,
+            pp_version: None,
         }
     }
 }
@@ -217,6 +225,11 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
         },
     };
 
+    let pp_version = match parse_pp_version(mode, args.pp_version) {
+        Ok(pp_version) => pp_version,
+        Err(content) => return orig.error(content).await,
+    };
+
     let Nochoke {
         miss_limit,
         version,
@@ -247,7 +260,23 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
 
     let version = version.unwrap_or_default();
 
-    let mut entries = match process_scores(scores, miss_limit, version).await {
+    // Calculate bonus pp
+    let actual_pp: f32 = scores
+        .iter()
+        .filter_map(|score| score.weight)
+        .map(|weight| weight.pp)
+        .sum();
+
+    let user_pp = user
+        .statistics
+        .as_ref()
+        .expect("missing stats")
+        .pp
+        .to_native();
+
+    let bonus_pp = user_pp - actual_pp;
+
+    let mut entries = match process_scores(scores, miss_limit, version, pp_version).await {
         Ok(entries) => entries,
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
@@ -256,20 +285,23 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
         }
     };
 
-    // Calculate bonus pp
-    let actual_pp: f32 = entries
-        .iter()
-        .map(|entry| entry.original_score.pp)
-        .zip(0..)
-        .fold(0.0, |sum, (pp, i)| sum + pp * 0.95_f32.powi(i));
-
-    let bonus_pp = user
-        .statistics
-        .as_ref()
-        .expect("missing stats")
-        .pp
-        .to_native()
-        - actual_pp;
+    // Total pp with the original scores but recalculated with the pp version
+    let pre_pp = if pp_version.is_some() {
+        let mut pps: Vec<_> = entries
+            .iter()
+            .map(|entry| entry.original_score.pp)
+            .collect();
+        pps.sort_unstable_by(|a, b| b.total_cmp(a));
+
+        let pre_pp = pps
+            .into_iter()
+            .zip(0..)
+            .fold(0.0, |sum, (pp, i)| sum + pp * 0.95_f32.powi(i));
+
+        (100.0 * (pre_pp + bonus_pp)).round() / 100.0
+    } else {
+        user_pp
+    };
 
     // Sort by unchoked pp
     entries.sort_unstable_by(|a, b| b.unchoked_pp().total_cmp(&a.unchoked_pp()));
@@ -324,8 +356,10 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
     let pagination = NoChokePagination::builder()
         .user(user)
         .entries(entries.into_boxed_slice())
+        .pre_pp(pre_pp)
         .unchoked_pp(unchoked_pp)
         .rank(rank)
+        .pp_version(pp_version)
         .content(content.into_boxed_str())
         .msg_owner(owner)
         .build();
@@ -406,6 +440,7 @@ async fn process_scores(
     scores: Vec<Score>,
     miss_limit: Option<u32>,
     version: NochokeVersion,
+    pp_version: Option<TopOldVersion>,
 ) -> Result<Vec<NochokeEntry>> {
     let mut entries = Vec::with_capacity(scores.len());
 
@@ -447,10 +482,10 @@ async fn process_scores(
             max_pp = pp;
         }
 
-        let score = ScoreSlim::new(score, pp);
+        let mut score = ScoreSlim::new(score, pp);
         let too_many_misses = score.statistics.miss > miss_limit;
 
-        let unchoked = match version {
+        let mut unchoked = match version {
             NochokeVersion::Unchoke if too_many_misses => None,
             // Skip unchoking because it has too many misses or because its a convert
             NochokeVersion::Unchoke => IfFc::new(&score, &map)
@@ -460,6 +495,37 @@ async fn process_scores(
             NochokeVersion::Perfect => perfect_score(&score, &map).await,
         };
 
+        if let Some(pp_version) = pp_version {
+            let VersionedAttrs {
+                pp,
+                max_pp: version_max_pp,
+                stars: version_stars,
+                max_combo: version_max_combo,
+            } = versioned_attrs(pp_version, &score, &map).await;
+
+            score.pp = pp;
+            max_pp = version_max_pp;
+            stars = version_stars;
+            max_combo = version_max_combo;
+
+            if let Some(ref mut unchoked) = unchoked {
+                let accuracy = match unchoked.max_statistics {
+                    Some(ref max_stats) => unchoked.statistics.accuracy(score.mode, max_stats),
+                    None => unchoked.statistics.legacy_accuracy(score.mode),
+                };
+
+                let unchoked_score = ScoreSlim {
+                    accuracy,
+                    statistics: unchoked.statistics.clone(),
+                    max_combo,
+                    grade: unchoked.grade,
+                    ..score.clone()
+                };
+
+                unchoked.pp = versioned_attrs(pp_version, &unchoked_score, &map).await.pp;
+            }
+        }
+
         let entry = NochokeEntry {
             original_idx: i,
             original_score: score,
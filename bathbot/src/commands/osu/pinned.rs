@@ -321,7 +321,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
-        && Context::ordr_available();
+        && Context::render_available();
 
     let origin = MessageOrigin::new(guild_id, orig.channel_id());
 
@@ -392,7 +392,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
                     let fut = map_strains_graph(
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
-                        entry.map.cover(),
+                        Some(entry.map.cover()),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
                     );
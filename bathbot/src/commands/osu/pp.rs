@@ -8,10 +8,10 @@ use rosu_v2::prelude::OsuError;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::user_not_found;
+use super::{PpVersion, TopPp, parse_pp_version, user_not_found};
 use crate::{
     Context,
-    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP, PP_VERSION_DESC, PP_VERSION_HELP},
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{EmbedData, PpMissingEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -50,6 +50,8 @@ pub struct Pp<'a> {
     amount: Option<u8>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = PP_VERSION_DESC, help = PP_VERSION_HELP)]
+    pp_version: Option<PpVersion>,
 }
 
 impl<'m> Pp<'m> {
@@ -75,6 +77,7 @@ impl<'m> Pp<'m> {
             each: None,
             amount: None,
             discord,
+            pp_version: None,
         })
     }
 }
@@ -176,7 +179,11 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
     let (user_id, mode) = user_id_mode!(orig, args);
 
     let Pp {
-        pp, each, amount, ..
+        pp,
+        each,
+        amount,
+        pp_version,
+        ..
     } = args;
 
     let Some(pp) = PpValue::parse(pp.as_ref()) else {
@@ -193,6 +200,11 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
         return orig.error("Number too large").await;
     }
 
+    let pp_version = match parse_pp_version(mode, pp_version) {
+        Ok(pp_version) => pp_version,
+        Err(content) => return orig.error(content).await,
+    };
+
     // Retrieve the user and their top scores
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
     let scores_fut = Context::osu_scores()
@@ -214,12 +226,23 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
         }
     };
 
+    let user_pp = user
+        .statistics
+        .as_ref()
+        .map_or(0.0, |stats| stats.pp.to_native());
+
+    let top = match TopPp::new(user_pp, scores, pp_version).await {
+        Ok(top) => top,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to recalculate top scores"));
+        }
+    };
+
     let target_pp = match pp {
         PpValue::Raw(value) => value,
-        PpValue::Delta(value) => user
-            .statistics
-            .as_ref()
-            .map_or(value, |stats| stats.pp + value),
+        PpValue::Delta(value) => top.total + value,
     };
 
     let rank = match Context::approx().rank(target_pp, mode).await {
@@ -232,7 +255,7 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
     };
 
     // Accumulate all necessary data
-    let embed_data = PpMissingEmbed::new(&user, &scores, target_pp as f64, rank, each, amount);
+    let embed_data = PpMissingEmbed::new(&user, &top, target_pp as f64, rank, each, amount);
 
     // Creating the embed
     let embed = embed_data.build();
@@ -0,0 +1,603 @@
+use bathbot_model::ScoreSlim;
+use bathbot_util::osu::{ExtractablePp, PpListUtil};
+use eyre::Result;
+use rosu_pp::any::DifficultyAttributes;
+use rosu_pp_older::*;
+use rosu_v2::prelude::{GameMode, Score};
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+use super::{TopOldCatchVersion, TopOldManiaVersion, TopOldOsuVersion, TopOldTaikoVersion};
+use crate::{active::impls::TopOldVersion, core::Context, manager::OsuMap};
+
+macro_rules! pp_std {
+    ($version:ident, $rosu_map:ident, $score:ident ) => {
+        pp_std!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.bits(),
+            $score.mods.bits(),
+        )
+    };
+
+    ($version:ident, $rosu_map:ident, $score:ident, lazer ) => {
+        pp_std!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.clone(),
+            $score.mods.clone(),
+            lazer: $score.set_on_lazer,
+            large_tick_hit: $score.statistics.large_tick_hit,
+            small_tick_hit: $score.statistics.small_tick_hit,
+            slider_end_hit: $score.statistics.slider_tail_hit,
+        )
+    };
+
+    (
+        $version:ident,
+        $rosu_map:ident,
+        $score:ident,
+        $max_mods:expr,
+        $curr_mods:expr,
+        $(
+            lazer: $lazer:expr,
+            large_tick_hit: $large_tick_hit:expr,
+            small_tick_hit: $small_tick_hit:expr,
+            slider_end_hit: $slider_end_hit:expr,
+        )?
+    ) => {{
+        let max_pp_res = $version::OsuPP::new($rosu_map)
+            .mods($max_mods)
+            $( .lazer($lazer) )?
+            .calculate();
+
+        let max_pp = max_pp_res.pp as f32;
+        let stars = max_pp_res.difficulty.stars as f32;
+
+        let attrs = $version::OsuPP::new($rosu_map)
+            .mods($curr_mods)
+            .attributes(max_pp_res.difficulty)
+            .n300($score.statistics.great)
+            .n100($score.statistics.ok)
+            .n50($score.statistics.meh)
+            .misses($score.statistics.miss)
+            .combo($score.max_combo)
+            $(
+                .lazer($lazer)
+                .large_tick_hits($large_tick_hit)
+                .small_tick_hits($small_tick_hit)
+                .slider_end_hits($slider_end_hit)
+            )?
+            .calculate();
+
+        let pp = attrs.pp as f32;
+        let max_combo = attrs.max_combo() as u32;
+
+        (pp, max_pp, stars, max_combo)
+    }};
+}
+
+macro_rules! pp_tko {
+    ($version:ident, $rosu_map:ident, $score:ident ) => {
+        pp_tko!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.bits(),
+            $score.mods.bits(),
+        )
+    };
+
+    ($version:ident, $rosu_map:ident, $score:ident, lazer) => {
+        pp_tko!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.clone(),
+            $score.mods.clone(),
+        )
+    };
+
+    (
+        $version:ident,
+        $rosu_map:ident,
+        $score:ident,
+        $max_mods:expr,
+        $curr_mods:expr,
+    ) => {{
+        let max_pp_res = $version::TaikoPP::new($rosu_map)
+            .mods($max_mods)
+            .calculate();
+
+        let max_pp = max_pp_res.pp as f32;
+        let stars = max_pp_res.difficulty.stars as f32;
+
+        let attrs = $version::TaikoPP::new($rosu_map)
+            .mods($curr_mods)
+            .attributes(max_pp_res.difficulty)
+            .n300($score.statistics.great)
+            .n100($score.statistics.ok)
+            .misses($score.statistics.miss)
+            .combo($score.max_combo)
+            .calculate();
+
+        let pp = attrs.pp as f32;
+        let max_combo = attrs.max_combo();
+
+        (pp, max_pp, stars, max_combo)
+    }};
+}
+
+macro_rules! pp_ctb {
+    ($version:ident, $rosu_map:ident, $score:ident ) => {
+        pp_ctb!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.bits(),
+            $score.mods.bits(),
+        )
+    };
+
+    ($version:ident, $rosu_map:ident, $score:ident, lazer) => {
+        pp_ctb!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.clone(),
+            $score.mods.clone(),
+        )
+    };
+
+    (
+        $version:ident,
+        $rosu_map:ident,
+        $score:ident,
+        $max_mods:expr,
+        $curr_mods:expr,
+    ) => {{
+        let max_pp_res = $version::FruitsPP::new($rosu_map)
+            .mods($max_mods)
+            .calculate();
+
+        let max_pp = max_pp_res.pp as f32;
+        let stars = max_pp_res.difficulty.stars as f32;
+        let stats = $score.statistics.as_legacy(GameMode::Catch);
+
+        let attrs = $version::FruitsPP::new($rosu_map)
+            .mods($curr_mods)
+            .attributes(max_pp_res.difficulty)
+            .fruits(stats.count_300)
+            .droplets(stats.count_100)
+            .tiny_droplets(stats.count_50)
+            .tiny_droplet_misses(stats.count_katu)
+            .misses(stats.count_miss)
+            .combo($score.max_combo)
+            .calculate();
+
+        let pp = attrs.pp as f32;
+        let max_combo = attrs.max_combo();
+
+        (pp, max_pp, stars, max_combo)
+    }};
+}
+
+macro_rules! pp_mna {
+    ($version:ident, $rosu_map:ident, $score:ident ) => {
+        pp_mna!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.bits(),
+            $score.mods.bits(),
+        )
+    };
+
+    ($version:ident, $rosu_map:ident, $score:ident, lazer) => {
+        pp_mna!(
+            $version,
+            $rosu_map,
+            $score,
+            $score.mods.clone(),
+            $score.mods.clone(),
+            lazer: $score.set_on_lazer,
+        )
+    };
+
+    (
+        $version:ident,
+        $rosu_map:ident,
+        $score:ident,
+        $max_mods:expr,
+        $curr_mods:expr,
+        $( lazer: $lazer:expr, )?
+    ) => {{
+        let max_pp_res = $version::ManiaPP::new($rosu_map)
+            .mods($max_mods)
+            $( .lazer($lazer) )?
+            .calculate();
+
+        let max_pp = max_pp_res.pp as f32;
+        let stars = max_pp_res.difficulty.stars as f32;
+
+        let attrs = $version::ManiaPP::new($rosu_map)
+            .mods($curr_mods)
+            .attributes(max_pp_res.difficulty)
+            .n320($score.statistics.perfect)
+            .n300($score.statistics.great)
+            .n200($score.statistics.good)
+            .n100($score.statistics.ok)
+            .n50($score.statistics.meh)
+            .misses($score.statistics.miss)
+            $( .lazer($lazer) )?
+            .calculate();
+
+        let pp = attrs.pp as f32;
+        let max_combo = attrs.max_combo();
+
+        (pp, max_pp, stars, max_combo)
+    }};
+}
+
+/// Previous pp versions that can be chosen for a command.
+///
+/// The current pp version of each mode is omitted since it's used by default.
+#[derive(Copy, Clone, CommandOption, CreateOption, Debug, PartialEq)]
+pub enum PpVersion {
+    #[option(name = "osu! May 2014 - July 2014", value = "osu_may14_july14")]
+    OsuMay14July14,
+    #[option(
+        name = "osu! July 2014 - February 2015",
+        value = "osu_july14_february15"
+    )]
+    OsuJuly14February15,
+    #[option(
+        name = "osu! February 2015 - April 2015",
+        value = "osu_february15_april15"
+    )]
+    OsuFebruary15April15,
+    #[option(name = "osu! April 2015 - May 2018", value = "osu_april15_may18")]
+    OsuApril15May18,
+    #[option(name = "osu! May 2018 - February 2019", value = "osu_may18_february19")]
+    OsuMay18February19,
+    #[option(
+        name = "osu! February 2019 - January 2021",
+        value = "osu_february19_january21"
+    )]
+    OsuFebruary19January21,
+    #[option(name = "osu! January 2021 - July 2021", value = "osu_january21_july21")]
+    OsuJanuary21July21,
+    #[option(
+        name = "osu! July 2021 - November 2021",
+        value = "osu_july21_november21"
+    )]
+    OsuJuly21November21,
+    #[option(
+        name = "osu! November 2021 - September 2022",
+        value = "osu_november21_september22"
+    )]
+    OsuNovember21September22,
+    #[option(
+        name = "osu! September 2022 - October 2024",
+        value = "osu_september22_october24"
+    )]
+    OsuSeptember22October24,
+    #[option(
+        name = "osu! October 2024 - March 2025",
+        value = "osu_october24_march25"
+    )]
+    OsuOctober24March25,
+    #[option(
+        name = "osu!taiko March 2014 - September 2020",
+        value = "taiko_march14_september20"
+    )]
+    TaikoMarch14September20,
+    #[option(
+        name = "osu!taiko September 2020 - September 2022",
+        value = "taiko_september20_september22"
+    )]
+    TaikoSeptember20September22,
+    #[option(
+        name = "osu!taiko September 2022 - October 2024",
+        value = "taiko_september22_october24"
+    )]
+    TaikoSeptember22October24,
+    #[option(
+        name = "osu!taiko October 2024 - March 2025",
+        value = "taiko_october24_march25"
+    )]
+    TaikoOctober24March25,
+    #[option(name = "osu!ctb March 2014 - May 2020", value = "ctb_march14_may20")]
+    CatchMarch14May20,
+    #[option(
+        name = "osu!ctb May 2020 - October 2024",
+        value = "ctb_may20_october24"
+    )]
+    CatchMay20October24,
+    #[option(
+        name = "osu!mania March 2014 - May 2018",
+        value = "mania_march14_may18"
+    )]
+    ManiaMarch14May18,
+    #[option(
+        name = "osu!mania May 2018 - October 2022",
+        value = "mania_may18_october22"
+    )]
+    ManiaMay18October22,
+    #[option(
+        name = "osu!mania October 2022 - October 2024",
+        value = "mania_october22_october24"
+    )]
+    ManiaOctober22October24,
+}
+
+impl PpVersion {
+    fn version(self) -> TopOldVersion {
+        match self {
+            Self::OsuMay14July14 => TopOldVersion::Osu(TopOldOsuVersion::May14July14),
+            Self::OsuJuly14February15 => TopOldVersion::Osu(TopOldOsuVersion::July14February15),
+            Self::OsuFebruary15April15 => TopOldVersion::Osu(TopOldOsuVersion::February15April15),
+            Self::OsuApril15May18 => TopOldVersion::Osu(TopOldOsuVersion::April15May18),
+            Self::OsuMay18February19 => TopOldVersion::Osu(TopOldOsuVersion::May18February19),
+            Self::OsuFebruary19January21 => {
+                TopOldVersion::Osu(TopOldOsuVersion::February19January21)
+            }
+            Self::OsuJanuary21July21 => TopOldVersion::Osu(TopOldOsuVersion::January21July21),
+            Self::OsuJuly21November21 => TopOldVersion::Osu(TopOldOsuVersion::July21November21),
+            Self::OsuNovember21September22 => {
+                TopOldVersion::Osu(TopOldOsuVersion::November21September22)
+            }
+            Self::OsuSeptember22October24 => {
+                TopOldVersion::Osu(TopOldOsuVersion::September22October24)
+            }
+            Self::OsuOctober24March25 => TopOldVersion::Osu(TopOldOsuVersion::October24March25),
+            Self::TaikoMarch14September20 => {
+                TopOldVersion::Taiko(TopOldTaikoVersion::March14September20)
+            }
+            Self::TaikoSeptember20September22 => {
+                TopOldVersion::Taiko(TopOldTaikoVersion::September20September22)
+            }
+            Self::TaikoSeptember22October24 => {
+                TopOldVersion::Taiko(TopOldTaikoVersion::September22October24)
+            }
+            Self::TaikoOctober24March25 => {
+                TopOldVersion::Taiko(TopOldTaikoVersion::October24March25)
+            }
+            Self::CatchMarch14May20 => TopOldVersion::Catch(TopOldCatchVersion::March14May20),
+            Self::CatchMay20October24 => TopOldVersion::Catch(TopOldCatchVersion::May20October24),
+            Self::ManiaMarch14May18 => TopOldVersion::Mania(TopOldManiaVersion::March14May18),
+            Self::ManiaMay18October22 => TopOldVersion::Mania(TopOldManiaVersion::May18October22),
+            Self::ManiaOctober22October24 => {
+                TopOldVersion::Mania(TopOldManiaVersion::October22October24)
+            }
+        }
+    }
+}
+
+/// Values of a score calculated with a specific pp version
+pub struct VersionedAttrs {
+    pub pp: f32,
+    pub max_pp: f32,
+    pub stars: f32,
+    pub max_combo: u32,
+}
+
+/// Validates the `pp_version` option against the mode of the command.
+///
+/// Returns `None` if no version was given.
+pub fn parse_pp_version(
+    mode: GameMode,
+    version: Option<PpVersion>,
+) -> Result<Option<TopOldVersion>, &'static str> {
+    let Some(version) = version.map(PpVersion::version) else {
+        return Ok(None);
+    };
+
+    if version.mode() == mode {
+        return Ok(Some(version));
+    }
+
+    let content = match version.mode() {
+        GameMode::Osu => "The chosen pp version only applies to osu!standard",
+        GameMode::Taiko => "The chosen pp version only applies to osu!taiko",
+        GameMode::Catch => "The chosen pp version only applies to osu!ctb",
+        GameMode::Mania => "The chosen pp version only applies to osu!mania",
+    };
+
+    Err(content)
+}
+
+/// Calculates the score's pp, max pp, stars, and max combo based on the
+/// given pp version.
+pub async fn versioned_attrs(
+    version: TopOldVersion,
+    score: &ScoreSlim,
+    map: &OsuMap,
+) -> VersionedAttrs {
+    let rosu_map = &map.pp_map;
+
+    let (pp, max_pp, stars, max_combo) = match version {
+        TopOldVersion::Osu(version) => match version {
+            TopOldOsuVersion::May14July14 => pp_std!(osu_2014_may, rosu_map, score),
+            TopOldOsuVersion::July14February15 => pp_std!(osu_2014_july, rosu_map, score),
+            TopOldOsuVersion::February15April15 => pp_std!(osu_2015_february, rosu_map, score),
+            TopOldOsuVersion::April15May18 => pp_std!(osu_2015_april, rosu_map, score),
+            TopOldOsuVersion::May18February19 => pp_std!(osu_2018, rosu_map, score),
+            TopOldOsuVersion::February19January21 => pp_std!(osu_2019, rosu_map, score),
+            TopOldOsuVersion::January21July21 => pp_std!(osu_2021_january, rosu_map, score),
+            TopOldOsuVersion::July21November21 => pp_std!(osu_2021_july, rosu_map, score),
+            TopOldOsuVersion::November21September22 => {
+                pp_std!(osu_2021_november, rosu_map, score)
+            }
+            TopOldOsuVersion::September22October24 => pp_std!(osu_2022, rosu_map, score),
+            TopOldOsuVersion::October24March25 => pp_std!(osu_2024, rosu_map, score, lazer),
+            TopOldOsuVersion::March25Now => current_attrs(score, map).await,
+        },
+        TopOldVersion::Taiko(version) => match version {
+            TopOldTaikoVersion::March14September20 => pp_tko!(taiko_ppv1, rosu_map, score),
+            TopOldTaikoVersion::September20September22 => pp_tko!(taiko_2020, rosu_map, score),
+            TopOldTaikoVersion::September22October24 => pp_tko!(taiko_2022, rosu_map, score),
+            TopOldTaikoVersion::October24March25 => pp_tko!(taiko_2024, rosu_map, score, lazer),
+            TopOldTaikoVersion::March25Now => current_attrs(score, map).await,
+        },
+        TopOldVersion::Catch(version) => match version {
+            TopOldCatchVersion::March14May20 => pp_ctb!(fruits_ppv1, rosu_map, score),
+            TopOldCatchVersion::May20October24 => pp_ctb!(fruits_2022, rosu_map, score),
+            TopOldCatchVersion::October24Now => current_attrs(score, map).await,
+        },
+        TopOldVersion::Mania(version) => match version {
+            TopOldManiaVersion::March14May18 => {
+                let max_pp_res = mania_ppv1::ManiaPP::new(rosu_map)
+                    .mods(score.mods.bits())
+                    .calculate();
+
+                let max_pp = max_pp_res.pp as f32;
+                let stars = max_pp_res.difficulty.stars as f32;
+
+                let attrs = mania_ppv1::ManiaPP::new(rosu_map)
+                    .mods(score.mods.bits())
+                    .attributes(max_pp_res)
+                    .score(score.score)
+                    .accuracy(score.accuracy)
+                    .calculate();
+
+                let pp = attrs.pp as f32;
+
+                let max_combo = Context::pp(map)
+                    .difficulty()
+                    .await
+                    .map_or(0, DifficultyAttributes::max_combo);
+
+                (pp, max_pp, stars, max_combo)
+            }
+            TopOldManiaVersion::May18October22 => {
+                let max_pp_res = mania_2018::ManiaPP::new(rosu_map)
+                    .mods(score.mods.bits())
+                    .calculate();
+
+                let max_pp = max_pp_res.pp as f32;
+                let stars = max_pp_res.difficulty.stars as f32;
+
+                let attrs = mania_2018::ManiaPP::new(rosu_map)
+                    .mods(score.mods.bits())
+                    .attributes(max_pp_res)
+                    .score(score.score)
+                    .calculate();
+
+                let pp = attrs.pp as f32;
+
+                let max_combo = Context::pp(map)
+                    .difficulty()
+                    .await
+                    .map_or(0, DifficultyAttributes::max_combo);
+
+                (pp, max_pp, stars, max_combo)
+            }
+            TopOldManiaVersion::October22October24 => pp_mna!(mania_2022, rosu_map, score),
+            TopOldManiaVersion::October24Now => current_attrs(score, map).await,
+        },
+    };
+
+    VersionedAttrs {
+        pp,
+        max_pp,
+        stars,
+        max_combo,
+    }
+}
+
+async fn current_attrs(score: &ScoreSlim, map: &OsuMap) -> (f32, f32, f32, u32) {
+    let mut calc = Context::pp(map)
+        .mode(score.mode)
+        .lazer(score.set_on_lazer)
+        .mods(score.mods.clone());
+
+    let Some(attrs) = calc.performance().await else {
+        return (0.0, 0.0, 0.0, 0);
+    };
+
+    let pp = calc
+        .score(score)
+        .performance()
+        .await
+        .map_or(0.0, |attrs| attrs.pp() as f32);
+
+    (
+        pp,
+        attrs.pp() as f32,
+        attrs.stars() as f32,
+        attrs.max_combo(),
+    )
+}
+
+/// The pp values of a user's top scores, possibly recalculated with a
+/// previous pp version
+pub struct TopPp {
+    /// Sorted in descending order
+    pub pps: Vec<f32>,
+    /// Total pp of the user including bonus pp
+    pub total: f32,
+    pub version: Option<TopOldVersion>,
+}
+
+impl TopPp {
+    pub async fn new(
+        user_pp: f32,
+        scores: Vec<Score>,
+        version: Option<TopOldVersion>,
+    ) -> Result<Self> {
+        let Some(version) = version else {
+            return Ok(Self {
+                pps: scores.extract_pp(),
+                total: user_pp,
+                version: None,
+            });
+        };
+
+        let actual_pp: f32 = scores
+            .iter()
+            .filter_map(|score| score.weight)
+            .map(|weight| weight.pp)
+            .sum();
+
+        let bonus_pp = user_pp - actual_pp;
+
+        let maps_id_checksum = scores
+            .iter()
+            .map(|score| {
+                (
+                    score.map_id as i32,
+                    score.map.as_ref().and_then(|map| map.checksum.as_deref()),
+                )
+            })
+            .collect();
+
+        let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+        let mut pps = Vec::with_capacity(scores.len());
+
+        for score in scores {
+            let pp = score.pp.unwrap_or(0.0);
+
+            // Keep the original pp if the map is unavailable
+            let Some(map) = maps.remove(&score.map_id) else {
+                pps.push(pp);
+
+                continue;
+            };
+
+            let map = map.convert(score.mode);
+            let score = ScoreSlim::new(score, pp);
+            pps.push(versioned_attrs(version, &score, &map).await.pp);
+        }
+
+        pps.sort_unstable_by(|a, b| b.total_cmp(a));
+        let total = bonus_pp + pps.accum_weighted() as f32;
+
+        Ok(Self {
+            pps,
+            total,
+            version: Some(version),
+        })
+    }
+}
@@ -3,12 +3,15 @@ use std::borrow::Cow;
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{CowUtils, MessageOrigin, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{
+    CowUtils, MessageOrigin, constants::GENERAL_ISSUE, datetime::DATE_FORMAT, matcher,
+};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError},
     request::UserId,
 };
+use time::Date;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
@@ -33,6 +36,8 @@ pub struct Profile<'a> {
     embed: Option<ProfileKind>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Show stat changes since this date (YYYY-MM-DD)")]
+    since: Option<Cow<'a, str>>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Debug, Eq, PartialEq)]
@@ -76,6 +81,7 @@ impl<'m> Profile<'m> {
             name,
             embed: None,
             discord,
+            since: None,
         })
     }
 }
@@ -197,6 +203,18 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         },
     };
 
+    let since = match args.since.as_deref() {
+        Some(date) => match Date::parse(date, DATE_FORMAT) {
+            Ok(date) => Some(date.midnight().assume_utc()),
+            Err(_) => {
+                let content = "Failed to parse date. Be sure to specify it as `YYYY-MM-DD`.";
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
+
     let (user_id, no_user_specified) = match user_id!(orig, args) {
         Some(user_id) => (user_id, false),
         None => match config.osu {
@@ -227,7 +245,14 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
     let peaks_fut = Context::client().osu_user_rank_acc_peak(user_id, mode);
     let user_id_fut = Context::user_config().discord_from_osu_id(user_id);
 
-    let (peaks_res, user_id_res) = tokio::join!(peaks_fut, user_id_fut);
+    let snapshot_fut = async {
+        match since {
+            Some(since) => Context::osu_user().snapshot(user_id, mode, since).await,
+            None => Ok(None),
+        }
+    };
+
+    let (peaks_res, user_id_res, snapshot_res) = tokio::join!(peaks_fut, user_id_fut, snapshot_fut);
 
     // Try to get the discord user id that is linked to the osu!user
     let discord_id = match user_id_res {
@@ -254,6 +279,15 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         }
     };
 
+    let snapshot = match snapshot_res {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            warn!(?err, "Failed to get user snapshot");
+
+            None
+        }
+    };
+
     let tz = no_user_specified.then_some(config.timezone).flatten();
     let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
 
@@ -262,6 +296,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         discord_id,
         tz,
         peaks,
+        snapshot,
         legacy_scores,
         kind,
         origin,
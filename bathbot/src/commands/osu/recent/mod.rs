@@ -10,10 +10,10 @@ use twilight_model::id::{Id, marker::UserMarker};
 
 use self::fix::*;
 pub use self::{leaderboard::*, list::*, score::*};
-use super::{HasMods, ModsResult, ScoreOrder, TopArgs, TopScoreOrder};
+use super::{HasMods, ModsResult, PpVersion, ScoreOrder, TopArgs, TopScoreOrder};
 use crate::{
     commands::{
-        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP, PP_VERSION_DESC, PP_VERSION_HELP,
         osu::{LeaderboardSort, top},
     },
     util::{InteractionCommandExt, interaction::InteractionCommand},
@@ -70,6 +70,8 @@ pub struct RecentScore<'a> {
     passes: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = PP_VERSION_DESC, help = PP_VERSION_HELP)]
+    pp_version: Option<PpVersion>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
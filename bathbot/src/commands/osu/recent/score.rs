@@ -27,8 +27,8 @@ use crate::{
         impls::{SingleScoreContent, SingleScorePagination},
     },
     commands::{
-        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{map_strains_graph, require_link, user_not_found},
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP, PP_VERSION_DESC, PP_VERSION_HELP,
+        osu::{PpVersion, map_strains_graph, parse_pp_version, require_link, user_not_found},
         utility::{MissAnalyzerCheck, ScoreEmbedDataWrap},
     },
     core::commands::{CommandOrigin, interaction::InteractionCommands, prefix::Args},
@@ -331,6 +331,7 @@ impl<'m> RecentScore<'m> {
             grade,
             passes,
             discord,
+            pp_version: None,
         })
     }
 }
@@ -372,6 +373,11 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         },
     };
 
+    let pp_version = match parse_pp_version(mode, args.pp_version) {
+        Ok(pp_version) => pp_version,
+        Err(content) => return orig.error(content).await,
+    };
+
     let RecentScore {
         grade,
         passes,
@@ -590,7 +596,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
-        && Context::ordr_available();
+        && Context::render_available();
 
     #[cfg(feature = "twitch")]
     let twitch_fut = async {
@@ -688,7 +694,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
                     let fut = map_strains_graph(
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
-                        entry.map.cover(),
+                        Some(entry.map.cover()),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
                     );
@@ -716,6 +722,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         SingleScorePagination::new(&user, entries, settings, score_data, author, content);
 
     pagination.set_index(num);
+    pagination.set_pp_version(pp_version);
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
@@ -813,6 +820,8 @@ pub struct Rs<'a> {
     passes: Option<bool>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = PP_VERSION_DESC, help = PP_VERSION_HELP)]
+    pp_version: Option<PpVersion>,
 }
 
 impl<'a> From<Rs<'a>> for RecentScore<'a> {
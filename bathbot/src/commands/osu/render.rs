@@ -6,15 +6,12 @@ use std::{
 use bathbot_macros::SlashCommand;
 use bathbot_util::{
     Authored, BucketName, EmbedBuilder, MessageBuilder,
-    constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE},
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
 };
 use eyre::{Report, Result, WrapErr};
-use rosu_render::{
-    client::error::{ApiError as OrdrApiError, ClientError as OrdrError},
-    model::RenderDone,
-};
 use rosu_v2::error::OsuError;
+use tokio::sync::mpsc;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     channel::Attachment,
@@ -28,19 +25,17 @@ use crate::{
     },
     core::{Context, commands::OwnedCommandOrigin},
     manager::{ReplayError, ReplaySettings},
-    tracking::OrdrReceivers,
+    tracking::{IRenderBackend, RenderError, RenderEvent, RenderSource},
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
-pub const RENDERER_NAME: &str = "Bathbot";
-
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "render",
-    desc = "Render an osu!standard play via o!rdr",
-    help = "Render a play via [o!rdr](https://ordr.issou.best/).\n\
-    Since [danser](https://github.com/Wieku/danser-go) is being used, \
-    only osu!standard is supported."
+    desc = "Render an osu!standard play",
+    help = "Render a play via [o!rdr](https://ordr.issou.best/) \
+    or a self-hosted [danser](https://github.com/Wieku/danser-go) instance.\n\
+    Since danser is being used, only osu!standard is supported."
 )]
 #[flags(SKIP_DEFER)]
 #[allow(clippy::large_enum_variant)]
@@ -100,7 +95,7 @@ pub struct RenderSettingsCopy {
 pub struct RenderSettingsDefault;
 
 pub async fn slash_render(mut command: InteractionCommand) -> Result<()> {
-    if !Context::ordr_available() {
+    if !Context::render_available() {
         command
             .error_callback("Rendering is currently unavailable")
             .await?;
@@ -170,38 +165,25 @@ async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Res
         "Commissioning render"
     );
 
-    let render_fut = Context::ordr()
-        .client()
-        .render_with_replay_url(&replay.url, RENDERER_NAME, &skin.skin)
-        .options(settings.options());
+    let render = Context::render();
+    let source = RenderSource::Url(&replay.url);
+    let render_fut = render.commission(source, &skin.skin, settings.options());
 
-    let render = match render_fut.await {
-        Ok(render) => render,
-        Err(err) => {
-            return match err {
-                OrdrError::Response {
-                    error:
-                        OrdrApiError {
-                            code: Some(code), ..
-                        },
-                    ..
-                } => {
-                    let content =
-                        format!("Error code {int} from o!rdr: {code}", int = code.to_u8());
-                    command.error(content).await?;
-
-                    Ok(())
-                }
-                _ => {
-                    let _ = command.error(ORDR_ISSUE).await;
+    let render_id = match render_fut.await {
+        Ok(render_id) => render_id,
+        Err(RenderError::Rejected(content)) => {
+            command.error(content).await?;
 
-                    Err(Report::new(err).wrap_err("Failed to commission render"))
-                }
-            };
+            return Ok(());
+        }
+        Err(RenderError::Report(err)) => {
+            let _ = command.error(render.issue()).await;
+
+            return Err(err);
         }
     };
 
-    let ongoing = OngoingRender::new(render.render_id, command, status, None, owner).await;
+    let ongoing = OngoingRender::new(render_id, command, status, None, owner).await;
 
     tokio::spawn(ongoing.await_render_url());
 
@@ -316,32 +298,25 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
 
     debug!(score_id, discord = owner.get(), "Commissioning render");
 
-    let render_fut = Context::ordr()
-        .client()
-        .render_with_replay_file(&replay, RENDERER_NAME, &skin.skin)
-        .options(settings.options());
-
-    let render = match render_fut.await {
-        Ok(render) => render,
-        Err(OrdrError::Response {
-            error: OrdrApiError {
-                code: Some(code), ..
-            },
-            ..
-        }) => {
-            let content = format!("Error code {int} from o!rdr: {code}", int = code.to_u8());
+    let render = Context::render();
+    let source = RenderSource::File(&replay);
+    let render_fut = render.commission(source, &skin.skin, settings.options());
+
+    let render_id = match render_fut.await {
+        Ok(render_id) => render_id,
+        Err(RenderError::Rejected(content)) => {
             command.error(content).await?;
 
             return Ok(());
         }
-        Err(err) => {
-            let _ = command.error(ORDR_ISSUE).await;
+        Err(RenderError::Report(err)) => {
+            let _ = command.error(render.issue()).await;
 
-            return Err(Report::new(err).wrap_err("Failed to commission render"));
+            return Err(err);
         }
     };
 
-    let ongoing_fut = OngoingRender::new(render.render_id, command, status, Some(score_id), owner);
+    let ongoing_fut = OngoingRender::new(render_id, command, status, Some(score_id), owner);
 
     tokio::spawn(ongoing_fut.await.await_render_url());
 
@@ -442,10 +417,13 @@ impl RenderStatus {
             ),
         };
 
-        let embed = EmbedBuilder::new()
+        let mut embed = EmbedBuilder::new()
             .description(content)
-            .title("Render status")
-            .url("https://ordr.issou.best/renders");
+            .title("Render status");
+
+        if let Some(url) = Context::try_render().and_then(IRenderBackend::renders_url) {
+            embed = embed.url(url);
+        }
 
         MessageBuilder::new().embed(embed)
     }
@@ -480,7 +458,7 @@ pub struct OngoingRender {
     render_id: u32,
     orig: OwnedCommandOrigin,
     status: RenderStatus,
-    receivers: OrdrReceivers,
+    events: mpsc::Receiver<RenderEvent>,
     score_id: Option<u64>,
     msg_owner: Id<UserMarker>,
 }
@@ -496,7 +474,7 @@ impl OngoingRender {
         Self {
             orig: orig.into(),
             render_id,
-            receivers: Context::ordr().subscribe_render_id(render_id).await,
+            events: Context::render().subscribe_render_id(render_id).await,
             status,
             score_id,
             msg_owner,
@@ -507,11 +485,31 @@ impl OngoingRender {
         const TIMEOUT_DURATION: Duration = Duration::from_secs(60 * 60 * 24);
         const INTERVAL: Duration = Duration::from_secs(5);
 
+        let render = Context::render();
         let mut last_update = Instant::now();
 
         loop {
-            tokio::select! {
-                progress = self.receivers.progress.recv() => {
+            let event = tokio::select! {
+                event = self.events.recv() => event,
+                _ = tokio::time::sleep(TIMEOUT_DURATION) => {
+                    let content = format!(
+                        "Timeout while waiting for {} updates, \
+                        there was probably a network issue.",
+                        render.name()
+                    );
+
+                    if let Err(err) = self.orig.error(content).await {
+                        warn!(?err, "Failed to update message");
+                    }
+
+                    render.unsubscribe_render_id(self.render_id).await;
+
+                    return;
+                },
+            };
+
+            match event {
+                Some(RenderEvent::Progress(progress)) => {
                     let now = Instant::now();
 
                     if last_update + INTERVAL > now {
@@ -520,77 +518,54 @@ impl OngoingRender {
 
                     last_update = now;
 
-                    let Some(progress) = progress else {
-                        return warn!("progress channel was closed");
-                    };
-
-
-                    self.status.set(RenderStatusInner::Rendering(progress.progress));
+                    self.status.set(RenderStatusInner::Rendering(progress));
                     let builder = self.status.as_message();
 
-                    if let Err(err) = self.orig.update( builder).await {
+                    if let Err(err) = self.orig.update(builder).await {
                         warn!(?err, "Failed to update message");
                     }
-                },
-                done = self.receivers.done.recv() => {
-                    let Some(RenderDone { render_id, video_url }) = done else {
-                        return warn!("done channel was closed");
-                    };
-
+                }
+                Some(RenderEvent::Done { video_url }) => {
                     if let Some(score_id) = self.score_id {
                         let replay_manager = Context::replay();
-                        let store_fut = replay_manager.store_video_url(score_id, video_url.as_ref());
+                        let store_fut =
+                            replay_manager.store_video_url(score_id, video_url.as_ref());
 
                         if let Err(err) = store_fut.await {
-                            warn!(?err, score_id, video_url, "Failed to store video url");
+                            warn!(?err, score_id, %video_url, "Failed to store video url");
                         } else {
-                            debug!(score_id, video_url, "Stored render video url");
+                            debug!(score_id, %video_url, "Stored render video url");
                         }
                     } else {
                         debug!("Missing score id, skip storing video url");
                     }
 
                     let video_url_with_user = format!("{video_url} <@{}>", self.msg_owner);
-                    let builder = MessageBuilder::new().content(video_url_with_user).embed(None);
+                    let builder = MessageBuilder::new()
+                        .content(video_url_with_user)
+                        .embed(None);
 
                     if let Err(err) = self.orig.update(builder).await {
                         warn!(?err, "Failed to update message");
                     }
 
-                    Context::ordr().unsubscribe_render_id(render_id).await;
+                    render.unsubscribe_render_id(self.render_id).await;
 
                     return;
-                },
-                failed = self.receivers.failed.recv() => {
-                    let Some(failed) = failed else {
-                        return warn!("failed channel was closed");
-                    };
-
-                    warn!(?failed, "Received error from o!rdr");
-
-                    let embed = EmbedBuilder::new().description(failed.error_message).color_red();
+                }
+                Some(RenderEvent::Failed { error }) => {
+                    let embed = EmbedBuilder::new().description(error).color_red();
                     let builder = MessageBuilder::new().embed(embed);
 
-                    if let Err(err) = self.orig.update( builder).await {
-                        warn!(?err, "Failed to update message");
-                    }
-
-                    Context::ordr().unsubscribe_render_id(failed.render_id).await;
-
-                    return;
-                },
-                _ = tokio::time::sleep(TIMEOUT_DURATION) => {
-                    let content = "Timeout while waiting for o!rdr updates, \
-                        there was probably a network issue.";
-
-                    if let Err(err) = self.orig.error(content).await {
+                    if let Err(err) = self.orig.update(builder).await {
                         warn!(?err, "Failed to update message");
                     }
 
-                    Context::ordr().unsubscribe_render_id(self.render_id).await;
+                    render.unsubscribe_render_id(self.render_id).await;
 
                     return;
-                },
+                }
+                None => return warn!("render event channel was closed"),
             }
         }
     }
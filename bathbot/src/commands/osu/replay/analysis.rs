@@ -0,0 +1,321 @@
+use rosu_pp::{Beatmap, model::hit_object::HitObjectKind};
+use rosu_v2::prelude::{GameModIntermode, GameModsIntermode};
+
+use super::osr::{ReplayFrame, ReplayKeys};
+
+const PLAYFIELD_W: f32 = 512.0;
+const PLAYFIELD_H: f32 = 384.0;
+
+pub const HEATMAP_COLS: usize = 64;
+pub const HEATMAP_ROWS: usize = 48;
+
+pub const KEY_NAMES: [&str; 4] = ["K1", "K2", "M1", "M2"];
+
+/// Map values that decide whether a press hits an object
+#[derive(Copy, Clone)]
+pub struct HitParams {
+    /// Circle radius in osu!pixels
+    radius: f32,
+    /// Hit window of a 50 in milliseconds
+    pub window: f64,
+    pub clock_rate: f64,
+    hard_rock: bool,
+}
+
+impl HitParams {
+    pub fn new(map: &Beatmap, mods: &GameModsIntermode) -> Self {
+        let hard_rock = mods.contains(GameModIntermode::HardRock);
+        let easy = mods.contains(GameModIntermode::Easy);
+
+        let adjust = |value: f32, hr_factor: f32| {
+            if hard_rock {
+                (value * hr_factor).min(10.0)
+            } else if easy {
+                value * 0.5
+            } else {
+                value
+            }
+        };
+
+        let cs = adjust(map.cs, 1.3);
+        let od = adjust(map.od, 1.4);
+
+        let clock_rate = if mods.contains(GameModIntermode::DoubleTime)
+            || mods.contains(GameModIntermode::Nightcore)
+        {
+            1.5
+        } else if mods.contains(GameModIntermode::HalfTime)
+            || mods.contains(GameModIntermode::Daycore)
+        {
+            0.75
+        } else {
+            1.0
+        };
+
+        Self {
+            radius: 54.4 - 4.48 * cs,
+            window: 200.0 - 10.0 * od as f64,
+            clock_rate,
+            hard_rock,
+        }
+    }
+}
+
+/// A circle or slider head that must be clicked
+pub struct HitCircle {
+    time: f64,
+    x: f32,
+    y: f32,
+}
+
+impl HitCircle {
+    pub fn from_map(map: &Beatmap, params: &HitParams) -> Vec<Self> {
+        map.hit_objects
+            .iter()
+            .filter(|h| matches!(h.kind, HitObjectKind::Circle | HitObjectKind::Slider(_)))
+            .map(|h| Self {
+                time: h.start_time,
+                x: h.pos.x,
+                y: if params.hard_rock {
+                    PLAYFIELD_H - h.pos.y
+                } else {
+                    h.pos.y
+                },
+            })
+            .collect()
+    }
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct KeyUsage {
+    pub presses: usize,
+    releases: usize,
+    hold_sum: i64,
+}
+
+impl KeyUsage {
+    /// Average duration in milliseconds that the key was held down
+    pub fn avg_hold(&self) -> Option<f64> {
+        (self.releases > 0).then(|| self.hold_sum as f64 / self.releases as f64)
+    }
+}
+
+pub struct ReplayAnalysis {
+    /// Offset of each hit in milliseconds, negative values are early
+    pub hit_errors: Vec<f64>,
+    /// Amount of circles and slider heads
+    pub circles: usize,
+    /// Usage of the keys in the order of [`KEY_NAMES`]
+    pub keys: [KeyUsage; 4],
+    /// Amount of cursor frames for each cell, row by row
+    pub heatmap: Vec<u32>,
+    pub params: HitParams,
+}
+
+struct Press {
+    time: i32,
+    x: f32,
+    y: f32,
+}
+
+impl ReplayAnalysis {
+    pub fn new(frames: &[ReplayFrame], circles: &[HitCircle], params: HitParams) -> Self {
+        let mut keys = [KeyUsage::default(); 4];
+        let mut press_starts = [0; 4];
+        let mut prev = [false; 4];
+        let mut presses = Vec::new();
+        let mut heatmap = vec![0; HEATMAP_COLS * HEATMAP_ROWS];
+
+        for frame in frames {
+            let curr = pressed_keys(frame.keys);
+            let mut new_press = false;
+
+            for (i, key) in keys.iter_mut().enumerate() {
+                if curr[i] && !prev[i] {
+                    key.presses += 1;
+                    press_starts[i] = frame.time;
+                    new_press = true;
+                } else if !curr[i] && prev[i] {
+                    key.releases += 1;
+                    key.hold_sum += (frame.time - press_starts[i]) as i64;
+                }
+            }
+
+            if new_press {
+                presses.push(Press {
+                    time: frame.time,
+                    x: frame.x,
+                    y: frame.y,
+                });
+            }
+
+            prev = curr;
+
+            if (0.0..PLAYFIELD_W).contains(&frame.x) && (0.0..PLAYFIELD_H).contains(&frame.y) {
+                let col = (frame.x / PLAYFIELD_W * HEATMAP_COLS as f32) as usize;
+                let row = (frame.y / PLAYFIELD_H * HEATMAP_ROWS as f32) as usize;
+                heatmap[row * HEATMAP_COLS + col] += 1;
+            }
+        }
+
+        let hit_errors = hit_errors(&presses, circles, &params);
+
+        Self {
+            hit_errors,
+            circles: circles.len(),
+            keys,
+            heatmap,
+            params,
+        }
+    }
+
+    /// Unstable rate based on the map's time i.e. not adjusted to the clock
+    /// rate
+    pub fn unstable_rate(&self) -> Option<f64> {
+        let len = self.hit_errors.len();
+
+        if len < 2 {
+            return None;
+        }
+
+        let mean = self.hit_errors.iter().sum::<f64>() / len as f64;
+
+        let variance = self
+            .hit_errors
+            .iter()
+            .map(|err| (err - mean) * (err - mean))
+            .sum::<f64>()
+            / len as f64;
+
+        Some(variance.sqrt() * 10.0)
+    }
+
+    /// Average of all negative and all non-negative hit errors
+    pub fn early_late(&self) -> (Option<f64>, Option<f64>) {
+        let avg = |errors: Vec<f64>| {
+            (!errors.is_empty()).then(|| errors.iter().sum::<f64>() / errors.len() as f64)
+        };
+
+        let (early, late): (Vec<_>, Vec<_>) =
+            self.hit_errors.iter().copied().partition(|&err| err < 0.0);
+
+        (avg(early), avg(late))
+    }
+}
+
+fn pressed_keys(keys: ReplayKeys) -> [bool; 4] {
+    // Key presses also set the mouse button bit
+    let k1 = keys.contains(ReplayKeys::K1);
+    let k2 = keys.contains(ReplayKeys::K2);
+
+    [
+        k1,
+        k2,
+        !k1 && keys.contains(ReplayKeys::M1),
+        !k2 && keys.contains(ReplayKeys::M2),
+    ]
+}
+
+/// Matches each circle with the first unused press that is within the hit
+/// window and on the circle.
+///
+/// This ignores notelock and stacking so the result is an approximation.
+fn hit_errors(presses: &[Press], circles: &[HitCircle], params: &HitParams) -> Vec<f64> {
+    let radius_sq = params.radius * params.radius;
+    let mut hit_errors = Vec::with_capacity(circles.len());
+    let mut next = 0;
+
+    for circle in circles {
+        while presses
+            .get(next)
+            .is_some_and(|press| (press.time as f64) < circle.time - params.window)
+        {
+            next += 1;
+        }
+
+        let hit = presses[next..]
+            .iter()
+            .take_while(|press| press.time as f64 <= circle.time + params.window)
+            .position(|press| {
+                let dx = press.x - circle.x;
+                let dy = press.y - circle.y;
+
+                dx * dx + dy * dy <= radius_sq
+            });
+
+        if let Some(idx) = hit {
+            hit_errors.push(presses[next + idx].time as f64 - circle.time);
+            next += idx + 1;
+        }
+    }
+
+    hit_errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(time: i32, x: f32, y: f32, keys: ReplayKeys) -> ReplayFrame {
+        ReplayFrame { time, x, y, keys }
+    }
+
+    #[test]
+    fn analyze_frames() {
+        let params = HitParams {
+            radius: 30.0,
+            window: 100.0,
+            clock_rate: 1.0,
+            hard_rock: false,
+        };
+
+        let circles = [
+            HitCircle {
+                time: 1000.0,
+                x: 100.0,
+                y: 100.0,
+            },
+            HitCircle {
+                time: 1200.0,
+                x: 300.0,
+                y: 200.0,
+            },
+            HitCircle {
+                time: 1400.0,
+                x: 400.0,
+                y: 300.0,
+            },
+        ];
+
+        let k1 = ReplayKeys::K1 | ReplayKeys::M1;
+        let k2 = ReplayKeys::K2 | ReplayKeys::M2;
+
+        let frames = [
+            frame(990, 105.0, 100.0, k1),
+            frame(1040, 200.0, 150.0, ReplayKeys::empty()),
+            // Press outside of the circle
+            frame(1190, 200.0, 200.0, k2),
+            frame(1205, 300.0, 210.0, k2 | k1),
+            frame(1250, 350.0, 250.0, ReplayKeys::empty()),
+            // Press outside of the hit window
+            frame(1600, 400.0, 300.0, ReplayKeys::M1),
+            frame(1650, 600.0, 300.0, ReplayKeys::empty()),
+        ];
+
+        let analysis = ReplayAnalysis::new(&frames, &circles, params);
+
+        assert_eq!(analysis.hit_errors, [-10.0, 5.0]);
+        assert_eq!(analysis.circles, 3);
+        assert_eq!(analysis.unstable_rate(), Some(75.0));
+        assert_eq!(analysis.early_late(), (Some(-10.0), Some(5.0)));
+
+        let presses: Vec<_> = analysis.keys.iter().map(|key| key.presses).collect();
+        assert_eq!(presses, [2, 1, 1, 0]);
+        assert_eq!(analysis.keys[0].avg_hold(), Some(47.5));
+        assert_eq!(analysis.keys[1].avg_hold(), Some(60.0));
+        assert_eq!(analysis.keys[3].avg_hold(), None);
+
+        // The last frame is outside of the playfield
+        assert_eq!(analysis.heatmap.iter().sum::<u32>(), 6);
+    }
+}
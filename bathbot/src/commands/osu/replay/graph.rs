@@ -0,0 +1,131 @@
+use eyre::{Result, WrapErr};
+use plotters::prelude::*;
+use plotters_skia::SkiaBackend;
+use skia_safe::{EncodedImageFormat, surfaces};
+
+use super::analysis::{HEATMAP_COLS, HEATMAP_ROWS, ReplayAnalysis};
+
+const W: u32 = 1350;
+const H: u32 = 711;
+
+const HIST_W: u32 = 740;
+const MARGIN: u32 = 15;
+
+/// Width of a hit error bar in milliseconds
+const BIN_MS: f64 = 2.0;
+
+pub fn replay_analysis_graph(analysis: &ReplayAnalysis) -> Result<Vec<u8>> {
+    let window = analysis.params.window;
+    let bin_limit = (window / BIN_MS).ceil() as i32;
+    let mut bins = vec![0_u32; 2 * bin_limit as usize + 1];
+
+    for err in analysis.hit_errors.iter() {
+        let bin = (err / BIN_MS).round() as i32;
+        let idx = (bin.clamp(-bin_limit, bin_limit) + bin_limit) as usize;
+        bins[idx] += 1;
+    }
+
+    let bin_max = bins.iter().copied().max().unwrap_or(0).max(1);
+    let heat_max = analysis.heatmap.iter().copied().max().unwrap_or(0).max(1);
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let (left, right) = root.split_horizontally(HIST_W);
+
+        // Hit error distribution
+        let mut chart = ChartBuilder::on(&left)
+            .x_label_area_size(30_i32)
+            .y_label_area_size(45_i32)
+            .margin(MARGIN as i32)
+            .caption("Hit errors", ("sans-serif", 30_i32, &WHITE))
+            .build_cartesian_2d(-bin_limit..bin_limit, 0..bin_max)
+            .wrap_err("Failed to build hit error chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .label_style(("sans-serif", 14_i32, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .x_label_formatter(&|bin| format!("{}ms", *bin as f64 * BIN_MS))
+            .draw()
+            .wrap_err("Failed to draw hit error mesh")?;
+
+        let iter = bins
+            .iter()
+            .enumerate()
+            .map(|(idx, count)| (idx as i32 - bin_limit, *count));
+
+        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
+
+        chart
+            .draw_series(Histogram::vertical(&chart).style(area_style).data(iter))
+            .wrap_err("Failed to draw hit errors")?;
+
+        // Cursor heatmap with the playfield's aspect ratio
+        let field_w = W - HIST_W - 2 * MARGIN;
+        let field_h = field_w * 3 / 4;
+        let margin_y = (H - field_h) / 2;
+
+        let mut chart = ChartBuilder::on(&right)
+            .margin_left(MARGIN as i32)
+            .margin_right(MARGIN as i32)
+            .margin_top(margin_y as i32)
+            .margin_bottom(margin_y as i32)
+            .build_cartesian_2d(0..HEATMAP_COLS, 0..HEATMAP_ROWS)
+            .wrap_err("Failed to build heatmap chart")?;
+
+        chart
+            .plotting_area()
+            .fill(&RGBColor(7, 18, 14))
+            .wrap_err("Failed to fill playfield")?;
+
+        let max_ln = (heat_max as f64).ln_1p();
+
+        let cells = analysis
+            .heatmap
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(idx, count)| {
+                let col = idx % HEATMAP_COLS;
+                // The chart's origin is at the bottom while the playfield's is at the top
+                let row = HEATMAP_ROWS - 1 - idx / HEATMAP_COLS;
+                let ratio = (*count as f64).ln_1p() / max_ln;
+                let color = HSLColor(0.6 * (1.0 - ratio), 0.9, 0.2 + 0.4 * ratio);
+
+                Rectangle::new([(col, row), (col + 1, row + 1)], color.filled())
+            });
+
+        chart
+            .draw_series(cells)
+            .wrap_err("Failed to draw heatmap")?;
+
+        let caption_style =
+            FontDesc::new(FontFamily::SansSerif, 30.0, FontStyle::Normal).color(&WHITE);
+
+        right
+            .draw_text(
+                "Cursor heatmap",
+                &caption_style,
+                (MARGIN as i32, MARGIN as i32),
+            )
+            .wrap_err("Failed to draw heatmap caption")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
@@ -0,0 +1,295 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, attachment,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    matcher,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    error::OsuError,
+    prelude::{GameMode, GameModsIntermode},
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+
+use self::{
+    analysis::{HitCircle, HitParams, KEY_NAMES, ReplayAnalysis},
+    graph::replay_analysis_graph,
+    osr::OsrReplay,
+};
+use crate::{
+    core::Context,
+    manager::{MapError, ReplayError},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+mod analysis;
+mod graph;
+mod osr;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "replay", desc = "Analyze osu!standard replays")]
+pub enum Replay {
+    #[command(name = "analyze")]
+    Analyze(ReplayAnalyze),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "analyze",
+    desc = "Show the hit errors, key usage, and cursor movement of a replay",
+    help = "Show the hit errors, key usage, and cursor movement of an osu!standard replay.\n\
+    The replay can be specified either through a score id or url, or through an attached .osr file.\n\
+    Hit errors are matched to circles and slider heads based on the hit window and circle size \
+    but notelock and stacking are not considered so the values are an approximation."
+)]
+pub struct ReplayAnalyze {
+    #[command(desc = "Specify the score through its id or url")]
+    score_id: Option<String>,
+    #[command(desc = "Specify the replay through a .osr file")]
+    replay: Option<Attachment>,
+}
+
+async fn slash_replay(mut command: InteractionCommand) -> Result<()> {
+    match Replay::from_interaction(command.input_data())? {
+        Replay::Analyze(args) => analyze(&command, args).await,
+    }
+}
+
+async fn analyze(command: &InteractionCommand, args: ReplayAnalyze) -> Result<()> {
+    let bytes = match (args.score_id, args.replay) {
+        (Some(score_id), None) => {
+            let score_id = match score_id.parse() {
+                Ok(score_id) => score_id,
+                Err(_) => match matcher::get_osu_score_id(&score_id) {
+                    Some((score_id, _)) => score_id,
+                    None => {
+                        command.error("Must give either a score id or url").await?;
+
+                        return Ok(());
+                    }
+                },
+            };
+
+            match Context::replay().get_replay(score_id).await {
+                Ok(Some(replay)) => replay.into_vec(),
+                Ok(None) => {
+                    let content = "Looks like the replay for that score is not available";
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+                Err(ReplayError::Osu(OsuError::NotFound)) => {
+                    command.error("Found no score with that id").await?;
+
+                    return Ok(());
+                }
+                Err(ReplayError::Osu(err)) => {
+                    let _ = command.error(OSU_API_ISSUE).await;
+
+                    return Err(Report::new(err).wrap_err("Failed to get replay"));
+                }
+                Err(ReplayError::AlreadyRequestedCheck(err)) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err(ReplayError::ALREADY_REQUESTED_TEXT));
+                }
+            }
+        }
+        (None, Some(replay)) => {
+            if !replay.filename.ends_with(".osr") {
+                let content = "The attached replay must be a .osr file";
+                command.error(content).await?;
+
+                return Ok(());
+            }
+
+            match Context::client().get_discord_attachment(&replay).await {
+                Ok(bytes) => bytes.to_vec(),
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to download attachment"));
+                }
+            }
+        }
+        (Some(_), Some(_)) => {
+            let content = "Specify either a score or a replay file, not both";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        (None, None) => {
+            let content = "You must specify either a score id or url, or a .osr file";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let replay = match OsrReplay::parse(&bytes) {
+        Ok(replay) => replay,
+        Err(err) => {
+            debug!(?err, "Failed to parse replay");
+
+            let content = "Failed to parse the replay. Be sure you provide a valid .osr file.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    if replay.mode != GameMode::Osu {
+        let content = "Only osu!standard replays can be analyzed";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let map_id = match Context::osu()
+        .beatmap()
+        .checksum(replay.map_md5.as_ref())
+        .await
+    {
+        Ok(map) => map.map_id,
+        Err(OsuError::NotFound) => {
+            let content = "Could not find the replay's map, only submitted maps can be analyzed";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get map by checksum"));
+        }
+    };
+
+    let map = match Context::osu_map()
+        .map(map_id, Some(replay.map_md5.as_ref()))
+        .await
+    {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!("Could not find beatmap with id `{map_id}`");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mods = GameModsIntermode::from_bits(replay.mods);
+    let params = HitParams::new(&map.pp_map, &mods);
+    let circles = HitCircle::from_map(&map.pp_map, &params);
+    let analysis = ReplayAnalysis::new(&replay.frames, &circles, params);
+
+    let bytes = match replay_analysis_graph(&analysis) {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!(?err, "Failed to create replay analysis graph");
+
+            None
+        }
+    };
+
+    let title = format!(
+        "{} - {} [{}]",
+        map.artist().cow_escape_markdown(),
+        map.title().cow_escape_markdown(),
+        map.version().cow_escape_markdown(),
+    );
+
+    let mut footer = format!("Replay of {}", replay.username);
+
+    if !mods.is_empty() {
+        let _ = write!(footer, " • +{mods}");
+    }
+
+    let mut embed = EmbedBuilder::new()
+        .description(description(&analysis))
+        .footer(FooterBuilder::new(footer))
+        .title(title)
+        .url(format!("{OSU_BASE}b/{}", map.map_id()));
+
+    let mut builder = MessageBuilder::new();
+
+    if let Some(bytes) = bytes {
+        embed = embed.image(attachment("replay_analysis.png"));
+        builder = builder.attachment("replay_analysis.png", bytes);
+    }
+
+    command.update(builder.embed(embed)).await?;
+
+    Ok(())
+}
+
+fn description(analysis: &ReplayAnalysis) -> String {
+    let mut description = String::with_capacity(256);
+
+    let clock_rate = analysis.params.clock_rate;
+
+    match analysis.unstable_rate() {
+        Some(ur) if (clock_rate - 1.0).abs() > f64::EPSILON => {
+            let _ = writeln!(
+                description,
+                "**Unstable rate:** {ur:.2} ({converted:.2} converted)",
+                converted = ur / clock_rate
+            );
+        }
+        Some(ur) => {
+            let _ = writeln!(description, "**Unstable rate:** {ur:.2}");
+        }
+        None => description.push_str("**Unstable rate:** -\n"),
+    }
+
+    let (early, late) = analysis.early_late();
+
+    let _ = writeln!(
+        description,
+        "**Hit error:** {early} early • {late} late",
+        early = early.map_or_else(|| "-".to_owned(), |early| format!("{early:.2}ms")),
+        late = late.map_or_else(|| "-".to_owned(), |late| format!("+{late:.2}ms")),
+    );
+
+    let _ = writeln!(
+        description,
+        "**Hits:** {hits}/{circles} circles and slider heads (±{window:.0}ms)",
+        hits = analysis.hit_errors.len(),
+        circles = analysis.circles,
+        window = analysis.params.window,
+    );
+
+    let total_presses: usize = analysis.keys.iter().map(|key| key.presses).sum();
+
+    description.push_str("**Keys:**");
+
+    for (name, key) in KEY_NAMES.iter().zip(analysis.keys.iter()) {
+        if key.presses == 0 {
+            continue;
+        }
+
+        let _ = write!(
+            description,
+            "\n`{name}`: {presses} presses ({percent:.1}%)",
+            presses = key.presses,
+            percent = 100.0 * key.presses as f64 / total_presses as f64,
+        );
+
+        if let Some(hold) = key.avg_hold() {
+            let _ = write!(description, " • avg hold {hold:.0}ms");
+        }
+    }
+
+    if total_presses == 0 {
+        description.push_str(" No presses");
+    }
+
+    description
+}
@@ -0,0 +1,330 @@
+use std::io::{Cursor, Error as IoError, ErrorKind, Result as IoResult, Write};
+
+use bitflags::bitflags;
+use eyre::{ContextCompat, Result, WrapErr};
+use lzma_rs::decompress::Options;
+use rosu_v2::prelude::GameMode;
+
+/// Upper limit for the size of decompressed frames so that attachments can't
+/// blow up in memory. Regular replays stay far below this.
+const MAX_DECOMPRESSED_LEN: usize = 16 * 1024 * 1024;
+
+/// Upper limit for the amount of frames, roughly an hour of gameplay.
+const MAX_FRAMES: usize = 500_000;
+
+/// The content of a `.osr` file that is relevant for analyzing it
+pub struct OsrReplay {
+    pub mode: GameMode,
+    pub map_md5: Box<str>,
+    pub username: Box<str>,
+    pub mods: u32,
+    pub frames: Vec<ReplayFrame>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReplayFrame {
+    /// Absolute time in milliseconds
+    pub time: i32,
+    pub x: f32,
+    pub y: f32,
+    pub keys: ReplayKeys,
+}
+
+bitflags! {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct ReplayKeys: u8 {
+        const M1 = 1 << 0;
+        const M2 = 1 << 1;
+        const K1 = 1 << 2;
+        const K2 = 1 << 3;
+    }
+}
+
+impl OsrReplay {
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        let mut reader = OsrReader { bytes };
+
+        let mode = match reader.u8()? {
+            0 => GameMode::Osu,
+            1 => GameMode::Taiko,
+            2 => GameMode::Catch,
+            3 => GameMode::Mania,
+            mode => bail!("Invalid mode {mode}"),
+        };
+
+        reader.skip(4).wrap_err("Missing game version")?;
+        let map_md5 = reader.string().wrap_err("Failed to read map md5")?;
+        let username = reader.string().wrap_err("Failed to read username")?;
+        reader.string().wrap_err("Failed to read replay md5")?;
+
+        // Hit counts, score, max combo, and perfect flag
+        reader
+            .skip(6 * 2 + 4 + 2 + 1)
+            .wrap_err("Missing score data")?;
+
+        let mods = reader.i32().wrap_err("Failed to read mods")? as u32;
+        reader.string().wrap_err("Failed to read life bar")?;
+        reader.skip(8).wrap_err("Missing timestamp")?;
+
+        let len = reader.i32().wrap_err("Failed to read frames length")?;
+        let compressed = usize::try_from(len)
+            .ok()
+            .and_then(|len| reader.take(len))
+            .wrap_err("Missing compressed frames")?;
+
+        let decompressed = decompress(compressed, MAX_DECOMPRESSED_LEN)?;
+        let frames = String::from_utf8(decompressed).wrap_err("Frames are not valid UTF-8")?;
+        let frames = parse_frames(&frames)?;
+
+        Ok(Self {
+            mode,
+            map_md5,
+            username,
+            mods,
+            frames,
+        })
+    }
+}
+
+/// Decompresses the frames while refusing to write more than `limit` bytes.
+fn decompress(compressed: &[u8], limit: usize) -> Result<Vec<u8>> {
+    let options = Options {
+        memlimit: Some(limit),
+        ..Default::default()
+    };
+
+    let mut writer = LimitedWriter {
+        bytes: Vec::new(),
+        limit,
+    };
+
+    lzma_rs::lzma_decompress_with_options(&mut Cursor::new(compressed), &mut writer, &options)
+        .wrap_err("Failed to decompress frames")?;
+
+    Ok(writer.bytes)
+}
+
+struct LimitedWriter {
+    bytes: Vec<u8>,
+    limit: usize,
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        if self.bytes.len() + buf.len() > self.limit {
+            return Err(IoError::new(
+                ErrorKind::OutOfMemory,
+                "decompressed frames exceed the size limit",
+            ));
+        }
+
+        self.bytes.extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+/// Parses the decompressed frames which are formatted as `w|x|y|z,` where `w`
+/// is the time since the previous frame and `z` the pressed keys.
+fn parse_frames(frames: &str) -> Result<Vec<ReplayFrame>> {
+    // Marks the last frame which contains the RNG seed
+    const SEED_FRAME: i32 = -12345;
+
+    let count = frames.bytes().filter(|&byte| byte == b',').count();
+
+    if count > MAX_FRAMES {
+        bail!("Too many replay frames ({count})");
+    }
+
+    let mut time = 0;
+
+    frames
+        .split(',')
+        .filter(|frame| !frame.is_empty())
+        .filter_map(|frame| {
+            let Some((delta, x, y, keys)) = parse_frame(frame) else {
+                return Some(Err(eyre!("Invalid replay frame `{frame}`")));
+            };
+
+            if delta == SEED_FRAME {
+                return None;
+            }
+
+            time += delta;
+
+            Some(Ok(ReplayFrame {
+                time,
+                x,
+                y,
+                keys: ReplayKeys::from_bits_truncate(keys),
+            }))
+        })
+        .collect()
+}
+
+fn parse_frame(frame: &str) -> Option<(i32, f32, f32, u8)> {
+    let mut values = frame.split('|');
+
+    let delta = values.next()?.parse().ok()?;
+    let x = values.next()?.parse().ok()?;
+    let y = values.next()?.parse().ok()?;
+    let keys: f32 = values.next()?.parse().ok()?;
+
+    Some((delta, x, y, keys as u8))
+}
+
+struct OsrReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> OsrReader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < len {
+            return None;
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+
+        Some(taken)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        self.take(len)
+            .map(|_| ())
+            .wrap_err("Unexpected end of file")
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        self.take(1)
+            .map(|bytes| bytes[0])
+            .wrap_err("Unexpected end of file")
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        self.take(4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(i32::from_le_bytes)
+            .wrap_err("Unexpected end of file")
+    }
+
+    fn uleb128(&mut self) -> Result<usize> {
+        let mut value = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7F) as usize) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+
+            shift += 7;
+
+            if shift >= usize::BITS {
+                bail!("Invalid string length");
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<Box<str>> {
+        match self.u8()? {
+            0x00 => Ok(Box::default()),
+            0x0B => {
+                let len = self.uleb128()?;
+                let bytes = self.take(len).wrap_err("Unexpected end of file")?;
+
+                std::str::from_utf8(bytes)
+                    .map(Box::from)
+                    .wrap_err("String is not valid UTF-8")
+            }
+            byte => bail!("Invalid string indicator {byte}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(s: &str) -> Vec<u8> {
+        let mut bytes = vec![0x0B, s.len() as u8];
+        bytes.extend_from_slice(s.as_bytes());
+
+        bytes
+    }
+
+    #[test]
+    fn parse_replay_frames() {
+        let frames =
+            parse_frames("0|256|-500|0,-1|256|-500|0,16|100.5|200|5,17|101|201.25|0,-12345|0|0|7,")
+                .unwrap();
+
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[1].time, -1);
+        assert_eq!(frames[2].time, 15);
+        assert_eq!(frames[2].x, 100.5);
+        assert_eq!(frames[2].keys, ReplayKeys::M1 | ReplayKeys::K1);
+        assert_eq!(frames[3].time, 32);
+        assert!(frames[3].keys.is_empty());
+
+        assert!(parse_frames("16|100|abc|0,").is_err());
+    }
+
+    #[test]
+    fn limit_decompressed_len() {
+        let mut compressed = Vec::new();
+        let frames = "16|100|200|0,".repeat(100);
+        lzma_rs::lzma_compress(&mut Cursor::new(frames.as_bytes()), &mut compressed).unwrap();
+
+        assert_eq!(
+            decompress(&compressed, frames.len()).unwrap(),
+            frames.as_bytes()
+        );
+        assert!(decompress(&compressed, frames.len() - 1).is_err());
+    }
+
+    #[test]
+    fn limit_frame_count() {
+        let frames = "0|0|0|0,".repeat(MAX_FRAMES + 1);
+
+        assert!(parse_frames(&frames).is_err());
+        assert!(parse_frames(&frames[8..]).is_ok());
+    }
+
+    #[test]
+    fn parse_osr() {
+        let mut compressed = Vec::new();
+        let frames = "0|256|-500|0,-1|256|-500|0,20|10|20|10,-12345|0|0|0,";
+        lzma_rs::lzma_compress(&mut Cursor::new(frames.as_bytes()), &mut compressed).unwrap();
+
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&20250101_i32.to_le_bytes());
+        bytes.extend(string("0123456789abcdef0123456789abcdef"));
+        bytes.extend(string("badewanne3"));
+        bytes.push(0x00);
+        bytes.extend_from_slice(&[0; 6 * 2 + 4 + 2 + 1]);
+        bytes.extend_from_slice(&72_i32.to_le_bytes());
+        bytes.extend(string("0|1,"));
+        bytes.extend_from_slice(&0_i64.to_le_bytes());
+        bytes.extend_from_slice(&(compressed.len() as i32).to_le_bytes());
+        bytes.extend(compressed);
+        bytes.extend_from_slice(&0_i64.to_le_bytes());
+
+        let replay = OsrReplay::parse(&bytes).unwrap();
+
+        assert_eq!(replay.mode, GameMode::Osu);
+        assert_eq!(replay.map_md5.as_ref(), "0123456789abcdef0123456789abcdef");
+        assert_eq!(replay.username.as_ref(), "badewanne3");
+        assert_eq!(replay.mods, 72);
+        assert_eq!(replay.frames.len(), 3);
+        assert_eq!(replay.frames[2].keys, ReplayKeys::M2 | ReplayKeys::K2);
+
+        assert!(OsrReplay::parse(&bytes[..40]).is_err());
+    }
+}
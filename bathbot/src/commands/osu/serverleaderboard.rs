@@ -1,7 +1,10 @@
 use std::borrow::Cow;
 
 use bathbot_macros::SlashCommand;
-use bathbot_model::{Countries, RankingKind, UserModeStatsColumn, UserStatsColumn, UserStatsKind};
+use bathbot_model::{
+    Countries, RankingKind, ScoreStatsColumn, ScoreStatsPeriod, UserModeStatsColumn,
+    UserStatsColumn, UserStatsKind, command_fields::GameModeOption,
+};
 use bathbot_util::{Authored, constants::GENERAL_ISSUE};
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
@@ -43,6 +46,10 @@ pub enum ServerLeaderboard {
     Catch(ServerLeaderboardCatch),
     #[command(name = "mania")]
     Mania(ServerLeaderboardMania),
+    #[command(name = "scores")]
+    Scores(ServerLeaderboardScores),
+    #[command(name = "topplays")]
+    TopPlays(ServerLeaderboardTopPlays),
 }
 
 impl ServerLeaderboard {
@@ -53,6 +60,7 @@ impl ServerLeaderboard {
             Self::Taiko(args) => args.country.as_deref(),
             Self::Catch(args) => args.country.as_deref(),
             Self::Mania(args) => args.country.as_deref(),
+            Self::Scores(_) | Self::TopPlays(_) => None,
         }
     }
 }
@@ -123,6 +131,40 @@ pub struct ServerLeaderboardMania {
     country: Option<String>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "scores",
+    desc = "Leaderboards based on recent scores of linked server members",
+    help = "Leaderboards based on recent scores of linked server members.\n\
+    Passed scores of linked users are stored as they are submitted so only passes that were set \
+    after linking are considered.\n\
+    `Ranked score gained` sums up by how much the best score on each map that gives pp \
+    improved compared to the previous best score on that map."
+)]
+pub struct ServerLeaderboardScores {
+    #[command(desc = "Specify what kind of leaderboard to show")]
+    kind: ScoreStatsColumn,
+    #[command(desc = "Specify the time period, defaults to week")]
+    period: Option<ScoreStatsPeriod>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "topplays",
+    desc = "The best pp plays of linked server members in a time period",
+    help = "The best pp plays of linked server members in a time period.\n\
+    Passed scores of linked users are stored as they are submitted so only passes that were set \
+    after linking are considered."
+)]
+pub struct ServerLeaderboardTopPlays {
+    #[command(desc = "Specify the time period, defaults to week")]
+    period: Option<ScoreStatsPeriod>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
 async fn country_code<'a>(
     command: &InteractionCommand,
     country: &'a str,
@@ -164,6 +206,23 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         .flatten()
         .and_then(|guild| Some((guild.id.to_native(), *guild.icon.as_ref()?)));
 
+    // Only relevant for leaderboards based on archived scores
+    let score_mode = match args {
+        ServerLeaderboard::Scores(ServerLeaderboardScores { mode, .. })
+        | ServerLeaderboard::TopPlays(ServerLeaderboardTopPlays { mode, .. }) => match mode {
+            Some(mode) => mode.into(),
+            None => match Context::user_config().mode(owner).await {
+                Ok(mode) => mode.unwrap_or(GameMode::Osu),
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err);
+                }
+            },
+        },
+        _ => GameMode::Osu,
+    };
+
     let author_name_fut = Context::user_config().osu_name(owner);
 
     let ((author_name_res, entries_res), kind) = match &args {
@@ -288,6 +347,34 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
                 },
             };
 
+            (tokio::join!(author_name_fut, entries_fut), kind)
+        }
+        ServerLeaderboard::Scores(args) => {
+            let period = args.period.unwrap_or(ScoreStatsPeriod::Week);
+
+            let entries_fut =
+                Context::osu_user().score_stats(&members, score_mode, args.kind, period);
+
+            let kind = RankingKind::ScoreStats {
+                guild_icon,
+                mode: score_mode,
+                column: args.kind,
+                period,
+            };
+
+            (tokio::join!(author_name_fut, entries_fut), kind)
+        }
+        ServerLeaderboard::TopPlays(args) => {
+            let period = args.period.unwrap_or(ScoreStatsPeriod::Week);
+
+            let entries_fut = Context::osu_user().top_plays(&members, score_mode, period);
+
+            let kind = RankingKind::TopPlays {
+                guild_icon,
+                mode: score_mode,
+                period,
+            };
+
             (tokio::join!(author_name_fut, entries_fut), kind)
         }
     };
@@ -311,7 +398,9 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
     };
 
     if entries.is_empty() {
-        let content = if args.country().is_some() {
+        let content = if let ServerLeaderboard::Scores(_) | ServerLeaderboard::TopPlays(_) = args {
+            "No scores found for members of this server in that time period".to_owned()
+        } else if args.country().is_some() {
             "No user data found for members of this server from that country".to_owned()
         } else {
             let link = InteractionCommands::get_command("link").map_or_else(
@@ -340,7 +429,10 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         return Ok(());
     }
 
-    let author_idx = author_name.and_then(|name| entries.name_pos(&name));
+    // Top plays may contain multiple entries of the same user
+    let author_idx = author_name
+        .filter(|_| !matches!(args, ServerLeaderboard::TopPlays(_)))
+        .and_then(|name| entries.name_pos(&name));
     let total = entries.len();
 
     let pagination = RankingPagination::builder()
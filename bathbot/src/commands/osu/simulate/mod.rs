@@ -1,6 +1,8 @@
 pub mod args;
 pub mod parsed_map;
 
+mod sweep;
+
 use std::borrow::Cow;
 
 use bathbot_macros::{HasMods, SlashCommand, command};
@@ -19,17 +21,17 @@ use twilight_model::{
     guild::Permissions,
 };
 
-use self::args::{ParseError, SimulateArg};
-use super::{
-    HasMods, ModsResult, TopOldCatchVersion, TopOldManiaVersion, TopOldOsuVersion,
-    TopOldTaikoVersion,
+use self::{
+    args::{ParseError, SimulateArg},
+    sweep::{AccRange, SweepArgs, SweepColumns},
 };
+use super::{HasMods, ModsResult, PpVersion, parse_pp_version};
 use crate::{
     active::{
         ActiveMessages,
         impls::{SimulateAttributes, SimulateComponents, SimulateData, SimulateMap, TopOldVersion},
     },
-    commands::osu::parsed_map::AttachedSimulateMap,
+    commands::{PP_VERSION_DESC, PP_VERSION_HELP, osu::parsed_map::AttachedSimulateMap},
     core::{
         Context,
         commands::{CommandOrigin, prefix::Args},
@@ -88,6 +90,21 @@ pub struct Simulate<'m> {
     od: Option<f32>,
     #[command(desc = "Specify a .osu file")]
     file: Option<Attachment>,
+    #[command(
+        desc = "Show pp for an accuracy range instead e.g. `95-100` or `95-100:0.5`",
+        help = "Instead of simulating a single score, show a table of pp values \
+        for an accuracy range.\n\
+        The range is given as `start-end` with an optional step e.g. `95-100:0.5`; \
+        the step defaults to 0.5.\n\
+        Use `sweep_misses` or `sweep_mods` to compare multiple miss counts or mod combinations."
+    )]
+    sweep: Option<String>,
+    #[command(desc = "Miss counts to compare in the sweep table e.g. `0,1,5`")]
+    sweep_misses: Option<String>,
+    #[command(desc = "Mod combinations to compare in the sweep table e.g. `NM,HD,HR,DT`")]
+    sweep_mods: Option<String>,
+    #[command(desc = PP_VERSION_DESC, help = PP_VERSION_HELP)]
+    pp_version: Option<PpVersion>,
 }
 
 pub async fn slash_simulate(mut command: InteractionCommand) -> Result<()> {
@@ -114,11 +131,9 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
     let mode = map.mode();
     debug!(?map, ?mode, "Processing simulate command...");
 
-    let version = match mode {
-        GameMode::Osu => TopOldVersion::Osu(TopOldOsuVersion::March25Now),
-        GameMode::Taiko => TopOldVersion::Taiko(TopOldTaikoVersion::March25Now),
-        GameMode::Catch => TopOldVersion::Catch(TopOldCatchVersion::October24Now),
-        GameMode::Mania => TopOldVersion::Mania(TopOldManiaVersion::October24Now),
+    let version = match parse_pp_version(mode, args.pp_version) {
+        Ok(version) => version.unwrap_or_else(|| TopOldVersion::current(mode)),
+        Err(content) => return orig.error(content).await,
     };
 
     let max_combo = match map {
@@ -153,6 +168,31 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
         },
     };
 
+    if let Some(acc) = args.sweep.take() {
+        if !version.is_current() {
+            let content = "Accuracy sweeps are only available for the current pp system";
+
+            return orig.error(content).await;
+        }
+
+        let sweep_args = SweepArgs {
+            acc,
+            columns: args.sweep_columns.take(),
+            mods,
+            clock_rate: args.clock_rate,
+            misses: args.misses.unwrap_or(0),
+            set_on_lazer,
+            attrs: SimulateAttributes {
+                ar: args.ar,
+                cs: args.cs,
+                hp: args.hp,
+                od: args.od,
+            },
+        };
+
+        return sweep::sweep(orig, map, sweep_args).await;
+    }
+
     let simulate_data = SimulateData {
         mods,
         acc: args.acc,
@@ -451,6 +491,9 @@ struct SimulateArgs {
     cs: Option<f32>,
     hp: Option<f32>,
     od: Option<f32>,
+    sweep: Option<AccRange>,
+    sweep_columns: Option<SweepColumns>,
+    pp_version: Option<PpVersion>,
 }
 
 impl SimulateArgs {
@@ -532,6 +575,24 @@ impl SimulateArgs {
 
         let mode = simulate.mode.map(GameMode::from);
 
+        let sweep_columns = match (simulate.sweep_misses, simulate.sweep_mods) {
+            (Some(_), Some(_)) => {
+                return Err("Only one of `sweep_misses` and `sweep_mods` can be specified");
+            }
+            (Some(misses), None) => Some(SweepColumns::parse_misses(&misses)?),
+            (None, Some(mods)) => Some(SweepColumns::parse_mods(&mods)?),
+            (None, None) => None,
+        };
+
+        let sweep = match simulate.sweep {
+            Some(ref sweep) => Some(AccRange::parse(sweep)?),
+            None if sweep_columns.is_some() => {
+                return Err("Specify an accuracy range through the `sweep` option \
+                    to compare miss counts or mod combinations");
+            }
+            None => None,
+        };
+
         let map = match simulate.file {
             Some(attachment) => Some(SimulateMapArg::Attachment(Box::new(attachment))),
             None => match simulate.map {
@@ -569,6 +630,9 @@ impl SimulateArgs {
             cs: simulate.cs,
             hp: simulate.hp,
             od: simulate.od,
+            sweep,
+            sweep_columns,
+            pp_version: simulate.pp_version,
         })
     }
 }
@@ -0,0 +1,422 @@
+use std::fmt::Write;
+
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, constants::OSU_BASE};
+use eyre::Result;
+use rosu_v2::prelude::{GameMods, GameModsIntermode};
+
+use crate::{
+    active::impls::{SimulateAttributes, SimulateMap},
+    core::commands::CommandOrigin,
+    manager::{Mods, PpManager},
+};
+
+/// Accuracy values given as `start-end` with an optional `:step`
+#[derive(Debug, PartialEq)]
+pub struct AccRange {
+    start: f32,
+    end: f32,
+    step: f32,
+}
+
+impl AccRange {
+    const DEFAULT_STEP: f32 = 0.5;
+    const MAX_ROWS: usize = 21;
+
+    pub fn parse(input: &str) -> Result<Self, &'static str> {
+        const INVALID: &str = "Failed to parse accuracy range, \
+            expected something like `95-100` or `95-100:0.5`";
+
+        let input = input.cow_replace('%', "");
+
+        let (range, step) = match input.split_once(':') {
+            Some((range, step)) => (range, Some(step)),
+            None => (input.as_ref(), None),
+        };
+
+        let (start, end) = range.split_once('-').ok_or(INVALID)?;
+        let start: f32 = start.trim().parse().map_err(|_| INVALID)?;
+        let end: f32 = end.trim().parse().map_err(|_| INVALID)?;
+
+        let step = match step {
+            Some(step) => step.trim().parse().map_err(|_| INVALID)?,
+            None => Self::DEFAULT_STEP,
+        };
+
+        if !(0.0..=100.0).contains(&start) || !(0.0..=100.0).contains(&end) {
+            return Err("Accuracy values must be between 0 and 100");
+        } else if step <= 0.0 || !step.is_finite() {
+            return Err("The accuracy step must be positive");
+        }
+
+        let range = Self {
+            start: start.min(end),
+            end: start.max(end),
+            step,
+        };
+
+        if range.len() > Self::MAX_ROWS {
+            return Err("The accuracy range may contain at most 21 values, try a larger step");
+        }
+
+        Ok(range)
+    }
+
+    fn len(&self) -> usize {
+        ((self.end - self.start) / self.step + 1e-4).floor() as usize + 1
+    }
+
+    fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        (0..self.len()).map(|i| (self.start + i as f32 * self.step).min(self.end))
+    }
+}
+
+/// Values that each get their own column next to the accuracy
+#[derive(Debug, PartialEq)]
+pub enum SweepColumns {
+    Misses(Vec<u32>),
+    Mods(Vec<GameModsIntermode>),
+}
+
+impl SweepColumns {
+    const MAX_COLUMNS: usize = 5;
+
+    pub fn parse_misses(input: &str) -> Result<Self, &'static str> {
+        let misses = split_list(input)
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| "Failed to parse miss counts e.g. `0,1,5`")
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+
+        Self::validate(misses).map(Self::Misses)
+    }
+
+    pub fn parse_mods(input: &str) -> Result<Self, &'static str> {
+        let mods = split_list(input)
+            .map(|mods| {
+                let mods = mods.trim_start_matches('+');
+
+                if mods.eq_ignore_ascii_case("NM") {
+                    Some(GameModsIntermode::new())
+                } else {
+                    GameModsIntermode::try_from_acronyms(mods)
+                }
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or("Failed to parse mod combinations e.g. `NM,HD,HDHR`")?;
+
+        Self::validate(mods).map(Self::Mods)
+    }
+
+    fn validate<T>(values: Vec<T>) -> Result<Vec<T>, &'static str> {
+        if values.is_empty() {
+            Err("At least one sweep column must be specified")
+        } else if values.len() > Self::MAX_COLUMNS {
+            Err("At most 5 sweep columns can be specified")
+        } else {
+            Ok(values)
+        }
+    }
+}
+
+fn split_list(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split([',', ' ', '/'])
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+pub struct SweepArgs {
+    pub acc: AccRange,
+    pub columns: Option<SweepColumns>,
+    pub mods: Option<GameMods>,
+    pub clock_rate: Option<f64>,
+    pub misses: u32,
+    pub set_on_lazer: bool,
+    pub attrs: SimulateAttributes,
+}
+
+struct SweepColumn {
+    header: String,
+    mods: GameMods,
+    misses: u32,
+}
+
+/// Responds with a table of the pp values for each accuracy of the range
+pub async fn sweep(orig: CommandOrigin<'_>, mut map: SimulateMap, args: SweepArgs) -> Result<()> {
+    let SweepArgs {
+        acc,
+        columns,
+        mods,
+        clock_rate,
+        misses,
+        set_on_lazer,
+        attrs,
+    } = args;
+
+    attrs.apply(map.pp_map_mut());
+
+    let mode = map.mode();
+    let mods = mods.unwrap_or_default();
+
+    // Values that are the same for all columns are shown in the footer
+    let (footer_mods, footer_misses) = match columns {
+        None => (true, true),
+        Some(SweepColumns::Misses(_)) => (true, false),
+        Some(SweepColumns::Mods(_)) => (false, true),
+    };
+
+    let columns = match columns {
+        None => vec![SweepColumn {
+            header: "PP".to_owned(),
+            mods: mods.clone(),
+            misses,
+        }],
+        Some(SweepColumns::Misses(counts)) => counts
+            .into_iter()
+            .map(|misses| SweepColumn {
+                header: format!("{misses} miss"),
+                mods: mods.clone(),
+                misses,
+            })
+            .collect(),
+        Some(SweepColumns::Mods(combinations)) => {
+            let mut columns = Vec::with_capacity(combinations.len());
+
+            for mods in combinations {
+                let header = if mods.is_empty() {
+                    "NM".to_owned()
+                } else {
+                    mods.to_string()
+                };
+
+                let Some(mods) = mods.try_with_mode(mode) else {
+                    let content = format!("Looks like `{header}` is invalid for the {mode:?} mode");
+
+                    return orig.error(content).await;
+                };
+
+                columns.push(SweepColumn {
+                    header,
+                    mods,
+                    misses,
+                });
+            }
+
+            columns
+        }
+    };
+
+    let accs: Vec<_> = acc.values().collect();
+    let mut pps = Vec::with_capacity(columns.len());
+
+    for column in columns.iter() {
+        let mods = Mods {
+            clock_rate,
+            ..Mods::new(column.mods.clone())
+        };
+
+        let mut calc = PpManager::from_parsed(map.pp_map())
+            .lazer(set_on_lazer)
+            .mods(mods);
+
+        let mut values = Vec::with_capacity(accs.len());
+
+        for &acc in accs.iter() {
+            match calc.performance_for_acc(acc as f64, column.misses).await {
+                Some(attrs) => values.push(attrs.pp()),
+                None => {
+                    let content = "The map is too suspicious, cannot calculate pp";
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+
+        pps.push(values);
+    }
+
+    let headers: Vec<_> = columns
+        .iter()
+        .map(|column| column.header.as_str())
+        .collect();
+    let description = sweep_table(&accs, &headers, &pps);
+
+    let title = match map {
+        SimulateMap::Full(ref map) => format!(
+            "{} - {} [{}]",
+            map.artist().cow_escape_markdown(),
+            map.title().cow_escape_markdown(),
+            map.version().cow_escape_markdown(),
+        ),
+        SimulateMap::Attached(ref map) => map.filename.as_ref().to_owned(),
+    };
+
+    let mut footer_text = String::new();
+
+    if footer_mods {
+        let _ = write!(
+            footer_text,
+            "Mods: {}",
+            if mods.is_empty() {
+                "NM".to_owned()
+            } else {
+                mods.to_string()
+            }
+        );
+    }
+
+    if footer_misses {
+        if !footer_text.is_empty() {
+            footer_text.push_str(" • ");
+        }
+
+        let _ = write!(
+            footer_text,
+            "{misses} miss{plural}",
+            plural = if misses == 1 { "" } else { "es" }
+        );
+    }
+
+    if let Some(clock_rate) = clock_rate {
+        let _ = write!(footer_text, " • Clock rate: {clock_rate:.2}");
+    }
+
+    footer_text.push_str(if set_on_lazer {
+        " • Lazer"
+    } else {
+        " • Stable"
+    });
+
+    let mut embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(footer_text))
+        .title(title);
+
+    if let SimulateMap::Full(ref map) = map {
+        embed = embed.url(format!("{OSU_BASE}b/{}", map.map_id()));
+    }
+
+    let content = "Simulated pp for an accuracy range:";
+    let builder = MessageBuilder::new().embed(embed).content(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Formats the pp values as code block table with a row for each accuracy
+/// and a column for each header.
+fn sweep_table(accs: &[f32], headers: &[&str], pps: &[Vec<f64>]) -> String {
+    const ACC_HEADER: &str = "Acc";
+
+    let accs: Vec<_> = accs.iter().map(|acc| format!("{acc:.2}%")).collect();
+    let acc_len = accs
+        .iter()
+        .fold(ACC_HEADER.len(), |len, acc| len.max(acc.len()));
+
+    let columns: Vec<Vec<_>> = pps
+        .iter()
+        .map(|values| values.iter().map(|pp| format!("{pp:.2}")).collect())
+        .collect();
+
+    let lens: Vec<_> = headers
+        .iter()
+        .zip(columns.iter())
+        .map(|(header, values)| {
+            values
+                .iter()
+                .fold(header.len(), |len, value| len.max(value.len()))
+        })
+        .collect();
+
+    let mut table = String::with_capacity(64 * (accs.len() + 2));
+    table.push_str("```\n");
+
+    let _ = write!(table, "{ACC_HEADER:<acc_len$}");
+
+    for (header, len) in headers.iter().zip(lens.iter()) {
+        let _ = write!(table, " | {header:>len$}");
+    }
+
+    table.push('\n');
+    table.push_str(&"-".repeat(acc_len));
+
+    for len in lens.iter() {
+        table.push_str("-+-");
+        table.push_str(&"-".repeat(*len));
+    }
+
+    for (i, acc) in accs.iter().enumerate() {
+        let _ = write!(table, "\n{acc:<acc_len$}");
+
+        for (values, len) in columns.iter().zip(lens.iter()) {
+            let _ = write!(table, " | {:>len$}", values[i]);
+        }
+    }
+
+    table.push_str("\n```");
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_acc_range() {
+        let range = AccRange::parse("95-100").unwrap();
+        let values: Vec<_> = range.values().collect();
+        assert_eq!(values.len(), 11);
+        assert_eq!(values[0], 95.0);
+        assert_eq!(values[10], 100.0);
+
+        let range = AccRange::parse("100% - 98%:0.25").unwrap();
+        let values: Vec<_> = range.values().collect();
+        assert_eq!(
+            values,
+            [98.0, 98.25, 98.5, 98.75, 99.0, 99.25, 99.5, 99.75, 100.0]
+        );
+
+        assert!(AccRange::parse("95").is_err());
+        assert!(AccRange::parse("90-101").is_err());
+        assert!(AccRange::parse("90-100:0").is_err());
+        assert!(AccRange::parse("80-100:0.5").is_err());
+    }
+
+    #[test]
+    fn parse_columns() {
+        assert_eq!(
+            SweepColumns::parse_misses("0, 1,5").unwrap(),
+            SweepColumns::Misses(vec![0, 1, 5])
+        );
+        assert!(SweepColumns::parse_misses("0,a").is_err());
+        assert!(SweepColumns::parse_misses("1,2,3,4,5,6").is_err());
+
+        let SweepColumns::Mods(mods) = SweepColumns::parse_mods("nm,HD hdhr").unwrap() else {
+            panic!("expected mods");
+        };
+
+        assert_eq!(mods.len(), 3);
+        assert!(mods[0].is_empty());
+        assert!(SweepColumns::parse_mods("NM,XY").is_err());
+    }
+
+    #[test]
+    fn format_table() {
+        let table = sweep_table(
+            &[99.0, 100.0],
+            &["0 miss", "1 miss"],
+            &[vec![287.5, 301.25], vec![270.0, 283.126]],
+        );
+
+        let expected = "```\n\
+            Acc     | 0 miss | 1 miss\n\
+            --------+--------+-------\n\
+            99.00%  | 287.50 | 270.00\n\
+            100.00% | 301.25 | 283.13\n\
+            ```";
+
+        assert_eq!(table, expected);
+    }
+}
@@ -21,10 +21,16 @@ use twilight_model::id::{Id, marker::UserMarker};
 
 use crate::{
     Context,
-    active::{ActiveMessages, impls::TopIfPagination},
+    active::{
+        ActiveMessages,
+        impls::{TopIfPagination, TopOldVersion},
+    },
     commands::{
-        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{require_link, user_not_found},
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP, PP_VERSION_DESC, PP_VERSION_HELP,
+        osu::{
+            PpVersion, VersionedAttrs, parse_pp_version, require_link, user_not_found,
+            versioned_attrs,
+        },
     },
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
@@ -71,6 +77,8 @@ pub struct TopIf<'a> {
     sort: Option<TopIfScoreOrder>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = PP_VERSION_DESC, help = PP_VERSION_HELP)]
+    pp_version: Option<PpVersion>,
 }
 
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
@@ -124,6 +132,7 @@ impl<'m> TopIf<'m> {
             query: None,
             sort: None,
             discord,
+            pp_version: None,
         })
     }
 }
@@ -226,6 +235,11 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         return orig.error(content).await;
     }
 
+    let pp_version = match parse_pp_version(mode, args.pp_version) {
+        Ok(pp_version) => pp_version,
+        Err(content) => return orig.error(content).await,
+    };
+
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
         None => match orig.guild_id() {
@@ -275,13 +289,14 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         .filter_map(|s| s.weight)
         .fold(0.0, |sum, weight| sum + weight.pp);
 
-    let bonus_pp = user
+    let user_pp = user
         .statistics
         .as_ref()
         .expect("missing stats")
         .pp
-        .to_native()
-        - actual_pp;
+        .to_native();
+
+    let bonus_pp = user_pp - actual_pp;
     let sort = args.sort.unwrap_or_default();
     let content = get_content(
         user.username.as_str(),
@@ -291,7 +306,9 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         sort,
     );
 
-    let mut entries = match process_scores(scores, mods, mode, sort, legacy_scores).await {
+    let process_fut = process_scores(scores, mods, mode, sort, legacy_scores, pp_version);
+
+    let mut entries = match process_fut.await {
         Ok(scores) => scores,
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
@@ -322,13 +339,20 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         }
     };
 
-    // Accumulate all necessary data
-    let pre_pp = user
-        .statistics
-        .as_ref()
-        .expect("missing stats")
-        .pp
-        .to_native();
+    // Total pp of the unmodified scores recalculated with the pp version
+    let pre_pp = if pp_version.is_some() {
+        let mut pps: Vec<_> = entries.iter().map(|entry| entry.old_pp).collect();
+        pps.sort_unstable_by(|a, b| b.total_cmp(a));
+
+        let pre_pp = pps
+            .into_iter()
+            .zip(0..)
+            .fold(0.0, |sum, (pp, i)| sum + pp * 0.95_f32.powi(i));
+
+        round(bonus_pp + pre_pp)
+    } else {
+        user_pp
+    };
 
     let pagination = TopIfPagination::builder()
         .user(user)
@@ -337,6 +361,7 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         .pre_pp(pre_pp)
         .post_pp(final_pp)
         .rank(rank)
+        .pp_version(pp_version)
         .content(content.into_boxed_str())
         .msg_owner(orig.user_id()?)
         .build();
@@ -468,6 +493,7 @@ async fn process_scores(
     mode: GameMode,
     sort: TopIfScoreOrder,
     legacy_scores: bool,
+    pp_version: Option<TopOldVersion>,
 ) -> Result<Vec<TopIfEntry>> {
     let mut entries = Vec::with_capacity(scores.len());
 
@@ -513,6 +539,15 @@ async fn process_scores(
         };
         map = map.convert(score.mode);
 
+        let old_pp = match pp_version {
+            Some(pp_version) => {
+                let original = ScoreSlim::new(score.clone(), 0.0);
+
+                versioned_attrs(pp_version, &original, &map).await.pp
+            }
+            None => score.pp.unwrap_or(0.0),
+        };
+
         let changed = match &arg_mods {
             ModSelection::Include(mods) if mods.is_empty() => {
                 let changed = !score.mods.is_empty();
@@ -560,11 +595,36 @@ async fn process_scores(
             };
         }
 
+        if let Some(pp_version) = pp_version {
+            let mut score = ScoreSlim::new(score, old_pp);
+
+            let VersionedAttrs {
+                pp,
+                max_pp,
+                stars,
+                max_combo,
+            } = versioned_attrs(pp_version, &score, &map).await;
+
+            score.pp = pp;
+
+            let entry = TopIfEntry {
+                original_idx: i,
+                score,
+                old_pp,
+                map,
+                stars,
+                max_pp,
+                max_combo,
+            };
+
+            entries.push(entry);
+
+            continue;
+        }
+
         let mut calc = Context::pp(&map).mode(score.mode).mods(score.mods.clone());
         let attrs = calc.performance().await;
 
-        let old_pp = score.pp.unwrap_or(0.0);
-
         let new_pp = if changed {
             if let Some(attrs) = calc.score(&score).performance().await {
                 attrs.pp() as f32
@@ -11,8 +11,6 @@ use bathbot_util::{
     query::{FilterCriteria, IFilterCriteria, Searchable, TopCriteria},
 };
 use eyre::{Report, Result};
-use rosu_pp::any::DifficultyAttributes;
-use rosu_pp_older::*;
 use rosu_v2::{
     prelude::{GameMode, OsuError, Score},
     request::UserId,
@@ -24,16 +22,19 @@ use twilight_model::id::{Id, marker::UserMarker};
 use super::TopIfEntry;
 use crate::{
     Context,
-    active::{ActiveMessages, impls::TopIfPagination},
+    active::{
+        ActiveMessages,
+        impls::{TopIfPagination, TopOldVersion},
+    },
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{HasMods, ModsResult, TopIfScoreOrder, require_link, user_not_found},
+        osu::{
+            HasMods, ModsResult, TopIfScoreOrder, VersionedAttrs, require_link, user_not_found,
+            versioned_attrs,
+        },
     },
     core::commands::{CommandOrigin, prefix::Args},
-    manager::{
-        OsuMap,
-        redis::osu::{UserArgs, UserArgsError},
-    },
+    manager::redis::osu::{UserArgs, UserArgsError},
     util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -606,6 +607,15 @@ impl<'m> TopOld<'m> {
         Ok(args)
     }
 
+    fn version(&self) -> TopOldVersion {
+        match self {
+            TopOld::Osu(o) => TopOldVersion::Osu(o.version),
+            TopOld::Taiko(t) => TopOldVersion::Taiko(t.version),
+            TopOld::Catch(c) => TopOldVersion::Catch(c.version),
+            TopOld::Mania(m) => TopOldVersion::Mania(m.version),
+        }
+    }
+
     fn date_range(&self) -> &'static str {
         match self {
             TopOld::Osu(o) => match o.version {
@@ -650,237 +660,6 @@ impl<'m> TopOld<'m> {
     }
 }
 
-macro_rules! pp_std {
-    ($version:ident, $rosu_map:ident, $score:ident ) => {
-        pp_std!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.bits(),
-            $score.mods.bits(),
-        )
-    };
-
-    ($version:ident, $rosu_map:ident, $score:ident, lazer ) => {
-        pp_std!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.clone(),
-            $score.mods.clone(),
-            lazer: $score.set_on_lazer,
-            large_tick_hit: $score.statistics.large_tick_hit,
-            small_tick_hit: $score.statistics.small_tick_hit,
-            slider_end_hit: $score.statistics.slider_tail_hit,
-        )
-    };
-
-    (
-        $version:ident,
-        $rosu_map:ident,
-        $score:ident,
-        $max_mods:expr,
-        $curr_mods:expr,
-        $(
-            lazer: $lazer:expr,
-            large_tick_hit: $large_tick_hit:expr,
-            small_tick_hit: $small_tick_hit:expr,
-            slider_end_hit: $slider_end_hit:expr,
-        )?
-    ) => {{
-        let max_pp_res = $version::OsuPP::new($rosu_map)
-            .mods($max_mods)
-            $( .lazer($lazer) )?
-            .calculate();
-
-        let max_pp = max_pp_res.pp as f32;
-        let stars = max_pp_res.difficulty.stars as f32;
-
-        let attrs = $version::OsuPP::new($rosu_map)
-            .mods($curr_mods)
-            .attributes(max_pp_res.difficulty)
-            .n300($score.statistics.great)
-            .n100($score.statistics.ok)
-            .n50($score.statistics.meh)
-            .misses($score.statistics.miss)
-            .combo($score.max_combo)
-            $(
-                .lazer($lazer)
-                .large_tick_hits($large_tick_hit)
-                .small_tick_hits($small_tick_hit)
-                .slider_end_hits($slider_end_hit)
-            )?
-            .calculate();
-
-        let pp = attrs.pp as f32;
-        let max_combo = attrs.max_combo() as u32;
-
-        (pp, max_pp, stars, max_combo)
-    }};
-}
-
-macro_rules! pp_tko {
-    ($version:ident, $rosu_map:ident, $score:ident ) => {
-        pp_tko!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.bits(),
-            $score.mods.bits(),
-        )
-    };
-
-    ($version:ident, $rosu_map:ident, $score:ident, lazer) => {
-        pp_tko!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.clone(),
-            $score.mods.clone(),
-        )
-    };
-
-    (
-        $version:ident,
-        $rosu_map:ident,
-        $score:ident,
-        $max_mods:expr,
-        $curr_mods:expr,
-    ) => {{
-        let max_pp_res = $version::TaikoPP::new($rosu_map)
-            .mods($max_mods)
-            .calculate();
-
-        let max_pp = max_pp_res.pp as f32;
-        let stars = max_pp_res.difficulty.stars as f32;
-
-        let attrs = $version::TaikoPP::new($rosu_map)
-            .mods($curr_mods)
-            .attributes(max_pp_res.difficulty)
-            .n300($score.statistics.great)
-            .n100($score.statistics.ok)
-            .misses($score.statistics.miss)
-            .combo($score.max_combo)
-            .calculate();
-
-        let pp = attrs.pp as f32;
-        let max_combo = attrs.max_combo();
-
-        (pp, max_pp, stars, max_combo)
-    }};
-}
-
-macro_rules! pp_ctb {
-    ($version:ident, $rosu_map:ident, $score:ident ) => {
-        pp_ctb!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.bits(),
-            $score.mods.bits(),
-        )
-    };
-
-    ($version:ident, $rosu_map:ident, $score:ident, lazer) => {
-        pp_ctb!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.clone(),
-            $score.mods.clone(),
-        )
-    };
-
-    (
-        $version:ident,
-        $rosu_map:ident,
-        $score:ident,
-        $max_mods:expr,
-        $curr_mods:expr,
-    ) => {{
-        let max_pp_res = $version::FruitsPP::new($rosu_map)
-            .mods($max_mods)
-            .calculate();
-
-        let max_pp = max_pp_res.pp as f32;
-        let stars = max_pp_res.difficulty.stars as f32;
-        let stats = $score.statistics.as_legacy(GameMode::Catch);
-
-        let attrs = $version::FruitsPP::new($rosu_map)
-            .mods($curr_mods)
-            .attributes(max_pp_res.difficulty)
-            .fruits(stats.count_300)
-            .droplets(stats.count_100)
-            .tiny_droplets(stats.count_50)
-            .tiny_droplet_misses(stats.count_katu)
-            .misses(stats.count_miss)
-            .combo($score.max_combo)
-            .calculate();
-
-        let pp = attrs.pp as f32;
-        let max_combo = attrs.max_combo();
-
-        (pp, max_pp, stars, max_combo)
-    }};
-}
-
-macro_rules! pp_mna {
-    ($version:ident, $rosu_map:ident, $score:ident ) => {
-        pp_mna!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.bits(),
-            $score.mods.bits(),
-        )
-    };
-
-    ($version:ident, $rosu_map:ident, $score:ident, lazer) => {
-        pp_mna!(
-            $version,
-            $rosu_map,
-            $score,
-            $score.mods.clone(),
-            $score.mods.clone(),
-            lazer: $score.set_on_lazer,
-        )
-    };
-
-    (
-        $version:ident,
-        $rosu_map:ident,
-        $score:ident,
-        $max_mods:expr,
-        $curr_mods:expr,
-        $( lazer: $lazer:expr, )?
-    ) => {{
-        let max_pp_res = $version::ManiaPP::new($rosu_map)
-            .mods($max_mods)
-            $( .lazer($lazer) )?
-            .calculate();
-
-        let max_pp = max_pp_res.pp as f32;
-        let stars = max_pp_res.difficulty.stars as f32;
-
-        let attrs = $version::ManiaPP::new($rosu_map)
-            .mods($curr_mods)
-            .attributes(max_pp_res.difficulty)
-            .n320($score.statistics.perfect)
-            .n300($score.statistics.great)
-            .n200($score.statistics.good)
-            .n100($score.statistics.ok)
-            .n50($score.statistics.meh)
-            .misses($score.statistics.miss)
-            $( .lazer($lazer) )?
-            .calculate();
-
-        let pp = attrs.pp as f32;
-        let max_combo = attrs.max_combo();
-
-        (pp, max_pp, stars, max_combo)
-    }};
-}
-
 /// Same as `user_id!` but the args aren't passed by reference
 macro_rules! user_id_ref {
     ($orig:ident, $args:ident) => {
@@ -981,7 +760,7 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
         .to_native();
     let bonus_pp = pre_pp - actual_pp;
 
-    let mut entries = match process_scores(scores, &args).await {
+    let mut entries = match process_scores(scores, args.version()).await {
         Ok(scores) => scores,
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
@@ -1055,7 +834,7 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
         .await
 }
 
-async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<TopIfEntry>> {
+async fn process_scores(scores: Vec<Score>, version: TopOldVersion) -> Result<Vec<TopIfEntry>> {
     let mut entries = Vec::with_capacity(scores.len());
 
     let maps_id_checksum = scores
@@ -1076,148 +855,24 @@ async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<Top
         };
         map = map.convert(score.mode);
 
-        async fn use_current_system(score: &Score, map: &OsuMap) -> (f32, f32, f32, u32) {
-            let Some(attrs) = Context::pp(map)
-                .mode(score.mode)
-                .lazer(score.set_on_lazer)
-                .mods(score.mods.clone())
-                .performance()
-                .await
-            else {
-                return (0.0, 0.0, 0.0, 0);
-            };
+        let old_pp = score.pp.expect("missing pp");
+        let mut score = ScoreSlim::new(score, old_pp);
 
-            let pp = score.pp.expect("missing pp");
-            let max_pp = attrs.pp() as f32;
-            let stars = attrs.stars() as f32;
-            let max_combo = attrs.max_combo();
+        let VersionedAttrs {
+            pp,
+            max_pp,
+            stars,
+            max_combo,
+        } = versioned_attrs(version, &score, &map).await;
 
-            (pp, max_pp, stars, max_combo)
+        // Scores already come with the pp of the current system
+        if !version.is_current() {
+            score.pp = pp;
         }
 
-        let rosu_map = &map.pp_map;
-
-        let (new_pp, max_pp, stars, max_combo) = match args {
-            TopOld::Osu(o) => match o.version {
-                TopOldOsuVersion::May14July14 => {
-                    pp_std!(osu_2014_may, rosu_map, score)
-                }
-                TopOldOsuVersion::July14February15 => {
-                    pp_std!(osu_2014_july, rosu_map, score)
-                }
-                TopOldOsuVersion::February15April15 => {
-                    pp_std!(osu_2015_february, rosu_map, score)
-                }
-                TopOldOsuVersion::April15May18 => {
-                    pp_std!(osu_2015_april, rosu_map, score)
-                }
-                TopOldOsuVersion::May18February19 => {
-                    pp_std!(osu_2018, rosu_map, score)
-                }
-                TopOldOsuVersion::February19January21 => {
-                    pp_std!(osu_2019, rosu_map, score)
-                }
-                TopOldOsuVersion::January21July21 => {
-                    pp_std!(osu_2021_january, rosu_map, score)
-                }
-                TopOldOsuVersion::July21November21 => {
-                    pp_std!(osu_2021_july, rosu_map, score)
-                }
-                TopOldOsuVersion::November21September22 => {
-                    pp_std!(osu_2021_november, rosu_map, score)
-                }
-                TopOldOsuVersion::September22October24 => {
-                    pp_std!(osu_2022, rosu_map, score)
-                }
-                TopOldOsuVersion::October24March25 => {
-                    pp_std!(osu_2024, rosu_map, score, lazer)
-                }
-                TopOldOsuVersion::March25Now => use_current_system(&score, &map).await,
-            },
-            TopOld::Taiko(t) => match t.version {
-                TopOldTaikoVersion::March14September20 => {
-                    pp_tko!(taiko_ppv1, rosu_map, score)
-                }
-                TopOldTaikoVersion::September20September22 => {
-                    pp_tko!(taiko_2020, rosu_map, score)
-                }
-                TopOldTaikoVersion::September22October24 => {
-                    pp_tko!(taiko_2022, rosu_map, score)
-                }
-                TopOldTaikoVersion::October24March25 => {
-                    pp_tko!(taiko_2024, rosu_map, score, lazer)
-                }
-                TopOldTaikoVersion::March25Now => use_current_system(&score, &map).await,
-            },
-            TopOld::Catch(c) => match c.version {
-                TopOldCatchVersion::March14May20 => pp_ctb!(fruits_ppv1, rosu_map, score),
-                TopOldCatchVersion::May20October24 => {
-                    pp_ctb!(fruits_2022, rosu_map, score)
-                }
-                TopOldCatchVersion::October24Now => use_current_system(&score, &map).await,
-            },
-            TopOld::Mania(m) => match m.version {
-                TopOldManiaVersion::March14May18 => {
-                    let max_pp_res = mania_ppv1::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .calculate();
-
-                    let max_pp = max_pp_res.pp as f32;
-                    let stars = max_pp_res.difficulty.stars as f32;
-
-                    let attrs = mania_ppv1::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .attributes(max_pp_res)
-                        .score(score.score)
-                        .accuracy(score.accuracy)
-                        .calculate();
-
-                    let pp = attrs.pp as f32;
-
-                    // Suspicious maps are very unlikely to appear in top
-                    // scores so the `map_or` is fine; more of a sanity check.
-                    let max_combo = Context::pp(&map)
-                        .difficulty()
-                        .await
-                        .map_or(0, DifficultyAttributes::max_combo);
-
-                    (pp, max_pp, stars, max_combo)
-                }
-                TopOldManiaVersion::May18October22 => {
-                    let max_pp_res = mania_2018::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .calculate();
-
-                    let max_pp = max_pp_res.pp as f32;
-                    let stars = max_pp_res.difficulty.stars as f32;
-
-                    let attrs = mania_2018::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .attributes(max_pp_res)
-                        .score(score.score)
-                        .calculate();
-
-                    let pp = attrs.pp as f32;
-
-                    let max_combo = Context::pp(&map)
-                        .difficulty()
-                        .await
-                        .map_or(0, DifficultyAttributes::max_combo);
-
-                    (pp, max_pp, stars, max_combo)
-                }
-                TopOldManiaVersion::October22October24 => {
-                    pp_mna!(mania_2022, rosu_map, score)
-                }
-                TopOldManiaVersion::October24Now => use_current_system(&score, &map).await,
-            },
-        };
-
-        let old_pp = score.pp.expect("missing pp");
-
         let entry = TopIfEntry {
             original_idx: i,
-            score: ScoreSlim::new(score, new_pp),
+            score,
             old_pp,
             map,
             stars,
@@ -2,21 +2,16 @@ use std::{borrow::Cow, iter};
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{
-    MessageBuilder,
-    constants::GENERAL_ISSUE,
-    matcher,
-    osu::{ExtractablePp, PpListUtil},
-};
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher, osu::PpListUtil};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::user_not_found;
+use super::{PpVersion, TopPp, parse_pp_version, user_not_found};
 use crate::{
     Context,
-    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP, PP_VERSION_DESC, PP_VERSION_HELP},
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{EmbedData, WhatIfEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -69,6 +64,8 @@ pub struct WhatIf<'a> {
     count: Option<usize>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = PP_VERSION_DESC, help = PP_VERSION_HELP)]
+    pp_version: Option<PpVersion>,
 }
 
 impl<'m> WhatIf<'m> {
@@ -93,6 +90,7 @@ impl<'m> WhatIf<'m> {
             name,
             count: None,
             discord,
+            pp_version: None,
         })
     }
 }
@@ -198,6 +196,11 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
         return orig.error("Number too large").await;
     }
 
+    let pp_version = match parse_pp_version(mode, args.pp_version) {
+        Ok(pp_version) => pp_version,
+        Err(content) => return orig.error(content).await,
+    };
+
     // Retrieve the user and their top scores
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
     let scores_fut = Context::osu_scores()
@@ -219,7 +222,23 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
         }
     };
 
-    let whatif_data = if scores.is_empty() {
+    let user_pp = user
+        .statistics
+        .as_ref()
+        .expect("missing stats")
+        .pp
+        .to_native();
+
+    let top = match TopPp::new(user_pp, scores, pp_version).await {
+        Ok(top) => top,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to recalculate top scores"));
+        }
+    };
+
+    let whatif_data = if top.pps.is_empty() {
         let pp = iter::repeat(pp)
             .zip(0..)
             .take(count)
@@ -235,24 +254,19 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
         };
 
         WhatIfData::NoScores { count, rank }
-    } else if pp < scores.last().and_then(|s| s.pp).unwrap_or(0.0) {
+    } else if pp < top.pps.last().copied().unwrap_or(0.0) {
         WhatIfData::NonTop200
     } else {
-        let mut pps = scores.extract_pp();
+        let mut pps = top.pps.clone();
         let max_pp = pps.first().copied().unwrap_or(0.0);
         let actual = pps.accum_weighted();
-        let total = user
-            .statistics
-            .as_ref()
-            .expect("missing stats")
-            .pp
-            .to_native() as f64;
+        let total = top.total as f64;
         let bonus_pp = f64::max(total - actual, 0.0);
 
         let idx = pps
             .iter()
             .position(|&pp_| pp_ < pp)
-            .unwrap_or(scores.len() - 1);
+            .unwrap_or(pps.len() - 1);
 
         pps.extend(iter::repeat_n(pp, count));
         pps.sort_unstable_by(|a, b| b.total_cmp(a));
@@ -282,7 +296,7 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
     };
 
     // Sending the embed
-    let embed = WhatIfEmbed::new(&user, pp, whatif_data);
+    let embed = WhatIfEmbed::new(&user, pp, whatif_data, &top);
     let builder = MessageBuilder::new().embed(embed.build());
     orig.create_message(builder).await?;
 
@@ -5,18 +5,25 @@ use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::CowUtils;
 use eyre::Result;
 use rosu_v2::prelude::{GameMode, Username};
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 
-pub use self::{track::*, track_list::*, untrack::*, untrack_all::*};
+pub use self::{
+    track::*, track_digest::*, track_leaderboard::*, track_list::*, track_milestones::*,
+    untrack::*, untrack_all::*,
+};
 use crate::{
     Context,
     core::commands::prefix::{Args, ArgsNum},
     manager::redis::osu::{UserArgs, UserArgsError},
+    tracking::{TrackKind, TrackMapStatuses},
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
 mod track;
+mod track_digest;
+mod track_leaderboard;
 mod track_list;
+mod track_milestones;
 mod untrack;
 mod untrack_all;
 
@@ -26,6 +33,12 @@ mod untrack_all;
 pub enum Track {
     #[command(name = "add")]
     Add(TrackAdd),
+    #[command(name = "leaderboard")]
+    Leaderboard(TrackLeaderboard),
+    #[command(name = "milestones")]
+    Milestones(TrackMilestones),
+    #[command(name = "digest")]
+    Digest(TrackDigest),
     #[command(name = "remove")]
     Remove(TrackRemove),
     #[command(name = "list")]
@@ -72,6 +85,86 @@ pub struct TrackAdd {
         desc = "Scores must have at most X max combo percent (0-100; default 100)"
     )]
     max_combo_percent: Option<f32>,
+    #[command(
+        min_value = 0.0,
+        desc = "Scores must have at least X stars (default 0.0)"
+    )]
+    min_stars: Option<f32>,
+    #[command(min_value = 0.0, desc = "Scores must have at most X stars")]
+    max_stars: Option<f32>,
+    #[command(
+        desc = "Scores must fit these mods e.g. `+hdhr!`, `+hd`, `-ez!`, or `nm`",
+        help = "Specify mods through the explicit `+mod!` / `+mod` / `-mod!` syntax.\n\
+        `+hdhr!` only notifies about scores with exactly HDHR, `+hd` about scores that include HD, \
+        and `-ezht!` about scores without EZ and HT. Use `nm` or `+nm!` for NoMod scores only \
+        and `-nm!` to exclude NoMod scores."
+    )]
+    mods: Option<String>,
+    #[command(desc = "Scores must be on maps with this status (default any)")]
+    map_status: Option<TrackMapStatus>,
+    #[command(desc = "Specify a second username")]
+    name2: Option<String>,
+    #[command(desc = "Specify a third username")]
+    name3: Option<String>,
+    #[command(desc = "Specify a fourth username")]
+    name4: Option<String>,
+    #[command(desc = "Specify a fifth username")]
+    name5: Option<String>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum TrackMapStatus {
+    #[option(name = "Any", value = "any")]
+    Any,
+    #[option(name = "Ranked", value = "ranked")]
+    Ranked,
+    #[option(name = "Loved", value = "loved")]
+    Loved,
+    #[option(name = "Ranked or loved", value = "ranked_loved")]
+    RankedOrLoved,
+    #[option(name = "Qualified", value = "qualified")]
+    Qualified,
+}
+
+impl From<TrackMapStatus> for TrackMapStatuses {
+    fn from(status: TrackMapStatus) -> Self {
+        match status {
+            TrackMapStatus::Any => Self::all(),
+            TrackMapStatus::Ranked => Self::RANKED,
+            TrackMapStatus::Loved => Self::LOVED,
+            TrackMapStatus::RankedOrLoved => Self::RANKED | Self::LOVED,
+            TrackMapStatus::Qualified => Self::QUALIFIED,
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "leaderboard",
+    desc = "Track map leaderboard entries of a player",
+    help = "Add users to the leaderboard tracking list for this channel.\n\
+    If a tracked user sets a score that reaches the given global or country rank \
+    on a map leaderboard, this channel will be notified about it.\n\
+    If neither rank is specified, only global #1 scores will be notified.\n\
+    Note that country ranks are only determined within the global top 100."
+)]
+pub struct TrackLeaderboard {
+    #[command(desc = "Choose a username to be tracked")]
+    name: String,
+    #[command(desc = "Specify a mode for the tracked users")]
+    mode: GameModeOption,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Notify about scores reaching at least global rank X (1-100)"
+    )]
+    global_rank: Option<u8>,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Notify about scores reaching at least country rank X (1-100; within global top 100)"
+    )]
+    country_rank: Option<u8>,
     #[command(desc = "Specify a second username")]
     name2: Option<String>,
     #[command(desc = "Specify a third username")]
@@ -82,11 +175,78 @@ pub struct TrackAdd {
     name5: Option<String>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "milestones",
+    desc = "Track rank, pp, and playcount milestones of a player",
+    help = "Add users to the milestone tracking list for this channel.\n\
+    Whenever a tracked user reaches one of the given global ranks, a multiple of the given pp value, \
+    or a multiple of the given playcount, this channel will be notified about it.\n\
+    If no milestone is specified, the ranks 1,000, 5,000, and 10,000, every 1,000pp, \
+    and every 10,000 plays will be notified.\n\
+    Note that milestones are only noticed once the bot requests the user's profile again \
+    which happens at most every ten minutes while they're playing."
+)]
+pub struct TrackMilestones {
+    #[command(desc = "Choose a username to be tracked")]
+    name: String,
+    #[command(desc = "Specify a mode for the tracked users")]
+    mode: GameModeOption,
+    #[command(desc = "Global ranks to be notified about e.g. `1000,5000,10000`")]
+    ranks: Option<String>,
+    #[command(
+        min_value = 100,
+        desc = "Notify whenever a multiple of this pp value is reached e.g. 1000"
+    )]
+    pp_step: Option<u32>,
+    #[command(
+        min_value = 1000,
+        desc = "Notify whenever a multiple of this playcount is reached e.g. 10000"
+    )]
+    playcount_step: Option<u32>,
+    #[command(desc = "Specify a second username")]
+    name2: Option<String>,
+    #[command(desc = "Specify a third username")]
+    name3: Option<String>,
+    #[command(desc = "Specify a fourth username")]
+    name4: Option<String>,
+    #[command(desc = "Specify a fifth username")]
+    name5: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "digest",
+    desc = "Collect top scores and post them as a periodic summary",
+    help = "Instead of notifying this channel about every new top score of tracked users, \
+    collect the scores and post them as a single summary on a schedule.\n\
+    The summary shows the best plays, the pp gained, and the current rank of each user.\n\
+    Leaderboard tracking is not affected by this."
+)]
+pub struct TrackDigest {
+    #[command(desc = "How often the summary should be posted")]
+    interval: TrackDigestOption,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum TrackDigestOption {
+    #[option(name = "Off", value = "off")]
+    Off,
+    #[option(name = "Hourly", value = "hourly")]
+    Hourly,
+    #[option(name = "Daily", value = "daily")]
+    Daily,
+    #[option(name = "Weekly", value = "weekly")]
+    Weekly,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "remove",
     desc = "Untrack players in a channel",
-    help = "Untrack players in a channel i.e. stop sending notifications when they get new top scores"
+    help = "Untrack players in a channel i.e. stop sending notifications when they get new top scores, \
+    leaderboard entries, or milestones.\n\
+    When removing a specific user, you can choose to only remove one kind of tracking."
 )]
 pub enum TrackRemove {
     #[command(name = "user")]
@@ -102,6 +262,28 @@ pub struct TrackRemoveUser {
     name: String,
     #[command(desc = "Specify an optional mode for the tracked user")]
     mode: Option<GameModeOption>,
+    #[command(desc = "Specify which kind of tracking to remove (default all)")]
+    kind: Option<TrackKindOption>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum TrackKindOption {
+    #[option(name = "Top scores", value = "top_scores")]
+    TopScores,
+    #[option(name = "Leaderboard", value = "leaderboard")]
+    Leaderboard,
+    #[option(name = "Milestones", value = "milestones")]
+    Milestones,
+}
+
+impl From<TrackKindOption> for TrackKind {
+    fn from(kind: TrackKindOption) -> Self {
+        match kind {
+            TrackKindOption::TopScores => Self::TopScores,
+            TrackKindOption::Leaderboard => Self::Leaderboard,
+            TrackKindOption::Milestones => Self::Milestones,
+        }
+    }
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -121,7 +303,14 @@ pub struct TrackList;
 async fn slash_track(mut command: InteractionCommand) -> Result<()> {
     match Track::from_interaction(command.input_data())? {
         Track::Add(add) => track((&mut command).into(), add.into()).await,
-        Track::Remove(TrackRemove::User(user)) => untrack((&mut command).into(), user.into()).await,
+        Track::Leaderboard(args) => track_leaderboard((&mut command).into(), args).await,
+        Track::Milestones(args) => track_milestones((&mut command).into(), args).await,
+        Track::Digest(args) => track_digest((&mut command).into(), args).await,
+        Track::Remove(TrackRemove::User(user)) => {
+            let kind = user.kind.map(TrackKind::from);
+
+            untrack((&mut command).into(), user.into(), kind).await
+        }
         Track::Remove(TrackRemove::All(all)) => {
             untrackall((&mut command).into(), all.mode.map(GameMode::from)).await
         }
@@ -171,6 +360,10 @@ struct TrackArgs {
     max_pp: Option<f32>,
     min_combo_percent: Option<f32>,
     max_combo_percent: Option<f32>,
+    min_stars: Option<f32>,
+    max_stars: Option<f32>,
+    mods: Option<String>,
+    map_statuses: Option<TrackMapStatuses>,
     more_names: Vec<String>,
 }
 
@@ -226,6 +419,10 @@ impl TrackArgs {
             max_pp: None,
             min_combo_percent: None,
             max_combo_percent: None,
+            min_stars: None,
+            max_stars: None,
+            mods: None,
+            map_statuses: None,
             more_names,
             mode,
         };
@@ -245,6 +442,10 @@ impl From<TrackAdd> for TrackArgs {
             max_pp,
             min_combo_percent,
             max_combo_percent,
+            min_stars,
+            max_stars,
+            mods,
+            map_status,
             name2,
             name3,
             name4,
@@ -279,13 +480,21 @@ impl From<TrackAdd> for TrackArgs {
             max_pp,
             min_combo_percent,
             max_combo_percent,
+            min_stars,
+            max_stars,
+            mods,
+            map_statuses: map_status.map(TrackMapStatuses::from),
         }
     }
 }
 
 impl From<TrackRemoveUser> for TrackArgs {
     fn from(remove: TrackRemoveUser) -> Self {
-        let TrackRemoveUser { name, mode } = remove;
+        let TrackRemoveUser {
+            name,
+            mode,
+            kind: _,
+        } = remove;
 
         Self {
             mode: mode.map(GameMode::from),
@@ -297,6 +506,10 @@ impl From<TrackRemoveUser> for TrackArgs {
             max_pp: None,
             min_combo_percent: None,
             max_combo_percent: None,
+            min_stars: None,
+            max_stars: None,
+            mods: None,
+            map_statuses: None,
         }
     }
 }
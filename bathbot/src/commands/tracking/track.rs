@@ -1,7 +1,12 @@
 use std::fmt::Write;
 
 use bathbot_macros::command;
-use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields};
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::GENERAL_ISSUE,
+    fields,
+    osu::{ModSelection, ModsResult},
+};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError};
 
@@ -25,6 +30,10 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         max_pp,
         min_combo_percent,
         max_combo_percent,
+        min_stars,
+        max_stars,
+        mods,
+        map_statuses,
     } = args;
 
     more_names.push(name);
@@ -37,6 +46,23 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
 
     let mode = mode.unwrap_or(GameMode::Osu);
 
+    let mods = match ModSelection::parse(mods.as_deref().map(str::trim)) {
+        ModsResult::Mods(mods) => Some(mods),
+        ModsResult::None => None,
+        ModsResult::Invalid => {
+            let content = "Failed to parse mods.\n\
+            If you want included mods, specify it e.g. as `+hrdt`.\n\
+            If you want exact mods, specify it e.g. as `+hdhr!`.\n\
+            And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    if let Some(Err(content)) = mods.clone().map(|mods| mods.validate(mode)) {
+        return orig.error(content).await;
+    }
+
     let users = match super::get_names(&more_names, mode).await {
         Ok(users) => users,
         Err((UserArgsError::Osu(OsuError::NotFound), name)) => {
@@ -55,14 +81,17 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     let params = TrackEntryParams::new()
         .with_index(min_index, max_index)
         .with_pp(min_pp, max_pp)
-        .with_combo_percent(min_combo_percent, max_combo_percent);
+        .with_combo_percent(min_combo_percent, max_combo_percent)
+        .with_stars(min_stars, max_stars)
+        .with_mods(mods)
+        .with_map_statuses(map_statuses);
 
     let channel = orig.channel_id();
     let mut success = Vec::with_capacity(users.len());
     let mut failure = Vec::new();
 
     for (username, user_id) in users {
-        let require = match OsuTracking::add_user(user_id, mode, channel, params).await {
+        let require = match OsuTracking::add_user(user_id, mode, channel, params.clone()).await {
             Ok(Some(require)) => require,
             Ok(None) => {
                 success.push(username);
@@ -122,13 +151,15 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         fields![fields { "Failed to track:".to_owned(), value, false }];
     }
 
-    let value = format!(
+    let mut value = format!(
         "`Index: {index}` | `PP: {pp}pp` | `Combo percent: {combo_percent}%`",
         index = params.index(),
         pp = params.pp(),
         combo_percent = params.combo_percent(),
     );
 
+    params.write_extra_filters(&mut value, " | ");
+
     fields![fields { "Parameters:".to_owned(), value, false }];
 
     let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
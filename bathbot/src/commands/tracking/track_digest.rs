@@ -0,0 +1,36 @@
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+
+use super::{TrackDigest, TrackDigestOption};
+use crate::{
+    core::commands::CommandOrigin,
+    tracking::{OsuTracking, TrackDigestInterval},
+};
+
+pub(super) async fn track_digest(orig: CommandOrigin<'_>, args: TrackDigest) -> Result<()> {
+    let interval = match args.interval {
+        TrackDigestOption::Off => None,
+        TrackDigestOption::Hourly => Some(TrackDigestInterval::Hourly),
+        TrackDigestOption::Daily => Some(TrackDigestInterval::Daily),
+        TrackDigestOption::Weekly => Some(TrackDigestInterval::Weekly),
+    };
+
+    if let Err(err) = OsuTracking::set_digest(orig.channel_id(), interval).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err.wrap_err("Failed to set tracking digest"));
+    }
+
+    let content = match interval {
+        Some(interval) => format!(
+            "New top scores of tracked users will now be collected \
+            and posted {interval} as a single summary"
+        ),
+        None => "New top scores of tracked users will now be posted immediately".to_owned(),
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
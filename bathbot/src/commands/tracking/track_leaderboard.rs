@@ -0,0 +1,111 @@
+use std::fmt::Write;
+
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+
+use super::TrackLeaderboard;
+use crate::{
+    core::commands::CommandOrigin,
+    manager::redis::osu::UserArgsError,
+    tracking::{OsuTracking, TrackLeaderboardParams},
+    util::Emote,
+};
+
+pub(super) async fn track_leaderboard(
+    orig: CommandOrigin<'_>,
+    args: TrackLeaderboard,
+) -> Result<()> {
+    let TrackLeaderboard {
+        name,
+        mode,
+        global_rank,
+        country_rank,
+        name2,
+        name3,
+        name4,
+        name5,
+    } = args;
+
+    let mut names = vec![name];
+    names.extend([name2, name3, name4, name5].into_iter().flatten());
+
+    if let Some(name) = names.iter().find(|name| name.len() > 15) {
+        let content = format!("`{name}` is too long for an osu! username");
+
+        return orig.error(content).await;
+    }
+
+    let mode = GameMode::from(mode);
+
+    let users = match super::get_names(&names, mode).await {
+        Ok(users) => users,
+        Err((UserArgsError::Osu(OsuError::NotFound), name)) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(content).await;
+        }
+        Err((err, _)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get names");
+
+            return Err(err);
+        }
+    };
+
+    let params = TrackLeaderboardParams::new(global_rank, country_rank);
+    let channel = orig.channel_id();
+    let mut success = Vec::with_capacity(users.len());
+    let mut failure = Vec::new();
+
+    for (username, user_id) in users {
+        match OsuTracking::add_leaderboard_user(user_id, mode, channel, params).await {
+            Ok(()) => success.push(username),
+            Err(err) => {
+                warn!(?err, "Failed to track osu leaderboard user");
+                failure.push(username);
+            }
+        }
+    }
+
+    let mut fields = Vec::with_capacity(3);
+    let mut iter = success.iter();
+
+    if let Some(name) = iter.next() {
+        let mut value = String::new();
+        let _ = write!(value, "`{name}`");
+
+        for name in iter {
+            let _ = write!(value, ", `{name}`");
+        }
+
+        fields![fields { "Now tracking:".to_owned(), value, false }];
+    }
+
+    let mut iter = failure.iter();
+
+    if let Some(name) = iter.next() {
+        let mut value = String::new();
+        let _ = write!(value, "`{name}`");
+
+        for name in iter {
+            let _ = write!(value, ", `{name}`");
+        }
+
+        fields![fields { "Failed to track:".to_owned(), value, false }];
+    }
+
+    fields![fields { "Parameters:".to_owned(), params.to_string(), false }];
+
+    let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
+
+    let embed = EmbedBuilder::new()
+        .fields(fields)
+        .footer(footer)
+        .title("Leaderboard tracking");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
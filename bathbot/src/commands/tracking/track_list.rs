@@ -11,14 +11,30 @@ use crate::{
     active::{ActiveMessages, impls::TrackListPagination},
     core::commands::CommandOrigin,
     manager::redis::osu::{UserArgs, UserArgsError},
-    tracking::{OsuTracking, TrackEntryParams},
+    tracking::{OsuTracking, TrackEntryParams, TrackLeaderboardParams, TrackMilestoneParams},
 };
 
 pub struct TracklistUserEntry {
     pub name: Username,
     pub user_id: u32,
     pub mode: GameMode,
-    pub params: TrackEntryParams,
+    pub params: TracklistParams,
+}
+
+pub enum TracklistParams {
+    TopScores(TrackEntryParams),
+    Leaderboard(TrackLeaderboardParams),
+    Milestones(TrackMilestoneParams),
+}
+
+impl TracklistParams {
+    const fn order(&self) -> u8 {
+        match self {
+            Self::TopScores(_) => 0,
+            Self::Leaderboard(_) => 1,
+            Self::Milestones(_) => 2,
+        }
+    }
 }
 
 #[command]
@@ -33,8 +49,21 @@ async fn prefix_tracklist(msg: &Message) -> Result<()> {
 pub async fn tracklist(orig: CommandOrigin<'_>) -> Result<()> {
     let channel_id = orig.channel_id();
 
-    let entries = match OsuTracking::tracked_users_in_channel(channel_id).await {
-        Ok(entries) => entries,
+    let top_fut = OsuTracking::tracked_users_in_channel(channel_id);
+    let leaderboard_fut = OsuTracking::tracked_leaderboard_users_in_channel(channel_id);
+    let milestone_fut = OsuTracking::tracked_milestone_users_in_channel(channel_id);
+
+    let entries: Vec<_> = match tokio::try_join!(top_fut, leaderboard_fut, milestone_fut) {
+        Ok((top, leaderboard, milestones)) => top
+            .into_iter()
+            .map(|(user_id, mode, params)| (user_id, mode, TracklistParams::TopScores(params)))
+            .chain(leaderboard.into_iter().map(|(user_id, mode, params)| {
+                (user_id, mode, TracklistParams::Leaderboard(params))
+            }))
+            .chain(milestones.into_iter().map(|(user_id, mode, params)| {
+                (user_id, mode, TracklistParams::Milestones(params))
+            }))
+            .collect(),
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -55,6 +84,7 @@ pub async fn tracklist(orig: CommandOrigin<'_>) -> Result<()> {
         (a.mode as u8)
             .cmp(&(b.mode as u8))
             .then(a.name.cmp(&b.name))
+            .then(a.params.order().cmp(&b.params.order()))
     });
 
     let pagination = TrackListPagination::builder()
@@ -70,7 +100,7 @@ pub async fn tracklist(orig: CommandOrigin<'_>) -> Result<()> {
 
 async fn get_users(
     channel: Id<ChannelMarker>,
-    tracked: Vec<(u32, GameMode, TrackEntryParams)>,
+    tracked: Vec<(u32, GameMode, TracklistParams)>,
 ) -> Result<Vec<TracklistUserEntry>, UserArgsError> {
     let user_ids: Vec<_> = tracked
         .iter()
@@ -109,7 +139,7 @@ async fn get_users(
                         params,
                     },
                     Err(UserArgsError::Osu(OsuError::NotFound)) => {
-                        OsuTracking::remove_user(user_id, None, channel).await;
+                        OsuTracking::remove_user(user_id, None, channel, None).await;
 
                         continue;
                     }
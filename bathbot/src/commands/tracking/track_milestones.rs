@@ -0,0 +1,132 @@
+use std::fmt::Write;
+
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+
+use super::TrackMilestones;
+use crate::{
+    core::commands::CommandOrigin,
+    manager::redis::osu::UserArgsError,
+    tracking::{OsuTracking, TrackMilestoneParams},
+    util::Emote,
+};
+
+pub(super) async fn track_milestones(orig: CommandOrigin<'_>, args: TrackMilestones) -> Result<()> {
+    let TrackMilestones {
+        name,
+        mode,
+        ranks,
+        pp_step,
+        playcount_step,
+        name2,
+        name3,
+        name4,
+        name5,
+    } = args;
+
+    let mut names = vec![name];
+    names.extend([name2, name3, name4, name5].into_iter().flatten());
+
+    if let Some(name) = names.iter().find(|name| name.len() > 15) {
+        let content = format!("`{name}` is too long for an osu! username");
+
+        return orig.error(content).await;
+    }
+
+    let ranks = match ranks.as_deref().map(parse_ranks).transpose() {
+        Ok(ranks) => ranks.unwrap_or_default(),
+        Err(content) => return orig.error(content).await,
+    };
+
+    let mode = GameMode::from(mode);
+
+    let users = match super::get_names(&names, mode).await {
+        Ok(users) => users,
+        Err((UserArgsError::Osu(OsuError::NotFound), name)) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(content).await;
+        }
+        Err((err, _)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get names");
+
+            return Err(err);
+        }
+    };
+
+    let params = TrackMilestoneParams::new(ranks, pp_step, playcount_step);
+    let channel = orig.channel_id();
+    let mut success = Vec::with_capacity(users.len());
+    let mut failure = Vec::new();
+
+    for (username, user_id) in users {
+        match OsuTracking::add_milestone_user(user_id, mode, channel, params.clone()).await {
+            Ok(()) => success.push(username),
+            Err(err) => {
+                warn!(?err, "Failed to track osu milestone user");
+                failure.push(username);
+            }
+        }
+    }
+
+    let mut fields = Vec::with_capacity(3);
+    let mut iter = success.iter();
+
+    if let Some(name) = iter.next() {
+        let mut value = String::new();
+        let _ = write!(value, "`{name}`");
+
+        for name in iter {
+            let _ = write!(value, ", `{name}`");
+        }
+
+        fields![fields { "Now tracking:".to_owned(), value, false }];
+    }
+
+    let mut iter = failure.iter();
+
+    if let Some(name) = iter.next() {
+        let mut value = String::new();
+        let _ = write!(value, "`{name}`");
+
+        for name in iter {
+            let _ = write!(value, ", `{name}`");
+        }
+
+        fields![fields { "Failed to track:".to_owned(), value, false }];
+    }
+
+    fields![fields { "Parameters:".to_owned(), params.to_string(), false }];
+
+    let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
+
+    let embed = EmbedBuilder::new()
+        .fields(fields)
+        .footer(footer)
+        .title("Milestone tracking");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+fn parse_ranks(ranks: &str) -> Result<Vec<u32>, String> {
+    ranks
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|rank| !rank.is_empty())
+        .map(|rank| {
+            let trimmed = rank.trim_start_matches('#').replace('_', "");
+
+            match trimmed.parse() {
+                Ok(0) | Err(_) => Err(format!(
+                    "Failed to parse `{rank}` as rank; must be a positive integer"
+                )),
+                Ok(rank) => Ok(rank),
+            }
+        })
+        .collect()
+}
@@ -7,7 +7,9 @@ use rosu_v2::prelude::{GameMode, OsuError};
 
 use super::TrackArgs;
 use crate::{
-    core::commands::CommandOrigin, manager::redis::osu::UserArgsError, tracking::OsuTracking,
+    core::commands::CommandOrigin,
+    manager::redis::osu::UserArgsError,
+    tracking::{OsuTracking, TrackKind},
     util::ChannelExt,
 };
 
@@ -15,7 +17,7 @@ use crate::{
 #[desc("Untrack user top scores in a channel")]
 #[help(
     "Stop notifying a channel about new plays in a user's top100.\n\
-    Specified users will be untracked for all modes.\n\
+    Specified users will be untracked for all modes and all kinds of tracking.\n\
     You can specify up to ten usernames per command invocation."
 )]
 #[usage("[username1] [username2] ...")]
@@ -24,7 +26,7 @@ use crate::{
 #[group(Tracking)]
 async fn prefix_untrack(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(None, args).await {
-        Ok(args) => untrack(msg.into(), args).await,
+        Ok(args) => untrack(msg.into(), args, None).await,
         Err(content) => {
             msg.error(content).await?;
 
@@ -33,7 +35,11 @@ async fn prefix_untrack(msg: &Message, args: Args<'_>) -> Result<()> {
     }
 }
 
-pub(super) async fn untrack(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()> {
+pub(super) async fn untrack(
+    orig: CommandOrigin<'_>,
+    args: TrackArgs,
+    kind: Option<TrackKind>,
+) -> Result<()> {
     let TrackArgs {
         name,
         mode,
@@ -68,7 +74,7 @@ pub(super) async fn untrack(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<
     let mut success = HashSet::with_capacity(users.len());
 
     for (username, user_id) in users {
-        OsuTracking::remove_user(user_id, mode, channel).await;
+        OsuTracking::remove_user(user_id, mode, channel, kind).await;
         success.insert(username);
     }
 
@@ -87,9 +93,14 @@ pub(super) async fn untrack(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<
         description.push_str("None");
     }
 
-    let embed = EmbedBuilder::new()
-        .title("Top score tracking")
-        .description(description);
+    let title = match kind {
+        Some(TrackKind::TopScores) => "Top score tracking",
+        Some(TrackKind::Leaderboard) => "Leaderboard tracking",
+        Some(TrackKind::Milestones) => "Milestone tracking",
+        None => "Tracking",
+    };
+
+    let embed = EmbedBuilder::new().title(title).description(description);
 
     let builder = MessageBuilder::new().embed(embed);
     orig.create_message(builder).await?;
@@ -0,0 +1,72 @@
+use bathbot_macros::SlashCommand;
+use bathbot_util::{Authored, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::{BotConfig, Context},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "apitoken",
+    desc = "Manage your token for the bot's web API",
+    help = "Manage your token for the bot's read-only web API.\n\
+    Requests to `/api/v1/...` must contain the header `Authorization: Bearer <token>`.\n\
+    Available endpoints are `/bookmarks`, `/channels/{channel_id}/tracked`, \
+    `/leaderboards/bg?variant=...`, `/leaderboards/hl?version=...` and `/maps/{map_id}`.\n\
+    Each user can only have one token at a time so creating a new one invalidates the previous one."
+)]
+#[flags(EPHEMERAL)]
+pub enum ApiToken {
+    #[command(name = "create")]
+    Create(ApiTokenCreate),
+    #[command(name = "revoke")]
+    Revoke(ApiTokenRevoke),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "create",
+    desc = "Create a new token, invalidating your previous one"
+)]
+pub struct ApiTokenCreate;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "revoke", desc = "Invalidate your current token")]
+pub struct ApiTokenRevoke;
+
+async fn slash_apitoken(mut command: InteractionCommand) -> Result<()> {
+    let user_id = command.user_id()?;
+
+    let content = match ApiToken::from_interaction(command.input_data())? {
+        ApiToken::Create(_) => match Context::api_token().create(user_id).await {
+            Ok(token) => format!(
+                "Your new API token: ||`{token}`||\n\
+                Keep it private, it won't be shown again.\n\
+                Base url: `{}/api/v1`",
+                BotConfig::get().server.public_url
+            ),
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        ApiToken::Revoke(_) => match Context::api_token().revoke(user_id).await {
+            Ok(true) => "Successfully revoked your API token".to_owned(),
+            Ok(false) => "You don't have an API token".to_owned(),
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
@@ -582,6 +582,8 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        card_theme,
+        card_layout,
     } = config;
 
     UserConfig {
@@ -594,6 +596,8 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        card_theme,
+        card_layout,
     }
 }
 
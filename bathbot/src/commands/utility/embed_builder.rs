@@ -22,7 +22,11 @@ use twilight_model::id::{
 };
 
 use crate::{
-    active::{ActiveMessages, impls::ScoreEmbedBuilderActive},
+    active::{
+        ActiveMessages,
+        impls::{ScoreEmbedBuilderActive, TopOldVersion},
+    },
+    commands::osu::{VersionedAttrs, versioned_attrs},
     core::Context,
     manager::{MapError, OsuMap, PpManager, redis::osu::UserArgsSlim},
     util::{InteractionCommandExt, interaction::InteractionCommand, osu::IfFc},
@@ -205,7 +209,7 @@ async fn exec(
         }
     };
 
-    let mut data = ScoreEmbedDataWrap::new_custom(score, map, 71, Some(7)).await;
+    let mut data = ScoreEmbedDataWrap::new_custom(score, map, Some(71), Some(7)).await;
 
     // Adjusting hitresults to better showcase the "Ratio" value
     if let ScoreEmbedDataStatus::Full(ref mut data) = data.inner {
@@ -278,7 +282,7 @@ impl ScoreEmbedDataWrap {
     pub async fn new_custom(
         score: Score,
         map: OsuMap,
-        pb_idx: usize,
+        pb_idx: Option<usize>,
         global_idx: Option<usize>,
     ) -> Self {
         let PpAttrs {
@@ -317,9 +321,10 @@ impl ScoreEmbedDataWrap {
                 max_pp,
                 replay_score_id: None,
                 miss_analyzer: None,
-                pb_idx: Some(ScoreEmbedDataPersonalBest::from_index(pb_idx)),
+                pb_idx: pb_idx.map(ScoreEmbedDataPersonalBest::from_index),
                 global_idx,
                 if_fc_pp,
+                pp_version: None,
                 #[cfg(feature = "twitch")]
                 twitch: None,
             }),
@@ -560,6 +565,7 @@ impl ScoreEmbedDataHalf {
             pb_idx: self.pb_idx,
             global_idx,
             if_fc_pp,
+            pp_version: None,
             #[cfg(feature = "twitch")]
             twitch: None,
         }
@@ -597,10 +603,41 @@ pub struct ScoreEmbedData {
     pub pb_idx: Option<ScoreEmbedDataPersonalBest>,
     pub global_idx: Option<usize>,
     pub if_fc_pp: Option<f32>,
+    /// Previous pp version that pp and stars were recalculated with
+    pub pp_version: Option<TopOldVersion>,
     #[cfg(feature = "twitch")]
     pub twitch: Option<Arc<TwitchData>>,
 }
 
+impl ScoreEmbedData {
+    /// Recalculates pp, max pp, stars, and max combo with the given pp
+    /// version.
+    ///
+    /// Does nothing if the version is already applied or belongs to a
+    /// different mode.
+    pub async fn apply_pp_version(&mut self, version: TopOldVersion) {
+        if self.pp_version == Some(version) || version.mode() != self.score.mode {
+            return;
+        }
+
+        let VersionedAttrs {
+            pp,
+            max_pp,
+            stars,
+            max_combo,
+        } = versioned_attrs(version, &self.score, &self.map).await;
+
+        self.score.pp = pp;
+        self.max_pp = max_pp;
+        self.stars = stars;
+        self.max_combo = max_combo;
+
+        // The if-fc value was calculated with the current pp version
+        self.if_fc_pp = None;
+        self.pp_version = Some(version);
+    }
+}
+
 #[cfg(feature = "twitch")]
 pub enum TwitchData {
     Vod {
@@ -924,6 +961,7 @@ impl ScoreEmbedDataRaw {
             pb_idx,
             global_idx,
             if_fc_pp,
+            pp_version: None,
             #[cfg(feature = "twitch")]
             twitch: self.twitch,
         })
@@ -1,3 +1,5 @@
+#[cfg(feature = "server")]
+mod api_token;
 mod authorities;
 mod changelog;
 mod commands;
@@ -25,6 +25,15 @@ pub struct BotConfig {
     pub redis_port: u16,
     pub redis_db_idx: u8,
     pub scores_ws_port: u16,
+    /// If specified, scores are replayed from this file instead of being
+    /// received through the scores websocket. A value of `-` reads from stdin.
+    pub scores_file: Option<PathBuf>,
+    /// Speed multiplier for replaying scores from [`BotConfig::scores_file`].
+    /// A value of `0.0` replays scores without any delay.
+    pub scores_file_speed: f32,
+    /// If specified, replays are rendered through the danser service at this
+    /// url instead of through o!rdr.
+    pub danser_url: Option<Box<str>>,
     pub owner: Id<UserMarker>,
     pub dev_guild: Id<GuildMarker>,
     pub hl_channel: Id<ChannelMarker>,
@@ -122,6 +131,9 @@ impl BotConfig {
             redis_port: env_var("REDIS_PORT")?,
             redis_db_idx: env_var("REDIS_DB_IDX")?,
             scores_ws_port: env_var("SCORES_WS_PORT")?,
+            scores_file: env_var_opt("SCORES_FILE")?,
+            scores_file_speed: env_var_opt("SCORES_FILE_SPEED")?.unwrap_or(1.0),
+            danser_url: env_var_opt("DANSER_URL")?,
             owner: env_var("OWNER_USER_ID")?,
             dev_guild: env_var("DEV_GUILD_ID")?,
             hl_channel: env_var("HL_IMAGE_CHANNEL")?,
@@ -188,6 +200,7 @@ env_kind! {
     u8: |s| { s.parse().map_err(|_| s) },
     u16: |s| { s.parse().map_err(|_| s) },
     u64: |s| { s.parse().map_err(|_| s) },
+    f32: |s| { s.parse().map_err(|_| s) },
     PathBuf: |s| { s.parse().map_err(|_| s) },
     Id<UserMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
     Id<GuildMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
@@ -220,6 +233,14 @@ fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     })
 }
 
+fn env_var_opt<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    match env::var(name) {
+        Ok(value) if value.is_empty() => Ok(None),
+        Ok(_) => env_var(name).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
 trait AsUsize {
     fn to_usize(self) -> usize;
 }
@@ -3,9 +3,10 @@ use rosu_v2::prelude::GameMode;
 
 use super::Context;
 use crate::manager::{
-    ApproxManager, BookmarkManager, GameManager, GithubManager, GuildConfigManager,
-    HuismetbenenCountryManager, MapManager, OsuMap, OsuUserManager, PpManager, ReplayManager,
-    ScoresManager, UserConfigManager, redis::RedisManager,
+    ApproxManager, BookmarkManager, CardSkillsManager, ChallengeManager, GameManager,
+    GithubManager, GuildConfigManager, HuismetbenenCountryManager, MapManager, MappoolManager,
+    OsuMap, OsuUserManager, PpManager, ReplayManager, ScoresManager, UserConfigManager,
+    redis::RedisManager,
 };
 
 impl Context {
@@ -44,10 +45,32 @@ impl Context {
         crate::manager::TwitchManager::new()
     }
 
+    #[cfg(feature = "server")]
+    pub fn api_token() -> crate::manager::ApiTokenManager {
+        crate::manager::ApiTokenManager::new()
+    }
+
+    #[cfg(feature = "matchlive")]
+    pub fn match_live() -> crate::manager::MatchLiveManager {
+        crate::manager::MatchLiveManager::new()
+    }
+
     pub fn bookmarks() -> BookmarkManager {
         BookmarkManager::new()
     }
 
+    pub fn card_skills() -> CardSkillsManager {
+        CardSkillsManager::new()
+    }
+
+    pub fn mappools() -> MappoolManager {
+        MappoolManager::new()
+    }
+
+    pub fn challenges() -> ChallengeManager {
+        ChallengeManager::new()
+    }
+
     pub fn replay() -> ReplayManager {
         let ctx = Self::get();
 
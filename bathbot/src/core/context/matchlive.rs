@@ -1,12 +1,31 @@
-use std::{collections::hash_map::Entry, slice};
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    slice,
+};
 
+use bathbot_psql::model::osu::DbMatchLiveChannel;
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
 use rosu_v2::prelude::{MatchEvent, OsuError};
+use smallvec::SmallVec;
 use tokio::time::{Duration, interval};
-use twilight_model::id::{Id, marker::ChannelMarker};
+use twilight_model::{
+    channel::message::embed::Embed,
+    id::{
+        Id,
+        marker::{ChannelMarker, MessageMarker},
+    },
+};
 
 use super::Context;
 use crate::{
+    active::impls::MatchCostPagination,
+    commands::osu::{
+        MatchCostFormulaKind, MatchResult, ModMultipliers, pool_breakdown, prepare_games,
+        process_match, retrieve_previous,
+    },
     embeds::MatchLiveEmbed,
+    manager::Mappool,
     matchlive::{Channel, MatchEntry, MatchTrackResult, TrackedMatch, send_match_messages},
     util::ChannelExt,
 };
@@ -30,7 +49,11 @@ impl Context {
             .map(|(key, _)| *key)
     }
 
-    pub async fn add_match_track(channel: Id<ChannelMarker>, match_id: u32) -> MatchTrackResult {
+    pub async fn add_match_track(
+        channel: Id<ChannelMarker>,
+        match_id: u32,
+        mappool: Option<Mappool>,
+    ) -> MatchTrackResult {
         let mut match_live = Context::get().data.matchlive.inner.lock().await;
 
         // Increment the track counter for the channel
@@ -54,6 +77,21 @@ impl Context {
                     return MatchTrackResult::Duplicate;
                 }
 
+                // Only label upcoming games if the match had no mappool yet
+                if entry.tracked.mappool.is_none() && mappool.is_some() {
+                    entry.tracked.mappool = mappool;
+
+                    let store_fut = Context::match_live().store_match(
+                        match_id,
+                        entry.tracked.osu_match.latest_event_id,
+                        entry.tracked.mappool.as_ref(),
+                    );
+
+                    if let Err(err) = store_fut.await {
+                        warn!(?err, "Failed to store mappool of tracked match");
+                    }
+                }
+
                 let embeds = &entry.tracked.embeds;
 
                 let channel = match send_match_messages(channel, embeds).await {
@@ -65,16 +103,22 @@ impl Context {
                     }
                 };
 
-                let id = channel.id;
+                let Channel { id, msg_id } = channel;
                 entry.channels.push(channel);
                 *match_live.channel_count.entry(id).or_insert(0) += 1;
 
+                let store_fut = Context::match_live().store_channel(match_id, id, msg_id);
+
+                if let Err(err) = store_fut.await {
+                    warn!(?err, "Failed to store match live channel");
+                }
+
                 MatchTrackResult::Added
             }
             // The match is not yet tracked -> request and store it
             Entry::Vacant(e) => match Context::osu().osu_match(match_id).await {
                 Ok(osu_match) => {
-                    let embeds = MatchLiveEmbed::new(&osu_match);
+                    let embeds = MatchLiveEmbed::new(&osu_match, mappool.as_ref());
 
                     let channel = match send_match_messages(channel, &embeds).await {
                         Ok(msg) => Channel::new(channel, msg),
@@ -87,8 +131,21 @@ impl Context {
 
                     // Only add to tracking if it's not already disbanded
                     if !matches!(osu_match.events.last(), Some(MatchEvent::Disbanded { .. })) {
-                        let tracked_match = TrackedMatch::new(osu_match, embeds);
-                        let id = channel.id;
+                        let Channel { id, msg_id } = channel;
+
+                        let store_fut = store_match_track(
+                            match_id,
+                            osu_match.latest_event_id,
+                            mappool.as_ref(),
+                            id,
+                            msg_id,
+                        );
+
+                        if let Err(err) = store_fut.await {
+                            warn!(?err, "Failed to store tracked match");
+                        }
+
+                        let tracked_match = TrackedMatch::new(osu_match, embeds, mappool);
                         e.insert(MatchEntry::new(tracked_match, channel));
                         *match_live.channel_count.entry(id).or_insert(0) += 1;
                     }
@@ -130,6 +187,13 @@ impl Context {
                     .entry(channel)
                     .and_modify(|count| *count -= 1);
 
+                if let Err(err) = Context::match_live()
+                    .remove_channel(match_id, channel)
+                    .await
+                {
+                    warn!(?err, "Failed to remove match live channel");
+                }
+
                 return true;
             }
         }
@@ -137,25 +201,153 @@ impl Context {
         false
     }
 
-    /// Returns how many channels tracked the match before it ended
-    async fn remove_all_match_tracks(&self, match_id: u32) -> usize {
+    /// Returns the entry of the match which contains all channels that tracked
+    /// it before it ended
+    async fn remove_all_match_tracks(&self, match_id: u32) -> Option<MatchEntry> {
         let mut match_live = self.data.matchlive.inner.lock().await;
+        let entry = match_live.match_channels.remove(&match_id)?;
 
-        if let Some(entry) = match_live.match_channels.remove(&match_id) {
-            for Channel { id, .. } in &entry.channels {
-                match_live
-                    .channel_count
-                    .entry(*id)
-                    .and_modify(|count| *count -= 1);
+        for Channel { id, .. } in &entry.channels {
+            match_live
+                .channel_count
+                .entry(*id)
+                .and_modify(|count| *count -= 1);
+        }
+
+        if let Err(err) = Context::match_live().remove_match(match_id).await {
+            warn!(?err, "Failed to remove tracked match");
+        }
+
+        Some(entry)
+    }
+
+    /// Resumes tracking all matches that were tracked before the last
+    /// shutdown.
+    ///
+    /// Matches continue after their last processed event and the last message
+    /// in each channel is edited instead of sending the previous embeds again.
+    ///
+    /// Returns `false` if some matches could not be resumed for now and it
+    /// should be tried again later.
+    async fn resume_match_tracks() -> bool {
+        let manager = Context::match_live();
+
+        let rows = match manager.channels().await {
+            Ok(rows) => rows,
+            Err(err) => {
+                warn!(?err, "Failed to get tracked matches");
+
+                return false;
             }
+        };
+
+        // Skip matches that were already resumed on a previous attempt
+        let rows: Vec<_> = {
+            let match_live = Context::get().data.matchlive.inner.lock().await;
+
+            rows.into_iter()
+                .filter(|row| {
+                    !match_live
+                        .match_channels
+                        .contains_key(&(row.match_id as u32))
+                })
+                .collect()
+        };
+
+        let mut stored_matches: HashMap<u32, StoredMatch, IntHasher> = HashMap::default();
+
+        for DbMatchLiveChannel {
+            match_id,
+            last_event_id,
+            mappool,
+            channel_id,
+            msg_id,
+        } in rows
+        {
+            let channel = Channel::new(Id::new(channel_id as u64), Id::new(msg_id as u64));
+
+            stored_matches
+                .entry(match_id as u32)
+                .or_insert_with(|| StoredMatch {
+                    last_event_id: last_event_id as u64,
+                    mappool,
+                    channels: SmallVec::new(),
+                })
+                .channels
+                .push(channel);
+        }
+
+        let mut resumed = 0;
+        let mut complete = true;
+
+        for (match_id, stored) in stored_matches {
+            // Request the match as it was when its last event was processed
+            let match_fut = Context::osu()
+                .osu_match(match_id)
+                .before(stored.last_event_id + 1);
+
+            let mut osu_match = match match_fut.await {
+                Ok(osu_match) => osu_match,
+                Err(OsuError::NotFound) => {
+                    warn!(match_id, "Match to resume tracking no longer exists");
+
+                    if let Err(err) = manager.remove_match(match_id).await {
+                        warn!(?err, "Failed to remove tracked match");
+                    }
+
+                    continue;
+                }
+                // Keep the match stored so it's retried later
+                Err(err) => {
+                    warn!(match_id, ?err, "Failed to request match to resume tracking");
+                    complete = false;
+
+                    continue;
+                }
+            };
+
+            // Upcoming requests start right after the last processed event
+            osu_match.latest_event_id = stored.last_event_id;
+
+            let mappool = stored
+                .mappool
+                .as_deref()
+                .and_then(|mappool| Mappool::parse(mappool).ok());
+
+            let embeds = MatchLiveEmbed::new(&osu_match, mappool.as_ref());
+            let tracked = TrackedMatch::new(osu_match, embeds, mappool);
+
+            let mut match_live = Context::get().data.matchlive.inner.lock().await;
+
+            // The match might have been tracked again in the meanwhile
+            let Entry::Vacant(e) = match_live.match_channels.entry(match_id) else {
+                continue;
+            };
+
+            let entry = e.insert(MatchEntry {
+                tracked,
+                channels: stored.channels,
+            });
+
+            let ids: SmallVec<[_; 2]> = entry.channels.iter().map(|channel| channel.id).collect();
+
+            for id in ids {
+                *match_live.channel_count.entry(id).or_insert(0) += 1;
+            }
+
+            resumed += 1;
+        }
 
-            entry.channels.len()
-        } else {
-            0
+        if resumed > 0 {
+            info!("Resumed tracking {resumed} matches");
         }
+
+        complete
     }
 
     pub async fn match_live_loop() {
+        let mut resume_pending = !Self::resume_match_tracks().await;
+
         // Update all matches every 10 seconds
         let mut interval = interval(Duration::from_secs(10));
         interval.tick().await;
@@ -169,6 +361,10 @@ impl Context {
         loop {
             interval.tick().await;
 
+            if resume_pending {
+                resume_pending = !Self::resume_match_tracks().await;
+            }
+
             {
                 // Tight scope makes sure this lock is dropped ASAP
                 let mut match_live = ctx.data.matchlive.inner.lock().await;
@@ -192,10 +388,18 @@ impl Context {
                         .embeds
                         .last_mut()
                         .expect("no last live embed")
-                        .update(&next_match);
+                        .update(&next_match, tracked_match.mappool.as_ref());
 
                     if next_match.end_time.is_some() {
                         remove.push(next_match.match_id);
+                    } else if next_match.latest_event_id != tracked_match.osu_match.latest_event_id
+                    {
+                        let update_fut = Context::match_live()
+                            .update_last_event(next_match.match_id, next_match.latest_event_id);
+
+                        if let Err(err) = update_fut.await {
+                            warn!(?err, "Failed to update last event of tracked match");
+                        }
                     }
 
                     tracked_match.osu_match = next_match;
@@ -221,13 +425,24 @@ impl Context {
 
                     // For all new embeds, send them to all channels
                     if let Some(embeds) = new_embeds {
+                        let match_id = tracked_match.osu_match.match_id;
+
                         for Channel { id, msg_id } in entry.channels.iter_mut() {
                             match send_match_messages(*id, &embeds).await {
                                 Ok(msg) => *msg_id = msg,
                                 Err(err) => {
-                                    error!(channel = id.get(), ?err, "Failed to send last msg")
+                                    error!(channel = id.get(), ?err, "Failed to send last msg");
+
+                                    continue;
                                 }
                             }
+
+                            let store_fut =
+                                Context::match_live().store_channel(match_id, *id, *msg_id);
+
+                            if let Err(err) = store_fut.await {
+                                warn!(?err, "Failed to store last msg of match live channel");
+                            }
                         }
 
                         tracked_match.embeds.extend(embeds);
@@ -237,9 +452,50 @@ impl Context {
 
             // Remove the match id entries
             for match_id in remove.drain(..) {
-                let count = ctx.remove_all_match_tracks(match_id).await;
+                let Some(entry) = ctx.remove_all_match_tracks(match_id).await else {
+                    continue;
+                };
+
+                let count = entry.channels.len();
                 let plural = if count == 1 { "" } else { "s" };
                 debug!("Match {match_id} over, removed from tracking for {count} channel{plural}");
+
+                let disbanded = matches!(
+                    entry.tracked.osu_match.events.last(),
+                    Some(MatchEvent::Disbanded { .. })
+                );
+
+                if disbanded {
+                    Self::send_match_summary(entry).await;
+                }
+            }
+        }
+    }
+
+    /// Sends the final score and match costs to all channels that tracked the
+    /// disbanded match
+    async fn send_match_summary(entry: MatchEntry) {
+        let match_id = entry.tracked.osu_match.match_id;
+
+        let (content, embed) = match match_summary(match_id, entry.tracked.mappool.as_ref()).await {
+            Ok(summary) => summary,
+            Err(err) => {
+                warn!(match_id, ?err, "Failed to create match summary");
+
+                return;
+            }
+        };
+
+        let http = Context::http();
+
+        for Channel { id, .. } in entry.channels.iter() {
+            let create_fut = http
+                .create_message(*id)
+                .content(&content)
+                .embeds(slice::from_ref(&embed));
+
+            if let Err(err) = create_fut.await {
+                warn!(channel = id.get(), ?err, "Failed to send match summary");
             }
         }
     }
@@ -248,8 +504,8 @@ impl Context {
         let mut match_live = self.data.matchlive.inner.lock().await;
         match_live.match_channels.clear();
 
-        let content = "I'm about to reboot so the match tracking will be aborted, \
-            you can restart it in just a moment...";
+        let content = "I'm about to reboot so the match tracking will pause, \
+            it will continue in just a moment...";
 
         let mut notified = 0;
 
@@ -265,3 +521,92 @@ impl Context {
         notified
     }
 }
+
+/// Match live state that was persisted before the last shutdown
+struct StoredMatch {
+    last_event_id: u64,
+    mappool: Option<String>,
+    channels: SmallVec<[Channel; 2]>,
+}
+
+async fn store_match_track(
+    match_id: u32,
+    last_event_id: u64,
+    mappool: Option<&Mappool>,
+    channel: Id<ChannelMarker>,
+    msg: Id<MessageMarker>,
+) -> Result<()> {
+    let manager = Context::match_live();
+
+    manager
+        .store_match(match_id, last_event_id, mappool)
+        .await?;
+
+    manager.store_channel(match_id, channel, msg).await
+}
+
+/// Requests the full match and calculates the match costs of all its games
+async fn match_summary(match_id: u32, mappool: Option<&Mappool>) -> Result<(String, Embed)> {
+    let osu = Context::osu();
+
+    let mut osu_match = osu
+        .osu_match(match_id)
+        .await
+        .wrap_err("Failed to get match")?;
+
+    retrieve_previous(&mut osu_match, osu)
+        .await
+        .wrap_err("Failed to get previous match events")?;
+
+    let games = prepare_games(&mut osu_match, 0, 0, ModMultipliers::NEUTRAL);
+    let formula = MatchCostFormulaKind::default();
+
+    let breakdown = match mappool {
+        Some(mappool) => pool_breakdown(&games, mappool),
+        None => Vec::new(),
+    };
+
+    let result = if games.is_empty() {
+        MatchResult::NoGames {
+            description: "No games were played".to_owned(),
+        }
+    } else {
+        process_match(&games, true, &osu_match.users, formula.formula())
+    };
+
+    let content = match result {
+        MatchResult::TeamVS {
+            ref blue, ref red, ..
+        } if blue.win_count > red.win_count => {
+            format!(
+                "The match is over, :large_blue_diamond: **Blue Team** won {} - {}",
+                blue.win_count, red.win_count
+            )
+        }
+        MatchResult::TeamVS {
+            ref blue, ref red, ..
+        } if red.win_count > blue.win_count => {
+            format!(
+                "The match is over, :small_red_triangle: **Red Team** won {} - {}",
+                red.win_count, blue.win_count
+            )
+        }
+        MatchResult::TeamVS { ref blue, .. } => format!(
+            "The match is over, it ended in a {0} - {0} draw",
+            blue.win_count
+        ),
+        MatchResult::HeadToHead { .. } | MatchResult::NoGames { .. } => {
+            "The match is over".to_owned()
+        }
+    };
+
+    let embed = MatchCostPagination::builder()
+        .osu_match(osu_match)
+        .formula(formula)
+        .breakdown(breakdown.into_boxed_slice())
+        .result(result)
+        .build_embed()
+        .build();
+
+    Ok((content, embed))
+}
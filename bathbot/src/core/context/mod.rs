@@ -30,7 +30,11 @@ use self::osutrack::OsuTrackUserNotifTimestamps;
 use super::{BotConfig, BotMetrics};
 use crate::{
     active::{ActiveMessages, impls::BackgroundGame},
-    tracking::{Ordr, OsuTracking, ScoresWebSocket, ScoresWebSocketDisconnect},
+    manager::ChallengeLeaderboards,
+    tracking::{
+        Danser, Ordr, OsuTracking, RenderBackend, ScoreArchive, ScoresFile, ScoresWebSocket,
+        ScoresWebSocketDisconnect,
+    },
 };
 
 mod discord;
@@ -105,22 +109,30 @@ impl Context {
         &Self::get().clients.custom
     }
 
-    pub fn ordr_available() -> bool {
-        Self::get().clients.ordr.is_some()
+    pub fn render_available() -> bool {
+        Self::get().clients.render.is_some()
     }
 
-    pub fn try_ordr() -> Option<&'static Ordr> {
-        Self::get().clients.ordr.as_deref()
+    pub fn try_render() -> Option<&'static RenderBackend> {
+        Self::get().clients.render.as_ref()
     }
 
-    /// Panics if ordr is not available
+    /// Panics if no render backend is available
     #[track_caller]
-    pub fn ordr() -> &'static Ordr {
+    pub fn render() -> &'static RenderBackend {
         Self::get()
             .clients
-            .ordr
-            .as_deref()
-            .expect("ordr unavailable")
+            .render
+            .as_ref()
+            .expect("render backend unavailable")
+    }
+
+    /// Only available if renders are done through o!rdr
+    pub fn try_ordr() -> Option<&'static Ordr> {
+        match Self::try_render() {
+            Some(RenderBackend::Ordr(ordr)) => Some(ordr),
+            _ => None,
+        }
     }
 
     pub fn psql() -> &'static Database {
@@ -131,6 +143,10 @@ impl Context {
         &Self::get().data.osu_tracking
     }
 
+    pub fn score_archive() -> &'static ScoreArchive {
+        &Self::get().data.score_archive
+    }
+
     #[cfg(feature = "server")]
     pub fn auth_standby() -> &'static bathbot_server::AuthenticationStandby {
         &Self::get().clients.auth_standby
@@ -140,6 +156,10 @@ impl Context {
         &self.data.guild_shards
     }
 
+    pub fn challenge_leaderboards() -> &'static ChallengeLeaderboards {
+        &Self::get().data.challenge_leaderboards
+    }
+
     pub fn miss_analyzer_guilds() -> &'static MissAnalyzerGuilds {
         &Self::get().data.miss_analyzer_guilds
     }
@@ -227,18 +247,27 @@ impl Context {
             .await
             .wrap_err("Failed to create custom client")?;
 
-        let ordr_fut = Ordr::new(
-            #[cfg(not(debug_assertions))]
-            config.tokens.ordr_key.as_ref(),
-        );
+        let render = match config.danser_url {
+            Some(ref url) => {
+                info!(url = url.as_ref(), "Rendering through danser");
 
-        let ordr = match tokio::time::timeout(Duration::from_secs(20), ordr_fut).await {
-            Ok(Ok(ordr)) => Some(Arc::new(ordr)),
-            Ok(Err(err)) => return Err(err),
-            Err(_) => {
-                warn!("o!rdr timed out, initializing without it");
+                Some(RenderBackend::Danser(Danser::new(url.as_ref())))
+            }
+            None => {
+                let ordr_fut = Ordr::new(
+                    #[cfg(not(debug_assertions))]
+                    config.tokens.ordr_key.as_ref(),
+                );
 
-                None
+                match tokio::time::timeout(Duration::from_secs(20), ordr_fut).await {
+                    Ok(Ok(ordr)) => Some(RenderBackend::Ordr(ordr)),
+                    Ok(Err(err)) => return Err(err),
+                    Err(_) => {
+                        warn!("o!rdr timed out, initializing without it");
+
+                        None
+                    }
+                }
             }
         };
 
@@ -267,7 +296,7 @@ impl Context {
             custom: custom_client,
             osu,
             psql,
-            ordr,
+            render,
             #[cfg(feature = "server")]
             auth_standby,
         };
@@ -291,12 +320,16 @@ impl Context {
 
         // Some websocket functionality relies on `Context::get` being
         // available so we should connect only after setting the context.
-        match ScoresWebSocket::connect().await {
-            Ok(disconnect) => {
-                *Self::get().scores_ws_disconnect.lock().unwrap() = Some(disconnect);
-            }
-            Err(err) => warn!(?err, "Failed to connect scores websocket"),
-        };
+        if let Some(ref path) = config.scores_file {
+            ScoresFile::spawn(path.clone(), config.scores_file_speed);
+        } else {
+            match ScoresWebSocket::connect().await {
+                Ok(disconnect) => {
+                    *Self::get().scores_ws_disconnect.lock().unwrap() = Some(disconnect);
+                }
+                Err(err) => warn!(?err, "Failed to connect scores websocket"),
+            };
+        }
 
         Ok((
             shards,
@@ -393,7 +426,7 @@ struct Clients {
     custom: BathbotClient,
     osu: Osu,
     psql: Database,
-    ordr: Option<Arc<Ordr>>,
+    render: Option<RenderBackend>,
     #[cfg(feature = "server")]
     auth_standby: Arc<bathbot_server::AuthenticationStandby>,
 }
@@ -407,10 +440,12 @@ struct ContextData {
     #[cfg(feature = "twitchtracking")]
     tracked_streams: TrackedStreams,
     osu_tracking: OsuTracking,
+    score_archive: ScoreArchive,
     guild_configs: GuildConfigs,
     guild_shards: GuildShards,
     miss_analyzer_guilds: MissAnalyzerGuilds,
     osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps,
+    challenge_leaderboards: ChallengeLeaderboards,
     #[cfg(feature = "twitch")]
     online_twitch_streams: crate::tracking::OnlineTwitchStreams,
 }
@@ -428,20 +463,23 @@ impl ContextData {
             guild_shards,
             miss_analyzer_guilds,
             osu_tracking,
+            score_archive,
         ) = tokio::join!(
             psql.select_guild_configs::<IntHasher>(),
             psql.select_tracked_twitch_streams::<IntHasher>(),
             Self::fetch_guild_shards(&cache),
             Self::fetch_miss_analyzer_guilds(&cache),
             OsuTracking::new(psql),
+            ScoreArchive::new(psql),
         );
 
         #[cfg(not(feature = "twitchtracking"))]
-        let (guild_configs_res, guild_shards, miss_analyzer_guilds, osu_tracking) = tokio::join!(
+        let (guild_configs_res, guild_shards, miss_analyzer_guilds, osu_tracking, score_archive) = tokio::join!(
             psql.select_guild_configs::<IntHasher>(),
             Self::fetch_guild_shards(&cache),
             Self::fetch_miss_analyzer_guilds(&cache),
-            OsuTracking::new(psql)
+            OsuTracking::new(psql),
+            ScoreArchive::new(psql),
         );
 
         Ok(Self {
@@ -456,6 +494,7 @@ impl ContextData {
                 .into_iter()
                 .collect(),
             osu_tracking: osu_tracking.wrap_err("Failed to create osu! tracking")?,
+            score_archive: score_archive.wrap_err("Failed to create score archive")?,
             application_id,
             games: Games::new(),
             guild_shards,
@@ -463,6 +502,7 @@ impl ContextData {
             matchlive: crate::matchlive::MatchLiveChannels::new(),
             miss_analyzer_guilds,
             osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps::default(),
+            challenge_leaderboards: ChallengeLeaderboards::default(),
             #[cfg(feature = "twitch")]
             online_twitch_streams: crate::tracking::OnlineTwitchStreams::default(),
         })
@@ -547,6 +587,8 @@ async fn bathbot_server(
         twitch_client_id: config.tokens.twitch_client_id.to_string(),
         twitch_token: config.tokens.twitch_token.to_string(),
         redirect_base: config.server.public_url.to_string(),
+        database_url: config.database_url.to_string(),
+        channel_access: Box::new(|user, channel| Box::pin(channel_access(user, channel))),
     };
 
     let (server, standby, tx) = bathbot_server::Server::new(builder)?;
@@ -555,3 +597,48 @@ async fn bathbot_server(
 
     Ok((standby, tx))
 }
+
+/// Checks whether the user can view the channel for the server's api.
+#[cfg(feature = "server")]
+async fn channel_access(
+    user: Id<UserMarker>,
+    channel: Id<ChannelMarker>,
+) -> Result<bathbot_server::ChannelAccess> {
+    use bathbot_server::ChannelAccess;
+    use eyre::Report;
+    use twilight_http::error::ErrorType;
+    use twilight_model::guild::Permissions;
+
+    use crate::core::commands::checks::check_channel_permissions;
+
+    let Some(ctx) = CONTEXT.get() else {
+        bail!("Context not yet initialized");
+    };
+
+    let channel = match ctx.clients.http.channel(channel).await {
+        Ok(response) => response
+            .model()
+            .await
+            .wrap_err("Failed to deserialize channel")?,
+        Err(err) => match err.kind() {
+            // Unknown channel or the bot can't see it
+            ErrorType::Response { status, .. } if matches!(status.get(), 403 | 404) => {
+                return Ok(ChannelAccess::UnknownChannel);
+            }
+            _ => return Err(Report::new(err).wrap_err("Failed to get channel")),
+        },
+    };
+
+    let Some(guild) = channel.guild_id else {
+        return Ok(ChannelAccess::UnknownChannel);
+    };
+
+    // Empty if the user is not a member of the guild
+    let permissions = check_channel_permissions(user, channel.id, guild).await;
+
+    if permissions.contains(Permissions::VIEW_CHANNEL) {
+        Ok(ChannelAccess::Granted)
+    } else {
+        Ok(ChannelAccess::Denied)
+    }
+}
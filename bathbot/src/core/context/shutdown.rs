@@ -17,7 +17,11 @@ use tokio::{sync::Mutex, task::JoinSet};
 use twilight_gateway::Shard;
 use twilight_model::id::{Id, marker::GuildMarker};
 
-use crate::{Context, util::ChannelExt};
+use crate::{
+    Context,
+    tracking::{IRenderBackend, ScoreArchive},
+    util::ChannelExt,
+};
 
 impl Context {
     #[cold]
@@ -48,15 +52,17 @@ impl Context {
             info!("Stopped match tracking in {count} channels");
         }
 
-        if let Some(ordr) = Context::try_ordr() {
-            info!("Disconnecting from ordr");
-            ordr.disconnect();
+        if let Some(render) = Context::try_render() {
+            info!("Disconnecting from {}", render.name());
+            render.disconnect();
         }
 
         if let Some(rx) = scores_ws_disconnect {
             let _: Result<_, _> = rx.await;
         }
 
+        ScoreArchive::flush().await;
+
         info!("Awaiting {} runners to shutdown...", runners.len());
         runners.shutdown().await;
 
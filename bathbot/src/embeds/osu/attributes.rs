@@ -18,6 +18,7 @@ impl AttributesEmbed {
         value: f32,
         mods: GameModsIntermode,
         clock_rate: Option<f32>,
+        filename: Option<&str>,
     ) -> Self {
         let mut builder = BeatmapAttributesBuilder::default().mods(&mods);
 
@@ -32,7 +33,7 @@ impl AttributesEmbed {
             builder = builder.clock_rate(clock_rate as f64);
         }
 
-        let title = format!(
+        let mut title = format!(
             "Adjusting {}",
             match kind {
                 AttributeKind::Ar => "AR",
@@ -42,6 +43,10 @@ impl AttributesEmbed {
             }
         );
 
+        if let Some(filename) = filename {
+            let _ = write!(title, " of {filename}");
+        }
+
         let nm_field = EmbedField {
             inline: true,
             name: "NM".to_owned(),
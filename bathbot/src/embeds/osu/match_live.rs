@@ -20,7 +20,10 @@ use rosu_v2::prelude::{
 use smallvec::SmallVec;
 use twilight_model::channel::message::embed::Embed;
 
-use crate::util::{Emote, osu::grade_emote};
+use crate::{
+    manager::Mappool,
+    util::{Emote, osu::grade_emote},
+};
 
 const DESCRIPTION_BUFFER: usize = 45;
 
@@ -88,7 +91,7 @@ macro_rules! team {
 }
 
 impl MatchLiveEmbed {
-    pub fn new(lobby: &OsuMatch) -> MatchLiveEmbeds {
+    pub fn new(lobby: &OsuMatch, mappool: Option<&Mappool>) -> MatchLiveEmbeds {
         let mut embeds = MatchLiveEmbeds::new();
 
         if lobby.events.is_empty() {
@@ -148,7 +151,7 @@ impl MatchLiveEmbed {
                         }
                     }
 
-                    let (description, image, footer) = game_content(lobby, game);
+                    let (description, image, footer) = game_content(lobby, game, mappool);
                     state = Some(next_state);
 
                     let embed = Self {
@@ -208,7 +211,11 @@ impl MatchLiveEmbed {
         embeds
     }
 
-    pub fn update(&mut self, lobby: &OsuMatch) -> (bool, Option<MatchLiveEmbeds>) {
+    pub fn update(
+        &mut self,
+        lobby: &OsuMatch,
+        mappool: Option<&Mappool>,
+    ) -> (bool, Option<MatchLiveEmbeds>) {
         if lobby.events.is_empty() {
             return (false, None);
         }
@@ -277,7 +284,7 @@ impl MatchLiveEmbed {
                             continue;
                         }
 
-                        let (description, image, footer) = game_content(lobby, game);
+                        let (description, image, footer) = game_content(lobby, game, mappool);
 
                         // Previous game not yet finished but next one already there => override
                         if !state.finished {
@@ -367,7 +374,7 @@ impl MatchLiveEmbed {
                         embed.description.push_str("• **Lobby was closed**")
                     }
                     MatchEvent::Game { game, .. } => {
-                        let (description, image, footer) = game_content(lobby, game);
+                        let (description, image, footer) = game_content(lobby, game, mappool);
 
                         let state = GameState {
                             game_id: game.game_id,
@@ -456,9 +463,14 @@ impl MatchLiveEmbed {
 fn game_content(
     lobby: &OsuMatch,
     game: &MatchGame,
+    mappool: Option<&Mappool>,
 ) -> (String, Option<String>, Option<FooterBuilder>) {
     let mut description = String::with_capacity(128);
 
+    if let Some(slot) = mappool.and_then(|mappool| mappool.game_slot(game)) {
+        let _ = write!(description, "`{slot}` ");
+    }
+
     match game.end_time {
         Some(_) => {
             let image = match game.map {
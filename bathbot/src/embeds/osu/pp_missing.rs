@@ -1,18 +1,19 @@
 use std::{
     cmp::Ordering,
     convert::identity,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Display, Formatter, Result as FmtResult, Write},
     iter,
 };
 
 use bathbot_util::{
     AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder,
     numbers::WithComma,
-    osu::{ExtractablePp, PpListUtil, pp_missing},
+    osu::{PpListUtil, pp_missing},
 };
-use rosu_v2::prelude::Score;
 
-use crate::{embeds::EmbedData, manager::redis::osu::CachedUser, util::CachedUserExt};
+use crate::{
+    commands::osu::TopPp, embeds::EmbedData, manager::redis::osu::CachedUser, util::CachedUserExt,
+};
 
 fn idx_suffix(idx: usize) -> &'static str {
     match idx % 100 {
@@ -37,18 +38,14 @@ pub struct PpMissingEmbed {
 impl PpMissingEmbed {
     pub fn new(
         user: &CachedUser,
-        scores: &[Score],
+        top: &TopPp,
         goal_pp: f64,
         rank: Option<u32>,
         each: Option<f32>,
         amount: Option<u8>,
     ) -> Self {
-        let stats_pp = user
-            .statistics
-            .as_ref()
-            .expect("missing stats")
-            .pp
-            .to_native() as f64;
+        let stats_pp = top.total as f64;
+        let scores = top.pps.as_slice();
 
         let username = user.username.as_str();
 
@@ -58,7 +55,7 @@ impl PpMissingEmbed {
             goal_pp = WithComma::new(goal_pp),
         );
 
-        let description = match (scores.last().and_then(|s| s.pp), each, amount) {
+        let description = match (scores.last().copied(), each, amount) {
             // No top scores
             (None, ..) => "No top scores found".to_owned(),
             // Total pp already above goal
@@ -96,7 +93,7 @@ impl PpMissingEmbed {
             }
             // Given score pp would be in top 200
             (Some(_), Some(each), _) => {
-                let mut pps = scores.extract_pp();
+                let mut pps = scores.to_vec();
                 let (required, idx) = pp_missing(stats_pp, goal_pp, scores);
                 let required = required as f32;
 
@@ -199,22 +196,20 @@ impl PpMissingEmbed {
                 }
             }
             (Some(_), None, Some(amount)) => {
-                let pps = scores.extract_pp();
-
                 let raw_delta = goal_pp - stats_pp;
                 let weight_sum: f64 = (0..amount as i32).map(|exp| FACTOR.powi(exp)).sum();
                 let mid_goal = stats_pp + (raw_delta / weight_sum);
-                let (required, _) = pp_missing(stats_pp, mid_goal, pps.as_slice());
+                let (required, _) = pp_missing(stats_pp, mid_goal, scores);
                 let mut required = required as f32;
 
-                let pb_start_idx = pps
+                let pb_start_idx = scores
                     .binary_search_by(|probe| required.total_cmp(probe))
                     .map_or_else(identity, |idx| idx + 1);
 
                 let pb_fmt = PersonalBestIndexFormatter::new(pb_start_idx, amount);
 
-                if scores.len() >= 100 && required < *pps.last().unwrap() {
-                    required = (*pps.last().unwrap() - 0.01).max(0.0);
+                if scores.len() >= 100 && required < *scores.last().unwrap() {
+                    required = (*scores.last().unwrap() - 0.01).max(0.0);
                 }
 
                 format!(
@@ -229,14 +224,25 @@ impl PpMissingEmbed {
             }
         };
 
-        let footer = rank.map(|rank| {
-            FooterBuilder::new(format!(
+        let mut footer_text = rank.map(|rank| {
+            format!(
                 "The current rank for {pp}pp is approx. #{rank}",
                 pp = WithComma::new(goal_pp),
                 rank = WithComma::new(rank),
-            ))
+            )
         });
 
+        if let Some(version) = top.version {
+            match footer_text {
+                Some(ref mut text) => {
+                    let _ = write!(text, " • {version}");
+                }
+                None => footer_text = Some(version.to_string()),
+            }
+        }
+
+        let footer = footer_text.map(FooterBuilder::new);
+
         Self {
             author: user.author_builder(false),
             description,
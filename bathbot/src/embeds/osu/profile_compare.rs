@@ -7,6 +7,7 @@ use std::{
 
 use bathbot_macros::EmbedData;
 use bathbot_model::{RankAccPeaks, rosu_v2::user::ArchivedUserStatistics};
+use bathbot_psql::model::osu::DbUserStatsHistoryEntry;
 use bathbot_util::{
     attachment,
     datetime::{DATE_FORMAT, SecToMinSec},
@@ -31,14 +32,35 @@ impl ProfileCompareEmbed {
         user2: &CachedUser,
         result1: CompareResult,
         result2: CompareResult,
+        since: Option<OffsetDateTime>,
     ) -> Self {
         let data1 = UserData::new(user1, result1.osutrack_peaks.as_ref());
         let data2 = UserData::new(user2, result2.osutrack_peaks.as_ref());
 
         let left = CompareStrings::new(&data1, &result1);
-        let max_left = left.max().max(data1.username.chars().count());
         let right = CompareStrings::new(&data2, &result2);
-        let max_right = right.max().max(data2.username.chars().count());
+
+        let has_gains = result1.snapshot.is_some() || result2.snapshot.is_some();
+
+        let gains = has_gains.then(|| {
+            let left = StatGains::new(data1.stats, result1.snapshot.as_ref());
+            let right = StatGains::new(data2.stats, result2.snapshot.as_ref());
+
+            (left, right)
+        });
+
+        let (gains_left, gains_right) = gains
+            .as_ref()
+            .map_or((0, 0), |(left, right)| (left.max(), right.max()));
+
+        let max_left = left
+            .max()
+            .max(gains_left)
+            .max(data1.username.chars().count());
+        let max_right = right
+            .max()
+            .max(gains_right)
+            .max(data2.username.chars().count());
         let mut d = String::with_capacity(512);
         d.push_str("```ansi\n");
 
@@ -451,8 +473,51 @@ impl ProfileCompareEmbed {
             max_right,
         );
 
+        if let Some((left, right)) = gains {
+            write_line(
+                &mut d,
+                "PP gain",
+                left.pp,
+                right.pp,
+                left.pp_num,
+                right.pp_num,
+                max_left,
+                max_right,
+            );
+
+            write_line(
+                &mut d,
+                "Rank gain",
+                left.rank,
+                right.rank,
+                left.rank_num,
+                right.rank_num,
+                max_left,
+                max_right,
+            );
+
+            write_line(
+                &mut d,
+                "PC gain",
+                left.playcount,
+                right.playcount,
+                left.playcount_num,
+                right.playcount_num,
+                max_left,
+                max_right,
+            );
+        }
+
         d.push_str("```");
 
+        if let Some(since) = since.filter(|_| has_gains) {
+            let _ = write!(
+                d,
+                "Gains since {}; based on stats stored by the bot",
+                since.format(DATE_FORMAT).unwrap()
+            );
+        }
+
         Self {
             description: d,
             image: attachment("avatar_fuse.png"),
@@ -691,6 +756,56 @@ impl CompareStrings {
     }
 }
 
+struct StatGains {
+    pp: Box<str>,
+    pp_num: f32,
+    rank: Box<str>,
+    rank_num: i64,
+    playcount: Box<str>,
+    playcount_num: i64,
+}
+
+impl StatGains {
+    fn new(stats: &ArchivedUserStatistics, snapshot: Option<&DbUserStatsHistoryEntry>) -> Self {
+        let Some(snapshot) = snapshot else {
+            return Self {
+                pp: Box::from("-"),
+                pp_num: f32::MIN,
+                rank: Box::from("-"),
+                rank_num: i64::MIN,
+                playcount: Box::from("-"),
+                playcount_num: i64::MIN,
+            };
+        };
+
+        let pp_num = stats.pp.to_native() - snapshot.pp;
+        let global_rank = stats.global_rank.to_native();
+
+        let (rank, rank_num) = if global_rank == 0 || snapshot.global_rank == 0 {
+            (Box::from("-"), i64::MIN)
+        } else {
+            let rank_num = snapshot.global_rank as i64 - global_rank as i64;
+
+            (format!("{rank_num:+}").into_boxed_str(), rank_num)
+        };
+
+        let playcount_num = stats.playcount.to_native() as i64 - snapshot.playcount as i64;
+
+        Self {
+            pp: format!("{pp_num:+.2}pp").into_boxed_str(),
+            pp_num,
+            rank,
+            rank_num,
+            playcount: format!("{playcount_num:+}").into_boxed_str(),
+            playcount_num,
+        }
+    }
+
+    fn max(&self) -> usize {
+        self.pp.len().max(self.rank.len()).max(self.playcount.len())
+    }
+}
+
 struct UserData<'u> {
     stats: &'u ArchivedUserStatistics,
     username: &'u str,
@@ -1,25 +1,29 @@
 use std::fmt::Write;
 
-use bathbot_macros::EmbedData;
 use bathbot_util::{
-    AuthorBuilder, CowUtils,
+    AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder,
     numbers::{WithComma, round},
 };
 
-use crate::{commands::osu::WhatIfData, manager::redis::osu::CachedUser, util::CachedUserExt};
+use crate::{
+    commands::osu::{TopPp, WhatIfData},
+    embeds::EmbedData,
+    manager::redis::osu::CachedUser,
+    util::CachedUserExt,
+};
 
-#[derive(EmbedData)]
 pub struct WhatIfEmbed {
     author: AuthorBuilder,
     description: String,
+    footer: Option<FooterBuilder>,
     thumbnail: String,
     title: String,
 }
 
 impl WhatIfEmbed {
-    pub fn new(user: &CachedUser, pp: f32, data: WhatIfData) -> Self {
+    pub fn new(user: &CachedUser, pp: f32, data: WhatIfData, top: &TopPp) -> Self {
         let stats = user.statistics.as_ref().expect("missing stats");
-        let stats_pp = stats.pp.to_native();
+        let stats_pp = top.total;
         let global_rank = stats.global_rank.to_native();
 
         let username = user.username.as_str().cow_escape_markdown();
@@ -122,11 +126,32 @@ impl WhatIfEmbed {
             }
         };
 
+        let footer = top
+            .version
+            .map(|version| FooterBuilder::new(version.to_string()));
+
         Self {
             author: user.author_builder(false),
             description,
+            footer,
             thumbnail: avatar_url.to_owned(),
             title,
         }
     }
 }
+
+impl EmbedData for WhatIfEmbed {
+    fn build(self) -> EmbedBuilder {
+        let builder = EmbedBuilder::new()
+            .author(self.author)
+            .description(self.description)
+            .thumbnail(self.thumbnail)
+            .title(self.title);
+
+        if let Some(footer) = self.footer {
+            builder.footer(footer)
+        } else {
+            builder
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use std::fmt::{Display, Write};
 
 use ::time::UtcOffset;
-use bathbot_psql::model::configs::{ListSize, OsuUsername, Retries, ScoreData, UserConfig};
+use bathbot_psql::model::configs::{
+    CardLayout, CardTheme, ListSize, OsuUsername, Retries, ScoreData, UserConfig,
+};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, FooterBuilder};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::message::embed::EmbedField, user::User};
@@ -108,6 +110,20 @@ impl ConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Card theme",
+                config.card_theme.unwrap_or_default(),
+                &[
+                    (CardTheme::Classic, "classic"),
+                    (CardTheme::Mode, "gamemode colors"),
+                    (CardTheme::Midnight, "midnight"),
+                ],
+            ),
+            create_field(
+                "Card layout",
+                config.card_layout.unwrap_or_default(),
+                &[(CardLayout::Full, "full"), (CardLayout::Banner, "banner")],
+            ),
         ];
 
         if let Some(skin_url) = skin_url {
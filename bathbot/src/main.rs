@@ -90,6 +90,18 @@ async fn async_main() -> Result<()> {
         tokio::spawn(tracking::twitch_tracking_loop());
     }
 
+    // Spawn osu tracking digest worker
+    tokio::spawn(tracking::osu_digest_loop());
+
+    // Spawn score archive worker
+    tokio::spawn(tracking::score_archive_loop());
+
+    // Spawn user snapshot downsampling worker
+    tokio::spawn(Context::osu_user().downsample_snapshots_loop());
+
+    // Spawn challenge results worker
+    tokio::spawn(Context::challenges().announce_loop());
+
     #[cfg(feature = "matchlive")]
     {
         // Spawn osu match ticker worker
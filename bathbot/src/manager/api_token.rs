@@ -0,0 +1,48 @@
+use bathbot_psql::Database;
+use eyre::{Result, WrapErr};
+use rand::{Rng, distributions::Alphanumeric};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::core::Context;
+
+const TOKEN_LEN: usize = 48;
+
+#[derive(Copy, Clone)]
+pub struct ApiTokenManager {
+    psql: &'static Database,
+}
+
+impl ApiTokenManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    /// Generates a new token for the user, invalidating their previous one.
+    ///
+    /// Only the token's hash is stored so the returned token can't be
+    /// retrieved again later on.
+    pub async fn create(self, user_id: Id<UserMarker>) -> Result<String> {
+        let token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(TOKEN_LEN)
+            .map(char::from)
+            .collect();
+
+        self.psql
+            .upsert_api_token(user_id, &token)
+            .await
+            .wrap_err("Failed to store api token")?;
+
+        Ok(token)
+    }
+
+    /// Returns whether the user had a token.
+    pub async fn revoke(self, user_id: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .delete_api_token(user_id)
+            .await
+            .wrap_err("Failed to delete api token")
+    }
+}
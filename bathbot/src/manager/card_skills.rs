@@ -0,0 +1,115 @@
+use bathbot_cards::Skills;
+use bathbot_psql::{Database, model::osu::DbCardSkills};
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+
+use crate::core::Context;
+
+pub struct SkillsSnapshot {
+    pub skills: Skills,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Copy, Clone)]
+pub struct CardSkillsManager {
+    psql: &'static Database,
+}
+
+impl CardSkillsManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    /// Returns the two most recent snapshots that were stored strictly before
+    /// `until`, the most recent one first.
+    pub async fn history(
+        self,
+        user_id: u32,
+        mode: GameMode,
+        until: OffsetDateTime,
+    ) -> Result<Vec<SkillsSnapshot>> {
+        let snapshots = self
+            .psql
+            .select_osu_user_card_skills(user_id, mode, until)
+            .await
+            .wrap_err("Failed to get card skills")?;
+
+        let snapshots = snapshots
+            .into_iter()
+            .filter_map(|snapshot| {
+                Some(SkillsSnapshot {
+                    skills: skills_from_db(mode, &snapshot)?,
+                    created_at: snapshot.created_at,
+                })
+            })
+            .collect();
+
+        Ok(snapshots)
+    }
+
+    pub async fn store(
+        self,
+        user_id: u32,
+        skills: &Skills,
+        created_at: OffsetDateTime,
+    ) -> Result<()> {
+        let mut snapshot = DbCardSkills {
+            acc: 0.0,
+            aim: None,
+            speed: None,
+            strain: None,
+            movement: None,
+            created_at,
+        };
+
+        match *skills {
+            Skills::Osu { acc, aim, speed } => {
+                snapshot.acc = acc;
+                snapshot.aim = Some(aim);
+                snapshot.speed = Some(speed);
+            }
+            Skills::Taiko { acc, strain } | Skills::Mania { acc, strain } => {
+                snapshot.acc = acc;
+                snapshot.strain = Some(strain);
+            }
+            Skills::Catch { acc, movement } => {
+                snapshot.acc = acc;
+                snapshot.movement = Some(movement);
+            }
+        }
+
+        self.psql
+            .insert_osu_user_card_skills(user_id, skills.mode(), &snapshot)
+            .await
+            .wrap_err("Failed to store card skills")
+    }
+}
+
+fn skills_from_db(mode: GameMode, snapshot: &DbCardSkills) -> Option<Skills> {
+    let acc = snapshot.acc;
+
+    let skills = match mode {
+        GameMode::Osu => Skills::Osu {
+            acc,
+            aim: snapshot.aim?,
+            speed: snapshot.speed?,
+        },
+        GameMode::Taiko => Skills::Taiko {
+            acc,
+            strain: snapshot.strain?,
+        },
+        GameMode::Catch => Skills::Catch {
+            acc,
+            movement: snapshot.movement?,
+        },
+        GameMode::Mania => Skills::Mania {
+            acc,
+            strain: snapshot.strain?,
+        },
+    };
+
+    Some(skills)
+}
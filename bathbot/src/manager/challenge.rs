@@ -0,0 +1,442 @@
+use std::{cmp::Reverse, collections::HashMap, fmt::Write, time::Instant};
+
+use bathbot_psql::{Database, model::osu::DbGuildChallenge};
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter,
+    constants::OSU_BASE,
+    datetime::HowLongAgoDynamic,
+    numbers::WithComma,
+    osu::{ModSelection, ModsResult},
+};
+use eyre::{Report, Result, WrapErr};
+use futures::{StreamExt, stream};
+use papaya::HashMap as PapayaMap;
+use rosu_v2::prelude::{GameMode, OsuError, Score, Username};
+use time::OffsetDateTime;
+use tokio::time::{Duration, interval};
+use twilight_http::error::ErrorType;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
+
+use crate::{core::Context, manager::redis::osu::UserArgsSlim, util::osu::grade_emote};
+
+#[derive(Copy, Clone)]
+pub struct ChallengeManager {
+    psql: &'static Database,
+}
+
+impl ChallengeManager {
+    /// How often announcing a challenge's results is attempted before giving
+    /// up on it.
+    const ANNOUNCE_ATTEMPTS: u32 = 5;
+    /// Amount of entries shown in the announcement of a challenge's results.
+    const ANNOUNCE_ENTRIES: usize = 10;
+    /// Interval in which ended challenges are checked for announcements.
+    const ANNOUNCE_INTERVAL: Duration = Duration::from_secs(60);
+    /// How many members' scores are requested at the same time.
+    const CONCURRENT_REQUESTS: usize = 8;
+    /// How long a leaderboard is reused before it's fetched again.
+    const LEADERBOARD_CACHE_DURATION: Duration = Duration::from_secs(120);
+
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn create(
+        self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        map_id: u32,
+        mode: GameMode,
+        mods: Option<&str>,
+        ends_at: OffsetDateTime,
+    ) -> Result<Option<GuildChallenge>> {
+        self.psql
+            .insert_guild_challenge(guild_id, channel_id, map_id, mode, mods, ends_at)
+            .await
+            .map(|challenge| challenge.map(GuildChallenge::from))
+            .wrap_err("Failed to insert challenge")
+    }
+
+    /// The challenge of the guild whose results have not been announced yet.
+    pub async fn active(self, guild_id: Id<GuildMarker>) -> Result<Option<GuildChallenge>> {
+        self.psql
+            .select_active_guild_challenge(guild_id)
+            .await
+            .map(|challenge| challenge.map(GuildChallenge::from))
+            .wrap_err("Failed to get active challenge")
+    }
+
+    pub async fn latest(self, guild_id: Id<GuildMarker>) -> Result<Option<GuildChallenge>> {
+        self.psql
+            .select_latest_guild_challenge(guild_id)
+            .await
+            .map(|challenge| challenge.map(GuildChallenge::from))
+            .wrap_err("Failed to get latest challenge")
+    }
+
+    /// Ends the ongoing challenge of the guild so that its results are
+    /// announced on the next check.
+    ///
+    /// Returns `false` if there was no ongoing challenge.
+    pub async fn end(self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        self.psql
+            .update_guild_challenge_end(guild_id)
+            .await
+            .wrap_err("Failed to end challenge")
+    }
+
+    /// Collects the best score of each linked guild member on the challenge's
+    /// map that was set within the challenge's time window and fits its mods.
+    ///
+    /// Members whose scores could not be retrieved are left out and counted
+    /// in [`ChallengeStandings::failed`].
+    ///
+    /// Leaderboards are cached for a short while so that repeated refreshes
+    /// don't request the scores of all members each time.
+    pub async fn leaderboard(self, challenge: &GuildChallenge) -> Result<ChallengeStandings> {
+        let leaderboards = Context::challenge_leaderboards();
+
+        let cached = leaderboards
+            .pin()
+            .get(&challenge.challenge_id)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < Self::LEADERBOARD_CACHE_DURATION)
+            .map(|(_, standings)| standings.clone());
+
+        if let Some(standings) = cached {
+            return Ok(standings);
+        }
+
+        let standings = self.fetch_leaderboard(challenge).await?;
+
+        leaderboards
+            .pin()
+            .insert(challenge.challenge_id, (Instant::now(), standings.clone()));
+
+        Ok(standings)
+    }
+
+    async fn fetch_leaderboard(self, challenge: &GuildChallenge) -> Result<ChallengeStandings> {
+        let members: Vec<_> = Context::cache()
+            .members(challenge.guild_id)
+            .await?
+            .into_iter()
+            .map(|id| id as i64)
+            .collect();
+
+        let user_ids = self
+            .psql
+            .select_osu_ids_by_discord_ids(&members)
+            .await
+            .wrap_err("Failed to get linked members")?;
+
+        let mods = challenge.mods();
+        let scores_manager = Context::osu_scores();
+
+        let mut scores_futs = stream::iter(user_ids)
+            .map(|user_id| async move {
+                let user_args = UserArgsSlim::user_id(user_id).mode(challenge.mode);
+
+                let scores_res = scores_manager
+                    .user_on_map(challenge.map_id, false)
+                    .exec(user_args)
+                    .await;
+
+                (user_id, scores_res)
+            })
+            .buffer_unordered(Self::CONCURRENT_REQUESTS);
+
+        let mut best_scores = Vec::new();
+        let mut failed = 0;
+
+        while let Some((user_id, scores_res)) = scores_futs.next().await {
+            let scores = match scores_res {
+                Ok(scores) => scores,
+                Err(OsuError::NotFound) => continue,
+                // One member's failure shouldn't hide everyone else's scores
+                Err(err) => {
+                    warn!(
+                        err = ?Report::new(err),
+                        user_id,
+                        challenge = challenge.challenge_id,
+                        "Failed to get user scores on challenge map, skipping member"
+                    );
+                    failed += 1;
+
+                    continue;
+                }
+            };
+
+            let best = scores
+                .into_iter()
+                .filter(|score| {
+                    score.ended_at >= challenge.starts_at && score.ended_at <= challenge.ends_at
+                })
+                .filter(|score| mods.as_ref().is_none_or(|mods| mods.filter_score(score)))
+                .max_by_key(|score| (score.score, Reverse(score.ended_at)));
+
+            best_scores.extend(best);
+        }
+
+        let user_ids: Vec<_> = best_scores
+            .iter()
+            .map(|score| score.user_id as i32)
+            .collect();
+        let mut names = Context::osu_user().names(&user_ids).await?;
+
+        let mut entries: Vec<_> = best_scores
+            .into_iter()
+            .map(|score| ChallengeEntry {
+                username: names
+                    .remove(&score.user_id)
+                    .unwrap_or_else(|| score.user_id.to_string().into()),
+                score,
+            })
+            .collect();
+
+        entries.sort_unstable_by_key(|entry| (Reverse(entry.score.score), entry.score.ended_at));
+
+        Ok(ChallengeStandings { entries, failed })
+    }
+
+    /// Regularly announces the results of challenges whose time window closed.
+    ///
+    /// Failed announcements are retried with an exponential backoff and
+    /// dropped after [`Self::ANNOUNCE_ATTEMPTS`] attempts.
+    pub async fn announce_loop(self) {
+        let mut interval = interval(Self::ANNOUNCE_INTERVAL);
+        interval.tick().await;
+
+        // Failed attempts and the time of the next attempt, mapped by
+        // challenge id
+        let mut retries: HashMap<i32, (u32, Instant)> = HashMap::new();
+
+        loop {
+            interval.tick().await;
+
+            Context::challenge_leaderboards()
+                .pin()
+                .retain(|_, (fetched_at, _)| {
+                    fetched_at.elapsed() < Self::LEADERBOARD_CACHE_DURATION
+                });
+
+            let challenges = match self.psql.select_due_guild_challenges().await {
+                Ok(challenges) => challenges,
+                Err(err) => {
+                    warn!(?err, "Failed to get due challenges");
+
+                    continue;
+                }
+            };
+
+            for challenge in challenges {
+                let challenge = GuildChallenge::from(challenge);
+                let challenge_id = challenge.challenge_id;
+
+                let backing_off = retries
+                    .get(&challenge_id)
+                    .is_some_and(|(_, retry_at)| *retry_at > Instant::now());
+
+                if backing_off {
+                    continue;
+                }
+
+                if let Err(err) = self.announce(&challenge).await {
+                    let attempts = retries
+                        .get(&challenge_id)
+                        .map_or(1, |(attempts, _)| attempts + 1);
+
+                    // Keep the challenge due so that it's retried later
+                    if attempts < Self::ANNOUNCE_ATTEMPTS {
+                        warn!(
+                            ?err,
+                            challenge = challenge_id,
+                            attempts,
+                            "Failed to announce challenge, retrying later"
+                        );
+
+                        let backoff = Self::ANNOUNCE_INTERVAL * 2_u32.pow(attempts);
+                        retries.insert(challenge_id, (attempts, Instant::now() + backoff));
+
+                        continue;
+                    }
+
+                    warn!(
+                        ?err,
+                        challenge = challenge_id,
+                        attempts,
+                        "Failed to announce challenge, giving up"
+                    );
+                }
+
+                retries.remove(&challenge_id);
+
+                if let Err(err) = self
+                    .psql
+                    .update_guild_challenge_announced(challenge_id)
+                    .await
+                {
+                    warn!(?err, "Failed to mark challenge as announced");
+                }
+            }
+        }
+    }
+
+    async fn announce(self, challenge: &GuildChallenge) -> Result<()> {
+        let map = Context::osu_map()
+            .map(challenge.map_id, None)
+            .await
+            .wrap_err("Failed to get challenge map")?
+            .convert(challenge.mode);
+
+        let standings = self.fetch_leaderboard(challenge).await?;
+        let entries = &standings.entries;
+
+        let mut description = String::with_capacity(1024);
+
+        if entries.is_empty() {
+            description.push_str("Nobody set a score in time :(");
+        }
+
+        for (entry, idx) in entries.iter().take(Self::ANNOUNCE_ENTRIES).zip(1..) {
+            entry.write(&mut description, idx);
+        }
+
+        let mut footer = format!(
+            "Challenge ended • {} participant{}",
+            entries.len(),
+            if entries.len() == 1 { "" } else { "s" }
+        );
+
+        ChallengeStandings::write_failed(standings.failed, &mut footer);
+
+        let embed = EmbedBuilder::new()
+            .title(format!(
+                "Challenge results: {} - {} [{}]",
+                map.artist().cow_escape_markdown(),
+                map.title().cow_escape_markdown(),
+                map.version().cow_escape_markdown()
+            ))
+            .url(format!("{OSU_BASE}b/{}", challenge.map_id))
+            .description(description)
+            .thumbnail(map.thumbnail())
+            .footer(FooterBuilder::new(footer))
+            .build();
+
+        let send_res = Context::http()
+            .create_message(challenge.channel_id)
+            .embeds(&[embed])
+            .await;
+
+        match send_res {
+            Ok(_) => Ok(()),
+            Err(err) => match err.kind() {
+                // Deleted channel or missing permissions; retrying won't help
+                ErrorType::Response { status, .. } if matches!(status.get(), 403 | 404) => {
+                    warn!(
+                        challenge = challenge.challenge_id,
+                        channel = %challenge.channel_id,
+                        "Cannot send challenge results to channel, skipping announcement"
+                    );
+
+                    Ok(())
+                }
+                _ => Err(Report::new(err).wrap_err("Failed to send challenge results")),
+            },
+        }
+    }
+}
+
+pub struct GuildChallenge {
+    pub challenge_id: i32,
+    pub guild_id: Id<GuildMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    pub map_id: u32,
+    pub mode: GameMode,
+    pub mods: Option<Box<str>>,
+    pub starts_at: OffsetDateTime,
+    pub ends_at: OffsetDateTime,
+}
+
+impl GuildChallenge {
+    pub fn is_ongoing(&self) -> bool {
+        self.ends_at > OffsetDateTime::now_utc()
+    }
+
+    /// The mod selection that scores need to fit, `None` if any mods are
+    /// allowed.
+    pub fn mods(&self) -> Option<ModSelection> {
+        match ModSelection::parse(self.mods.as_deref()) {
+            ModsResult::Mods(mods) => Some(mods),
+            ModsResult::None | ModsResult::Invalid => None,
+        }
+    }
+}
+
+impl From<DbGuildChallenge> for GuildChallenge {
+    fn from(challenge: DbGuildChallenge) -> Self {
+        Self {
+            challenge_id: challenge.challenge_id,
+            guild_id: Id::new(challenge.guild_id as u64),
+            channel_id: Id::new(challenge.channel_id as u64),
+            map_id: challenge.map_id as u32,
+            mode: GameMode::from(challenge.gamemode as u8),
+            mods: challenge.mods.map(String::into_boxed_str),
+            starts_at: challenge.starts_at,
+            ends_at: challenge.ends_at,
+        }
+    }
+}
+
+/// Recently fetched challenge leaderboards, mapped by challenge id.
+pub type ChallengeLeaderboards = PapayaMap<i32, (Instant, ChallengeStandings)>;
+
+#[derive(Clone)]
+pub struct ChallengeStandings {
+    pub entries: Vec<ChallengeEntry>,
+    /// Amount of members whose scores could not be retrieved.
+    pub failed: usize,
+}
+
+impl ChallengeStandings {
+    /// Notes in the footer how many members were left out, if any.
+    pub fn write_failed(failed: usize, footer: &mut String) {
+        if failed > 0 {
+            let _ = write!(
+                footer,
+                " • Failed to check {failed} member{}",
+                if failed == 1 { "" } else { "s" }
+            );
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ChallengeEntry {
+    pub username: Username,
+    pub score: Score,
+}
+
+impl ChallengeEntry {
+    pub fn write(&self, description: &mut String, idx: usize) {
+        let score = &self.score;
+
+        let _ = writeln!(
+            description,
+            "**#{idx}** [{name}]({OSU_BASE}u/{user_id}): {grade} `{score}` • \
+            {acc:.2}% • `{combo}x` • +{mods} • {ago}",
+            name = self.username.cow_escape_markdown(),
+            user_id = score.user_id,
+            grade = grade_emote(score.grade),
+            score = WithComma::new(score.score),
+            acc = score.accuracy,
+            combo = score.max_combo,
+            mods = ModsFormatter::new(&score.mods, false),
+            ago = HowLongAgoDynamic::new(&score.ended_at),
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use bathbot_model::{BgGameScore, HlGameScore, HlVersion};
+use bathbot_model::{BgGameScore, BgGameVariant, HlGameScore, HlVersion};
 use bathbot_psql::{
     Database,
     model::games::{DbMapTagsParams, MapsetTagsEntries},
@@ -56,9 +56,9 @@ impl GameManager {
             .wrap_err("Failed to upsert higherlower score")
     }
 
-    pub async fn bggame_leaderboard(self) -> Result<Vec<BgGameScore>> {
+    pub async fn bggame_leaderboard(self, variant: BgGameVariant) -> Result<Vec<BgGameScore>> {
         self.psql
-            .select_bggame_scores()
+            .select_bggame_scores_by_variant(variant as i16)
             .await
             .wrap_err("failed to get bggame leaderboard")
     }
@@ -78,6 +78,7 @@ impl GameManager {
     pub async fn bggame_increment_scores(
         self,
         scores: &HashMap<Id<UserMarker>, u32, IntHasher>,
+        variant: BgGameVariant,
     ) -> Result<()> {
         let mut user_ids = Vec::with_capacity(scores.len());
         let mut amounts = Vec::with_capacity(scores.len());
@@ -88,7 +89,7 @@ impl GameManager {
         }
 
         self.psql
-            .increment_bggame_scores(&user_ids, &amounts)
+            .increment_bggame_scores(&user_ids, &amounts, variant as i16)
             .await
             .wrap_err("failed to increment score")
     }
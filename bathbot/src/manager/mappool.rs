@@ -0,0 +1,348 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use bathbot_psql::{Database, model::osu::DbMappoolSlot};
+use bathbot_util::matcher;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{GameMod, GameModIntermode, GameModsIntermode, MatchGame};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::core::Context;
+
+#[derive(Copy, Clone)]
+pub struct MappoolManager {
+    psql: &'static Database,
+}
+
+impl MappoolManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn get(self, guild_id: Id<GuildMarker>, name: &str) -> Result<Option<Mappool>> {
+        let slots = self
+            .psql
+            .select_guild_mappool(guild_id, name)
+            .await
+            .wrap_err("Failed to get mappool")?;
+
+        if slots.is_empty() {
+            return Ok(None);
+        }
+
+        let slots = slots
+            .into_iter()
+            .filter_map(|DbMappoolSlot { slot, map_id }| {
+                SlotLabel::parse(&slot).map(|label| (label, map_id as u32))
+            })
+            .collect();
+
+        let mut mappool = Mappool { slots };
+        mappool.sort();
+
+        Ok(Some(mappool))
+    }
+
+    pub async fn names(self, guild_id: Id<GuildMarker>) -> Result<Vec<String>> {
+        self.psql
+            .select_guild_mappool_names(guild_id)
+            .await
+            .wrap_err("Failed to get mappool names")
+    }
+
+    pub async fn store(
+        self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        mappool: &Mappool,
+    ) -> Result<()> {
+        let (slots, map_ids): (Vec<_>, Vec<_>) = mappool
+            .slots
+            .iter()
+            .map(|(label, map_id)| (label.as_str().to_owned(), *map_id as i32))
+            .unzip();
+
+        self.psql
+            .upsert_guild_mappool(guild_id, name, &slots, &map_ids)
+            .await
+            .wrap_err("Failed to store mappool")
+    }
+
+    /// Returns whether the mappool existed
+    pub async fn delete(self, guild_id: Id<GuildMarker>, name: &str) -> Result<bool> {
+        self.psql
+            .delete_guild_mappool(guild_id, name)
+            .await
+            .wrap_err("Failed to delete mappool")
+    }
+}
+
+/// Maps of a tournament stage, each labeled with a slot like `NM1` or `TB`
+pub struct Mappool {
+    slots: Vec<(SlotLabel, u32)>,
+}
+
+impl Mappool {
+    pub const MAX_SLOTS: usize = 32;
+
+    /// Parses slot-to-map pairs such as `NM1:1234 NM2:5678 HD1:9012`.
+    ///
+    /// Pairs may be separated by whitespace or commas, slot and map by `:` or
+    /// `=`. Maps can be given by id or url.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut slots: Vec<(SlotLabel, u32)> = Vec::new();
+
+        for pair in input
+            .split([' ', ',', '\n'])
+            .filter(|pair| !pair.is_empty())
+        {
+            let Some((slot, map)) = pair.split_once([':', '=']) else {
+                return Err(format!(
+                    "Failed to parse `{pair}`, expected a slot and a map like `NM1:1234`"
+                ));
+            };
+
+            let Some(label) = SlotLabel::parse(slot) else {
+                return Err(format!(
+                    "Invalid slot `{slot}`, slots must consist of up to {} letters and digits",
+                    SlotLabel::MAX_LEN
+                ));
+            };
+
+            let Some(map_id) = matcher::get_osu_map_id(map) else {
+                return Err(format!("Failed to parse map `{map}` of slot `{label}`"));
+            };
+
+            if slots
+                .iter()
+                .any(|(other, _)| other.as_str() == label.as_str())
+            {
+                return Err(format!("Slot `{label}` was specified multiple times"));
+            }
+
+            slots.push((label, map_id));
+        }
+
+        if slots.is_empty() {
+            return Err("The mappool must contain at least one slot".to_owned());
+        } else if slots.len() > Self::MAX_SLOTS {
+            return Err(format!(
+                "A mappool can contain at most {} slots",
+                Self::MAX_SLOTS
+            ));
+        }
+
+        let mut mappool = Self { slots };
+        mappool.sort();
+
+        Ok(mappool)
+    }
+
+    /// Returns the slot of the map
+    pub fn slot(&self, map_id: u32) -> Option<&SlotLabel> {
+        self.slots
+            .iter()
+            .find_map(|(label, id)| (*id == map_id).then_some(label))
+    }
+
+    /// Returns the slot of the game's map
+    pub fn game_slot(&self, game: &MatchGame) -> Option<&SlotLabel> {
+        game.map.as_ref().and_then(|map| self.slot(map.map_id))
+    }
+
+    pub fn slots(&self) -> &[(SlotLabel, u32)] {
+        &self.slots
+    }
+
+    fn sort(&mut self) {
+        self.slots
+            .sort_by(|(a, _), (b, _)| (a.pool, a.as_str()).cmp(&(b.pool, b.as_str())));
+    }
+}
+
+#[derive(Clone)]
+pub struct SlotLabel {
+    name: Box<str>,
+    pool: ModPool,
+}
+
+impl SlotLabel {
+    pub const MAX_LEN: usize = 8;
+
+    pub fn parse(slot: &str) -> Option<Self> {
+        let slot = slot.trim();
+
+        if slot.is_empty()
+            || slot.len() > Self::MAX_LEN
+            || !slot.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+
+        let name = slot.to_ascii_uppercase().into_boxed_str();
+        let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+
+        Some(Self {
+            pool: ModPool::from_abbreviation(prefix),
+            name,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    pub fn pool(&self) -> ModPool {
+        self.pool
+    }
+}
+
+/// Formats the slots the same way they can be parsed
+impl Display for Mappool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut iter = self.slots.iter();
+
+        if let Some((label, map_id)) = iter.next() {
+            write!(f, "{label}:{map_id}")?;
+
+            for (label, map_id) in iter {
+                write!(f, " {label}:{map_id}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for SlotLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.pad(&self.name)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ModPool {
+    NoMod,
+    Hidden,
+    HardRock,
+    DoubleTime,
+    FreeMod,
+    TieBreaker,
+    Other,
+}
+
+impl ModPool {
+    pub const ALL: [Self; 7] = [
+        Self::NoMod,
+        Self::Hidden,
+        Self::HardRock,
+        Self::DoubleTime,
+        Self::FreeMod,
+        Self::TieBreaker,
+        Self::Other,
+    ];
+
+    fn from_abbreviation(abbreviation: &str) -> Self {
+        match abbreviation {
+            "NM" => Self::NoMod,
+            "HD" => Self::Hidden,
+            "HR" => Self::HardRock,
+            "DT" => Self::DoubleTime,
+            "FM" => Self::FreeMod,
+            "TB" => Self::TieBreaker,
+            _ => Self::Other,
+        }
+    }
+
+    /// Classifies a game based on the mods that were enforced by the lobby.
+    ///
+    /// If no mods were enforced but some players picked mods themselves, the
+    /// game is considered freemod.
+    pub fn from_game(game: &MatchGame) -> Self {
+        let mods = without_nofail(game.mods.iter().map(GameMod::intermode).collect());
+
+        if mods.is_empty() {
+            let free_mod = game.scores.iter().any(|score| {
+                !without_nofail(score.mods.iter().map(GameMod::intermode).collect()).is_empty()
+            });
+
+            return if free_mod { Self::FreeMod } else { Self::NoMod };
+        }
+
+        if mods.contains(GameModIntermode::DoubleTime) || mods.contains(GameModIntermode::Nightcore)
+        {
+            Self::DoubleTime
+        } else if mods.contains(GameModIntermode::HardRock) {
+            Self::HardRock
+        } else if mods.contains(GameModIntermode::Hidden) && mods.len() == 1 {
+            Self::Hidden
+        } else {
+            Self::Other
+        }
+    }
+
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Self::NoMod => "NM",
+            Self::Hidden => "HD",
+            Self::HardRock => "HR",
+            Self::DoubleTime => "DT",
+            Self::FreeMod => "FM",
+            Self::TieBreaker => "TB",
+            Self::Other => "Other",
+        }
+    }
+}
+
+fn without_nofail(mods: GameModsIntermode) -> GameModsIntermode {
+    mods - GameModIntermode::NoFail
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mappool() {
+        let mappool =
+            Mappool::parse("hd1:2 NM1=1, tb:https://osu.ppy.sh/beatmaps/9\nEZ1:5 nm2:3").unwrap();
+
+        let slots: Vec<_> = mappool
+            .slots()
+            .iter()
+            .map(|(label, map_id)| (label.as_str(), label.pool(), *map_id))
+            .collect();
+
+        let expected = [
+            ("NM1", ModPool::NoMod, 1),
+            ("NM2", ModPool::NoMod, 3),
+            ("HD1", ModPool::Hidden, 2),
+            ("TB", ModPool::TieBreaker, 9),
+            ("EZ1", ModPool::Other, 5),
+        ];
+
+        assert_eq!(slots, expected);
+        assert_eq!(mappool.slot(9).map(SlotLabel::as_str), Some("TB"));
+        assert!(mappool.slot(4).is_none());
+    }
+
+    #[test]
+    fn parse_invalid_mappool() {
+        assert!(Mappool::parse("").is_err());
+        assert!(Mappool::parse("NM1 1234").is_err());
+        assert!(Mappool::parse("NM1:1 nm1:2").is_err());
+        assert!(Mappool::parse("NM-1:1").is_err());
+        assert!(Mappool::parse("NM1:abc").is_err());
+    }
+
+    #[test]
+    fn mappool_roundtrip() {
+        let mappool = Mappool::parse("TB:9 nm1=1, HD1:2").unwrap();
+        let formatted = mappool.to_string();
+        assert_eq!(formatted, "NM1:1 HD1:2 TB:9");
+
+        let reparsed = Mappool::parse(&formatted).unwrap();
+        assert_eq!(reparsed.to_string(), formatted);
+    }
+}
@@ -0,0 +1,75 @@
+use bathbot_psql::{Database, model::osu::DbMatchLiveChannel};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, MessageMarker},
+};
+
+use crate::{core::Context, manager::Mappool};
+
+#[derive(Copy, Clone)]
+pub struct MatchLiveManager {
+    psql: &'static Database,
+}
+
+impl MatchLiveManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn channels(self) -> Result<Vec<DbMatchLiveChannel>> {
+        self.psql
+            .select_match_live_channels()
+            .await
+            .wrap_err("Failed to get match live channels")
+    }
+
+    pub async fn store_match(
+        self,
+        match_id: u32,
+        last_event_id: u64,
+        mappool: Option<&Mappool>,
+    ) -> Result<()> {
+        let mappool = mappool.map(Mappool::to_string);
+
+        self.psql
+            .upsert_match_live_match(match_id, last_event_id, mappool.as_deref())
+            .await
+            .wrap_err("Failed to store match live match")
+    }
+
+    pub async fn update_last_event(self, match_id: u32, last_event_id: u64) -> Result<()> {
+        self.psql
+            .update_match_live_event(match_id, last_event_id)
+            .await
+            .wrap_err("Failed to update last match live event")
+    }
+
+    pub async fn store_channel(
+        self,
+        match_id: u32,
+        channel: Id<ChannelMarker>,
+        msg: Id<MessageMarker>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_match_live_channel(match_id, channel, msg)
+            .await
+            .wrap_err("Failed to store match live channel")
+    }
+
+    pub async fn remove_channel(self, match_id: u32, channel: Id<ChannelMarker>) -> Result<()> {
+        self.psql
+            .delete_match_live_channel(match_id, channel)
+            .await
+            .wrap_err("Failed to remove match live channel")
+    }
+
+    pub async fn remove_match(self, match_id: u32) -> Result<()> {
+        self.psql
+            .delete_match_live_match(match_id)
+            .await
+            .wrap_err("Failed to remove match live match")
+    }
+}
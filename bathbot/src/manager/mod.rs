@@ -1,11 +1,20 @@
+#[cfg(feature = "server")]
+pub use self::api_token::ApiTokenManager;
+#[cfg(feature = "matchlive")]
+pub use self::match_live::MatchLiveManager;
 #[cfg(feature = "twitch")]
 pub use self::twitch::TwitchManager;
 pub use self::{
     bookmarks::BookmarkManager,
+    card_skills::{CardSkillsManager, SkillsSnapshot},
+    challenge::{
+        ChallengeEntry, ChallengeLeaderboards, ChallengeManager, ChallengeStandings, GuildChallenge,
+    },
     games::GameManager,
     github::GithubManager,
     guild_config::GuildConfigManager,
     huismetbenen_country::HuismetbenenCountryManager,
+    mappool::{Mappool, MappoolManager, ModPool, SlotLabel},
     osu_map::{MapError, MapManager, OsuMap, OsuMapSlim},
     osu_scores::ScoresManager,
     osu_user::OsuUserManager,
@@ -18,10 +27,13 @@ pub use self::{
 pub mod redis;
 
 mod bookmarks;
+mod card_skills;
+mod challenge;
 mod games;
 mod github;
 mod guild_config;
 mod huismetbenen_country;
+mod mappool;
 mod osu_map;
 mod osu_scores;
 mod osu_user;
@@ -30,5 +42,11 @@ mod rank_pp_approx;
 mod replay;
 mod user_config;
 
+#[cfg(feature = "server")]
+mod api_token;
+
+#[cfg(feature = "matchlive")]
+mod match_live;
+
 #[cfg(feature = "twitch")]
 mod twitch;
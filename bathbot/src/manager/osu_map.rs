@@ -261,7 +261,7 @@ impl MapManager {
     }
 
     /// Request a [`BeatmapsetExtended`] from a mapset id
-    async fn retrieve_mapset(self, mapset_id: u32) -> Result<BeatmapsetExtended> {
+    pub async fn retrieve_mapset(self, mapset_id: u32) -> Result<BeatmapsetExtended> {
         match Context::osu().beatmapset(mapset_id).await {
             Ok(mapset) => {
                 let mapset_clone = mapset.clone();
@@ -1,12 +1,17 @@
 use std::{borrow::Cow, collections::HashMap};
 
-use bathbot_model::{RankingEntries, UserModeStatsColumn, UserStatsColumn};
-use bathbot_psql::Database;
+use bathbot_model::{
+    RankingEntries, RankingEntry, ScoreStatsColumn, ScoreStatsPeriod, ServerTopPlay,
+    UserModeStatsColumn, UserStatsColumn,
+};
+use bathbot_psql::{Database, model::osu::DbUserStatsHistoryEntry};
 use bathbot_util::{CowUtils, IntHasher};
 use eyre::{Result, WrapErr};
-use rosu_v2::prelude::{GameMode, UserExtended, Username};
+use rosu_v2::prelude::{GameMode, GameModsIntermode, UserExtended, Username};
+use time::OffsetDateTime;
+use tokio::time::{Duration, interval};
 
-use crate::core::Context;
+use crate::{core::Context, tracking::OsuTracking};
 
 #[derive(Copy, Clone)]
 pub struct OsuUserManager {
@@ -85,9 +90,134 @@ impl OsuUserManager {
             .wrap_err("Failed to get user mode stats")
     }
 
+    pub async fn score_stats(
+        self,
+        discord_ids: &[i64],
+        mode: GameMode,
+        column: ScoreStatsColumn,
+        period: ScoreStatsPeriod,
+    ) -> Result<RankingEntries> {
+        let since = OffsetDateTime::now_utc() - period.duration();
+
+        self.psql
+            .select_archived_score_stats(discord_ids, mode, column, since)
+            .await
+            .wrap_err("Failed to get score stats")
+    }
+
+    /// The highest pp scores of the linked discord users that were set within
+    /// the period.
+    pub async fn top_plays(
+        self,
+        discord_ids: &[i64],
+        mode: GameMode,
+        period: ScoreStatsPeriod,
+    ) -> Result<RankingEntries> {
+        const LIMIT: usize = 50;
+
+        let since = OffsetDateTime::now_utc() - period.duration();
+
+        let scores = self
+            .psql
+            .select_archived_top_scores(discord_ids, mode, since, LIMIT)
+            .await
+            .wrap_err("Failed to get top plays")?;
+
+        let user_ids: Vec<_> = scores.iter().map(|score| score.user_id).collect();
+
+        let maps_id_checksum = scores.iter().map(|score| (score.map_id, None)).collect();
+
+        let (names_res, maps_res) = tokio::join!(
+            self.names(&user_ids),
+            Context::osu_map().maps(&maps_id_checksum)
+        );
+
+        let names = names_res?;
+
+        let maps = maps_res.unwrap_or_else(|err| {
+            warn!(?err, "Failed to get maps of top plays");
+
+            HashMap::default()
+        });
+
+        let top_plays = scores
+            .into_iter()
+            .map(|score| {
+                let user_id = score.user_id as u32;
+                let map_id = score.map_id as u32;
+
+                let map = maps
+                    .get(&map_id)
+                    .map(|map| format!("{} - {} [{}]", map.artist(), map.title(), map.version()));
+
+                let value = ServerTopPlay {
+                    map_id,
+                    map,
+                    mods: GameModsIntermode::try_from_acronyms(&score.mods).unwrap_or_default(),
+                    pp: score.pp.unwrap_or(0.0),
+                    accuracy: score.accuracy,
+                    ended_at: score.ended_at,
+                };
+
+                RankingEntry {
+                    country: None,
+                    name: names
+                        .get(&user_id)
+                        .cloned()
+                        .unwrap_or_else(|| user_id.to_string().into()),
+                    value,
+                }
+            })
+            .enumerate()
+            .collect();
+
+        Ok(RankingEntries::TopPlays(top_plays))
+    }
+
     pub async fn store(self, user: &UserExtended, mode: GameMode) {
-        if let Err(err) = self.psql.upsert_osu_user(user, mode).await {
-            warn!(?err, "Failed to upsert osu user");
+        match self.psql.upsert_osu_user(user, mode).await {
+            Ok(Some(prev)) => OsuTracking::process_milestones(user, mode, &prev),
+            Ok(None) => {}
+            Err(err) => warn!(?err, "Failed to upsert osu user"),
+        }
+    }
+
+    /// The stored snapshot of the user's stats closest to the given date.
+    pub async fn snapshot(
+        self,
+        user_id: u32,
+        mode: GameMode,
+        at: OffsetDateTime,
+    ) -> Result<Option<DbUserStatsHistoryEntry>> {
+        self.psql
+            .select_osu_user_snapshot(user_id, mode, at)
+            .await
+            .wrap_err("Failed to get user snapshot")
+    }
+
+    pub async fn snapshots(
+        self,
+        user_id: u32,
+        mode: GameMode,
+        since: OffsetDateTime,
+    ) -> Result<Vec<DbUserStatsHistoryEntry>> {
+        self.psql
+            .select_osu_user_snapshots(user_id, mode, since)
+            .await
+            .wrap_err("Failed to get user snapshots")
+    }
+
+    /// Regularly thins out old user snapshots.
+    pub async fn downsample_snapshots_loop(self) {
+        let mut interval = interval(Duration::from_secs(6 * 60 * 60));
+
+        loop {
+            interval.tick().await;
+
+            match self.psql.downsample_osu_user_snapshots().await {
+                Ok(count) => debug!(count, "Downsampled user snapshots"),
+                Err(err) => warn!(?err, "Failed to downsample user snapshots"),
+            }
         }
     }
 
@@ -168,6 +168,32 @@ impl<'m> PpManager<'m> {
 
         Some(calc.calculate())
     }
+
+    /// Calculate performance attributes for the given accuracy and amount of
+    /// misses instead of a specific score.
+    ///
+    /// Returns `None` if the map is too suspicious.
+    pub async fn performance_for_acc(
+        &mut self,
+        acc: f64,
+        misses: u32,
+    ) -> Option<PerformanceAttributes> {
+        let mut calc = self
+            .difficulty()
+            .await?
+            .to_owned()
+            .performance()
+            .mods(self.mods.inner.clone())
+            .lazer(self.lazer)
+            .accuracy(acc)
+            .misses(misses);
+
+        if let Some(clock_rate) = self.mods.clock_rate {
+            calc = calc.clock_rate(clock_rate);
+        }
+
+        Some(calc.calculate())
+    }
 }
 
 pub struct ScoreData {
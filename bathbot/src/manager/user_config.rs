@@ -8,7 +8,7 @@ use eyre::{Result, WrapErr};
 use rosu_v2::prelude::{GameMode, Username};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use crate::core::Context;
+use crate::{core::Context, tracking::ScoreArchive};
 
 #[derive(Copy, Clone)]
 pub struct UserConfigManager {
@@ -111,10 +111,28 @@ impl UserConfigManager {
         user_id: Id<UserMarker>,
         config: &UserConfig<OsuUserId>,
     ) -> Result<()> {
+        let prev_osu_id = self.osu_id(user_id).await?;
+
         self.psql
             .upsert_user_config(user_id, config)
             .await
-            .wrap_err("failed to store user config")
+            .wrap_err("failed to store user config")?;
+
+        if let Some(osu_id) = config.osu {
+            ScoreArchive::add_user(osu_id);
+        }
+
+        // The previous osu!user was unlinked or replaced
+        if let Some(prev_osu_id) = prev_osu_id.filter(|&prev| config.osu != Some(prev)) {
+            // Other discord users might still be linked to it
+            let still_linked = self.discord_from_osu_id(prev_osu_id).await?.is_some();
+
+            if !still_linked {
+                ScoreArchive::remove_user(prev_osu_id);
+            }
+        }
+
+        Ok(())
     }
 
     pub async fn store_score_embed_settings(
@@ -9,7 +9,10 @@ use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
 };
 
-use crate::embeds::{MatchLiveEmbed, MatchLiveEmbeds};
+use crate::{
+    embeds::{MatchLiveEmbed, MatchLiveEmbeds},
+    manager::Mappool,
+};
 
 pub struct MatchLiveChannels {
     // use tokio's mutex because it locks across futures
@@ -81,13 +84,16 @@ pub struct TrackedMatch {
     pub osu_match: OsuMatch,
     /// All embeds of the match
     pub embeds: Vec<MatchLiveEmbed>,
+    /// Mappool to label the games with
+    pub mappool: Option<Mappool>,
 }
 
 impl TrackedMatch {
-    pub fn new(osu_match: OsuMatch, embeds: MatchLiveEmbeds) -> Self {
+    pub fn new(osu_match: OsuMatch, embeds: MatchLiveEmbeds, mappool: Option<Mappool>) -> Self {
         Self {
             osu_match,
             embeds: embeds.into_vec(),
+            mappool,
         }
     }
 }
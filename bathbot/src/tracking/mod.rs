@@ -1,16 +1,33 @@
+use rosu_v2::prelude::Score;
+
 #[cfg(feature = "twitch")]
 pub use self::twitch::online_streams::OnlineTwitchStreams;
 #[cfg(feature = "twitchtracking")]
 pub use self::twitch::twitch_loop::twitch_tracking_loop;
 pub use self::{
-    ordr::{Ordr, OrdrReceivers},
-    osu::{OsuTracking, TrackEntryParams},
+    ordr::Ordr,
+    osu::{
+        OsuTracking, TrackDigestInterval, TrackDigestUserEntry, TrackEntryParams, TrackKind,
+        TrackLeaderboardParams, TrackMapStatuses, TrackMilestoneParams, osu_digest_loop,
+    },
+    render::{Danser, IRenderBackend, RenderBackend, RenderError, RenderEvent, RenderSource},
+    score_archive::{ScoreArchive, score_archive_loop},
+    scores_file::ScoresFile,
     scores_ws::{ScoresWebSocket, ScoresWebSocketDisconnect},
 };
 
 mod ordr;
 mod osu;
+mod render;
+mod score_archive;
+mod scores_file;
 mod scores_ws;
 
 #[cfg(feature = "twitch")]
 mod twitch;
+
+/// Passes a newly submitted score on to everything that is interested in it.
+fn process_score(score: Score) {
+    ScoreArchive::process_score(&score);
+    OsuTracking::process_score(score);
+}
@@ -1,53 +1,31 @@
-use std::{
-    borrow::Borrow,
-    sync::{Arc, Mutex},
-};
+use std::sync::{Arc, Mutex};
 
-use bathbot_util::IntHasher;
+use bathbot_util::constants::ORDR_ISSUE;
 use eyre::{Report, Result, WrapErr};
-use flexmap::tokio::TokioRwLockMap;
 use rosu_render::{
     OrdrClient, OrdrWebsocket,
-    model::{RenderDone, RenderFailed, RenderProgress, Verification},
+    client::error::{ApiError as OrdrApiError, ClientError as OrdrError},
+    model::{RenderOptions, RenderSkinOption, Verification},
     websocket::event::RawEvent,
 };
 use tokio::sync::{mpsc, oneshot};
 
+use super::render::{IRenderBackend, RenderError, RenderEvent, RenderSource, RenderSubscribers};
+
+const RENDERER_NAME: &str = "Bathbot";
+
 pub struct Ordr {
     pub client: OrdrClient,
-    pub senders: Arc<SenderMap>,
+    pub subscribers: Arc<RenderSubscribers>,
     pub shutdown_tx: Mutex<Option<oneshot::Sender<()>>>,
 }
 
-type SenderMap = TokioRwLockMap<RenderId, OrdrSenders, IntHasher>;
-
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct RenderId(u32);
-
-impl Borrow<u32> for RenderId {
-    fn borrow(&self) -> &u32 {
-        &self.0
-    }
-}
-
-pub struct OrdrSenders {
-    pub done: mpsc::Sender<RenderDone>,
-    pub failed: mpsc::Sender<RenderFailed>,
-    pub progress: mpsc::Sender<RenderProgress>,
-}
-
-pub struct OrdrReceivers {
-    pub done: mpsc::Receiver<RenderDone>,
-    pub failed: mpsc::Receiver<RenderFailed>,
-    pub progress: mpsc::Receiver<RenderProgress>,
-}
-
 impl Ordr {
     pub async fn new(
         #[cfg(not(debug_assertions))] verification_key: impl Into<Box<str>>,
     ) -> Result<Self> {
-        let senders = Arc::new(SenderMap::with_shard_amount_and_hasher(8, IntHasher));
-        let senders_clone = Arc::clone(&senders);
+        let subscribers = Arc::new(RenderSubscribers::new());
+        let subscribers_clone = Arc::clone(&subscribers);
 
         #[cfg(debug_assertions)]
         let verification = Verification::DevModeSuccess;
@@ -66,11 +44,15 @@ impl Ordr {
 
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
-        tokio::spawn(handle_ordr_events(websocket, senders_clone, shutdown_rx));
+        tokio::spawn(handle_ordr_events(
+            websocket,
+            subscribers_clone,
+            shutdown_rx,
+        ));
 
         Ok(Self {
             client,
-            senders,
+            subscribers,
             shutdown_tx: Mutex::new(Some(shutdown_tx)),
         })
     }
@@ -78,45 +60,79 @@ impl Ordr {
     pub fn client(&self) -> &OrdrClient {
         &self.client
     }
+}
 
-    pub fn disconnect(&self) {
-        if let Ok(mut unlocked) = self.shutdown_tx.lock() {
-            if let Some(tx) = unlocked.take() {
-                let _ = tx.send(());
-            }
-        }
+impl IRenderBackend for Ordr {
+    fn name(&self) -> &'static str {
+        "o!rdr"
     }
 
-    pub async fn subscribe_render_id(&self, render_id: u32) -> OrdrReceivers {
-        let (done_tx, done_rx) = mpsc::channel(1);
-        let (failed_tx, failed_rx) = mpsc::channel(1);
-        let (progress_tx, progress_rx) = mpsc::channel(4);
+    fn renders_url(&self) -> Option<&str> {
+        Some("https://ordr.issou.best/renders")
+    }
 
-        let senders = OrdrSenders {
-            done: done_tx,
-            failed: failed_tx,
-            progress: progress_tx,
-        };
+    fn issue(&self) -> &'static str {
+        ORDR_ISSUE
+    }
 
-        let receivers = OrdrReceivers {
-            done: done_rx,
-            failed: failed_rx,
-            progress: progress_rx,
+    async fn commission<'a>(
+        &'a self,
+        replay: RenderSource<'a>,
+        skin: &'a RenderSkinOption<'a>,
+        options: &'a RenderOptions,
+    ) -> Result<u32, RenderError> {
+        let render_res = match replay {
+            RenderSource::File(bytes) => {
+                self.client
+                    .render_with_replay_file(bytes, RENDERER_NAME, skin)
+                    .options(options)
+                    .await
+            }
+            RenderSource::Url(url) => {
+                self.client
+                    .render_with_replay_url(url, RENDERER_NAME, skin)
+                    .options(options)
+                    .await
+            }
         };
 
-        self.senders.own(RenderId(render_id)).await.insert(senders);
+        match render_res {
+            Ok(render) => Ok(render.render_id),
+            Err(OrdrError::Response {
+                error: OrdrApiError {
+                    code: Some(code), ..
+                },
+                ..
+            }) => Err(RenderError::Rejected(format!(
+                "Error code {int} from o!rdr: {code}",
+                int = code.to_u8()
+            ))),
+            Err(err) => Err(RenderError::Report(
+                Report::new(err).wrap_err("Failed to commission o!rdr render"),
+            )),
+        }
+    }
 
-        receivers
+    async fn subscribe_render_id(&self, render_id: u32) -> mpsc::Receiver<RenderEvent> {
+        self.subscribers.subscribe(render_id).await
     }
 
-    pub async fn unsubscribe_render_id(&self, render_id: u32) {
-        self.senders.own(RenderId(render_id)).await.remove();
+    async fn unsubscribe_render_id(&self, render_id: u32) {
+        self.subscribers.unsubscribe(render_id).await;
+    }
+
+    fn disconnect(&self) {
+        if let Ok(mut unlocked) = self.shutdown_tx.lock() {
+            if let Some(tx) = unlocked.take() {
+                let _ = tx.send(());
+            }
+        }
     }
 }
 
 async fn handle_ordr_events(
     mut websocket: OrdrWebsocket,
-    senders: Arc<SenderMap>,
+    subscribers: Arc<RenderSubscribers>,
     mut shutdown_rx: oneshot::Receiver<()>,
 ) {
     loop {
@@ -136,54 +152,62 @@ async fn handle_ordr_events(
 
         match event_res {
             Ok(RawEvent::RenderProgress(progress)) => {
-                let render_id = progress.render_id;
-                let guard = senders.read(&render_id).await;
-
-                if let Some(senders) = guard.get() {
-                    match progress.deserialize() {
-                        Ok(progress) => {
-                            let _ = senders.progress.send(progress).await;
-                        }
-                        Err(err) => warn!(
-                            err = ?Report::new(err),
-                            ?progress,
-                            "Failed to deserialize o!rdr event"
-                        ),
+                if !subscribers.is_subscribed(progress.render_id).await {
+                    continue;
+                }
+
+                match progress.deserialize() {
+                    Ok(progress) => {
+                        let event = RenderEvent::Progress(progress.progress.into());
+                        subscribers.send(progress.render_id, event).await;
                     }
+                    Err(err) => warn!(
+                        err = ?Report::new(err),
+                        ?progress,
+                        "Failed to deserialize o!rdr event"
+                    ),
                 }
             }
             Ok(RawEvent::RenderDone(done)) => {
-                let render_id = done.render_id;
-                let guard = senders.read(&render_id).await;
-
-                if let Some(senders) = guard.get() {
-                    match done.deserialize() {
-                        Ok(done) => {
-                            let _ = senders.done.send(done).await;
-                        }
-                        Err(err) => warn!(
-                            err = ?Report::new(err),
-                            ?done,
-                            "Failed to deserialize o!rdr event"
-                        ),
+                if !subscribers.is_subscribed(done.render_id).await {
+                    continue;
+                }
+
+                match done.deserialize() {
+                    Ok(done) => {
+                        let event = RenderEvent::Done {
+                            video_url: done.video_url.into(),
+                        };
+
+                        subscribers.send(done.render_id, event).await;
                     }
+                    Err(err) => warn!(
+                        err = ?Report::new(err),
+                        ?done,
+                        "Failed to deserialize o!rdr event"
+                    ),
                 }
             }
             Ok(RawEvent::RenderFailed(failed)) => {
-                let render_id = failed.render_id;
-                let guard = senders.read(&render_id).await;
-
-                if let Some(senders) = guard.get() {
-                    match failed.deserialize() {
-                        Ok(failed) => {
-                            let _ = senders.failed.send(failed).await;
-                        }
-                        Err(err) => warn!(
-                            err = ?Report::new(err),
-                            ?failed,
-                            "Failed to deserialize o!rdr event"
-                        ),
+                if !subscribers.is_subscribed(failed.render_id).await {
+                    continue;
+                }
+
+                match failed.deserialize() {
+                    Ok(failed) => {
+                        warn!(?failed, "Received error from o!rdr");
+
+                        let event = RenderEvent::Failed {
+                            error: failed.error_message.into(),
+                        };
+
+                        subscribers.send(failed.render_id, event).await;
                     }
+                    Err(err) => warn!(
+                        err = ?Report::new(err),
+                        ?failed,
+                        "Failed to deserialize o!rdr event"
+                    ),
                 }
             }
             Ok(_) => {}
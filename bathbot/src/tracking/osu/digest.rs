@@ -0,0 +1,469 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::NonZeroU64,
+};
+
+use bathbot_psql::model::osu::{DbTrackedOsuDigest, DbTrackedOsuDigestScore};
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::{Duration, OffsetDateTime};
+use tokio::time::{Duration as StdDuration, interval};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use super::OsuTracking;
+use crate::{
+    active::{ActiveMessages, impls::TrackDigestPagination},
+    core::Context,
+    manager::redis::osu::{UserArgs, UserArgsSlim},
+};
+
+/// Amount of plays that are shown per user in a digest.
+const PLAYS_PER_USER: usize = 3;
+
+pub(super) type TrackDigests = HashMap<NonZeroU64, TrackDigest, IntHasher>;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TrackDigestInterval {
+    Hourly,
+    Daily,
+    Weekly,
+}
+
+impl TrackDigestInterval {
+    const fn duration(self) -> Duration {
+        match self {
+            Self::Hourly => Duration::HOUR,
+            Self::Daily => Duration::DAY,
+            Self::Weekly => Duration::WEEK,
+        }
+    }
+
+    const fn from_db(interval: i16) -> Self {
+        match interval {
+            0 => Self::Hourly,
+            1 => Self::Daily,
+            _ => Self::Weekly,
+        }
+    }
+
+    const fn into_db(self) -> i16 {
+        match self {
+            Self::Hourly => 0,
+            Self::Daily => 1,
+            Self::Weekly => 2,
+        }
+    }
+}
+
+impl Display for TrackDigestInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let interval = match self {
+            Self::Hourly => "hourly",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        };
+
+        f.write_str(interval)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(super) struct TrackDigest {
+    interval: TrackDigestInterval,
+    next_post: OffsetDateTime,
+}
+
+impl TrackDigest {
+    pub(super) fn new(interval: TrackDigestInterval) -> Self {
+        Self {
+            interval,
+            next_post: OffsetDateTime::now_utc() + interval.duration(),
+        }
+    }
+
+    /// Moves `next_post` past `now` while keeping the posting schedule.
+    fn advance(&mut self, now: OffsetDateTime) {
+        while self.next_post <= now {
+            self.next_post += self.interval.duration();
+        }
+    }
+}
+
+impl From<DbTrackedOsuDigest> for TrackDigest {
+    fn from(digest: DbTrackedOsuDigest) -> Self {
+        Self {
+            interval: TrackDigestInterval::from_db(digest.interval),
+            next_post: digest.next_post,
+        }
+    }
+}
+
+/// All scores of a user within one digest.
+pub struct TrackDigestUserEntry {
+    pub user_id: u32,
+    pub username: Box<str>,
+    pub mode: GameMode,
+    pub pp_gained: f32,
+    /// The user's current global rank.
+    pub rank: Option<u32>,
+    pub score_count: usize,
+    /// The user's best plays, sorted by pp.
+    pub scores: Box<[DbTrackedOsuDigestScore]>,
+}
+
+impl OsuTracking {
+    pub async fn set_digest(
+        channel: Id<ChannelMarker>,
+        interval: Option<TrackDigestInterval>,
+    ) -> Result<()> {
+        let channel_id = channel.into_nonzero();
+        let psql = Context::psql();
+
+        let Some(interval) = interval else {
+            Self::digests().write().unwrap().remove(&channel_id);
+
+            return psql
+                .delete_tracked_osu_digest(channel.get())
+                .await
+                .wrap_err("Failed to delete digest");
+        };
+
+        let digest = TrackDigest::new(interval);
+
+        psql.upsert_tracked_osu_digest(channel.get(), interval.into_db(), digest.next_post)
+            .await
+            .wrap_err("Failed to upsert digest")?;
+
+        Self::digests().write().unwrap().insert(channel_id, digest);
+
+        Ok(())
+    }
+
+    /// Splits the given channels into channels that have a digest and
+    /// channels that should be notified immediately.
+    pub(super) fn partition_digest_channels(
+        channels: Vec<NonZeroU64>,
+    ) -> (Vec<NonZeroU64>, Vec<NonZeroU64>) {
+        let digests = Self::digests().read().unwrap();
+
+        channels
+            .into_iter()
+            .partition(|channel_id| digests.contains_key(channel_id))
+    }
+}
+
+/// Stores the score for all given channels so that it will be included in the
+/// channels' next digest.
+pub(super) async fn buffer_score(channels: Vec<NonZeroU64>, score: DbTrackedOsuDigestScore) {
+    let psql = Context::psql();
+
+    for channel_id in channels {
+        if let Err(err) = psql
+            .insert_tracked_osu_digest_score(channel_id.get(), &score)
+            .await
+        {
+            log!(warn:
+                channel = channel_id,
+                score_id = score.score_id,
+                ?err,
+                "Failed to buffer digest score"
+            );
+        }
+    }
+}
+
+#[cold]
+pub async fn osu_digest_loop() {
+    let mut interval = interval(StdDuration::from_secs(60));
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let now = OffsetDateTime::now_utc();
+
+        let due: Vec<_> = OsuTracking::digests()
+            .write()
+            .unwrap()
+            .iter_mut()
+            .filter(|(_, digest)| digest.next_post <= now)
+            .map(|(channel_id, digest)| {
+                digest.advance(now);
+
+                (*channel_id, *digest)
+            })
+            .collect();
+
+        for (channel_id, digest) in due {
+            post_digest(channel_id, digest).await;
+        }
+    }
+}
+
+async fn post_digest(channel_id: NonZeroU64, digest: TrackDigest) {
+    let psql = Context::psql();
+
+    let upsert_fut = psql.upsert_tracked_osu_digest(
+        channel_id.get(),
+        digest.interval.into_db(),
+        digest.next_post,
+    );
+
+    if let Err(err) = upsert_fut.await {
+        log!(warn: channel = channel_id, ?err, "Failed to update digest");
+    }
+
+    let scores = match psql
+        .select_tracked_osu_digest_scores(channel_id.get())
+        .await
+    {
+        Ok(scores) if scores.is_empty() => return,
+        Ok(scores) => scores,
+        Err(err) => {
+            log!(warn: channel = channel_id, ?err, "Failed to get digest scores");
+
+            return;
+        }
+    };
+
+    // Scores are only removed once they've been posted so that they're
+    // included in the next digest otherwise.
+    let score_ids: Vec<_> = scores.iter().map(|score| score.score_id).collect();
+
+    let mut entries = digest_entries(scores);
+    add_ranks(&mut entries).await;
+
+    log!(info:
+        channel = channel_id,
+        interval = %digest.interval,
+        users = entries.len(),
+        "Posting digest"
+    );
+
+    let pagination = TrackDigestPagination::builder()
+        .entries(entries.into_boxed_slice())
+        .interval(digest.interval)
+        .build();
+
+    let channel = Id::new(channel_id.get());
+
+    if let Err(err) = ActiveMessages::builder(pagination).begin(channel).await {
+        log!(warn: %channel, ?err, "Failed to post digest");
+
+        return;
+    }
+
+    let delete_fut = psql.delete_posted_tracked_osu_digest_scores(channel_id.get(), &score_ids);
+
+    if let Err(err) = delete_fut.await {
+        log!(warn: %channel, ?err, "Failed to delete posted digest scores");
+    }
+}
+
+/// Groups scores by user and sorts users by the amount of gained pp.
+fn digest_entries(mut scores: Vec<DbTrackedOsuDigestScore>) -> Vec<TrackDigestUserEntry> {
+    let mut users = HashMap::<(i32, i16), Vec<_>>::new();
+
+    // Scores are grouped in order of their date
+    scores.sort_unstable_by_key(|score| score.ended_at);
+
+    for score in scores {
+        users
+            .entry((score.user_id, score.gamemode))
+            .or_default()
+            .push(score);
+    }
+
+    let mut entries: Vec<_> = users
+        .into_iter()
+        .map(|((user_id, mode), mut scores)| {
+            let first = &scores[0];
+            let last = &scores[scores.len() - 1];
+
+            let pp_gained = last.pp_after - first.pp_before;
+            let username = Box::from(last.username.as_str());
+
+            let score_count = scores.len();
+            scores.sort_unstable_by(|a, b| b.pp.total_cmp(&a.pp));
+            scores.truncate(PLAYS_PER_USER);
+
+            TrackDigestUserEntry {
+                user_id: user_id as u32,
+                username,
+                mode: GameMode::from(mode as u8),
+                pp_gained,
+                rank: None,
+                score_count,
+                scores: scores.into_boxed_slice(),
+            }
+        })
+        .collect();
+
+    entries.sort_unstable_by(|a, b| b.pp_gained.total_cmp(&a.pp_gained));
+
+    entries
+}
+
+async fn add_ranks(entries: &mut [TrackDigestUserEntry]) {
+    for entry in entries {
+        let user_args = UserArgsSlim::user_id(entry.user_id).mode(entry.mode);
+
+        entry.rank = match Context::redis().osu_user(UserArgs::Args(user_args)).await {
+            Ok(user) => user
+                .statistics
+                .as_ref()
+                .map(|stats| stats.global_rank.to_native())
+                .filter(|&rank| rank > 0),
+            Err(err) => {
+                log!(warn: user = entry.user_id, ?err, "Failed to get user for digest");
+
+                None
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minutes after 2025-01-01 00:00 UTC
+    fn at(minutes: i64) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_735_689_600).unwrap() + Duration::minutes(minutes)
+    }
+
+    fn score(
+        score_id: i64,
+        user_id: i32,
+        pp: f32,
+        pp_before: f32,
+        pp_after: f32,
+        ended_at: OffsetDateTime,
+    ) -> DbTrackedOsuDigestScore {
+        DbTrackedOsuDigestScore {
+            score_id,
+            user_id,
+            username: format!("user{user_id}"),
+            gamemode: GameMode::Osu as i16,
+            map_id: score_id as i32,
+            map_title: String::new(),
+            mods: String::new(),
+            pp,
+            top_index: 1,
+            pp_before,
+            pp_after,
+            ended_at,
+        }
+    }
+
+    #[test]
+    fn groups_scores_by_user_and_mode() {
+        let mut taiko = score(4, 1, 100.0, 500.0, 510.0, at(3));
+        taiko.gamemode = GameMode::Taiko as i16;
+
+        let scores = vec![
+            score(1, 1, 200.0, 1000.0, 1010.0, at(0)),
+            score(2, 2, 300.0, 2000.0, 2005.0, at(1)),
+            score(3, 1, 250.0, 1010.0, 1030.0, at(2)),
+            taiko,
+        ];
+
+        let entries = digest_entries(scores);
+        assert_eq!(entries.len(), 3);
+
+        let osu = entries
+            .iter()
+            .find(|entry| entry.user_id == 1 && entry.mode == GameMode::Osu)
+            .unwrap();
+
+        assert_eq!(osu.score_count, 2);
+
+        // Best plays first
+        let score_ids: Vec<_> = osu.scores.iter().map(|score| score.score_id).collect();
+        assert_eq!(score_ids, [3, 1]);
+
+        // From before the first score until after the last one
+        assert_eq!(osu.pp_gained, 30.0);
+    }
+
+    #[test]
+    fn pp_gain_uses_score_dates() {
+        // Buffered out of order
+        let scores = vec![
+            score(2, 1, 250.0, 1010.0, 1030.0, at(2)),
+            score(1, 1, 200.0, 1000.0, 1010.0, at(0)),
+        ];
+
+        let entries = digest_entries(scores);
+        assert_eq!(entries[0].pp_gained, 30.0);
+    }
+
+    #[test]
+    fn sorts_users_by_pp_gain() {
+        let scores = vec![
+            score(1, 1, 100.0, 1000.0, 1005.0, at(0)),
+            score(2, 2, 100.0, 2000.0, 2050.0, at(0)),
+            score(3, 3, 100.0, 3000.0, 3020.0, at(0)),
+        ];
+
+        let user_ids: Vec<_> = digest_entries(scores)
+            .iter()
+            .map(|entry| entry.user_id)
+            .collect();
+
+        assert_eq!(user_ids, [2, 3, 1]);
+    }
+
+    #[test]
+    fn truncates_plays_per_user() {
+        let scores = (0..5)
+            .map(|i| {
+                let pp = 100.0 + i as f32;
+                score(i, 1, pp, 1000.0, 1000.0, at(0))
+            })
+            .collect();
+
+        let entries = digest_entries(scores);
+        assert_eq!(entries[0].score_count, 5);
+        assert_eq!(entries[0].scores.len(), PLAYS_PER_USER);
+    }
+
+    #[test]
+    fn advance_keeps_schedule() {
+        let mut digest = TrackDigest {
+            interval: TrackDigestInterval::Hourly,
+            next_post: at(720),
+        };
+
+        // Not due yet
+        digest.advance(at(719));
+        assert_eq!(digest.next_post, at(720));
+
+        // Missed posts are skipped rather than shifting the schedule
+        digest.advance(at(870));
+        assert_eq!(digest.next_post, at(900));
+
+        digest.advance(at(900));
+        assert_eq!(digest.next_post, at(960));
+    }
+
+    #[test]
+    fn advance_by_interval() {
+        let start = at(0);
+
+        for (interval, next) in [
+            (TrackDigestInterval::Daily, at(1440)),
+            (TrackDigestInterval::Weekly, at(10080)),
+        ] {
+            let mut digest = TrackDigest {
+                interval,
+                next_post: start,
+            };
+
+            digest.advance(start);
+            assert_eq!(digest.next_post, next, "{interval}");
+        }
+    }
+}
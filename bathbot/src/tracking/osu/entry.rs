@@ -8,15 +8,21 @@ use std::{
     },
 };
 
-use bathbot_psql::model::osu::DbTrackedOsuUser;
+use bathbot_psql::model::osu::{
+    DbTrackedOsuLeaderboardUser, DbTrackedOsuMilestoneUser, DbTrackedOsuUser,
+};
 use bathbot_util::IntHasher;
 use rosu_v2::{model::GameMode, prelude::Score};
 use time::OffsetDateTime;
 
-use super::TrackEntryParams;
+use super::{
+    TrackEntryParams, TrackKind, TrackLeaderboardParams, TrackMapStatuses, TrackMilestoneParams,
+};
 use crate::core::Context;
 
 type Channels = HashMap<NonZeroU64, TrackEntryParams, IntHasher>;
+type LeaderboardChannels = HashMap<NonZeroU64, TrackLeaderboardParams, IntHasher>;
+type MilestoneChannels = HashMap<NonZeroU64, TrackMilestoneParams, IntHasher>;
 
 #[derive(Default)]
 pub struct TrackEntry {
@@ -25,6 +31,11 @@ pub struct TrackEntry {
     /// Unix timestamp of the last update
     last_ended_at: AtomicI64,
     channels: RwLock<Channels>,
+    leaderboard_channels: RwLock<LeaderboardChannels>,
+    milestone_channels: RwLock<MilestoneChannels>,
+    /// Unix timestamp of the last time the user's stats were refreshed
+    /// for milestones
+    last_milestone_refresh: AtomicI64,
 }
 
 impl TrackEntry {
@@ -32,6 +43,27 @@ impl TrackEntry {
         self.channels.read().unwrap()
     }
 
+    pub fn leaderboard_channels(&self) -> RwLockReadGuard<'_, LeaderboardChannels> {
+        self.leaderboard_channels.read().unwrap()
+    }
+
+    pub fn milestone_channels(&self) -> RwLockReadGuard<'_, MilestoneChannels> {
+        self.milestone_channels.read().unwrap()
+    }
+
+    /// Returns `true` if the last milestone refresh happened at least
+    /// `interval` seconds ago in which case the refresh timestamp is updated.
+    pub fn try_milestone_refresh(&self, interval: i64) -> bool {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let last = self.last_milestone_refresh.load(Ordering::SeqCst);
+
+        now - last >= interval
+            && self
+                .last_milestone_refresh
+                .compare_exchange(last, now, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+    }
+
     /// Pp value of the 100th top score
     pub fn last_entry(&self) -> (f32, OffsetDateTime) {
         let pp = f32::from_bits(self.last_pp.load(Ordering::SeqCst));
@@ -51,22 +83,69 @@ impl TrackEntry {
 
     fn is_empty(&self) -> bool {
         self.channels.read().unwrap().is_empty()
+            && self.leaderboard_channels.read().unwrap().is_empty()
+            && self.milestone_channels.read().unwrap().is_empty()
     }
 
-    fn remove_channel(&self, channel_id: NonZeroU64) {
-        self.channels.write().unwrap().remove(&channel_id);
+    /// Removes the channel for the given kind or for all kinds if `None`.
+    fn remove_channel(&self, channel_id: NonZeroU64, kind: Option<TrackKind>) {
+        if kind.is_none_or(|kind| kind == TrackKind::TopScores) {
+            self.channels.write().unwrap().remove(&channel_id);
+        }
+
+        if kind.is_none_or(|kind| kind == TrackKind::Leaderboard) {
+            self.leaderboard_channels
+                .write()
+                .unwrap()
+                .remove(&channel_id);
+        }
+
+        if kind.is_none_or(|kind| kind == TrackKind::Milestones) {
+            self.milestone_channels.write().unwrap().remove(&channel_id);
+        }
     }
 
     pub fn add(&self, channel_id: NonZeroU64, params: TrackEntryParams) {
         self.channels.write().unwrap().insert(channel_id, params);
     }
 
+    pub fn add_leaderboard(&self, channel_id: NonZeroU64, params: TrackLeaderboardParams) {
+        self.leaderboard_channels
+            .write()
+            .unwrap()
+            .insert(channel_id, params);
+    }
+
+    pub fn add_milestone(&self, channel_id: NonZeroU64, params: TrackMilestoneParams) {
+        self.milestone_channels
+            .write()
+            .unwrap()
+            .insert(channel_id, params);
+    }
+
     pub fn needs_last_pp(&self) -> bool {
         self.last_pp.load(Ordering::SeqCst) == 0
     }
 
     /// Stores the 100th score's pp value both in-memory and in the DB.
     pub async fn insert_last_pp(&self, user_id: u32, mode: GameMode, top_scores: &[Score]) {
+        let (pp, last_ended_at) = self.store_top_scores(top_scores);
+        let upsert_fut = Context::psql().upsert_tracked_last_pp(user_id, mode, pp, last_ended_at);
+
+        if let Err(err) = upsert_fut.await {
+            error!(
+                user_id,
+                ?mode,
+                last_pp = pp,
+                ?err,
+                "Failed to upsert tracked last pp"
+            );
+        }
+    }
+
+    /// Stores the 100th score's pp value and the latest score date in-memory
+    /// and returns them.
+    pub fn store_top_scores(&self, top_scores: &[Score]) -> (f32, OffsetDateTime) {
         let pp = top_scores
             .last()
             .filter(|_| top_scores.len() == 100)
@@ -80,17 +159,8 @@ impl TrackEntry {
             .unwrap_or_else(OffsetDateTime::now_utc);
 
         self.store_last_pp(pp, last_ended_at);
-        let upsert_fut = Context::psql().upsert_tracked_last_pp(user_id, mode, pp, last_ended_at);
 
-        if let Err(err) = upsert_fut.await {
-            error!(
-                user_id,
-                ?mode,
-                last_pp = pp,
-                ?err,
-                "Failed to upsert tracked last pp"
-            );
-        }
+        (pp, last_ended_at)
     }
 
     fn store_last_pp(&self, pp: f32, ended_at: OffsetDateTime) {
@@ -112,10 +182,34 @@ impl TrackEntry {
                 user.max_index.map(|n| n as u8),
             )
             .with_pp(user.min_pp, user.max_pp)
-            .with_combo_percent(user.min_combo_percent, user.max_combo_percent);
+            .with_combo_percent(user.min_combo_percent, user.max_combo_percent)
+            .with_stars(user.min_stars, user.max_stars)
+            .with_mods(TrackEntryParams::mods_from_db(user.mods, user.mods_kind))
+            .with_map_statuses(
+                user.map_statuses
+                    .map(|bits| TrackMapStatuses::from_bits_truncate(bits as u8)),
+            );
 
         self.channels.write().unwrap().insert(channel_id, params);
     }
+
+    fn insert_leaderboard(&self, user: DbTrackedOsuLeaderboardUser) {
+        let Some(channel_id) = NonZeroU64::new(user.channel_id as u64) else {
+            return;
+        };
+
+        let params = TrackLeaderboardParams::from_db(user.max_global_rank, user.max_country_rank);
+        self.add_leaderboard(channel_id, params);
+    }
+
+    fn insert_milestone(&self, user: DbTrackedOsuMilestoneUser) {
+        let Some(channel_id) = NonZeroU64::new(user.channel_id as u64) else {
+            return;
+        };
+
+        let params = TrackMilestoneParams::from_db(user.ranks, user.pp_step, user.playcount_step);
+        self.add_milestone(channel_id, params);
+    }
 }
 
 #[derive(Clone, Default)]
@@ -137,14 +231,19 @@ impl TrackedUser {
         Arc::clone(&self.modes[mode as usize])
     }
 
-    pub fn remove_channel(&self, channel_id: NonZeroU64, mode: Option<GameMode>) {
+    pub fn remove_channel(
+        &self,
+        channel_id: NonZeroU64,
+        mode: Option<GameMode>,
+        kind: Option<TrackKind>,
+    ) {
         if let Some(mode) = mode {
             if let Some(entry) = self.try_get(mode) {
-                entry.remove_channel(channel_id);
+                entry.remove_channel(channel_id, kind);
             }
         } else {
             for entry in self.modes.iter() {
-                entry.remove_channel(channel_id);
+                entry.remove_channel(channel_id, kind);
             }
         }
     }
@@ -152,4 +251,12 @@ impl TrackedUser {
     pub fn insert(&self, user: DbTrackedOsuUser) {
         self.modes[user.gamemode as usize].insert(user);
     }
+
+    pub fn insert_leaderboard(&self, user: DbTrackedOsuLeaderboardUser) {
+        self.modes[user.gamemode as usize].insert_leaderboard(user);
+    }
+
+    pub fn insert_milestone(&self, user: DbTrackedOsuMilestoneUser) {
+        self.modes[user.gamemode as usize].insert_milestone(user);
+    }
 }
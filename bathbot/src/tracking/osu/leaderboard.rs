@@ -0,0 +1,141 @@
+use std::{slice, sync::Arc};
+
+use rosu_v2::prelude::{RankStatus, Score};
+
+use super::{
+    entry::TrackEntry,
+    process_score::{embed_builder, jitter, notify_channels},
+};
+use crate::{
+    core::{BotMetrics, Context},
+    manager::redis::osu::{UserArgs, UserArgsSlim},
+};
+
+/// Amount of scores that are requested from the map leaderboard. Country
+/// ranks can only be determined within these scores because the country
+/// leaderboard of a map requires a supporter's user authentication.
+const LEADERBOARD_LIMIT: u32 = 100;
+
+/// Whether the map of the score has a leaderboard.
+pub fn has_leaderboard(score: &Score) -> bool {
+    // If the map is not available, let's check the leaderboard regardless
+    score.map.as_ref().is_none_or(|map| {
+        matches!(
+            map.status,
+            RankStatus::Ranked | RankStatus::Approved | RankStatus::Qualified | RankStatus::Loved
+        )
+    })
+}
+
+pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
+    // Same as for top scores, give the osu!api some time to process the score
+    tokio::time::sleep(jitter()).await;
+
+    let user_id = score.user_id;
+    let score_id = score.id;
+    let map_id = score.map_id;
+    let mode = score.mode;
+
+    let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+    let user_fut = Context::redis().osu_user(UserArgs::Args(user_args));
+
+    let leaderboard_fut =
+        Context::osu_scores().map_leaderboard(map_id, mode, None, LEADERBOARD_LIMIT, false);
+
+    let checksum = score.map.as_ref().and_then(|map| map.checksum.as_deref());
+    let map_fut = Context::osu_map().map(map_id, checksum);
+
+    let (user, leaderboard, map) = match tokio::join!(user_fut, leaderboard_fut, map_fut) {
+        (Ok(user), Ok(scores), Ok(map)) => (user, scores, map),
+        (Err(err), ..) => {
+            log!(warn: user = user_id, ?mode, score_id, ?err, "Failed to get user");
+
+            return;
+        }
+        (_, Err(err), _) => {
+            log!(warn:
+                map = map_id,
+                user = user_id,
+                score_id,
+                ?err,
+                "Failed to get map leaderboard"
+            );
+
+            return;
+        }
+        (.., Err(err)) => {
+            log!(warn:
+                map = map_id,
+                user = user_id,
+                score_id,
+                ?err,
+                "Failed to get map"
+            );
+
+            return;
+        }
+    };
+
+    // Scores outside of the global top 100 are skipped even if they would
+    // reach the tracked country rank.
+    let Some(idx) = leaderboard.iter().position(|s| s.id == score_id) else {
+        log!(info:
+            user = user_id,
+            map = map_id,
+            score_id,
+            "Not on map leaderboard",
+        );
+
+        return;
+    };
+
+    let global_rank = idx + 1;
+
+    let country_code = user.country_code.as_str();
+
+    // The score's own user might be missing so we ensure that the rank is at
+    // least 1.
+    let country_rank = leaderboard[..=idx]
+        .iter()
+        .filter(|score| {
+            score
+                .user
+                .as_ref()
+                .is_some_and(|user| user.country_code.as_str() == country_code)
+        })
+        .count()
+        .max(1);
+
+    let channels: Vec<_> = entry
+        .leaderboard_channels()
+        .iter()
+        .filter_map(|(channel_id, params)| {
+            params
+                .matches(global_rank, country_rank)
+                .then_some(*channel_id)
+        })
+        .collect();
+
+    log!(info:
+        user = user_id,
+        map = map_id,
+        score_id,
+        global_rank,
+        country_rank,
+        channels = channels.len(),
+        "New leaderboard score",
+    );
+
+    if channels.is_empty() {
+        return;
+    }
+
+    BotMetrics::osu_tracking_hit(score.mode);
+
+    let content = format!("Global #{global_rank} • Country #{country_rank} ({country_code})");
+
+    let (builder, _) = embed_builder(&user, score, map, None, Some(global_rank)).await;
+    let embed = builder.build();
+
+    notify_channels(channels, Some(&content), slice::from_ref(&embed)).await;
+}
@@ -0,0 +1,123 @@
+use std::{fmt::Write, slice};
+
+use bathbot_psql::model::osu::DbUserModeSnapshot;
+use bathbot_util::{
+    AuthorBuilder, EmbedBuilder, FooterBuilder, constants::OSU_BASE, osu::flag_url,
+};
+use rosu_v2::prelude::{GameMode, UserExtended};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use super::{
+    OsuTracking,
+    process_score::{jitter, notify_channels},
+};
+use crate::{
+    core::Context,
+    manager::redis::osu::{UserArgs, UserArgsSlim},
+    util::Emote,
+};
+
+/// Minimum amount of seconds between two milestone refreshes of a user.
+/// Coincides with the duration that users are cached for.
+pub(super) const REFRESH_INTERVAL: i64 = 10 * 60;
+
+/// Requests the user so that, if the user is not cached, the new stats will be
+/// stored and thus compared against the previous stats.
+pub(super) async fn refresh_user(user_id: u32, mode: GameMode) {
+    // Give the osu!api some time to update the user's stats
+    tokio::time::sleep(jitter()).await;
+
+    let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+
+    if let Err(err) = Context::redis().osu_user(UserArgs::Args(user_args)).await {
+        log!(warn: user = user_id, ?mode, ?err, "Failed to refresh user for milestones");
+    }
+}
+
+/// Removes the user's milestone tracking in the channel from the database.
+pub(super) async fn remove_user(user_id: u32, mode: Option<GameMode>, channel: Id<ChannelMarker>) {
+    let delete_fut =
+        Context::psql().delete_tracked_osu_milestone_user(user_id, mode, channel.get());
+
+    if let Err(err) = delete_fut.await {
+        log!(error: user = user_id, ?mode, %channel, ?err, "Failed to delete tracked milestone user");
+    }
+}
+
+impl OsuTracking {
+    /// Checks whether the user reached any milestones since the previous
+    /// snapshot of their stats and notifies the corresponding channels.
+    pub fn process_milestones(user: &UserExtended, mode: GameMode, prev: &DbUserModeSnapshot) {
+        let Some(ref stats) = user.statistics else {
+            return;
+        };
+
+        let entry_opt = Self::users()
+            .read()
+            .unwrap()
+            .get(&user.user_id)
+            .and_then(|tracked| tracked.try_get(mode));
+
+        let Some(entry) = entry_opt else { return };
+
+        let curr = DbUserModeSnapshot {
+            pp: stats.pp,
+            global_rank: stats.global_rank.unwrap_or(0) as i32,
+            playcount: stats.playcount as i32,
+        };
+
+        let notifs: Vec<_> = entry
+            .milestone_channels()
+            .iter()
+            .filter_map(|(channel_id, params)| {
+                let milestones = params.reached(prev, &curr);
+
+                (!milestones.is_empty()).then_some((*channel_id, milestones))
+            })
+            .collect();
+
+        if notifs.is_empty() {
+            return;
+        }
+
+        log!(info:
+            user = user.user_id,
+            ?mode,
+            prev_pp = prev.pp,
+            pp = curr.pp,
+            prev_rank = prev.global_rank,
+            rank = curr.global_rank,
+            prev_playcount = prev.playcount,
+            playcount = curr.playcount,
+            channels = notifs.len(),
+            "Milestones reached",
+        );
+
+        let author = AuthorBuilder::new(user.username.to_string())
+            .url(format!("{OSU_BASE}u/{}", user.user_id))
+            .icon_url(flag_url(user.country_code.as_str()));
+
+        let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
+        let thumbnail = user.avatar_url.clone();
+
+        tokio::spawn(async move {
+            for (channel_id, milestones) in notifs {
+                let mut description = String::new();
+
+                for milestone in milestones {
+                    let _ = writeln!(description, "🎉 {milestone}");
+                }
+
+                let embed = EmbedBuilder::new()
+                    .author(author.clone())
+                    .description(description)
+                    .footer(footer.clone())
+                    .thumbnail(thumbnail.as_str())
+                    .title("Milestone reached")
+                    .build();
+
+                notify_channels(vec![channel_id], None, slice::from_ref(&embed)).await;
+            }
+        });
+    }
+}
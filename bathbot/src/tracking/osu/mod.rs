@@ -5,7 +5,11 @@ macro_rules! log {
     };
 }
 
-use std::{collections::HashMap, sync::RwLock};
+use std::{
+    collections::HashMap,
+    num::NonZeroU64,
+    sync::{Arc, RwLock},
+};
 
 use bathbot_psql::Database;
 use bathbot_util::{IntHasher, datetime::NAIVE_DATETIME_FORMAT};
@@ -13,11 +17,24 @@ use eyre::{Result, WrapErr};
 use rosu_v2::{model::GameMode, prelude::Score};
 use twilight_model::id::{Id, marker::ChannelMarker};
 
-use self::{entry::TrackedUser, require_top::RequireTopScores};
-pub use self::{params::TrackEntryParams, stats::OsuTrackingStats};
+use self::{
+    digest::TrackDigests,
+    entry::{TrackEntry, TrackedUser},
+    require_top::RequireTopScores,
+};
+pub use self::{
+    digest::{TrackDigestInterval, TrackDigestUserEntry, osu_digest_loop},
+    params::{
+        TrackEntryParams, TrackKind, TrackLeaderboardParams, TrackMapStatuses, TrackMilestoneParams,
+    },
+    stats::OsuTrackingStats,
+};
 use crate::core::Context;
 
+mod digest;
 mod entry;
+mod leaderboard;
+mod milestone;
 mod params;
 mod process_score;
 mod require_top;
@@ -27,6 +44,7 @@ type TrackedUsers = RwLock<HashMap<u32, TrackedUser, IntHasher>>;
 
 pub struct OsuTracking {
     users: TrackedUsers,
+    digests: RwLock<TrackDigests>,
 }
 
 impl OsuTracking {
@@ -38,14 +56,53 @@ impl OsuTracking {
             .await
             .wrap_err("Failed to fetch tracked users")?;
 
+        let leaderboard_data = psql
+            .select_tracked_osu_leaderboard_users()
+            .await
+            .wrap_err("Failed to fetch tracked leaderboard users")?;
+
+        let milestone_data = psql
+            .select_tracked_osu_milestone_users()
+            .await
+            .wrap_err("Failed to fetch tracked milestone users")?;
+
+        let digest_data = psql
+            .select_tracked_osu_digests()
+            .await
+            .wrap_err("Failed to fetch tracked digests")?;
+
         let mut users = HashMap::<u32, TrackedUser, IntHasher>::default();
 
         for user in data {
             users.entry(user.user_id as u32).or_default().insert(user);
         }
 
+        for user in leaderboard_data {
+            users
+                .entry(user.user_id as u32)
+                .or_default()
+                .insert_leaderboard(user);
+        }
+
+        for user in milestone_data {
+            users
+                .entry(user.user_id as u32)
+                .or_default()
+                .insert_milestone(user);
+        }
+
+        let digests = digest_data
+            .into_iter()
+            .filter_map(|digest| {
+                let channel_id = NonZeroU64::new(digest.channel_id as u64)?;
+
+                Some((channel_id, digest.into()))
+            })
+            .collect();
+
         Ok(Self {
             users: RwLock::new(users),
+            digests: RwLock::new(digests),
         })
     }
 
@@ -57,35 +114,27 @@ impl OsuTracking {
         &Context::tracking().users
     }
 
-    pub(super) fn process_score(score: Score) {
-        let Some(pp) = score.pp else { return };
-
-        let entry_opt = Self::users()
-            .read()
-            .unwrap()
-            .get(&score.user_id)
-            .and_then(|user| user.try_get(score.mode));
+    fn digests() -> &'static RwLock<TrackDigests> {
+        &Context::tracking().digests
+    }
 
-        let Some(entry) = entry_opt else {
+    pub(super) fn process_score(score: Score) {
+        let Some(actions) = ScoreActions::new(Self::users(), &score) else {
             return;
         };
 
-        let (last_pp, last_updated) = entry.last_entry();
-
-        log!(info:
-            user = score.user_id,
-            score_id = score.id,
-            pp,
-            ended_at = %score.ended_at.format(NAIVE_DATETIME_FORMAT).unwrap(),
-            last_pp,
-            last_ended_at = %last_updated.format(NAIVE_DATETIME_FORMAT).unwrap(),
-        );
+        if actions.leaderboard {
+            let entry = Arc::clone(&actions.entry);
+            tokio::spawn(leaderboard::process_score(score.clone(), entry));
+        }
 
-        if last_pp > pp || last_updated >= score.ended_at {
-            return;
+        if actions.milestone_refresh {
+            tokio::spawn(milestone::refresh_user(score.user_id, score.mode));
         }
 
-        tokio::spawn(process_score::process_score(score, entry));
+        if actions.top_score {
+            tokio::spawn(process_score::process_score(score, actions.entry));
+        }
     }
 
     pub async fn remove_channel(channel: Id<ChannelMarker>, mode: Option<GameMode>) {
@@ -97,25 +146,77 @@ impl OsuTracking {
             // should be fine since user entries are small so we won't flood
             // the memory and the user entry won't contain channels so there's
             // no overhead to processing scores either.
-            user.remove_channel(channel_id, mode);
+            user.remove_channel(channel_id, mode, None);
         }
 
-        let delete_fut = Context::psql().delete_tracked_osu_channel(channel.get(), mode);
+        let psql = Context::psql();
+        let delete_fut = psql.delete_tracked_osu_channel(channel.get(), mode);
 
         if let Err(err) = delete_fut.await {
             error!(%channel, ?mode, ?err, "Failed to remove tracked users of channel");
         }
+
+        let delete_fut = psql.delete_tracked_osu_leaderboard_channel(channel.get(), mode);
+
+        if let Err(err) = delete_fut.await {
+            error!(%channel, ?mode, ?err, "Failed to remove tracked leaderboard users of channel");
+        }
+
+        let delete_fut = psql.delete_tracked_osu_milestone_channel(channel.get(), mode);
+
+        if let Err(err) = delete_fut.await {
+            error!(%channel, ?mode, ?err, "Failed to remove tracked milestone users of channel");
+        }
+
+        // The digest stays if only a single mode is no longer tracked
+        let Some(mode) = mode else {
+            if let Err(err) = Self::set_digest(channel, None).await {
+                error!(%channel, ?err, "Failed to remove digest of channel");
+            }
+
+            return;
+        };
+
+        if let Err(err) = psql
+            .delete_tracked_osu_digest_scores(channel.get(), mode)
+            .await
+        {
+            error!(%channel, ?mode, ?err, "Failed to remove digest scores of channel");
+        }
     }
 
-    pub async fn remove_user(user_id: u32, mode: Option<GameMode>, channel: Id<ChannelMarker>) {
+    /// Untracks the user in the channel for the given kind of notifications
+    /// or for all kinds if `None`.
+    pub async fn remove_user(
+        user_id: u32,
+        mode: Option<GameMode>,
+        channel: Id<ChannelMarker>,
+        kind: Option<TrackKind>,
+    ) {
         if let Some(user) = Self::users().read().unwrap().get(&user_id) {
-            user.remove_channel(channel.into_nonzero(), mode);
+            user.remove_channel(channel.into_nonzero(), mode, kind);
         }
 
-        let delete_fut = Context::psql().delete_tracked_osu_user(user_id, mode, channel.get());
+        let psql = Context::psql();
 
-        if let Err(err) = delete_fut.await {
-            error!(user_id, ?mode, %channel, ?err, "Failed to delete tracked user");
+        if kind.is_none_or(|kind| kind == TrackKind::TopScores) {
+            let delete_fut = psql.delete_tracked_osu_user(user_id, mode, channel.get());
+
+            if let Err(err) = delete_fut.await {
+                error!(user_id, ?mode, %channel, ?err, "Failed to delete tracked user");
+            }
+        }
+
+        if kind.is_none_or(|kind| kind == TrackKind::Leaderboard) {
+            let delete_fut = psql.delete_tracked_osu_leaderboard_user(user_id, mode, channel.get());
+
+            if let Err(err) = delete_fut.await {
+                error!(user_id, ?mode, %channel, ?err, "Failed to delete tracked leaderboard user");
+            }
+        }
+
+        if kind.is_none_or(|kind| kind == TrackKind::Milestones) {
+            milestone::remove_user(user_id, mode, channel).await;
         }
     }
 
@@ -128,17 +229,10 @@ impl OsuTracking {
     ) -> Result<Option<RequireTopScores>> {
         let entry = params.into_db_entry(user_id, mode);
 
-        let user_entry = Self::users()
-            .write()
-            .unwrap()
-            .entry(user_id)
-            .or_default()
-            .get(mode);
-
-        let channel_id = channel.into_nonzero();
-        user_entry.add(channel_id, params);
+        let needs_last_pp =
+            Self::insert_user(Self::users(), user_id, mode, channel.into_nonzero(), params);
 
-        if user_entry.needs_last_pp() {
+        if needs_last_pp {
             return Ok(Some(RequireTopScores::new(entry, channel.get())));
         }
 
@@ -150,6 +244,66 @@ impl OsuTracking {
         Ok(None)
     }
 
+    /// Adds the channel to the user's entry and returns whether the entry
+    /// still requires the user's top scores.
+    fn insert_user(
+        users: &TrackedUsers,
+        user_id: u32,
+        mode: GameMode,
+        channel_id: NonZeroU64,
+        params: TrackEntryParams,
+    ) -> bool {
+        let user_entry = users.write().unwrap().entry(user_id).or_default().get(mode);
+
+        user_entry.add(channel_id, params);
+
+        user_entry.needs_last_pp()
+    }
+
+    pub async fn add_leaderboard_user(
+        user_id: u32,
+        mode: GameMode,
+        channel: Id<ChannelMarker>,
+        params: TrackLeaderboardParams,
+    ) -> Result<()> {
+        let entry = params.into_db_entry(user_id, mode);
+
+        Self::users()
+            .write()
+            .unwrap()
+            .entry(user_id)
+            .or_default()
+            .get(mode)
+            .add_leaderboard(channel.into_nonzero(), params);
+
+        Context::psql()
+            .upsert_tracked_osu_leaderboard_user(&entry, channel.get())
+            .await
+            .wrap_err("Failed to upsert tracked osu leaderboard user")
+    }
+
+    pub async fn add_milestone_user(
+        user_id: u32,
+        mode: GameMode,
+        channel: Id<ChannelMarker>,
+        params: TrackMilestoneParams,
+    ) -> Result<()> {
+        let entry = params.clone().into_db_entry(user_id, mode);
+
+        Self::users()
+            .write()
+            .unwrap()
+            .entry(user_id)
+            .or_default()
+            .get(mode)
+            .add_milestone(channel.into_nonzero(), params);
+
+        Context::psql()
+            .upsert_tracked_osu_milestone_user(&entry, channel.get())
+            .await
+            .wrap_err("Failed to upsert tracked osu milestone user")
+    }
+
     pub async fn tracked_users_in_channel(
         channel: Id<ChannelMarker>,
     ) -> Result<Vec<(u32, GameMode, TrackEntryParams)>> {
@@ -169,4 +323,406 @@ impl OsuTracking {
 
         Ok(entries)
     }
+
+    pub async fn tracked_leaderboard_users_in_channel(
+        channel: Id<ChannelMarker>,
+    ) -> Result<Vec<(u32, GameMode, TrackLeaderboardParams)>> {
+        let entries = Context::psql()
+            .select_tracked_osu_leaderboard_users_channel(channel.get())
+            .await
+            .wrap_err("Failed to fetch leaderboard users")?
+            .into_iter()
+            .map(|entry| {
+                let user_id = entry.user_id as u32;
+                let mode = GameMode::from(entry.gamemode as u8);
+                let params = TrackLeaderboardParams::from(entry);
+
+                (user_id, mode, params)
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    pub async fn tracked_milestone_users_in_channel(
+        channel: Id<ChannelMarker>,
+    ) -> Result<Vec<(u32, GameMode, TrackMilestoneParams)>> {
+        let entries = Context::psql()
+            .select_tracked_osu_milestone_users_channel(channel.get())
+            .await
+            .wrap_err("Failed to fetch milestone users")?
+            .into_iter()
+            .map(|entry| {
+                let user_id = entry.user_id as u32;
+                let mode = GameMode::from(entry.gamemode as u8);
+                let params = TrackMilestoneParams::from(entry);
+
+                (user_id, mode, params)
+            })
+            .collect();
+
+        Ok(entries)
+    }
+}
+
+/// What needs to be done for an incoming score.
+struct ScoreActions {
+    entry: Arc<TrackEntry>,
+    /// Whether the score's map leaderboard should be checked
+    leaderboard: bool,
+    /// Whether the user's stats should be refreshed for milestones
+    milestone_refresh: bool,
+    /// Whether the score is a potential new top score
+    top_score: bool,
+}
+
+impl ScoreActions {
+    /// Returns `None` if the score's user is not tracked for its mode.
+    fn new(users: &TrackedUsers, score: &Score) -> Option<Self> {
+        let entry = users
+            .read()
+            .unwrap()
+            .get(&score.user_id)
+            .and_then(|user| user.try_get(score.mode))?;
+
+        let leaderboard = score.passed
+            && !entry.leaderboard_channels().is_empty()
+            && leaderboard::has_leaderboard(score);
+
+        let milestone_refresh = !entry.milestone_channels().is_empty()
+            && entry.try_milestone_refresh(milestone::REFRESH_INTERVAL);
+
+        let top_score = Self::is_potential_top_score(&entry, score);
+
+        Some(Self {
+            entry,
+            leaderboard,
+            milestone_refresh,
+            top_score,
+        })
+    }
+
+    fn is_potential_top_score(entry: &TrackEntry, score: &Score) -> bool {
+        if entry.channels().is_empty() {
+            return false;
+        }
+
+        let Some(pp) = score.pp else { return false };
+
+        let (last_pp, last_updated) = entry.last_entry();
+
+        log!(info:
+            user = score.user_id,
+            score_id = score.id,
+            pp,
+            ended_at = %score.ended_at.format(NAIVE_DATETIME_FORMAT).unwrap(),
+            last_pp,
+            last_ended_at = %last_updated.format(NAIVE_DATETIME_FORMAT).unwrap(),
+        );
+
+        last_pp <= pp && last_updated < score.ended_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::RankStatus;
+
+    use super::*;
+    use crate::tracking::scores_file::tests::replay;
+
+    const USER_ID: u32 = 2;
+
+    fn channel(id: u64) -> NonZeroU64 {
+        NonZeroU64::new(id).unwrap()
+    }
+
+    fn score_json(id: u64, pp: Option<f32>, ended_at: &str, passed: bool) -> String {
+        crate::tracking::scores_file::tests::score_json(id, USER_ID, pp, ended_at, passed)
+    }
+
+    /// `count` top scores starting at 200pp and decreasing by 1pp each.
+    async fn top_scores(count: u64) -> Vec<Score> {
+        let ndjson: String = (0..count)
+            .map(|i| score_json(i, Some(200.0 - i as f32), "2025-01-01T00:00:00Z", true) + "\n")
+            .collect();
+
+        replay(&ndjson).await
+    }
+
+    /// Tracks the user in a channel the same way `/track add` does.
+    async fn tracked_users(top_score_count: u64) -> TrackedUsers {
+        let users = TrackedUsers::default();
+        let params = TrackEntryParams::new();
+
+        let needs_last_pp =
+            OsuTracking::insert_user(&users, USER_ID, GameMode::Osu, channel(1), params.clone());
+        assert!(needs_last_pp);
+
+        let top_scores = top_scores(top_score_count).await;
+
+        RequireTopScores::new(params.into_db_entry(USER_ID, GameMode::Osu), 1)
+            .callback_in_memory(&users, &top_scores);
+
+        users
+    }
+
+    /// Runs the score through the same steps as [`OsuTracking::process_score`]
+    /// and [`process_score::process_score`] and returns the channels that
+    /// would be notified.
+    ///
+    /// `tops` are the user's top scores as requested after the score was set.
+    fn notified_channels(users: &TrackedUsers, score: &Score, tops: &[Score]) -> Vec<u64> {
+        let Some(actions) = ScoreActions::new(users, score) else {
+            return Vec::new();
+        };
+
+        if !actions.top_score {
+            return Vec::new();
+        }
+
+        let Some(idx) = process_score::top_index(tops, score.id) else {
+            return Vec::new();
+        };
+
+        let data = params::TrackScoreData {
+            idx: idx as u8 + 1,
+            pp: score.pp.unwrap(),
+            combo_percent: Some(90.0),
+            stars: Some(5.0),
+            mods: score.mods.clone(),
+            status: RankStatus::Ranked,
+        };
+
+        let mut channels: Vec<_> = process_score::matching_channels(&actions.entry, &data)
+            .into_iter()
+            .map(NonZeroU64::get)
+            .collect();
+
+        channels.sort_unstable();
+
+        channels
+    }
+
+    /// The given top scores including `score`, sorted by pp and capped at 100.
+    fn tops_with(mut tops: Vec<Score>, score: &Score) -> Vec<Score> {
+        tops.push(score.clone());
+        tops.sort_by(|a, b| b.pp.partial_cmp(&a.pp).unwrap());
+        tops.truncate(100);
+
+        tops
+    }
+
+    async fn top_score_actions(users: &TrackedUsers, ndjson: &[String]) -> Vec<bool> {
+        replay(&ndjson.join("\n"))
+            .await
+            .iter()
+            .map(|score| ScoreActions::new(users, score).unwrap().top_score)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn untracked_user() {
+        let users = TrackedUsers::default();
+        let ndjson = score_json(1, Some(100.0), "2025-01-02T00:00:00Z", true);
+
+        for score in replay(&ndjson).await {
+            assert!(ScoreActions::new(&users, &score).is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn untracked_mode() {
+        let users = tracked_users(100).await;
+
+        let mut scores = replay(&score_json(1, Some(150.0), "2025-01-02T00:00:00Z", true)).await;
+        scores[0].mode = GameMode::Taiko;
+
+        assert!(ScoreActions::new(&users, &scores[0]).is_none());
+    }
+
+    #[tokio::test]
+    async fn require_top_stores_last_pp() {
+        let users = tracked_users(100).await;
+
+        let entry = users
+            .read()
+            .unwrap()
+            .get(&USER_ID)
+            .unwrap()
+            .get(GameMode::Osu);
+        assert!(!entry.needs_last_pp());
+        assert_eq!(entry.last_entry().0, 101.0);
+
+        // Tracking the user in another channel no longer requires top scores
+        let params = TrackEntryParams::new();
+        let needs_last_pp =
+            OsuTracking::insert_user(&users, USER_ID, GameMode::Osu, channel(2), params);
+        assert!(!needs_last_pp);
+    }
+
+    #[tokio::test]
+    async fn require_top_with_incomplete_top_scores() {
+        let users = tracked_users(50).await;
+
+        // Without a 100th score there is no pp threshold
+        let params = TrackEntryParams::new();
+        let needs_last_pp =
+            OsuTracking::insert_user(&users, USER_ID, GameMode::Osu, channel(2), params);
+        assert!(needs_last_pp);
+
+        let ndjson = [
+            // Any pp value is a potential top score
+            score_json(1, Some(1.0), "2025-01-02T00:00:00Z", true),
+            // Still older than the latest top score
+            score_json(2, Some(1.0), "2024-12-31T00:00:00Z", true),
+        ];
+
+        assert_eq!(top_score_actions(&users, &ndjson).await, [true, false]);
+    }
+
+    #[tokio::test]
+    async fn process_score_top_scores() {
+        let users = tracked_users(100).await;
+
+        let ndjson = [
+            // Not enough pp
+            score_json(1, Some(100.0), "2025-01-02T00:00:00Z", true),
+            // Potential top score
+            score_json(2, Some(150.0), "2025-01-02T00:01:00Z", true),
+            // Older than the latest top score
+            score_json(3, Some(150.0), "2024-12-31T00:00:00Z", true),
+            // No pp
+            score_json(4, None, "2025-01-02T00:02:00Z", true),
+        ];
+
+        assert_eq!(
+            top_score_actions(&users, &ndjson).await,
+            [false, true, false, false]
+        );
+    }
+
+    #[tokio::test]
+    async fn process_score_removed_channel() {
+        let users = tracked_users(100).await;
+
+        users.read().unwrap().get(&USER_ID).unwrap().remove_channel(
+            channel(1),
+            Some(GameMode::Osu),
+            None,
+        );
+
+        let ndjson = score_json(1, Some(150.0), "2025-01-02T00:00:00Z", true);
+
+        // The entry has no channels left so it no longer counts as tracked
+        for score in replay(&ndjson).await {
+            assert!(ScoreActions::new(&users, &score).is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn untrack_milestones_only() {
+        let users = tracked_users(100).await;
+        let params = TrackMilestoneParams::new(Vec::new(), None, None);
+
+        let entry = users
+            .read()
+            .unwrap()
+            .get(&USER_ID)
+            .unwrap()
+            .get(GameMode::Osu);
+        entry.add_milestone(channel(1), params);
+        assert!(!entry.milestone_channels().is_empty());
+
+        users.read().unwrap().get(&USER_ID).unwrap().remove_channel(
+            channel(1),
+            Some(GameMode::Osu),
+            Some(TrackKind::Milestones),
+        );
+
+        assert!(entry.milestone_channels().is_empty());
+
+        // Top score tracking in the same channel is unaffected
+        let ndjson = score_json(1, Some(150.0), "2025-01-02T00:00:00Z", true);
+
+        for score in replay(&ndjson).await {
+            assert!(ScoreActions::new(&users, &score).unwrap().top_score);
+        }
+    }
+
+    #[tokio::test]
+    async fn process_score_leaderboard() {
+        let users = TrackedUsers::default();
+
+        users
+            .write()
+            .unwrap()
+            .entry(USER_ID)
+            .or_default()
+            .get(GameMode::Osu)
+            .add_leaderboard(channel(1), TrackLeaderboardParams::new(None, None));
+
+        let ndjson = [
+            score_json(1, Some(100.0), "2025-01-02T00:00:00Z", true),
+            score_json(2, Some(100.0), "2025-01-02T00:01:00Z", false),
+        ]
+        .join("\n");
+
+        let actions: Vec<_> = replay(&ndjson)
+            .await
+            .iter()
+            .map(|score| ScoreActions::new(&users, score).unwrap())
+            .map(|actions| (actions.leaderboard, actions.top_score))
+            .collect();
+
+        assert_eq!(actions, [(true, false), (false, false)]);
+    }
+
+    #[tokio::test]
+    async fn process_score_notifies_channels() {
+        for require_top in [true, false] {
+            let users = if require_top {
+                tracked_users(100).await
+            } else {
+                // Tracked without the top scores so that any pp value is a
+                // potential top score
+                let users = TrackedUsers::default();
+                let params = TrackEntryParams::new();
+                OsuTracking::insert_user(&users, USER_ID, GameMode::Osu, channel(1), params);
+
+                users
+            };
+
+            // Only notified about top 10 scores
+            let params = TrackEntryParams::new().with_index(None, Some(10));
+            OsuTracking::insert_user(&users, USER_ID, GameMode::Osu, channel(2), params);
+
+            let tops = top_scores(100).await;
+
+            let ndjson = [
+                // Not a top score
+                score_json(100, Some(100.0), "2025-01-02T00:00:00Z", true),
+                // Top score at index 51
+                score_json(101, Some(150.5), "2025-01-02T00:01:00Z", true),
+                // Top score at index 2
+                score_json(102, Some(199.5), "2025-01-02T00:02:00Z", true),
+            ]
+            .join("\n");
+
+            let scores = replay(&ndjson).await;
+
+            // The non-top score is skipped whether it's filtered out by its
+            // pp or because it's not among the fetched top scores
+            let non_top = notified_channels(&users, &scores[0], &tops);
+            assert!(non_top.is_empty(), "require_top={require_top}");
+
+            let tops = tops_with(tops, &scores[1]);
+            let channels = notified_channels(&users, &scores[1], &tops);
+            assert_eq!(channels, [1], "require_top={require_top}");
+
+            let tops = tops_with(tops, &scores[2]);
+            let channels = notified_channels(&users, &scores[2], &tops);
+            assert_eq!(channels, [1, 2], "require_top={require_top}");
+        }
+    }
 }
@@ -1,9 +1,16 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
-use bathbot_psql::model::osu::DbTrackedOsuUserInChannel;
-use rosu_v2::model::GameMode;
+use bathbot_psql::model::osu::{
+    DbTrackedOsuLeaderboardUserInChannel, DbTrackedOsuMilestoneUserInChannel,
+    DbTrackedOsuUserInChannel, DbUserModeSnapshot,
+};
+use bathbot_util::{numbers::WithComma, osu::ModSelection};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{GameMods, GameModsIntermode, RankStatus},
+};
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct TrackEntryParams {
     /// `1..=100`
     index: Range<u8>,
@@ -11,18 +18,27 @@ pub struct TrackEntryParams {
     pp: Range<f32>,
     /// 0.0..=100.0
     combo_percent: Range<f32>,
+    /// `0.0..`
+    stars: Range<f32>,
+    /// `None` if scores may have any mods
+    mods: Option<ModSelection>,
+    map_statuses: TrackMapStatuses,
 }
 
 impl TrackEntryParams {
     pub const DEFAULT_MAX_COMBO_PERCENT: f32 = 100.0;
     pub const DEFAULT_MAX_INDEX: u8 = 100;
     pub const DEFAULT_MAX_PP: f32 = f32::INFINITY;
+    pub const DEFAULT_MAX_STARS: f32 = f32::INFINITY;
     pub const DEFAULT_MIN_COMBO_PERCENT: f32 = 0.0;
     pub const DEFAULT_MIN_INDEX: u8 = 1;
     pub const DEFAULT_MIN_PP: f32 = 0.0;
-    // Compile-time assertion that `TrackEntryParams` is no larger than 20
-    // bytes so that its `Copy` impl is justified.
-    const _SMALL_ENOUGH: [(); 0] = [(); 0 - { 1 - (size_of::<TrackEntryParams>() <= 20) as usize }];
+    pub const DEFAULT_MIN_STARS: f32 = 0.0;
+    const MODS_EXACT: i16 = 1;
+    const MODS_EXCLUDE: i16 = 2;
+    const MODS_EXCLUDE_NOMOD: i16 = 3;
+    // How the mods are matched, as stored in the DB
+    const MODS_INCLUDE: i16 = 0;
 
     pub const fn new() -> Self {
         Self {
@@ -32,6 +48,9 @@ impl TrackEntryParams {
                 Self::DEFAULT_MIN_COMBO_PERCENT,
                 Self::DEFAULT_MAX_COMBO_PERCENT,
             ),
+            stars: Range::new_raw(Self::DEFAULT_MIN_STARS, Self::DEFAULT_MAX_STARS),
+            mods: None,
+            map_statuses: TrackMapStatuses::all(),
         }
     }
 
@@ -61,6 +80,80 @@ impl TrackEntryParams {
         }
     }
 
+    pub fn with_stars(self, min: Option<f32>, max: Option<f32>) -> Self {
+        Self {
+            stars: Range::<f32>::new(min, max, Self::DEFAULT_MIN_STARS, Self::DEFAULT_MAX_STARS),
+            ..self
+        }
+    }
+
+    /// Scores must fit the mod selection.
+    pub fn with_mods(self, mods: Option<ModSelection>) -> Self {
+        Self { mods, ..self }
+    }
+
+    /// Mod selection from the acronyms and match kind as stored in the DB.
+    pub(super) fn mods_from_db(mods: Option<String>, kind: Option<i16>) -> Option<ModSelection> {
+        let (mods, kind) = mods.zip(kind)?;
+
+        let Some(mods) = GameModsIntermode::try_from_acronyms(&mods)
+            .or_else(|| mods.is_empty().then(GameModsIntermode::new))
+        else {
+            warn!(%mods, "Invalid tracked mods in DB");
+
+            return None;
+        };
+
+        let selection = match kind {
+            Self::MODS_INCLUDE => ModSelection::Include(mods),
+            Self::MODS_EXACT => ModSelection::Exact(mods),
+            Self::MODS_EXCLUDE => ModSelection::Exclude { mods, nomod: false },
+            Self::MODS_EXCLUDE_NOMOD => ModSelection::Exclude { mods, nomod: true },
+            _ => {
+                warn!(kind, "Invalid tracked mods kind in DB");
+
+                return None;
+            }
+        };
+
+        Some(selection)
+    }
+
+    /// Acronyms and match kind of the mod selection to be stored in the DB.
+    fn mods_into_db(&self) -> (Option<String>, Option<i16>) {
+        let Some(ref selection) = self.mods else {
+            return (None, None);
+        };
+
+        let (mods, kind) = match selection {
+            ModSelection::Include(mods) => (mods, Self::MODS_INCLUDE),
+            ModSelection::Exact(mods) => (mods, Self::MODS_EXACT),
+            ModSelection::Exclude { mods, nomod: false } => (mods, Self::MODS_EXCLUDE),
+            ModSelection::Exclude { mods, nomod: true } => (mods, Self::MODS_EXCLUDE_NOMOD),
+        };
+
+        let acronyms = mods.iter().map(|gamemod| gamemod.acronym()).fold(
+            String::with_capacity(mods.len() * 2),
+            |mut acronyms, acronym| {
+                acronyms.push_str(acronym.as_str());
+
+                acronyms
+            },
+        );
+
+        (Some(acronyms), Some(kind))
+    }
+
+    pub fn with_map_statuses(self, map_statuses: Option<TrackMapStatuses>) -> Self {
+        Self {
+            map_statuses: match map_statuses {
+                Some(statuses) if !statuses.is_empty() => statuses,
+                _ => TrackMapStatuses::all(),
+            },
+            ..self
+        }
+    }
+
     pub const fn index(&self) -> Range<u8> {
         self.index
     }
@@ -73,21 +166,67 @@ impl TrackEntryParams {
         self.combo_percent
     }
 
-    pub const fn matches(&self, idx: u8, pp: f32, combo_percent: Option<f32>) -> bool {
-        self.index.contains(idx)
-            && self.pp.contains(pp)
-            && match combo_percent {
+    pub fn matches(&self, score: &TrackScoreData) -> bool {
+        self.index.contains(score.idx)
+            && self.pp.contains(score.pp)
+            && match score.combo_percent {
                 // Manual `Option::is_some_and` to preserve const-ness
                 Some(percent) => self.combo_percent.contains(percent),
                 None => false,
             }
+            && match score.stars {
+                Some(stars) => self.stars.contains(stars),
+                None => false,
+            }
+            && self
+                .mods
+                .as_ref()
+                .is_none_or(|mods| mods.filter_mods(&score.mods))
+            && self
+                .map_statuses
+                .intersects(TrackMapStatuses::from_rank_status(score.status))
     }
 
-    pub(super) const fn into_db_entry(
-        self,
-        user_id: u32,
-        mode: GameMode,
-    ) -> DbTrackedOsuUserInChannel {
+    /// Appends all filters that differ from their default to `buf`, each one
+    /// prefixed with `separator`.
+    pub fn write_extra_filters(&self, buf: &mut String, separator: &str) {
+        if self.stars.start > Self::DEFAULT_MIN_STARS || self.stars.end < Self::DEFAULT_MAX_STARS {
+            let _ = write!(buf, "{separator}`Stars: {}`", self.stars);
+        }
+
+        match self.mods {
+            Some(ModSelection::Include(ref mods) | ModSelection::Exact(ref mods))
+                if mods.is_empty() =>
+            {
+                let _ = write!(buf, "{separator}`Mods: NM`");
+            }
+            Some(ModSelection::Include(ref mods)) => {
+                let _ = write!(buf, "{separator}`Mods: +{mods}`");
+            }
+            Some(ModSelection::Exact(ref mods)) => {
+                let _ = write!(buf, "{separator}`Mods: +{mods}!`");
+            }
+            Some(ModSelection::Exclude { ref mods, nomod }) => {
+                let nomod = if nomod { "NM" } else { "" };
+                let mods = if mods.is_empty() {
+                    String::new()
+                } else {
+                    mods.to_string()
+                };
+
+                let _ = write!(buf, "{separator}`Mods: -{nomod}{mods}!`");
+            }
+            None => {}
+        }
+
+        if self.map_statuses != TrackMapStatuses::all() {
+            let _ = write!(buf, "{separator}`Status: {}`", self.map_statuses);
+        }
+    }
+
+    pub(super) fn into_db_entry(&self, user_id: u32, mode: GameMode) -> DbTrackedOsuUserInChannel {
+        let (mods, mods_kind) = self.mods_into_db();
+
         DbTrackedOsuUserInChannel {
             user_id: user_id as i32,
             gamemode: mode as i16,
@@ -97,6 +236,11 @@ impl TrackEntryParams {
             max_pp: Some(self.pp.end),
             min_combo_percent: Some(self.combo_percent.start),
             max_combo_percent: Some(self.combo_percent.end),
+            mods,
+            mods_kind,
+            min_stars: Some(self.stars.start),
+            max_stars: Some(self.stars.end),
+            map_statuses: Some(self.map_statuses.bits() as i16),
         }
     }
 }
@@ -114,6 +258,13 @@ impl From<DbTrackedOsuUserInChannel> for TrackEntryParams {
             .with_index(map_as_u8(entry.min_index), map_as_u8(entry.max_index))
             .with_pp(entry.min_pp, entry.max_pp)
             .with_combo_percent(entry.min_combo_percent, entry.max_combo_percent)
+            .with_stars(entry.min_stars, entry.max_stars)
+            .with_mods(Self::mods_from_db(entry.mods, entry.mods_kind))
+            .with_map_statuses(
+                entry
+                    .map_statuses
+                    .map(|bits| TrackMapStatuses::from_bits_truncate(bits as u8)),
+            )
     }
 }
 
@@ -123,6 +274,334 @@ impl Default for TrackEntryParams {
     }
 }
 
+/// The different kinds of notifications a user can be tracked for.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TrackKind {
+    TopScores,
+    Leaderboard,
+    Milestones,
+}
+
+/// Parameters to notify about scores that reach a global or country rank on
+/// a map leaderboard.
+#[derive(Copy, Clone)]
+pub struct TrackLeaderboardParams {
+    /// `1..=100`
+    global_rank: Option<u8>,
+    /// `1..=100`
+    country_rank: Option<u8>,
+}
+
+impl TrackLeaderboardParams {
+    pub const DEFAULT_GLOBAL_RANK: u8 = 1;
+    pub const MAX_RANK: u8 = 100;
+
+    /// If neither rank is specified, the global rank defaults to
+    /// [`TrackLeaderboardParams::DEFAULT_GLOBAL_RANK`].
+    pub fn new(global_rank: Option<u8>, country_rank: Option<u8>) -> Self {
+        let clamp = |rank: u8| rank.clamp(1, Self::MAX_RANK);
+
+        let global_rank = match (global_rank, country_rank) {
+            (None, None) => Some(Self::DEFAULT_GLOBAL_RANK),
+            (global_rank, _) => global_rank,
+        };
+
+        Self {
+            global_rank: global_rank.map(clamp),
+            country_rank: country_rank.map(clamp),
+        }
+    }
+
+    /// Both ranks are 1-indexed.
+    pub const fn matches(&self, global_rank: usize, country_rank: usize) -> bool {
+        let global_matches = match self.global_rank {
+            Some(max) => global_rank <= max as usize,
+            None => false,
+        };
+
+        let country_matches = match self.country_rank {
+            Some(max) => country_rank <= max as usize,
+            None => false,
+        };
+
+        global_matches || country_matches
+    }
+
+    pub(super) const fn into_db_entry(
+        self,
+        user_id: u32,
+        mode: GameMode,
+    ) -> DbTrackedOsuLeaderboardUserInChannel {
+        const fn map_as_i16(opt: Option<u8>) -> Option<i16> {
+            match opt {
+                Some(rank) => Some(rank as i16),
+                None => None,
+            }
+        }
+
+        DbTrackedOsuLeaderboardUserInChannel {
+            user_id: user_id as i32,
+            gamemode: mode as i16,
+            max_global_rank: map_as_i16(self.global_rank),
+            max_country_rank: map_as_i16(self.country_rank),
+        }
+    }
+
+    pub(super) fn from_db(max_global_rank: Option<i16>, max_country_rank: Option<i16>) -> Self {
+        let clamp = |rank: i16| rank.clamp(1, Self::MAX_RANK as i16) as u8;
+
+        Self {
+            global_rank: max_global_rank.map(clamp),
+            country_rank: max_country_rank.map(clamp),
+        }
+    }
+}
+
+impl From<DbTrackedOsuLeaderboardUserInChannel> for TrackLeaderboardParams {
+    fn from(entry: DbTrackedOsuLeaderboardUserInChannel) -> Self {
+        Self::from_db(entry.max_global_rank, entry.max_country_rank)
+    }
+}
+
+impl Display for TrackLeaderboardParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self.global_rank, self.country_rank) {
+            (Some(global), Some(country)) => {
+                write!(f, "`Global rank: 1-{global}` • `Country rank: 1-{country}`")
+            }
+            (Some(global), None) => write!(f, "`Global rank: 1-{global}`"),
+            (None, Some(country)) => write!(f, "`Country rank: 1-{country}`"),
+            (None, None) => f.write_str("`None`"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TrackMilestoneParams {
+    /// Global ranks that should be notified when reached, sorted ascendingly
+    ranks: Box<[u32]>,
+    /// Notify whenever a multiple of this pp value is reached
+    pp_step: Option<u32>,
+    /// Notify whenever a multiple of this playcount is reached
+    playcount_step: Option<u32>,
+}
+
+impl TrackMilestoneParams {
+    pub const DEFAULT_PLAYCOUNT_STEP: u32 = 10_000;
+    pub const DEFAULT_PP_STEP: u32 = 1000;
+    pub const DEFAULT_RANKS: [u32; 3] = [1000, 5000, 10_000];
+
+    /// If no milestone is specified, all default milestones are used.
+    pub fn new(ranks: Vec<u32>, pp_step: Option<u32>, playcount_step: Option<u32>) -> Self {
+        if ranks.is_empty() && pp_step.is_none() && playcount_step.is_none() {
+            return Self {
+                ranks: Box::new(Self::DEFAULT_RANKS),
+                pp_step: Some(Self::DEFAULT_PP_STEP),
+                playcount_step: Some(Self::DEFAULT_PLAYCOUNT_STEP),
+            };
+        }
+
+        let mut ranks = ranks;
+        ranks.retain(|&rank| rank > 0);
+        ranks.sort_unstable();
+        ranks.dedup();
+
+        Self {
+            ranks: ranks.into_boxed_slice(),
+            pp_step: pp_step.filter(|&step| step > 0),
+            playcount_step: playcount_step.filter(|&step| step > 0),
+        }
+    }
+
+    /// Returns all milestones that were reached between the two snapshots.
+    pub fn reached(
+        &self,
+        prev: &DbUserModeSnapshot,
+        curr: &DbUserModeSnapshot,
+    ) -> Vec<TrackMilestone> {
+        let mut milestones = Vec::new();
+
+        let prev_rank = prev.global_rank as u32;
+        let curr_rank = curr.global_rank as u32;
+
+        // A rank of 0 means the user was inactive
+        if curr_rank > 0 && (prev_rank == 0 || curr_rank < prev_rank) {
+            let rank = self
+                .ranks
+                .iter()
+                .copied()
+                .find(|&rank| curr_rank <= rank && (prev_rank == 0 || rank < prev_rank));
+
+            if let Some(rank) = rank {
+                milestones.push(TrackMilestone::Rank(rank));
+            }
+        }
+
+        if let Some(step) = self.pp_step {
+            let prev_steps = prev.pp.max(0.0) as u32 / step;
+            let curr_steps = curr.pp.max(0.0) as u32 / step;
+
+            if curr_steps > prev_steps {
+                milestones.push(TrackMilestone::Pp(curr_steps * step));
+            }
+        }
+
+        if let Some(step) = self.playcount_step {
+            let prev_steps = prev.playcount.max(0) as u32 / step;
+            let curr_steps = curr.playcount.max(0) as u32 / step;
+
+            if curr_steps > prev_steps {
+                milestones.push(TrackMilestone::Playcount(curr_steps * step));
+            }
+        }
+
+        milestones
+    }
+
+    pub(super) fn into_db_entry(
+        self,
+        user_id: u32,
+        mode: GameMode,
+    ) -> DbTrackedOsuMilestoneUserInChannel {
+        DbTrackedOsuMilestoneUserInChannel {
+            user_id: user_id as i32,
+            gamemode: mode as i16,
+            ranks: self.ranks.iter().map(|&rank| rank as i32).collect(),
+            pp_step: self.pp_step.map(|step| step as i32),
+            playcount_step: self.playcount_step.map(|step| step as i32),
+        }
+    }
+
+    pub(super) fn from_db(
+        ranks: Vec<i32>,
+        pp_step: Option<i32>,
+        playcount_step: Option<i32>,
+    ) -> Self {
+        let mut ranks: Box<[u32]> = ranks.into_iter().map(|rank| rank.max(0) as u32).collect();
+        ranks.sort_unstable();
+
+        Self {
+            ranks,
+            pp_step: pp_step.map(|step| step.max(0) as u32),
+            playcount_step: playcount_step.map(|step| step.max(0) as u32),
+        }
+    }
+}
+
+impl From<DbTrackedOsuMilestoneUserInChannel> for TrackMilestoneParams {
+    fn from(entry: DbTrackedOsuMilestoneUserInChannel) -> Self {
+        Self::from_db(entry.ranks, entry.pp_step, entry.playcount_step)
+    }
+}
+
+impl Display for TrackMilestoneParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.ranks.is_empty() && self.pp_step.is_none() && self.playcount_step.is_none() {
+            return f.write_str("`None`");
+        }
+
+        let mut separator = "";
+
+        if let [first, rest @ ..] = self.ranks.as_ref() {
+            write!(f, "`Ranks: {}", WithComma::new(*first))?;
+
+            for rank in rest {
+                write!(f, "/{}", WithComma::new(*rank))?;
+            }
+
+            f.write_str("`")?;
+            separator = " • ";
+        }
+
+        if let Some(step) = self.pp_step {
+            write!(f, "{separator}`PP: every {}`", WithComma::new(step))?;
+            separator = " • ";
+        }
+
+        if let Some(step) = self.playcount_step {
+            write!(f, "{separator}`Playcount: every {}`", WithComma::new(step))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A milestone that was reached by a user.
+#[derive(Copy, Clone)]
+pub enum TrackMilestone {
+    Rank(u32),
+    Pp(u32),
+    Playcount(u32),
+}
+
+impl Display for TrackMilestone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Rank(rank) => write!(f, "Entered the global top {}", WithComma::new(*rank)),
+            Self::Pp(pp) => write!(f, "Reached {}pp", WithComma::new(*pp)),
+            Self::Playcount(playcount) => write!(f, "Reached {} plays", WithComma::new(*playcount)),
+        }
+    }
+}
+
+/// Data of a new top score that is checked against [`TrackEntryParams`].
+pub struct TrackScoreData {
+    /// `1..=100`
+    pub idx: u8,
+    pub pp: f32,
+    pub combo_percent: Option<f32>,
+    pub stars: Option<f32>,
+    pub mods: GameMods,
+    pub status: RankStatus,
+}
+
+bitflags::bitflags! {
+    /// Map rank statuses for which scores should be notified.
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct TrackMapStatuses: u8 {
+        /// Both ranked and approved maps
+        const RANKED    = 1 << 0;
+        const LOVED     = 1 << 1;
+        const QUALIFIED = 1 << 2;
+        /// Pending, WIP, or graveyard maps
+        const UNRANKED  = 1 << 3;
+    }
+}
+
+impl TrackMapStatuses {
+    pub const fn from_rank_status(status: RankStatus) -> Self {
+        match status {
+            RankStatus::Ranked | RankStatus::Approved => Self::RANKED,
+            RankStatus::Loved => Self::LOVED,
+            RankStatus::Qualified => Self::QUALIFIED,
+            RankStatus::Pending | RankStatus::WIP | RankStatus::Graveyard => Self::UNRANKED,
+        }
+    }
+}
+
+impl Display for TrackMapStatuses {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut iter = self.iter_names().map(|(name, _)| match name {
+            "RANKED" => "Ranked",
+            "LOVED" => "Loved",
+            "QUALIFIED" => "Qualified",
+            _ => "Unranked",
+        });
+
+        let Some(first) = iter.next() else {
+            return f.write_str("None");
+        };
+
+        f.write_str(first)?;
+
+        for name in iter {
+            write!(f, "/{name}")?;
+        }
+
+        Ok(())
+    }
+}
+
 macro_rules! const_helpers {
     ( $ty:ty, $unwrap_or:ident, $clamp:ident ) => {
         impl Range<$ty> {
@@ -162,3 +641,107 @@ impl<T: Display> Display for Range<T> {
         write!(f, "{}-{}", self.start, self.end)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bathbot_util::osu::ModsResult;
+
+    use super::*;
+
+    fn params(mods: &str) -> TrackEntryParams {
+        let ModsResult::Mods(mods) = ModSelection::parse(Some(mods)) else {
+            panic!("invalid mods `{mods}`");
+        };
+
+        TrackEntryParams::new().with_mods(Some(mods))
+    }
+
+    fn score(acronyms: &str) -> TrackScoreData {
+        let mods = GameModsIntermode::try_from_acronyms(acronyms)
+            .or_else(|| acronyms.is_empty().then(GameModsIntermode::new))
+            .and_then(|mods| mods.try_with_mode(GameMode::Osu))
+            .unwrap();
+
+        TrackScoreData {
+            idx: 1,
+            pp: 100.0,
+            combo_percent: Some(100.0),
+            stars: Some(5.0),
+            mods,
+            status: RankStatus::Ranked,
+        }
+    }
+
+    #[test]
+    fn matches_mods() {
+        #[rustfmt::skip]
+        let cases = [
+            // NoMod only
+            ("nm", "", true),
+            ("nm", "HD", false),
+            ("+nm!", "", true),
+            ("+nm!", "CL", false),
+            // Exact
+            ("+hdhr!", "HDHR", true),
+            ("+hdhr!", "HD", false),
+            ("+hdhr!", "HDHRDT", false),
+            ("hdhr", "HDHR", true),
+            ("hdhr", "HR", false),
+            // Include
+            ("+hd", "HD", true),
+            ("+hd", "HDDT", true),
+            ("+hd", "", false),
+            ("+dt", "NC", true),
+            // Exclude
+            ("-ez!", "HD", true),
+            ("-ez!", "", true),
+            ("-ez!", "EZHD", false),
+            ("-nm!", "", false),
+            ("-nm!", "HD", true),
+            ("-nmez!", "EZ", false),
+            // Lazer-only mods
+            ("+da", "DA", true),
+            ("+da", "HD", false),
+            ("+cl!", "CL", true),
+            ("+cl!", "", false),
+            ("-ac!", "ACHD", false),
+            ("-ac!", "HD", true),
+        ];
+
+        for (selection, mods, expected) in cases {
+            assert_eq!(
+                params(selection).matches(&score(mods)),
+                expected,
+                "{selection} with {mods:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_without_mods() {
+        let params = TrackEntryParams::new();
+
+        for mods in ["", "HDHR", "DA", "CL"] {
+            assert!(params.matches(&score(mods)), "{mods:?}");
+        }
+    }
+
+    #[test]
+    fn mods_db_roundtrip() {
+        for selection in [
+            "nm", "+hdhr!", "+hd", "-ezht!", "-nm!", "-nmez!", "+da", "+clac!",
+        ] {
+            let params = params(selection);
+            let entry = params.into_db_entry(2, GameMode::Osu);
+
+            assert_eq!(
+                TrackEntryParams::mods_from_db(entry.mods, entry.mods_kind),
+                params.mods,
+                "{selection}"
+            );
+        }
+
+        let entry = TrackEntryParams::new().into_db_entry(2, GameMode::Osu);
+        assert_eq!((entry.mods.as_deref(), entry.mods_kind), (None, None));
+    }
+}
@@ -1,20 +1,24 @@
-use std::{slice, sync::Arc, time::Duration};
+use std::{num::NonZeroU64, slice, sync::Arc, time::Duration};
 
 use bathbot_model::embed_builder::{
     ComboValue, HitresultsValue, ScoreEmbedSettings, SettingValue, SettingsButtons, SettingsImage,
     Value,
 };
-use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{EmbedBuilder, constants::UNKNOWN_CHANNEL};
+use bathbot_psql::model::{configs::ScoreData, osu::DbTrackedOsuDigestScore};
+use bathbot_util::{
+    EmbedBuilder, ModsFormatter,
+    constants::UNKNOWN_CHANNEL,
+    osu::{ExtractablePp, PpListUtil},
+};
 use rand::Rng;
 use rosu_v2::{model::GameMode, prelude::Score};
 use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType as TwilightErrorType,
 };
-use twilight_model::id::Id;
+use twilight_model::{channel::message::Embed, id::Id};
 
-use super::{OsuTracking, entry::TrackEntry};
+use super::{OsuTracking, digest, entry::TrackEntry, params::TrackScoreData};
 use crate::{
     active::{
         IActiveMessage,
@@ -81,7 +85,7 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
 
     entry.insert_last_pp(user_id, mode, &tops).await;
 
-    let Some(idx) = tops.iter().position(|s| s.id == score_id) else {
+    let Some(idx) = top_index(&tops, score_id) else {
         log!(info:
             user = user_id,
             map = map_id,
@@ -95,39 +99,114 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
 
     BotMetrics::osu_tracking_hit(score.mode);
 
+    let digest_score = digest_score(&user, &score, &map, idx, &tops);
+
     let combo = score.max_combo;
-    let (builder, max_combo) = embed_builder(&user, score, map, idx).await;
-    let idx = idx as u8 + 1;
+    let mods = score.mods.clone();
+    let status = map.status();
+    let (builder, attrs) = embed_builder(&user, score, map, Some(idx), None).await;
     let embed = builder.build();
     let embeds = slice::from_ref(&embed);
-    let combo_percent = max_combo.map(|max| 100.0 * combo as f32 / max as f32);
+
+    let data = TrackScoreData {
+        idx: idx as u8 + 1,
+        pp,
+        combo_percent: attrs.map(|(max_combo, _)| 100.0 * combo as f32 / max_combo as f32),
+        stars: attrs.map(|(_, stars)| stars),
+        mods,
+        status,
+    };
 
     log!(info:
         user = user_id,
         map = map_id,
         score_id,
-        idx,
+        idx = data.idx,
         pp,
-        combo_percent,
+        combo_percent = data.combo_percent,
+        stars = data.stars,
         "New top score",
     );
 
-    let http = Context::http();
+    let channels = matching_channels(&entry, &data);
+
+    let (digest_channels, channels) = OsuTracking::partition_digest_channels(channels);
+
+    if !digest_channels.is_empty() {
+        digest::buffer_score(digest_channels, digest_score).await;
+    }
+
+    notify_channels(channels, None, embeds).await;
+}
+
+/// Returns the 0-indexed position of the score within the user's top scores.
+pub(super) fn top_index(tops: &[Score], score_id: u64) -> Option<usize> {
+    tops.iter().position(|s| s.id == score_id)
+}
 
-    let channels: Vec<_> = entry
+/// Returns all channels whose filters match the top score.
+pub(super) fn matching_channels(entry: &TrackEntry, data: &TrackScoreData) -> Vec<NonZeroU64> {
+    entry
         .channels()
         .iter()
-        .filter_map(|(channel_id, params)| {
-            params
-                .matches(idx, pp, combo_percent)
-                .then_some(*channel_id)
-        })
-        .collect();
+        .filter_map(|(channel_id, params)| params.matches(data).then_some(*channel_id))
+        .collect()
+}
+
+/// `idx` is 0-indexed.
+fn digest_score(
+    user: &CachedUser,
+    score: &Score,
+    map: &OsuMap,
+    idx: usize,
+    tops: &[Score],
+) -> DbTrackedOsuDigestScore {
+    let mut pps = tops.extract_pp();
+    let pp_after = pps.accum_weighted() as f32;
+    pps.remove(idx);
+    let pp_before = pps.accum_weighted() as f32;
+
+    let map_title = format!(
+        "{artist} - {title} [{version}]",
+        artist = map.artist(),
+        title = map.title(),
+        version = map.version(),
+    );
+
+    DbTrackedOsuDigestScore {
+        score_id: score.id as i64,
+        user_id: score.user_id as i32,
+        username: user.username.as_str().to_owned(),
+        gamemode: score.mode as i16,
+        map_id: score.map_id as i32,
+        map_title,
+        mods: ModsFormatter::new(&score.mods, false).to_string(),
+        pp: score.pp.unwrap_or(0.0),
+        top_index: idx as i16 + 1,
+        pp_before,
+        pp_after,
+        ended_at: score.ended_at,
+    }
+}
+
+/// Sends the notification to all channels and removes channels that no longer
+/// exist.
+pub(super) async fn notify_channels(
+    channels: Vec<NonZeroU64>,
+    content: Option<&str>,
+    embeds: &[Embed],
+) {
+    let http = Context::http();
 
     for channel_id in channels {
         let channel = Id::new(channel_id.get());
+        let mut req = http.create_message(channel).embeds(embeds);
 
-        let Err(err) = http.create_message(channel).embeds(embeds).await else {
+        if let Some(content) = content {
+            req = req.content(content);
+        }
+
+        let Err(err) = req.await else {
             continue;
         };
 
@@ -152,16 +231,18 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
 }
 
 /// Random [`Duration`] between 30s and 60s
-fn jitter() -> Duration {
+pub(super) fn jitter() -> Duration {
     rand::thread_rng().gen_range(Duration::from_secs(30)..Duration::from_secs(60))
 }
 
-async fn embed_builder(
+/// `pb_idx` is 0-indexed and `global_idx` is 1-indexed.
+pub(super) async fn embed_builder(
     user: &CachedUser,
     score: Score,
     map: OsuMap,
-    idx: usize,
-) -> (EmbedBuilder, Option<u32>) {
+    pb_idx: Option<usize>,
+    global_idx: Option<usize>,
+) -> (EmbedBuilder, Option<(u32, f32)>) {
     let settings = match score.mode {
         GameMode::Mania => create_mania_settings(),
         _ => create_settings(),
@@ -171,11 +252,13 @@ async fn embed_builder(
     let msg_owner = Id::new(1);
     let content = SingleScoreContent::None;
 
-    let embed_data = ScoreEmbedDataWrap::new_custom(score, map, idx, None).await;
+    let embed_data = ScoreEmbedDataWrap::new_custom(score, map, pb_idx, global_idx).await;
 
     // This is always `Some` considering `ScoreEmbedDataWrap::new_custom`
     // creates *full* data but let's map regardless to be extra sure.
-    let max_combo = embed_data.try_get().map(|data| data.max_combo);
+    let attrs = embed_data
+        .try_get()
+        .map(|data| (data.max_combo, data.stars));
 
     let entries = Box::<[_]>::from([embed_data]);
 
@@ -183,7 +266,7 @@ async fn embed_builder(
         SingleScorePagination::new(user, entries, settings, score_data, msg_owner, content);
 
     match pagination.build_page().await {
-        Ok(data) => (data.into_embed(), max_combo),
+        Ok(data) => (data.into_embed(), attrs),
         // Unreachable because `async_build_page` can only fail while
         // converting to full score data but it already starts off as
         // full.
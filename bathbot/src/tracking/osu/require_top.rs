@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use bathbot_psql::model::osu::DbTrackedOsuUserInChannel;
 use eyre::{Result, WrapErr};
 use rosu_v2::{model::GameMode, prelude::Score};
 
-use super::OsuTracking;
+use super::{OsuTracking, TrackedUsers, entry::TrackEntry};
 use crate::core::Context;
 
 /// If [`OsuTracking::add_user`] was missing the user's 100th score's pp value,
@@ -41,13 +43,7 @@ impl RequireTopScores {
         let user_id = self.user_id();
         let mode = self.mode();
 
-        let entry_opt = OsuTracking::users()
-            .read()
-            .unwrap()
-            .get(&user_id)
-            .map(|user| user.get(mode));
-
-        if let Some(entry) = entry_opt {
+        if let Some(entry) = self.tracked_entry(OsuTracking::users()) {
             entry.insert_last_pp(user_id, mode, top_scores).await;
         }
 
@@ -60,6 +56,25 @@ impl RequireTopScores {
 
         Ok(())
     }
+
+    /// Same as [`RequireTopScores::callback`] but only stores the top scores
+    /// in-memory without touching the DB.
+    #[cfg(test)]
+    pub(super) fn callback_in_memory(mut self, users: &TrackedUsers, top_scores: &[Score]) {
+        if let Some(entry) = self.tracked_entry(users) {
+            entry.store_top_scores(top_scores);
+        }
+
+        self.called_back = true;
+    }
+
+    fn tracked_entry(&self, users: &TrackedUsers) -> Option<Arc<TrackEntry>> {
+        users
+            .read()
+            .unwrap()
+            .get(&self.user_id())
+            .map(|user| user.get(self.mode()))
+    }
 }
 
 impl Drop for RequireTopScores {
@@ -35,8 +35,13 @@ impl OsuTrackingStats {
             for mode in MODES {
                 let user = entry.get(mode);
                 let channels_guard = user.channels();
+                let leaderboard_guard = user.leaderboard_channels();
+                let milestone_guard = user.milestone_channels();
 
-                if channels_guard.is_empty() {
+                if channels_guard.is_empty()
+                    && leaderboard_guard.is_empty()
+                    && milestone_guard.is_empty()
+                {
                     continue;
                 }
 
@@ -44,6 +49,8 @@ impl OsuTrackingStats {
                 modes_count[mode as usize] += 1;
 
                 channels.extend(channels_guard.keys().copied());
+                channels.extend(leaderboard_guard.keys().copied());
+                channels.extend(milestone_guard.keys().copied());
             }
         }
 
@@ -0,0 +1,320 @@
+use std::{sync::Arc, time::Duration};
+
+use bathbot_client::{Client as BathbotClient, ClientError};
+use bathbot_model::{DanserRenderState, DanserReplay, DanserSkin};
+use bathbot_util::constants::DANSER_ISSUE;
+use eyre::{Report, WrapErr};
+use rosu_render::model::{RenderOptions, RenderSkinOption};
+use tokio::{sync::mpsc, time::MissedTickBehavior};
+
+use super::{IRenderBackend, RenderError, RenderEvent, RenderSource, RenderSubscribers};
+use crate::core::Context;
+
+/// Self-hosted danser service.
+///
+/// Renders are commissioned through a multipart `POST {url}/render` which
+/// responds with `{"renderID": u32}`. Their state is then polled through
+/// `GET {url}/render/{render_id}` which responds with one of
+/// - `{"state": "queued"}`
+/// - `{"state": "rendering", "progress": string}`
+/// - `{"state": "done", "videoUrl": string}`
+/// - `{"state": "failed", "error": string}`
+pub struct Danser {
+    url: Box<str>,
+    subscribers: Arc<RenderSubscribers>,
+}
+
+impl Danser {
+    /// Amount of consecutive failed requests after which a render is
+    /// considered as failed.
+    const MAX_POLL_ERRORS: usize = 5;
+    const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn new(url: impl Into<Box<str>>) -> Self {
+        Self {
+            url: url.into(),
+            subscribers: Arc::new(RenderSubscribers::new()),
+        }
+    }
+}
+
+impl IRenderBackend for Danser {
+    fn name(&self) -> &'static str {
+        "danser"
+    }
+
+    fn renders_url(&self) -> Option<&str> {
+        None
+    }
+
+    fn issue(&self) -> &'static str {
+        DANSER_ISSUE
+    }
+
+    async fn commission<'a>(
+        &'a self,
+        replay: RenderSource<'a>,
+        skin: &'a RenderSkinOption<'a>,
+        options: &'a RenderOptions,
+    ) -> Result<u32, RenderError> {
+        commission_render(Context::client(), &self.url, replay, skin, options).await
+    }
+
+    async fn subscribe_render_id(&self, render_id: u32) -> mpsc::Receiver<RenderEvent> {
+        let rx = self.subscribers.subscribe(render_id).await;
+        let url = self.url.clone();
+        let subscribers = Arc::clone(&self.subscribers);
+
+        tokio::spawn(poll_render(
+            Context::client(),
+            url,
+            subscribers,
+            render_id,
+            Self::POLL_INTERVAL,
+        ));
+
+        rx
+    }
+
+    async fn unsubscribe_render_id(&self, render_id: u32) {
+        self.subscribers.unsubscribe(render_id).await;
+    }
+
+    fn disconnect(&self) {
+        // Nothing to disconnect from; pollers stop as soon as their render is
+        // no longer subscribed to.
+    }
+}
+
+async fn commission_render(
+    client: &BathbotClient,
+    url: &str,
+    replay: RenderSource<'_>,
+    skin: &RenderSkinOption<'_>,
+    options: &RenderOptions,
+) -> Result<u32, RenderError> {
+    let replay = match replay {
+        RenderSource::File(bytes) => DanserReplay::File(bytes),
+        RenderSource::Url(url) => DanserReplay::Url(url),
+    };
+
+    let skin = match skin {
+        RenderSkinOption::Official { name } => DanserSkin::Official(name.as_ref()),
+        RenderSkinOption::Custom { id } => DanserSkin::Custom(*id),
+    };
+
+    let options = serde_json::to_vec(options)
+        .wrap_err("Failed to serialize render options")
+        .map_err(RenderError::Report)?;
+
+    let render_fut = client.commission_danser_render(url, replay, skin, &options);
+
+    match render_fut.await {
+        Ok(render) => Ok(render.render_id),
+        Err(ClientError::BadRequest) => Err(RenderError::Rejected(
+            "The renderer refused the replay".to_owned(),
+        )),
+        Err(err) => Err(RenderError::Report(
+            Report::new(err).wrap_err("Failed to commission danser render"),
+        )),
+    }
+}
+
+async fn poll_render(
+    client: &'static BathbotClient,
+    url: Box<str>,
+    subscribers: Arc<RenderSubscribers>,
+    render_id: u32,
+    poll_interval: Duration,
+) {
+    let mut interval = tokio::time::interval(poll_interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut errors = 0;
+
+    loop {
+        interval.tick().await;
+
+        if !subscribers.is_subscribed(render_id).await {
+            return;
+        }
+
+        let state = match client.get_danser_render_state(&url, render_id).await {
+            Ok(state) => {
+                errors = 0;
+
+                state
+            }
+            Err(err) => {
+                warn!(render_id, err = ?Report::new(err), "Failed to poll danser render");
+                errors += 1;
+
+                if errors >= Danser::MAX_POLL_ERRORS {
+                    let event = RenderEvent::Failed {
+                        error: "Lost connection to the renderer".into(),
+                    };
+
+                    return subscribers.send(render_id, event).await;
+                }
+
+                continue;
+            }
+        };
+
+        let event = match state {
+            DanserRenderState::Queued => RenderEvent::Progress("Waiting in queue".into()),
+            DanserRenderState::Rendering { progress } => RenderEvent::Progress(progress),
+            DanserRenderState::Done { video_url } => {
+                return subscribers
+                    .send(render_id, RenderEvent::Done { video_url })
+                    .await;
+            }
+            DanserRenderState::Failed { error } => {
+                warn!(render_id, %error, "Received error from danser");
+
+                return subscribers
+                    .send(render_id, RenderEvent::Failed { error })
+                    .await;
+            }
+        };
+
+        subscribers.send(render_id, event).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::{
+        Json, Router,
+        extract::{Path, State},
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    const DONE_ID: u32 = 1;
+    const FAILED_ID: u32 = 2;
+
+    /// Amount of state requests per render id so far
+    type Polls = Arc<[AtomicUsize; 3]>;
+
+    async fn commission() -> Json<Value> {
+        Json(json!({ "renderID": DONE_ID }))
+    }
+
+    /// Renders go through the queue and rendering states before they finish.
+    async fn render_state(State(polls): State<Polls>, Path(render_id): Path<u32>) -> Json<Value> {
+        let poll = polls[render_id as usize].fetch_add(1, Ordering::SeqCst);
+
+        let state = match (render_id, poll) {
+            (_, 0) => json!({ "state": "queued" }),
+            (_, 1) => json!({ "state": "rendering", "progress": "Rendering 50%" }),
+            (DONE_ID, _) => json!({ "state": "done", "videoUrl": "https://danser/video.mp4" }),
+            _ => json!({ "state": "failed", "error": "Beatmap not found" }),
+        };
+
+        Json(state)
+    }
+
+    /// Serves the stub danser service on an unused port and returns its url.
+    async fn stub_server() -> String {
+        let app = Router::new()
+            .route("/render", post(commission))
+            .route("/render/{render_id}", get(render_state))
+            .with_state(Polls::default());
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        format!("http://{addr}")
+    }
+
+    fn client() -> &'static BathbotClient {
+        let client = BathbotClient::without_twitch("").unwrap();
+
+        Box::leak(Box::new(client))
+    }
+
+    async fn events(client: &'static BathbotClient, url: &str, render_id: u32) -> Vec<RenderEvent> {
+        let subscribers = Arc::new(RenderSubscribers::new());
+        let mut rx = subscribers.subscribe(render_id).await;
+
+        tokio::spawn(poll_render(
+            client,
+            url.into(),
+            Arc::clone(&subscribers),
+            render_id,
+            Duration::from_millis(10),
+        ));
+
+        let mut events = Vec::new();
+
+        while let Some(event) = rx.recv().await {
+            let last = !matches!(event, RenderEvent::Progress(_));
+            events.push(event);
+
+            if last {
+                break;
+            }
+        }
+
+        subscribers.unsubscribe(render_id).await;
+
+        events
+    }
+
+    #[track_caller]
+    fn assert_progress(events: &[RenderEvent]) {
+        assert!(
+            matches!(&events[0], RenderEvent::Progress(progress) if &**progress == "Waiting in queue")
+        );
+        assert!(
+            matches!(&events[1], RenderEvent::Progress(progress) if &**progress == "Rendering 50%")
+        );
+    }
+
+    #[tokio::test]
+    async fn commission_until_done() {
+        let url = stub_server().await;
+        let client = client();
+
+        let skin = RenderSkinOption::Official {
+            name: "default".into(),
+        };
+
+        let replay = RenderSource::Url("https://replays/replay.osr");
+        let options = RenderOptions::default();
+
+        let render_id = match commission_render(client, &url, replay, &skin, &options).await {
+            Ok(render_id) => render_id,
+            Err(RenderError::Rejected(reason)) => panic!("rejected: {reason}"),
+            Err(RenderError::Report(err)) => panic!("{err:?}"),
+        };
+
+        assert_eq!(render_id, DONE_ID);
+
+        let events = events(client, &url, render_id).await;
+        assert_eq!(events.len(), 3);
+        assert_progress(&events);
+        assert!(
+            matches!(&events[2], RenderEvent::Done { video_url } if &**video_url == "https://danser/video.mp4")
+        );
+    }
+
+    #[tokio::test]
+    async fn poll_until_failed() {
+        let url = stub_server().await;
+        let client = client();
+
+        let events = events(client, &url, FAILED_ID).await;
+        assert_eq!(events.len(), 3);
+        assert_progress(&events);
+        assert!(
+            matches!(&events[2], RenderEvent::Failed { error } if &**error == "Beatmap not found")
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::borrow::Borrow;
+
+use bathbot_util::IntHasher;
+use enum_dispatch::enum_dispatch;
+use eyre::Report;
+use flexmap::tokio::TokioRwLockMap;
+use rosu_render::model::{RenderOptions, RenderSkinOption};
+use tokio::sync::mpsc;
+
+pub use self::danser::Danser;
+use super::Ordr;
+
+mod danser;
+
+/// Service that turns replays into videos
+#[enum_dispatch(IRenderBackend)]
+pub enum RenderBackend {
+    Ordr,
+    Danser,
+}
+
+#[enum_dispatch]
+pub trait IRenderBackend {
+    /// Name of the backend as shown to users.
+    fn name(&self) -> &'static str;
+
+    /// Url to an overview of the backend's renders.
+    fn renders_url(&self) -> Option<&str>;
+
+    /// Message that is shown to users on an unexpected error.
+    fn issue(&self) -> &'static str;
+
+    /// Commission a render and return its render id.
+    async fn commission<'a>(
+        &'a self,
+        replay: RenderSource<'a>,
+        skin: &'a RenderSkinOption<'a>,
+        options: &'a RenderOptions,
+    ) -> Result<u32, RenderError>;
+
+    /// Receive [`RenderEvent`]s for the given render id.
+    async fn subscribe_render_id(&self, render_id: u32) -> mpsc::Receiver<RenderEvent>;
+
+    async fn unsubscribe_render_id(&self, render_id: u32);
+
+    fn disconnect(&self);
+}
+
+pub enum RenderSource<'a> {
+    File(&'a [u8]),
+    Url(&'a str),
+}
+
+pub enum RenderError {
+    /// The backend refused the render; the message can be shown to users.
+    Rejected(String),
+    Report(Report),
+}
+
+pub enum RenderEvent {
+    Progress(Box<str>),
+    Done { video_url: Box<str> },
+    Failed { error: Box<str> },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct RenderId(u32);
+
+impl Borrow<u32> for RenderId {
+    fn borrow(&self) -> &u32 {
+        &self.0
+    }
+}
+
+/// Render ids that are currently awaited, mapped to the sender of their
+/// events.
+pub struct RenderSubscribers {
+    inner: TokioRwLockMap<RenderId, mpsc::Sender<RenderEvent>, IntHasher>,
+}
+
+impl RenderSubscribers {
+    pub fn new() -> Self {
+        Self {
+            inner: TokioRwLockMap::with_shard_amount_and_hasher(8, IntHasher),
+        }
+    }
+
+    pub async fn subscribe(&self, render_id: u32) -> mpsc::Receiver<RenderEvent> {
+        let (tx, rx) = mpsc::channel(4);
+        self.inner.own(RenderId(render_id)).await.insert(tx);
+
+        rx
+    }
+
+    pub async fn unsubscribe(&self, render_id: u32) {
+        self.inner.own(RenderId(render_id)).await.remove();
+    }
+
+    pub async fn is_subscribed(&self, render_id: u32) -> bool {
+        self.inner.read(&render_id).await.get().is_some()
+    }
+
+    /// Forward the event to the subscriber of the render id, if any.
+    pub async fn send(&self, render_id: u32, event: RenderEvent) {
+        let guard = self.inner.read(&render_id).await;
+
+        if let Some(tx) = guard.get() {
+            let _ = tx.send(event).await;
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    collections::HashSet,
+    mem,
+    sync::{Mutex, RwLock},
+};
+
+use bathbot_psql::Database;
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::Score;
+use tokio::time::{Duration, interval};
+
+use crate::core::Context;
+
+/// Stores scores of linked users so that they can be looked up later on
+/// without requesting the osu!api.
+pub struct ScoreArchive {
+    linked: RwLock<HashSet<u32, IntHasher>>,
+    pending: Mutex<Vec<Score>>,
+}
+
+impl ScoreArchive {
+    /// Interval in which pending scores are written to the database.
+    const FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+    /// How many scores may be kept pending while the database is unreachable.
+    const MAX_PENDING: usize = 50_000;
+
+    // `Context` won't be initialized at this point so we require an explicit
+    // `Database` argument.
+    pub async fn new(psql: &Database) -> Result<Self> {
+        let linked = psql
+            .select_linked_osu_user_ids()
+            .await
+            .wrap_err("Failed to fetch linked users")?
+            .into_iter()
+            .collect();
+
+        Ok(Self {
+            linked: RwLock::new(linked),
+            pending: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Start archiving scores of the given user.
+    pub fn add_user(user_id: u32) {
+        Context::score_archive()
+            .linked
+            .write()
+            .unwrap()
+            .insert(user_id);
+    }
+
+    /// Stop archiving scores of the given user and discard their pending
+    /// scores.
+    pub fn remove_user(user_id: u32) {
+        let archive = Context::score_archive();
+        archive.linked.write().unwrap().remove(&user_id);
+
+        archive
+            .pending
+            .lock()
+            .unwrap()
+            .retain(|score| score.user_id != user_id);
+    }
+
+    pub(super) fn process_score(score: &Score) {
+        let archive = Context::score_archive();
+
+        if !archive.linked.read().unwrap().contains(&score.user_id) {
+            return;
+        }
+
+        archive.pending.lock().unwrap().push(score.clone());
+    }
+
+    /// Write all pending scores to the database.
+    ///
+    /// If that fails, the scores are kept pending for the next flush. Once
+    /// more than [`Self::MAX_PENDING`] scores pile up, the oldest ones are
+    /// dropped.
+    pub async fn flush() {
+        let archive = Context::score_archive();
+        let scores = mem::take(&mut *archive.pending.lock().unwrap());
+
+        if scores.is_empty() {
+            return;
+        }
+
+        if let Err(err) = Context::psql().insert_archived_scores(&scores).await {
+            warn!(?err, len = scores.len(), "Failed to archive scores");
+
+            // Scores that came in meanwhile go after the requeued ones
+            let mut pending = archive.pending.lock().unwrap();
+            pending.splice(0..0, scores);
+
+            let excess = pending.len().saturating_sub(Self::MAX_PENDING);
+
+            if excess > 0 {
+                pending.drain(..excess);
+                warn!(excess, "Too many pending scores, dropped the oldest ones");
+            }
+        }
+    }
+}
+
+pub async fn score_archive_loop() {
+    let mut interval = interval(ScoreArchive::FLUSH_INTERVAL);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+        ScoreArchive::flush().await;
+    }
+}
@@ -0,0 +1,159 @@
+use std::{path::PathBuf, time::Duration};
+
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::Score;
+use time::OffsetDateTime;
+use tokio::{
+    fs::File,
+    io::{self, AsyncBufRead, AsyncBufReadExt, BufReader},
+};
+
+/// Alternative to [`ScoresWebSocket`] that replays newline-delimited [`Score`]
+/// JSON from a file or stdin.
+///
+/// [`ScoresWebSocket`]: super::ScoresWebSocket
+pub struct ScoresFile;
+
+impl ScoresFile {
+    /// A path of `-` reads from stdin.
+    pub fn spawn(path: PathBuf, speed: f32) {
+        tokio::spawn(async move {
+            let res = if path.as_os_str() == "-" {
+                info!(speed, "Replaying scores from stdin...");
+                let reader = BufReader::new(io::stdin());
+
+                Self::replay(reader, speed, super::process_score).await
+            } else {
+                info!(path = %path.display(), speed, "Replaying scores from file...");
+
+                match File::open(&path).await {
+                    Ok(file) => {
+                        let reader = BufReader::new(file);
+
+                        Self::replay(reader, speed, super::process_score).await
+                    }
+                    Err(err) => Err(err).wrap_err("Failed to open scores file"),
+                }
+            };
+
+            match res {
+                Ok(count) => info!(count, "Finished replaying scores"),
+                Err(err) => error!(?err, "Failed to replay scores"),
+            }
+        });
+    }
+
+    /// Reads one [`Score`] per line and passes it to `process`.
+    ///
+    /// The delay between two scores is the difference of their `ended_at`
+    /// divided by `speed`. A `speed` of `0.0` or less means no delay.
+    ///
+    /// Returns the amount of processed scores.
+    pub async fn replay<R, F>(reader: R, speed: f32, mut process: F) -> Result<usize>
+    where
+        R: AsyncBufRead + Unpin,
+        F: FnMut(Score),
+    {
+        let mut lines = reader.lines();
+        let mut prev_ended_at: Option<OffsetDateTime> = None;
+        let mut count = 0;
+
+        while let Some(line) = lines.next_line().await.wrap_err("Failed to read line")? {
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let score: Score = match serde_json::from_str(line) {
+                Ok(score) => score,
+                Err(err) => {
+                    warn!(?err, line, "Failed to deserialize score");
+
+                    continue;
+                }
+            };
+
+            if speed > 0.0 {
+                if let Some(prev) = prev_ended_at {
+                    let secs = (score.ended_at - prev).as_seconds_f32() / speed;
+
+                    if secs > 0.0 {
+                        tokio::time::sleep(Duration::from_secs_f32(secs)).await;
+                    }
+                }
+
+                prev_ended_at = Some(score.ended_at);
+            }
+
+            count += 1;
+            process(score);
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    pub fn score_json(
+        id: u64,
+        user_id: u32,
+        pp: Option<f32>,
+        ended_at: &str,
+        passed: bool,
+    ) -> String {
+        let pp = pp.map_or_else(|| "null".to_owned(), |pp| pp.to_string());
+
+        format!(
+            concat!(
+                r#"{{"classic_total_score":0,"preserve":true,"processed":true,"ranked":true,"#,
+                r#""maximum_statistics":{{"great":100}},"mods":[],"statistics":{{"great":100}},"#,
+                r#""total_score_without_mods":1000000,"beatmap_id":1,"best_id":null,"id":{id},"#,
+                r#""rank":"S","type":"solo_score","user_id":{user_id},"accuracy":1.0,"#,
+                r#""build_id":null,"ended_at":"{ended_at}","has_replay":false,"#,
+                r#""is_perfect_combo":true,"legacy_perfect":null,"legacy_score_id":null,"#,
+                r#""legacy_total_score":0,"max_combo":100,"passed":{passed},"pp":{pp},"#,
+                r#""ruleset_id":0,"started_at":null,"total_score":1000000,"replay":false,"#,
+                r#""current_user_attributes":{{"pin":null}}}}"#,
+            ),
+            id = id,
+            user_id = user_id,
+            ended_at = ended_at,
+            passed = passed,
+            pp = pp,
+        )
+    }
+
+    pub async fn replay(ndjson: &str) -> Vec<Score> {
+        let mut scores = Vec::new();
+
+        ScoresFile::replay(ndjson.as_bytes(), 0.0, |score| scores.push(score))
+            .await
+            .unwrap();
+
+        scores
+    }
+
+    #[tokio::test]
+    async fn replay_skips_invalid_lines() {
+        let ndjson = format!(
+            "{}\n\nnot a score\n{}\n",
+            score_json(1, 2, Some(100.0), "2025-01-01T00:00:00Z", true),
+            score_json(2, 2, Some(200.0), "2025-01-01T00:01:00Z", true),
+        );
+
+        let mut scores = Vec::new();
+
+        let count = ScoresFile::replay(ndjson.as_bytes(), 0.0, |score| scores.push(score))
+            .await
+            .unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].id, 1);
+        assert_eq!(scores[1].id, 2);
+    }
+}
@@ -6,7 +6,7 @@ use rosu_v2::prelude::Score;
 use tokio::{net::TcpStream, sync::oneshot};
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, tungstenite::Message};
 
-use crate::{core::BotConfig, tracking::OsuTracking};
+use crate::core::BotConfig;
 
 type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
@@ -122,7 +122,7 @@ impl ScoresWebSocket {
                 Self::store_resume_id(score.id);
             }
 
-            OsuTracking::process_score(score);
+            super::process_score(score);
         }
     }
 